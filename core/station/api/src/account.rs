@@ -14,6 +14,7 @@ pub struct AccountCallerPrivilegesDTO {
 pub struct AccountDTO {
     pub id: UuidDTO,
     pub name: String,
+    pub asset_id: UuidDTO,
     pub address: String,
     pub blockchain: String,
     pub standard: String,
@@ -45,8 +46,7 @@ pub struct EditAccountOperationDTO {
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct AddAccountOperationInput {
     pub name: String,
-    pub blockchain: String,
-    pub standard: String,
+    pub asset_id: UuidDTO,
     pub metadata: Vec<MetadataDTO>,
     pub read_permission: AllowDTO,
     pub configs_permission: AllowDTO,
@@ -88,3 +88,16 @@ pub struct ListAddressBookEntriesResponseDTO {
     pub total: u64,
     pub privileges: Vec<AddressBookEntryCallerPrivilegesDTO>,
 }
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ValidateAddressInput {
+    pub blockchain: String,
+    pub standard: String,
+    pub address: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ValidateAddressResponse {
+    pub valid: bool,
+    pub reason: Option<String>,
+}
@@ -0,0 +1,71 @@
+use super::{AccountDTO, TimestampRfc3339};
+use crate::{PaginationInput, UuidDTO};
+use candid::{CandidType, Deserialize, Principal};
+
+/// The ICRC-1 account that is allowed to spend tokens on behalf of a station account.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AllowanceSpenderDTO {
+    pub owner: Principal,
+    pub subaccount: Option<Vec<u8>>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum AllowanceStatusDTO {
+    Active,
+    Revoked,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AllowanceDTO {
+    pub id: UuidDTO,
+    pub account_id: UuidDTO,
+    pub request_id: UuidDTO,
+    pub spender: AllowanceSpenderDTO,
+    pub amount: candid::Nat,
+    pub expires_at: Option<TimestampRfc3339>,
+    pub status: AllowanceStatusDTO,
+    pub created_at: TimestampRfc3339,
+    pub last_modification_timestamp: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ApproveAllowanceOperationInput {
+    pub from_account_id: UuidDTO,
+    pub spender: AllowanceSpenderDTO,
+    pub amount: candid::Nat,
+    pub expected_allowance: Option<candid::Nat>,
+    pub expires_at: Option<TimestampRfc3339>,
+    pub fee: Option<candid::Nat>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ApproveAllowanceOperationDTO {
+    pub from_account: Option<AccountDTO>,
+    pub allowance: Option<AllowanceDTO>,
+    pub input: ApproveAllowanceOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RevokeAllowanceOperationInput {
+    pub from_account_id: UuidDTO,
+    pub allowance_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RevokeAllowanceOperationDTO {
+    pub input: RevokeAllowanceOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListAccountAllowancesInput {
+    pub account_id: UuidDTO,
+    pub status: Option<AllowanceStatusDTO>,
+    pub paginate: Option<PaginationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListAccountAllowancesResponse {
+    pub allowances: Vec<AllowanceDTO>,
+    pub next_offset: Option<u64>,
+    pub total: u64,
+}
@@ -0,0 +1,27 @@
+use super::TimestampRfc3339;
+use crate::{RequestSpecifierDTO, UuidDTO};
+use candid::{CandidType, Deserialize};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ApprovalDelegationDTO {
+    pub id: UuidDTO,
+    pub delegator_id: UuidDTO,
+    pub delegate_id: UuidDTO,
+    pub specifier: Option<RequestSpecifierDTO>,
+    pub expires_at: TimestampRfc3339,
+    pub created_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddApprovalDelegationOperationInput {
+    pub delegator_id: UuidDTO,
+    pub delegate_id: UuidDTO,
+    pub specifier: Option<RequestSpecifierDTO>,
+    pub expires_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddApprovalDelegationOperationDTO {
+    pub delegation: Option<ApprovalDelegationDTO>,
+    pub input: AddApprovalDelegationOperationInput,
+}
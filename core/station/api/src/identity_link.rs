@@ -0,0 +1,34 @@
+use super::TimestampRfc3339;
+use crate::{RequestDTO, UuidDTO};
+use candid::{CandidType, Deserialize, Principal};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct IdentityLinkDTO {
+    pub id: UuidDTO,
+    pub user_id: UuidDTO,
+    pub new_identity: Principal,
+    pub retire_current_identity: bool,
+    pub expires_at: TimestampRfc3339,
+    pub created_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct StartIdentityLinkInput {
+    pub new_identity: Principal,
+    pub retire_current_identity: bool,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct StartIdentityLinkResponse {
+    pub link: IdentityLinkDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ConfirmIdentityLinkInput {
+    pub link_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ConfirmIdentityLinkResponse {
+    pub request: RequestDTO,
+}
@@ -7,6 +7,15 @@ pub use capabilities::*;
 mod address_book;
 pub use address_book::*;
 
+mod allowance;
+pub use allowance::*;
+
+mod approval_delegation;
+pub use approval_delegation::*;
+
+mod asset;
+pub use asset::*;
+
 mod common;
 pub use common::*;
 
@@ -28,12 +37,27 @@ pub use transfer::*;
 mod request;
 pub use request::*;
 
+mod request_comment;
+pub use request_comment::*;
+
+mod request_template;
+pub use request_template::*;
+
 mod user;
 pub use user::*;
 
+mod identity_link;
+pub use identity_link::*;
+
+mod recovery;
+pub use recovery::*;
+
 mod user_group;
 pub use user_group::*;
 
+mod role_template;
+pub use role_template::*;
+
 mod change_canister;
 pub use change_canister::*;
 
@@ -43,6 +67,9 @@ pub use external_canister::*;
 mod request_policy;
 pub use request_policy::*;
 
+mod standing_order;
+pub use standing_order::*;
+
 mod permission;
 pub use permission::*;
 
@@ -7,6 +7,9 @@ pub const SYSTEM_MESSAGE_NOTIFICATION_TYPE: &str = "system-message";
 pub const REQUEST_CREATED_NOTIFICATION_TYPE: &str = "request-created";
 pub const REQUEST_FAILED_NOTIFICATION_TYPE: &str = "request-failed";
 pub const REQUEST_REJECTED_NOTIFICATION_TYPE: &str = "request-rejected";
+pub const REQUEST_TIME_LOCKED_NOTIFICATION_TYPE: &str = "request-time-locked";
+pub const REQUEST_CANCELLED_NOTIFICATION_TYPE: &str = "request-cancelled";
+pub const REQUEST_COMMENT_MENTION_NOTIFICATION_TYPE: &str = "request-comment-mention";
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum NotificationStatusDTO {
@@ -20,6 +23,9 @@ pub enum NotificationTypeDTO {
     RequestCreated(RequestCreatedNotificationDTO),
     RequestFailed(RequestFailedNotificationDTO),
     RequestRejected(RequestRejectedNotificationDTO),
+    RequestTimeLocked(RequestTimeLockedNotificationDTO),
+    RequestCancelled(RequestCancelledNotificationDTO),
+    RequestCommentMention(RequestCommentMentionNotificationDTO),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -44,6 +50,28 @@ pub struct RequestRejectedNotificationDTO {
     pub reasons: Option<Vec<EvaluationSummaryReasonDTO>>,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestTimeLockedNotificationDTO {
+    pub request_id: UuidDTO,
+    pub operation_type: RequestOperationTypeDTO,
+    pub unlocks_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestCancelledNotificationDTO {
+    pub request_id: UuidDTO,
+    pub operation_type: RequestOperationTypeDTO,
+    pub reason: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestCommentMentionNotificationDTO {
+    pub request_id: UuidDTO,
+    pub operation_type: RequestOperationTypeDTO,
+    pub comment_id: UuidDTO,
+    pub author_id: UuidDTO,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum NotificationTypeInput {
     SystemMessage,
@@ -66,3 +66,49 @@ pub struct EditPermissionOperationInput {
     pub users: Option<Vec<UuidDTO>>,
     pub user_groups: Option<Vec<UuidDTO>>,
 }
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum AccessReasonDTO {
+    Public,
+    Authenticated,
+    DefaultAccess,
+    UserGrant,
+    GroupGrant(Vec<UuidDTO>),
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum AccessDenialReasonDTO {
+    NotAUser,
+    UserNotActive,
+    NoMatchingGrant,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum AccessCheckOutcomeDTO {
+    Allowed(AccessReasonDTO),
+    Denied(AccessDenialReasonDTO),
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ResourceAccessCheckDTO {
+    pub resource: ResourceDTO,
+    pub outcome: AccessCheckOutcomeDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccessExplanationDTO {
+    pub resource: ResourceDTO,
+    pub allowed: bool,
+    pub checks: Vec<ResourceAccessCheckDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ExplainAccessInput {
+    pub user_id: UuidDTO,
+    pub resource: Option<ResourceDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ExplainAccessResponse {
+    pub explanations: Vec<AccessExplanationDTO>,
+}
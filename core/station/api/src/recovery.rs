@@ -0,0 +1,51 @@
+use super::TimestampRfc3339;
+use candid::{CandidType, Deserialize, Principal};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RecoveryConfigDTO {
+    pub guardians: Vec<Principal>,
+    pub threshold: u16,
+    pub delay_seconds: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum ChangeRecoveryConfigDTO {
+    Set(RecoveryConfigDTO),
+    Remove,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecoveryAdminDTO {
+    pub name: String,
+    pub identity: Principal,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum RecoveryActionDTO {
+    InstallAdminIdentity(RecoveryAdminDTO),
+    ReenableAdminGroup,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RecoveryAttemptDTO {
+    pub action: RecoveryActionDTO,
+    pub approvals: Vec<Principal>,
+    pub started_at: TimestampRfc3339,
+    pub executable_at: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SubmitRecoveryInput {
+    pub action: RecoveryActionDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SubmitRecoveryResponse {
+    pub recovery: RecoveryAttemptDTO,
+    pub executed: bool,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CancelRecoveryResponse {
+    pub recovery: RecoveryAttemptDTO,
+}
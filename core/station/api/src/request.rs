@@ -1,20 +1,30 @@
 use super::{
-    EditAccountOperationInput, TimestampRfc3339, TransferOperationDTO, TransferOperationInput,
+    BatchTransferOperationDTO, BatchTransferOperationInput, EditAccountOperationInput,
+    TimestampRfc3339, TransferOperationDTO, TransferOperationInput,
 };
 use crate::{
     AddAccountOperationDTO, AddAccountOperationInput, AddAddressBookEntryOperationDTO,
-    AddAddressBookEntryOperationInput, AddUserGroupOperationDTO, AddUserGroupOperationInput,
-    AddUserOperationDTO, AddUserOperationInput, CallExternalCanisterOperationDTO,
-    CallExternalCanisterOperationInput, ChangeCanisterOperationDTO, ChangeCanisterOperationInput,
+    AddAddressBookEntryOperationInput, AddApprovalDelegationOperationDTO,
+    AddApprovalDelegationOperationInput, AddAssetOperationDTO, AddAssetOperationInput,
+    AddUserGroupOperationDTO, AddUserGroupOperationInput, AddUserOperationDTO,
+    AddUserOperationInput, ApplyRoleTemplateOperationDTO, ApplyRoleTemplateOperationInput,
+    ApproveAllowanceOperationDTO, ApproveAllowanceOperationInput, CallExternalCanisterOperationDTO,
+    CallExternalCanisterOperationInput, CancelStandingOrderOperationDTO,
+    CancelStandingOrderOperationInput, ChangeCanisterOperationDTO, ChangeCanisterOperationInput,
     ChangeExternalCanisterOperationDTO, ChangeExternalCanisterOperationInput,
     CreateExternalCanisterOperationDTO, CreateExternalCanisterOperationInput, DisplayUserDTO,
-    EditAccountOperationDTO, EditAddressBookEntryOperationDTO, EditAddressBookEntryOperationInput,
+    DraftRequestPolicyDTO, EditAccountOperationDTO, EditAddressBookEntryOperationDTO,
+    EditAddressBookEntryOperationInput, EditAssetOperationDTO, EditAssetOperationInput,
     EditPermissionOperationDTO, EditPermissionOperationInput, EditUserGroupOperationDTO,
-    EditUserGroupOperationInput, EditUserOperationDTO, EditUserOperationInput,
-    ManageSystemInfoOperationDTO, ManageSystemInfoOperationInput, PaginationInput,
+    EditUserGroupOperationInput, EditUserOperationDTO, EditUserOperationInput, EvaluationStatusDTO,
+    EvaluationSummaryReasonDTO, LinkUserIdentityOperationDTO, ManageSystemInfoOperationDTO,
+    ManageSystemInfoOperationInput, PaginationInput, RecurringExecutionScheduleDTO,
     RemoveAddressBookEntryOperationDTO, RemoveAddressBookEntryOperationInput,
-    RemoveUserGroupOperationDTO, RemoveUserGroupOperationInput, RequestEvaluationResultDTO,
-    RequestPolicyRuleDTO, RequestSpecifierDTO, SortDirection, UuidDTO,
+    RemoveAssetOperationDTO, RemoveAssetOperationInput, RemoveUserGroupOperationDTO,
+    RemoveUserGroupOperationInput, RemoveUserOperationDTO, RemoveUserOperationInput,
+    RequestEvaluationResultDTO, RequestPolicyRuleDTO, RequestSpecifierDTO,
+    RevokeAllowanceOperationDTO, RevokeAllowanceOperationInput, SimulatedRequestPolicyResultDTO,
+    SortDirection, UuidDTO,
 };
 use candid::{CandidType, Deserialize, Principal};
 
@@ -52,21 +62,33 @@ pub enum RequestApprovalStatusDTO {
 pub enum RequestExecutionScheduleDTO {
     Immediate,
     Scheduled { execution_time: TimestampRfc3339 },
+    Recurring(RecurringExecutionScheduleDTO),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum RequestOperationDTO {
     Transfer(Box<TransferOperationDTO>),
+    BatchTransfer(Box<BatchTransferOperationDTO>),
     AddAccount(Box<AddAccountOperationDTO>),
     EditAccount(Box<EditAccountOperationDTO>),
     AddAddressBookEntry(Box<AddAddressBookEntryOperationDTO>),
     EditAddressBookEntry(Box<EditAddressBookEntryOperationDTO>),
     RemoveAddressBookEntry(Box<RemoveAddressBookEntryOperationDTO>),
+    AddAsset(Box<AddAssetOperationDTO>),
+    EditAsset(Box<EditAssetOperationDTO>),
+    RemoveAsset(Box<RemoveAssetOperationDTO>),
+    ApproveAllowance(Box<ApproveAllowanceOperationDTO>),
+    RevokeAllowance(Box<RevokeAllowanceOperationDTO>),
+    CancelStandingOrder(Box<CancelStandingOrderOperationDTO>),
+    AddApprovalDelegation(Box<AddApprovalDelegationOperationDTO>),
     AddUser(Box<AddUserOperationDTO>),
     EditUser(Box<EditUserOperationDTO>),
+    RemoveUser(Box<RemoveUserOperationDTO>),
+    LinkUserIdentity(Box<LinkUserIdentityOperationDTO>),
     AddUserGroup(Box<AddUserGroupOperationDTO>),
     EditUserGroup(Box<EditUserGroupOperationDTO>),
     RemoveUserGroup(Box<RemoveUserGroupOperationDTO>),
+    ApplyRoleTemplate(Box<ApplyRoleTemplateOperationDTO>),
     ChangeCanister(Box<ChangeCanisterOperationDTO>),
     ChangeExternalCanister(Box<ChangeExternalCanisterOperationDTO>),
     CreateExternalCanister(Box<CreateExternalCanisterOperationDTO>),
@@ -76,21 +98,32 @@ pub enum RequestOperationDTO {
     EditRequestPolicy(Box<EditRequestPolicyOperationDTO>),
     RemoveRequestPolicy(Box<RemoveRequestPolicyOperationDTO>),
     ManageSystemInfo(Box<ManageSystemInfoOperationDTO>),
+    Bundle(Box<BundleOperationDTO>),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum RequestOperationInput {
     Transfer(TransferOperationInput),
+    BatchTransfer(BatchTransferOperationInput),
     AddAccount(AddAccountOperationInput),
     EditAccount(EditAccountOperationInput),
     AddAddressBookEntry(AddAddressBookEntryOperationInput),
     EditAddressBookEntry(EditAddressBookEntryOperationInput),
     RemoveAddressBookEntry(RemoveAddressBookEntryOperationInput),
+    AddAsset(AddAssetOperationInput),
+    EditAsset(EditAssetOperationInput),
+    RemoveAsset(RemoveAssetOperationInput),
+    ApproveAllowance(ApproveAllowanceOperationInput),
+    RevokeAllowance(RevokeAllowanceOperationInput),
+    CancelStandingOrder(CancelStandingOrderOperationInput),
+    AddApprovalDelegation(AddApprovalDelegationOperationInput),
     AddUser(AddUserOperationInput),
     EditUser(EditUserOperationInput),
+    RemoveUser(RemoveUserOperationInput),
     AddUserGroup(AddUserGroupOperationInput),
     EditUserGroup(EditUserGroupOperationInput),
     RemoveUserGroup(RemoveUserGroupOperationInput),
+    ApplyRoleTemplate(ApplyRoleTemplateOperationInput),
     ChangeCanister(ChangeCanisterOperationInput),
     ChangeExternalCanister(ChangeExternalCanisterOperationInput),
     CreateExternalCanister(CreateExternalCanisterOperationInput),
@@ -100,21 +133,43 @@ pub enum RequestOperationInput {
     EditRequestPolicy(EditRequestPolicyOperationInput),
     RemoveRequestPolicy(RemoveRequestPolicyOperationInput),
     ManageSystemInfo(ManageSystemInfoOperationInput),
+    Bundle(BundleOperationInput),
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BundleOperationInput {
+    pub operations: Vec<RequestOperationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BundleOperationDTO {
+    pub operations: Vec<RequestOperationDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum RequestOperationTypeDTO {
     Transfer,
+    BatchTransfer,
     AddAccount,
     EditAccount,
     AddAddressBookEntry,
     EditAddressBookEntry,
     RemoveAddressBookEntry,
+    AddAsset,
+    EditAsset,
+    RemoveAsset,
+    ApproveAllowance,
+    RevokeAllowance,
+    CancelStandingOrder,
+    AddApprovalDelegation,
     AddUser,
     EditUser,
+    RemoveUser,
+    LinkUserIdentity,
     AddUserGroup,
     EditUserGroup,
     RemoveUserGroup,
+    ApplyRoleTemplate,
     ChangeCanister,
     ChangeExternalCanister,
     CreateExternalCanister,
@@ -124,21 +179,33 @@ pub enum RequestOperationTypeDTO {
     EditRequestPolicy,
     RemoveRequestPolicy,
     ManageSystemInfo,
+    Bundle,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum ListRequestsOperationTypeDTO {
     Transfer(Option<UuidDTO>),
+    BatchTransfer(Option<UuidDTO>),
     AddAccount,
     EditAccount,
     AddAddressBookEntry,
     EditAddressBookEntry,
     RemoveAddressBookEntry,
+    AddAsset,
+    EditAsset,
+    RemoveAsset,
+    ApproveAllowance(Option<UuidDTO>),
+    RevokeAllowance(Option<UuidDTO>),
+    CancelStandingOrder(Option<UuidDTO>),
+    AddApprovalDelegation,
     AddUser,
     EditUser,
+    RemoveUser,
+    LinkUserIdentity,
     AddUserGroup,
     EditUserGroup,
     RemoveUserGroup,
+    ApplyRoleTemplate,
     ChangeCanister,
     ChangeExternalCanister(Option<Principal>),
     CreateExternalCanister,
@@ -148,16 +215,26 @@ pub enum ListRequestsOperationTypeDTO {
     EditRequestPolicy,
     RemoveRequestPolicy,
     ManageSystemInfo,
+    Bundle,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct RequestApprovalDTO {
     pub approver_id: UuidDTO,
+    pub delegate_id: Option<UuidDTO>,
     pub status: RequestApprovalStatusDTO,
     pub status_reason: Option<String>,
     pub decided_at: TimestampRfc3339,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestApprovalChangeDTO {
+    pub approver_id: UuidDTO,
+    pub status: Option<RequestApprovalStatusDTO>,
+    pub status_reason: Option<String>,
+    pub decided_at: TimestampRfc3339,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct RequestDTO {
     pub id: UuidDTO,
@@ -166,6 +243,7 @@ pub struct RequestDTO {
     pub operation: RequestOperationDTO,
     pub requested_by: UuidDTO,
     pub approvals: Vec<RequestApprovalDTO>,
+    pub approval_history: Vec<RequestApprovalChangeDTO>,
     pub created_at: TimestampRfc3339,
     pub status: RequestStatusDTO,
     pub expiration_dt: TimestampRfc3339,
@@ -194,11 +272,27 @@ pub struct CreateRequestInput {
     pub execution_plan: Option<RequestExecutionScheduleDTO>,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SimulateRequestInput {
+    pub operation: RequestOperationInput,
+    pub draft_policies: Option<Vec<DraftRequestPolicyDTO>>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SimulateRequestResponse {
+    pub status: EvaluationStatusDTO,
+    pub policy_results: Vec<SimulatedRequestPolicyResultDTO>,
+    pub result_reasons: Vec<EvaluationSummaryReasonDTO>,
+    pub possible_approvers: Vec<UuidDTO>,
+    pub auto_approved: bool,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct SubmitRequestApprovalInput {
     pub decision: RequestApprovalStatusDTO,
     pub request_id: UuidDTO,
     pub reason: Option<String>,
+    pub on_behalf_of: Option<UuidDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -208,6 +302,47 @@ pub struct SubmitRequestApprovalResponse {
     pub additional_info: RequestAdditionalInfoDTO,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ChangeRequestApprovalInput {
+    pub request_id: UuidDTO,
+    pub decision: Option<RequestApprovalStatusDTO>,
+    pub reason: Option<String>,
+    pub on_behalf_of: Option<UuidDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ChangeRequestApprovalResponse {
+    pub request: RequestDTO,
+    pub privileges: RequestCallerPrivilegesDTO,
+    pub additional_info: RequestAdditionalInfoDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct VetoRequestInput {
+    pub request_id: UuidDTO,
+    pub reason: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct VetoRequestResponse {
+    pub request: RequestDTO,
+    pub privileges: RequestCallerPrivilegesDTO,
+    pub additional_info: RequestAdditionalInfoDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CancelRequestInput {
+    pub request_id: UuidDTO,
+    pub reason: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CancelRequestResponse {
+    pub request: RequestDTO,
+    pub privileges: RequestCallerPrivilegesDTO,
+    pub additional_info: RequestAdditionalInfoDTO,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct GetRequestInput {
     pub request_id: UuidDTO,
@@ -277,6 +412,7 @@ pub struct AddRequestPolicyOperationInput {
 pub struct AddRequestPolicyOperationDTO {
     pub policy_id: Option<UuidDTO>,
     pub input: AddRequestPolicyOperationInput,
+    pub warnings: Vec<String>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -289,6 +425,7 @@ pub struct EditRequestPolicyOperationInput {
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct EditRequestPolicyOperationDTO {
     pub input: EditRequestPolicyOperationInput,
+    pub warnings: Vec<String>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
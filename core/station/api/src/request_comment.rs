@@ -0,0 +1,51 @@
+use super::TimestampRfc3339;
+use crate::{PaginationInput, UuidDTO};
+use candid::{CandidType, Deserialize};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestCommentDTO {
+    pub id: UuidDTO,
+    pub request_id: UuidDTO,
+    pub author_id: UuidDTO,
+    pub content: String,
+    pub mentions: Vec<UuidDTO>,
+    pub created_at: TimestampRfc3339,
+    pub last_modification_timestamp: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddRequestCommentInput {
+    pub request_id: UuidDTO,
+    pub content: String,
+    pub mentions: Vec<UuidDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddRequestCommentResponse {
+    pub comment: RequestCommentDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EditRequestCommentInput {
+    pub comment_id: UuidDTO,
+    pub content: String,
+    pub mentions: Vec<UuidDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EditRequestCommentResponse {
+    pub comment: RequestCommentDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListRequestCommentsInput {
+    pub request_id: UuidDTO,
+    pub paginate: Option<PaginationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListRequestCommentsResponse {
+    pub comments: Vec<RequestCommentDTO>,
+    pub next_offset: Option<u64>,
+    pub total: u64,
+}
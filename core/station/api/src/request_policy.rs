@@ -11,10 +11,16 @@ pub enum RequestSpecifierDTO {
     AddUser,
     EditAccount(ResourceIdsDTO),
     EditUser(ResourceIdsDTO),
+    RemoveUser(ResourceIdsDTO),
+    LinkUserIdentity(ResourceIdsDTO),
     AddAddressBookEntry,
     EditAddressBookEntry(ResourceIdsDTO),
     RemoveAddressBookEntry(ResourceIdsDTO),
+    AddAsset,
+    EditAsset(ResourceIdsDTO),
+    RemoveAsset(ResourceIdsDTO),
     Transfer(ResourceIdsDTO),
+    BatchTransfer(ResourceIdsDTO),
     ChangeCanister,
     ChangeExternalCanister(ChangeExternalCanisterResourceTargetDTO),
     CreateExternalCanister(CreateExternalCanisterResourceTargetDTO),
@@ -26,7 +32,9 @@ pub enum RequestSpecifierDTO {
     AddUserGroup,
     EditUserGroup(ResourceIdsDTO),
     RemoveUserGroup(ResourceIdsDTO),
+    ApplyRoleTemplate(ResourceIdsDTO),
     ManageSystemInfo,
+    AddApprovalDelegation,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -54,6 +62,18 @@ pub struct QuorumDTO {
     pub min_approved: u16,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ApproverWeightDTO {
+    pub approvers: UserSpecifierDTO,
+    pub weight: u32,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct WeightedQuorumDTO {
+    pub weights: Vec<ApproverWeightDTO>,
+    pub min_weight: u64,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum RequestPolicyRuleInput {
     Remove,
@@ -72,13 +92,42 @@ pub enum RequestPolicyRuleDTO {
     AutoApproved,
     QuorumPercentage(QuorumPercentageDTO),
     Quorum(QuorumDTO),
+    WeightedQuorum(WeightedQuorumDTO),
     AllowListedByMetadata(MetadataDTO),
     AllowListed,
+    AllowListedWithinLimit(TransferLimitDTO),
+    AllowListedWithinRollingLimit(RollingTransferLimitDTO),
+    AmountAtMost(candid::Nat),
+    AmountAbove(candid::Nat),
+    TimeLock(TimeLockDTO),
     AnyOf(Vec<RequestPolicyRuleDTO>),
     AllOf(Vec<RequestPolicyRuleDTO>),
     Not(Box<RequestPolicyRuleDTO>),
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum TransferLimitScopeDTO {
+    PerAccount,
+    PerAddressBookEntry,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferLimitDTO {
+    pub scope: TransferLimitScopeDTO,
+    pub max_amount: candid::Nat,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RollingTransferLimitDTO {
+    pub limit: TransferLimitDTO,
+    pub window_seconds: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TimeLockDTO {
+    pub delay_seconds: u64,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum EvaluatedRequestPolicyRuleDTO {
     AutoApproved,
@@ -92,10 +141,32 @@ pub enum EvaluatedRequestPolicyRuleDTO {
         min_approved: usize,
         approvers: Vec<UuidDTO>,
     },
+    WeightedQuorum {
+        min_weight: u64,
+        total_possible_weight: u64,
+        approved_weight: u64,
+        remaining_weight: u64,
+        approvers: Vec<UuidDTO>,
+    },
     AllowListedByMetadata {
         metadata: MetadataDTO,
     },
     AllowListed,
+    AllowListedWithinLimit {
+        limit: TransferLimitDTO,
+    },
+    AllowListedWithinRollingLimit {
+        limit: RollingTransferLimitDTO,
+    },
+    AmountAtMost {
+        amount: candid::Nat,
+    },
+    AmountAbove {
+        amount: candid::Nat,
+    },
+    TimeLock {
+        delay_seconds: u64,
+    },
     AnyOf(Vec<RequestPolicyRuleResultDTO>),
     AllOf(Vec<RequestPolicyRuleResultDTO>),
     Not(Box<RequestPolicyRuleResultDTO>),
@@ -113,6 +184,8 @@ pub enum EvaluationSummaryReasonDTO {
     AllowList,
     AllowListMetadata,
     AutoApproved,
+    TransferLimit,
+    AmountThreshold,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -137,6 +210,20 @@ pub struct RequestPolicyDTO {
     pub rule: RequestPolicyRuleDTO,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct DraftRequestPolicyDTO {
+    pub policy_id: Option<UuidDTO>,
+    pub specifier: RequestSpecifierDTO,
+    pub rule: RequestPolicyRuleDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SimulatedRequestPolicyResultDTO {
+    pub policy_id: Option<UuidDTO>,
+    pub specifier: RequestSpecifierDTO,
+    pub result: RequestPolicyRuleResultDTO,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct GetRequestPolicyInput {
     pub id: UuidDTO,
@@ -0,0 +1,98 @@
+use super::TimestampRfc3339;
+use crate::{MetadataDTO, PaginationInput, RequestExecutionScheduleDTO, UuidDTO};
+use candid::{CandidType, Deserialize};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestTemplateDTO {
+    pub id: UuidDTO,
+    pub name: String,
+    pub description: Option<String>,
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    pub operation: RequestTemplateOperationDTO,
+    pub created_at: TimestampRfc3339,
+    pub last_modification_timestamp: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum RequestTemplateOperationDTO {
+    Transfer(TransferRequestTemplateDTO),
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferRequestTemplateDTO {
+    pub from_account_id: UuidDTO,
+    pub to: Option<String>,
+    pub amount: Option<candid::Nat>,
+    pub metadata: Vec<MetadataDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddRequestTemplateInput {
+    pub name: String,
+    pub description: Option<String>,
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    pub operation: RequestTemplateOperationDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddRequestTemplateResponse {
+    pub template: RequestTemplateDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EditRequestTemplateInput {
+    pub template_id: UuidDTO,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    pub operation: Option<RequestTemplateOperationDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EditRequestTemplateResponse {
+    pub template: RequestTemplateDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RemoveRequestTemplateInput {
+    pub template_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RemoveRequestTemplateResponse {
+    pub template: RequestTemplateDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetRequestTemplateInput {
+    pub template_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetRequestTemplateResponse {
+    pub template: RequestTemplateDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListRequestTemplatesInput {
+    pub paginate: Option<PaginationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListRequestTemplatesResponse {
+    pub templates: Vec<RequestTemplateDTO>,
+    pub next_offset: Option<u64>,
+    pub total: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CreateRequestFromTemplateInput {
+    pub template_id: UuidDTO,
+    pub amount: Option<candid::Nat>,
+    pub destination: Option<String>,
+    pub summary: Option<String>,
+    pub execution_plan: Option<RequestExecutionScheduleDTO>,
+}
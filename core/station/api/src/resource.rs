@@ -6,10 +6,12 @@ pub enum ResourceDTO {
     Permission(PermissionResourceActionDTO),
     Account(AccountResourceActionDTO),
     AddressBook(ResourceActionDTO),
+    Asset(ResourceActionDTO),
     ChangeCanister(ChangeCanisterResourceActionDTO),
     ExternalCanister(ExternalCanisterResourceActionDTO),
     Request(RequestResourceActionDTO),
     RequestPolicy(ResourceActionDTO),
+    RequestTemplate(ResourceActionDTO),
     System(SystemResourceActionDTO),
     User(UserResourceActionDTO),
     UserGroup(ResourceActionDTO),
@@ -48,6 +50,8 @@ pub enum UserResourceActionDTO {
     Create,
     Read(ResourceIdDTO),
     Update(ResourceIdDTO),
+    Remove(ResourceIdDTO),
+    Delegate(ResourceIdDTO),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -118,4 +122,5 @@ pub struct CallExternalCanisterResourceTargetDTO {
 pub enum RequestResourceActionDTO {
     List,
     Read(ResourceIdDTO),
+    Cancel(ResourceIdDTO),
 }
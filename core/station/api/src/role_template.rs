@@ -0,0 +1,43 @@
+use crate::{RequestSpecifierDTO, ResourceDTO, UuidDTO};
+use candid::{CandidType, Deserialize};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum RoleTemplateDTO {
+    Viewer,
+    Operator,
+    Treasurer,
+    Auditor,
+    Admin,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ApplyRoleTemplateOperationInput {
+    pub user_group_id: UuidDTO,
+    pub template: RoleTemplateDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ApplyRoleTemplateOperationDTO {
+    pub input: ApplyRoleTemplateOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RoleTemplateDiffDTO {
+    pub added_permissions: Vec<ResourceDTO>,
+    pub unchanged_permissions: Vec<ResourceDTO>,
+    pub added_request_policies: Vec<RequestSpecifierDTO>,
+    pub unchanged_request_policies: Vec<RequestSpecifierDTO>,
+    pub skipped_request_policies: Vec<RequestSpecifierDTO>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetRoleTemplateDiffInput {
+    pub user_group_id: UuidDTO,
+    pub template: RoleTemplateDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetRoleTemplateDiffResponse {
+    pub diff: RoleTemplateDiffDTO,
+}
@@ -0,0 +1,80 @@
+use super::TimestampRfc3339;
+use crate::UuidDTO;
+use candid::{CandidType, Deserialize};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum RecurrenceIntervalDTO {
+    Minutes(u32),
+    Hours(u32),
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RecurringExecutionScheduleDTO {
+    pub interval: RecurrenceIntervalDTO,
+    pub start_at: TimestampRfc3339,
+    pub end_at: Option<TimestampRfc3339>,
+    pub max_occurrences: Option<u32>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum StandingOrderStatusDTO {
+    Active,
+    Finished,
+    Cancelled,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct StandingOrderFailureDTO {
+    pub occurrence: u32,
+    pub reason: String,
+    pub failed_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct StandingOrderDTO {
+    pub request_id: UuidDTO,
+    pub account_id: UuidDTO,
+    pub schedule: RecurringExecutionScheduleDTO,
+    pub next_execution_at: Option<TimestampRfc3339>,
+    pub transfer_ids: Vec<UuidDTO>,
+    pub last_failure: Option<StandingOrderFailureDTO>,
+    pub status: StandingOrderStatusDTO,
+    pub created_at: TimestampRfc3339,
+    pub last_modification_timestamp: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CancelStandingOrderOperationInput {
+    pub request_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CancelStandingOrderOperationDTO {
+    pub standing_order: Option<StandingOrderDTO>,
+    pub input: CancelStandingOrderOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListUpcomingExecutionsInput {
+    pub from_account_id: Option<UuidDTO>,
+    pub until: Option<TimestampRfc3339>,
+    pub limit: Option<u16>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct UpcomingExecutionDTO {
+    pub request_id: UuidDTO,
+    pub occurrence: u32,
+    pub execution_time: TimestampRfc3339,
+    pub from_account_id: UuidDTO,
+    pub to: String,
+    pub amount: candid::Nat,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListUpcomingExecutionsResponse {
+    pub executions: Vec<UpcomingExecutionDTO>,
+}
@@ -1,4 +1,5 @@
 use super::TimestampRfc3339;
+use crate::{ChangeRecoveryConfigDTO, RecoveryAttemptDTO, RecoveryConfigDTO};
 use candid::{CandidType, Deserialize, Principal};
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
@@ -9,6 +10,8 @@ pub struct SystemInfoDTO {
     pub cycles: u64,
     pub last_upgrade_timestamp: TimestampRfc3339,
     pub raw_rand_successful: bool,
+    pub recovery_config: Option<RecoveryConfigDTO>,
+    pub pending_recovery: Option<RecoveryAttemptDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -19,6 +22,7 @@ pub struct ManageSystemInfoOperationDTO {
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct ManageSystemInfoOperationInput {
     pub name: Option<String>,
+    pub recovery_config: Option<ChangeRecoveryConfigDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
@@ -1,5 +1,5 @@
 use super::{AccountDTO, TimestampRfc3339};
-use crate::{MetadataDTO, UuidDTO};
+use crate::{MetadataDTO, RequestEvaluationResultDTO, UuidDTO};
 use candid::{CandidType, Deserialize};
 
 pub type NetworkIdDTO = String;
@@ -28,6 +28,35 @@ pub struct TransferOperationDTO {
     pub transfer_id: Option<UuidDTO>,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BatchTransferOperationInput {
+    pub transfers: Vec<TransferOperationInput>,
+}
+
+/// The execution state of a single row of a batch transfer, in the same order as the input rows.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BatchTransferRowDTO {
+    pub transfer_id: Option<UuidDTO>,
+    pub status: Option<TransferStatusDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BatchTransferSummaryDTO {
+    pub total: u64,
+    pub pending: u64,
+    pub completed: u64,
+    pub failed: u64,
+    /// One line per row with the header `row,from_account_id,to,amount,fee,status,transfer_id,reason`.
+    pub csv: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BatchTransferOperationDTO {
+    pub input: BatchTransferOperationInput,
+    pub rows: Vec<BatchTransferRowDTO>,
+    pub summary: BatchTransferSummaryDTO,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum TransferStatusDTO {
     Created,
@@ -102,3 +131,49 @@ pub struct TransferListItemDTO {
 pub struct ListAccountTransfersResponse {
     pub transfers: Vec<TransferListItemDTO>,
 }
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EstimateTransferInput {
+    pub transfer: TransferOperationInput,
+}
+
+/// The outcome of a transfer request that was validated and evaluated without being created.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EstimateTransferResponse {
+    /// The fee of the transfer, either the one given in the input or the current fee of the blockchain.
+    pub fee: candid::Nat,
+    /// The current balance of the account that the transfer is made from.
+    pub balance: candid::Nat,
+    /// The evaluation of the policies that would apply to the request, as if it was just created.
+    pub evaluation_result: RequestEvaluationResultDTO,
+    /// The users that would be able to approve the request.
+    pub possible_approvers: Vec<UuidDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListAccountActivityInput {
+    pub account_id: UuidDTO,
+    pub from_dt: Option<TimestampRfc3339>,
+    pub to_dt: Option<TimestampRfc3339>,
+}
+
+/// A deposit into the account that was found in its ledger history.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct IncomingTransferDTO {
+    pub account_id: UuidDTO,
+    pub tx_id: u64,
+    pub from: Option<String>,
+    pub amount: candid::Nat,
+    pub timestamp: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum AccountActivityDTO {
+    Outgoing(TransferListItemDTO),
+    Incoming(IncomingTransferDTO),
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListAccountActivityResponse {
+    pub activity: Vec<AccountActivityDTO>,
+}
@@ -6,12 +6,15 @@ use candid::{CandidType, Deserialize, Principal};
 pub struct UserCallerPrivilegesDTO {
     pub id: UuidDTO,
     pub can_edit: bool,
+    pub can_remove: bool,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
 pub enum UserStatusDTO {
     Active,
     Inactive,
+    Suspended,
+    Archived,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -76,6 +79,28 @@ pub struct EditUserOperationDTO {
     pub input: EditUserOperationInput,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RemoveUserOperationInput {
+    pub user_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RemoveUserOperationDTO {
+    pub input: RemoveUserOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct LinkUserIdentityOperationInput {
+    pub user_id: UuidDTO,
+    pub identity: Principal,
+    pub retired_identity: Option<Principal>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct LinkUserIdentityOperationDTO {
+    pub input: LinkUserIdentityOperationInput,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct ListUsersInput {
     pub search_term: Option<String>,
@@ -107,6 +132,10 @@ pub enum UserPrivilege {
     AddRequestPolicy,
     ListAddressBookEntries,
     AddAddressBookEntry,
+    ListAssets,
+    AddAsset,
+    ListRequestTemplates,
+    AddRequestTemplate,
     ChangeCanister,
     ListRequests,
 }
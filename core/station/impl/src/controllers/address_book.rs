@@ -1,6 +1,7 @@
+use crate::mappers::blockchain::BlockchainMapper;
 use crate::mappers::HelperMapper;
 use crate::models::resource::{Resource, ResourceAction};
-use crate::models::ListAddressBookEntriesInput;
+use crate::models::{validate_blockchain_address, ListAddressBookEntriesInput};
 use crate::{
     core::middlewares::{authorize, call_context},
     services::AddressBookService,
@@ -12,7 +13,7 @@ use orbit_essentials::with_middleware;
 use station_api::{
     AddressBookEntryCallerPrivilegesDTO, GetAddressBookEntryInputDTO,
     GetAddressBookEntryResponseDTO, ListAddressBookEntriesInputDTO,
-    ListAddressBookEntriesResponseDTO,
+    ListAddressBookEntriesResponseDTO, ValidateAddressInput, ValidateAddressResponse,
 };
 
 // Canister entrypoints for the controller.
@@ -30,6 +31,11 @@ async fn list_address_book_entries(
     CONTROLLER.list_address_book_entries(input).await
 }
 
+#[query(name = "validate_address")]
+async fn validate_address(input: ValidateAddressInput) -> ApiResult<ValidateAddressResponse> {
+    CONTROLLER.validate_address(input).await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
     static ref CONTROLLER: AddressBookController =
@@ -102,4 +108,20 @@ impl AddressBookController {
             privileges,
         })
     }
+
+    /// Checks the address format without requiring any permission since no station data is read.
+    async fn validate_address(
+        &self,
+        input: ValidateAddressInput,
+    ) -> ApiResult<ValidateAddressResponse> {
+        let blockchain = BlockchainMapper::to_blockchain(input.blockchain)?;
+        let standard = BlockchainMapper::to_blockchain_standard(input.standard)?;
+
+        let result = validate_blockchain_address(&blockchain, &standard, &input.address);
+
+        Ok(ValidateAddressResponse {
+            valid: result.is_ok(),
+            reason: result.err(),
+        })
+    }
 }
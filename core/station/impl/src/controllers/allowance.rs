@@ -0,0 +1,48 @@
+use crate::{
+    core::middlewares::{authorize, call_context},
+    models::resource::Resource,
+    services::{AllowanceService, ALLOWANCE_SERVICE},
+};
+use ic_cdk_macros::query;
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::{ListAccountAllowancesInput, ListAccountAllowancesResponse};
+use std::sync::Arc;
+
+#[query(name = "list_account_allowances")]
+async fn list_account_allowances(
+    input: ListAccountAllowancesInput,
+) -> ApiResult<ListAccountAllowancesResponse> {
+    CONTROLLER.list_account_allowances(input).await
+}
+
+lazy_static! {
+    static ref CONTROLLER: AllowanceController =
+        AllowanceController::new(Arc::clone(&ALLOWANCE_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct AllowanceController {
+    allowance_service: Arc<AllowanceService>,
+}
+
+impl AllowanceController {
+    pub fn new(allowance_service: Arc<AllowanceService>) -> Self {
+        Self { allowance_service }
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn list_account_allowances(
+        &self,
+        input: ListAccountAllowancesInput,
+    ) -> ApiResult<ListAccountAllowancesResponse> {
+        let result = self.allowance_service.list_account_allowances(input)?;
+
+        Ok(ListAccountAllowancesResponse {
+            allowances: result.items.into_iter().map(Into::into).collect(),
+            next_offset: result.next_offset,
+            total: result.total,
+        })
+    }
+}
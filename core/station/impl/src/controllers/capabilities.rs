@@ -1,14 +1,16 @@
 use crate::{
     core::{
         middlewares::{authorize, call_context},
-        read_system_info, ASSETS,
+        read_system_info,
     },
     models::resource::{Resource, SystemResourceAction},
+    repositories::ASSET_REPOSITORY,
     SYSTEM_VERSION,
 };
 use ic_cdk_macros::query;
 use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
+use orbit_essentials::repository::Repository;
 use orbit_essentials::with_middleware;
 use station_api::{CapabilitiesDTO, CapabilitiesResponse};
 
@@ -32,7 +34,7 @@ impl CapabilitiesController {
 
     #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::Capabilities)]))]
     async fn capabilities(&self) -> ApiResult<CapabilitiesResponse> {
-        let assets = ASSETS.with(|asset| asset.borrow().clone());
+        let assets = ASSET_REPOSITORY.list();
         let system = read_system_info();
 
         Ok(CapabilitiesResponse {
@@ -0,0 +1,82 @@
+use crate::{
+    core::middlewares::{call_context, use_canister_call_metric},
+    services::{IdentityLinkService, IDENTITY_LINK_SERVICE},
+};
+use ic_cdk_macros::update;
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::{
+    ConfirmIdentityLinkInput, ConfirmIdentityLinkResponse, StartIdentityLinkInput,
+    StartIdentityLinkResponse,
+};
+use std::sync::Arc;
+
+// Canister entrypoints for the controller.
+#[update(name = "start_identity_link")]
+async fn start_identity_link(
+    input: StartIdentityLinkInput,
+) -> ApiResult<StartIdentityLinkResponse> {
+    CONTROLLER.start_identity_link(input).await
+}
+
+#[update(name = "confirm_identity_link")]
+async fn confirm_identity_link(
+    input: ConfirmIdentityLinkInput,
+) -> ApiResult<ConfirmIdentityLinkResponse> {
+    CONTROLLER.confirm_identity_link(input).await
+}
+
+// Controller initialization and implementation.
+lazy_static! {
+    static ref CONTROLLER: IdentityLinkController =
+        IdentityLinkController::new(Arc::clone(&IDENTITY_LINK_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct IdentityLinkController {
+    identity_link_service: Arc<IdentityLinkService>,
+}
+
+impl IdentityLinkController {
+    pub fn new(identity_link_service: Arc<IdentityLinkService>) -> Self {
+        Self {
+            identity_link_service,
+        }
+    }
+
+    /// Starts linking a new identity to the user associated with the caller.
+    ///
+    /// No authorization required since the link is only for the caller user, which is checked by the service.
+    #[with_middleware(tail = use_canister_call_metric("start_identity_link", &result))]
+    async fn start_identity_link(
+        &self,
+        input: StartIdentityLinkInput,
+    ) -> ApiResult<StartIdentityLinkResponse> {
+        let link = self
+            .identity_link_service
+            .start_identity_link(input, &call_context())
+            .await?;
+
+        Ok(StartIdentityLinkResponse { link: link.into() })
+    }
+
+    /// Confirms the identity link from the new identity.
+    ///
+    /// No authorization required since the caller is not associated with a user yet, the service
+    /// checks that it is the identity that the link was started for.
+    #[with_middleware(tail = use_canister_call_metric("confirm_identity_link", &result))]
+    async fn confirm_identity_link(
+        &self,
+        input: ConfirmIdentityLinkInput,
+    ) -> ApiResult<ConfirmIdentityLinkResponse> {
+        let request = self
+            .identity_link_service
+            .confirm_identity_link(input, &call_context())
+            .await?;
+
+        Ok(ConfirmIdentityLinkResponse {
+            request: request.to_dto(),
+        })
+    }
+}
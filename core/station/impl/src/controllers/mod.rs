@@ -17,6 +17,11 @@ pub use account::*;
 
 mod address_book;
 pub use address_book::*;
+mod allowance;
+pub use allowance::*;
+
+mod asset;
+pub use asset::*;
 
 mod notification;
 pub use notification::*;
@@ -27,18 +32,36 @@ pub use transfer::*;
 mod request;
 pub use request::*;
 
+mod request_comment;
+pub use request_comment::*;
+
+mod request_template;
+pub use request_template::*;
+
 mod user;
 pub use user::*;
 
+mod identity_link;
+pub use identity_link::*;
+
+mod recovery;
+pub use recovery::*;
+
 mod request_policy;
 pub use request_policy::*;
 
+mod standing_order;
+pub use standing_order::*;
+
 mod permission;
 pub use permission::*;
 
 mod user_group;
 pub use user_group::*;
 
+mod role_template;
+pub use role_template::*;
+
 mod http;
 pub use http::*;
 
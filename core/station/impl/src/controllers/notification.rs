@@ -60,6 +60,12 @@ impl NotificationController {
                                 Uuid::from_bytes(request_id).hyphenated()
                             ));
                         }
+                        NotificationMapperError::RequestCommentNotFound { comment_id } => {
+                            print(format!(
+                                "Request comment {} not found when mapping to NotificationDTO",
+                                Uuid::from_bytes(comment_id).hyphenated()
+                            ));
+                        }
                         NotificationMapperError::InvalidRequestStatus {
                             expected, found
                         } => {
@@ -3,6 +3,7 @@ use crate::{
         authorization::Authorization,
         middlewares::{authorize, call_context},
     },
+    mappers::HelperMapper,
     models::resource::{PermissionResourceAction, Resource},
     services::permission::{PermissionService, PERMISSION_SERVICE},
 };
@@ -11,8 +12,8 @@ use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
 use orbit_essentials::with_middleware;
 use station_api::{
-    GetPermissionInput, GetPermissionResponse, ListPermissionsInput, ListPermissionsResponse,
-    PermissionCallerPrivilegesDTO,
+    ExplainAccessInput, ExplainAccessResponse, GetPermissionInput, GetPermissionResponse,
+    ListPermissionsInput, ListPermissionsResponse, PermissionCallerPrivilegesDTO,
 };
 use std::sync::Arc;
 
@@ -22,6 +23,11 @@ async fn get_permission(input: GetPermissionInput) -> ApiResult<GetPermissionRes
     CONTROLLER.get_permission(input).await
 }
 
+#[query(name = "explain_access")]
+async fn explain_access(input: ExplainAccessInput) -> ApiResult<ExplainAccessResponse> {
+    CONTROLLER.explain_access(input).await
+}
+
 #[query(name = "list_permissions")]
 async fn list_permissions(input: ListPermissionsInput) -> ApiResult<ListPermissionsResponse> {
     CONTROLLER.list_permissions(input).await
@@ -62,6 +68,18 @@ impl PermissionController {
         })
     }
 
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Permission(PermissionResourceAction::Read)]))]
+    async fn explain_access(&self, input: ExplainAccessInput) -> ApiResult<ExplainAccessResponse> {
+        let user_id = *HelperMapper::to_uuid(input.user_id)?.as_bytes();
+        let explanations = self
+            .permission_service
+            .explain_access(&user_id, input.resource.map(Resource::from))?;
+
+        Ok(ExplainAccessResponse {
+            explanations: explanations.into_iter().map(Into::into).collect(),
+        })
+    }
+
     #[with_middleware(guard = authorize(&call_context(), &[Resource::Permission(PermissionResourceAction::Read)]))]
     async fn list_permissions(
         &self,
@@ -0,0 +1,72 @@
+use crate::{
+    core::middlewares::{authorize, call_context, use_canister_call_metric},
+    models::resource::{Resource, SystemResourceAction},
+    services::{RecoveryService, RECOVERY_SERVICE},
+};
+use ic_cdk_macros::update;
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::{CancelRecoveryResponse, SubmitRecoveryInput, SubmitRecoveryResponse};
+use std::sync::Arc;
+
+// Canister entrypoints for the controller.
+#[update(name = "submit_recovery")]
+async fn submit_recovery(input: SubmitRecoveryInput) -> ApiResult<SubmitRecoveryResponse> {
+    CONTROLLER.submit_recovery(input).await
+}
+
+#[update(name = "cancel_recovery")]
+async fn cancel_recovery() -> ApiResult<CancelRecoveryResponse> {
+    CONTROLLER.cancel_recovery().await
+}
+
+// Controller initialization and implementation.
+lazy_static! {
+    static ref CONTROLLER: RecoveryController =
+        RecoveryController::new(Arc::clone(&RECOVERY_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct RecoveryController {
+    recovery_service: Arc<RecoveryService>,
+}
+
+impl RecoveryController {
+    pub fn new(recovery_service: Arc<RecoveryService>) -> Self {
+        Self { recovery_service }
+    }
+
+    /// Submits a recovery action on behalf of the calling guardian.
+    ///
+    /// No authorization required since guardians don't need to be users of the station, the service
+    /// checks that the caller is one of the configured guardians.
+    #[with_middleware(tail = use_canister_call_metric("submit_recovery", &result))]
+    async fn submit_recovery(
+        &self,
+        input: SubmitRecoveryInput,
+    ) -> ApiResult<SubmitRecoveryResponse> {
+        let (recovery, executed) = self
+            .recovery_service
+            .submit_recovery(input.action.into(), &call_context())
+            .await?;
+
+        Ok(SubmitRecoveryResponse {
+            recovery: recovery.into(),
+            executed,
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    #[with_middleware(tail = use_canister_call_metric("cancel_recovery", &result))]
+    async fn cancel_recovery(&self) -> ApiResult<CancelRecoveryResponse> {
+        let recovery = self
+            .recovery_service
+            .cancel_recovery(&call_context())
+            .await?;
+
+        Ok(CancelRecoveryResponse {
+            recovery: recovery.into(),
+        })
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     core::middlewares::{authorize, call_context, use_canister_call_metric},
-    mappers::HelperMapper,
-    models::resource::{RequestResourceAction, Resource},
+    mappers::{authorization::CreateRequestInputRef, HelperMapper},
+    models::resource::{RequestResourceAction, Resource, ResourceAction},
     services::{RequestService, REQUEST_SERVICE},
 };
 use ic_cdk_macros::{query, update};
@@ -9,10 +9,13 @@ use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
 use orbit_essentials::with_middleware;
 use station_api::{
-    CreateRequestInput, CreateRequestResponse, GetNextApprovableRequestInput,
-    GetNextApprovableRequestResponse, GetRequestInput, GetRequestResponse, ListRequestsInput,
-    ListRequestsResponse, RequestAdditionalInfoDTO, RequestCallerPrivilegesDTO,
-    SubmitRequestApprovalInput, SubmitRequestApprovalResponse,
+    CancelRequestInput, CancelRequestResponse, ChangeRequestApprovalInput,
+    ChangeRequestApprovalResponse, CreateRequestInput, CreateRequestResponse,
+    GetNextApprovableRequestInput, GetNextApprovableRequestResponse, GetRequestInput,
+    GetRequestResponse, ListRequestsInput, ListRequestsResponse, RequestAdditionalInfoDTO,
+    RequestCallerPrivilegesDTO, SimulateRequestInput, SimulateRequestResponse,
+    SubmitRequestApprovalInput, SubmitRequestApprovalResponse, VetoRequestInput,
+    VetoRequestResponse,
 };
 use std::sync::Arc;
 
@@ -41,6 +44,28 @@ async fn submit_request_approval(
     CONTROLLER.submit_request_approval(input).await
 }
 
+#[update(name = "change_request_approval")]
+async fn change_request_approval(
+    input: ChangeRequestApprovalInput,
+) -> ApiResult<ChangeRequestApprovalResponse> {
+    CONTROLLER.change_request_approval(input).await
+}
+
+#[update(name = "veto_request")]
+async fn veto_request(input: VetoRequestInput) -> ApiResult<VetoRequestResponse> {
+    CONTROLLER.veto_request(input).await
+}
+
+#[query(name = "simulate_request")]
+async fn simulate_request(input: SimulateRequestInput) -> ApiResult<SimulateRequestResponse> {
+    CONTROLLER.simulate_request(input).await
+}
+
+#[update(name = "cancel_request")]
+async fn cancel_request(input: CancelRequestInput) -> ApiResult<CancelRequestResponse> {
+    CONTROLLER.cancel_request(input).await
+}
+
 #[update(name = "create_request")]
 async fn create_request(input: CreateRequestInput) -> ApiResult<CreateRequestResponse> {
     CONTROLLER.create_request(input).await
@@ -61,7 +86,7 @@ impl RequestController {
         Self { request_service }
     }
 
-    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(guard = authorize(&call_context(), &CreateRequestInputRef(&input).to_resources()))]
     #[with_middleware(tail = use_canister_call_metric("create_request", &result))]
     async fn create_request(&self, input: CreateRequestInput) -> ApiResult<CreateRequestResponse> {
         let ctx = &call_context();
@@ -81,6 +106,17 @@ impl RequestController {
         })
     }
 
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::RequestPolicy(ResourceAction::List)]))]
+    async fn simulate_request(
+        &self,
+        input: SimulateRequestInput,
+    ) -> ApiResult<SimulateRequestResponse> {
+        let ctx = call_context();
+        let simulation = self.request_service.simulate_request(input, &ctx).await?;
+
+        Ok(simulation.into())
+    }
+
     #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
     async fn get_request(&self, input: GetRequestInput) -> ApiResult<GetRequestResponse> {
         let ctx = &call_context();
@@ -190,4 +226,70 @@ impl RequestController {
             additional_info: additional_info.into(),
         })
     }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("change_request_approval", &result))]
+    async fn change_request_approval(
+        &self,
+        input: ChangeRequestApprovalInput,
+    ) -> ApiResult<ChangeRequestApprovalResponse> {
+        let ctx = &call_context();
+        let request = self
+            .request_service
+            .change_request_approval(input, ctx)
+            .await?;
+        let privileges = self
+            .request_service
+            .get_caller_privileges_for_request(&request.id, ctx)
+            .await?;
+        let additional_info = self
+            .request_service
+            .get_request_additional_info(&request, true)?;
+
+        Ok(ChangeRequestApprovalResponse {
+            request: request.to_dto(),
+            privileges: privileges.into(),
+            additional_info: additional_info.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("veto_request", &result))]
+    async fn veto_request(&self, input: VetoRequestInput) -> ApiResult<VetoRequestResponse> {
+        let ctx = &call_context();
+        let request = self.request_service.veto_request(input, ctx).await?;
+        let privileges = self
+            .request_service
+            .get_caller_privileges_for_request(&request.id, ctx)
+            .await?;
+        let additional_info = self
+            .request_service
+            .get_request_additional_info(&request, true)?;
+
+        Ok(VetoRequestResponse {
+            request: request.to_dto(),
+            privileges: privileges.into(),
+            additional_info: additional_info.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("cancel_request", &result))]
+    async fn cancel_request(&self, input: CancelRequestInput) -> ApiResult<CancelRequestResponse> {
+        let ctx = &call_context();
+        let request = self.request_service.cancel_request(input, ctx).await?;
+        let privileges = self
+            .request_service
+            .get_caller_privileges_for_request(&request.id, ctx)
+            .await?;
+        let additional_info = self
+            .request_service
+            .get_request_additional_info(&request, true)?;
+
+        Ok(CancelRequestResponse {
+            request: request.to_dto(),
+            privileges: privileges.into(),
+            additional_info: additional_info.into(),
+        })
+    }
 }
@@ -0,0 +1,102 @@
+use crate::{
+    core::middlewares::{authorize, call_context, use_canister_call_metric},
+    models::resource::Resource,
+    services::{RequestCommentService, REQUEST_COMMENT_SERVICE},
+};
+use ic_cdk_macros::{query, update};
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::{
+    AddRequestCommentInput, AddRequestCommentResponse, EditRequestCommentInput,
+    EditRequestCommentResponse, ListRequestCommentsInput, ListRequestCommentsResponse,
+};
+use std::sync::Arc;
+
+// Canister entrypoints for the controller.
+#[update(name = "add_request_comment")]
+async fn add_request_comment(
+    input: AddRequestCommentInput,
+) -> ApiResult<AddRequestCommentResponse> {
+    CONTROLLER.add_request_comment(input).await
+}
+
+#[update(name = "edit_request_comment")]
+async fn edit_request_comment(
+    input: EditRequestCommentInput,
+) -> ApiResult<EditRequestCommentResponse> {
+    CONTROLLER.edit_request_comment(input).await
+}
+
+#[query(name = "list_request_comments")]
+async fn list_request_comments(
+    input: ListRequestCommentsInput,
+) -> ApiResult<ListRequestCommentsResponse> {
+    CONTROLLER.list_request_comments(input).await
+}
+
+// Controller initialization and implementation.
+lazy_static! {
+    static ref CONTROLLER: RequestCommentController =
+        RequestCommentController::new(Arc::clone(&REQUEST_COMMENT_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct RequestCommentController {
+    request_comment_service: Arc<RequestCommentService>,
+}
+
+impl RequestCommentController {
+    pub fn new(request_comment_service: Arc<RequestCommentService>) -> Self {
+        Self {
+            request_comment_service,
+        }
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("add_request_comment", &result))]
+    async fn add_request_comment(
+        &self,
+        input: AddRequestCommentInput,
+    ) -> ApiResult<AddRequestCommentResponse> {
+        let comment = self
+            .request_comment_service
+            .add_comment(input, &call_context())
+            .await?;
+
+        Ok(AddRequestCommentResponse {
+            comment: comment.into(),
+        })
+    }
+
+    /// Only the author can edit the comment, which is checked by the service.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("edit_request_comment", &result))]
+    async fn edit_request_comment(
+        &self,
+        input: EditRequestCommentInput,
+    ) -> ApiResult<EditRequestCommentResponse> {
+        let comment = self
+            .request_comment_service
+            .edit_comment(input, &call_context())
+            .await?;
+
+        Ok(EditRequestCommentResponse {
+            comment: comment.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn list_request_comments(
+        &self,
+        input: ListRequestCommentsInput,
+    ) -> ApiResult<ListRequestCommentsResponse> {
+        let result = self.request_comment_service.list_comments(input)?;
+
+        Ok(ListRequestCommentsResponse {
+            comments: result.items.into_iter().map(Into::into).collect(),
+            next_offset: result.next_offset,
+            total: result.total,
+        })
+    }
+}
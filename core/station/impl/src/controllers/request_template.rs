@@ -0,0 +1,185 @@
+use crate::{
+    core::middlewares::{authorize, call_context, use_canister_call_metric},
+    mappers::{authorization::CreateRequestFromTemplateInputRef, HelperMapper},
+    models::resource::Resource,
+    services::{RequestService, RequestTemplateService, REQUEST_SERVICE, REQUEST_TEMPLATE_SERVICE},
+};
+use ic_cdk_macros::{query, update};
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::{
+    AddRequestTemplateInput, AddRequestTemplateResponse, CreateRequestFromTemplateInput,
+    CreateRequestResponse, EditRequestTemplateInput, EditRequestTemplateResponse,
+    GetRequestTemplateInput, GetRequestTemplateResponse, ListRequestTemplatesInput,
+    ListRequestTemplatesResponse, RemoveRequestTemplateInput, RemoveRequestTemplateResponse,
+};
+use std::sync::Arc;
+
+// Canister entrypoints for the controller.
+#[update(name = "add_request_template")]
+async fn add_request_template(
+    input: AddRequestTemplateInput,
+) -> ApiResult<AddRequestTemplateResponse> {
+    CONTROLLER.add_request_template(input).await
+}
+
+#[update(name = "edit_request_template")]
+async fn edit_request_template(
+    input: EditRequestTemplateInput,
+) -> ApiResult<EditRequestTemplateResponse> {
+    CONTROLLER.edit_request_template(input).await
+}
+
+#[update(name = "remove_request_template")]
+async fn remove_request_template(
+    input: RemoveRequestTemplateInput,
+) -> ApiResult<RemoveRequestTemplateResponse> {
+    CONTROLLER.remove_request_template(input).await
+}
+
+#[query(name = "get_request_template")]
+async fn get_request_template(
+    input: GetRequestTemplateInput,
+) -> ApiResult<GetRequestTemplateResponse> {
+    CONTROLLER.get_request_template(input).await
+}
+
+#[query(name = "list_request_templates")]
+async fn list_request_templates(
+    input: ListRequestTemplatesInput,
+) -> ApiResult<ListRequestTemplatesResponse> {
+    CONTROLLER.list_request_templates(input).await
+}
+
+#[update(name = "create_request_from_template")]
+async fn create_request_from_template(
+    input: CreateRequestFromTemplateInput,
+) -> ApiResult<CreateRequestResponse> {
+    CONTROLLER.create_request_from_template(input).await
+}
+
+// Controller initialization and implementation.
+lazy_static! {
+    static ref CONTROLLER: RequestTemplateController = RequestTemplateController::new(
+        Arc::clone(&REQUEST_TEMPLATE_SERVICE),
+        Arc::clone(&REQUEST_SERVICE)
+    );
+}
+
+#[derive(Debug)]
+pub struct RequestTemplateController {
+    request_template_service: Arc<RequestTemplateService>,
+    request_service: Arc<RequestService>,
+}
+
+impl RequestTemplateController {
+    pub fn new(
+        request_template_service: Arc<RequestTemplateService>,
+        request_service: Arc<RequestService>,
+    ) -> Self {
+        Self {
+            request_template_service,
+            request_service,
+        }
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("add_request_template", &result))]
+    async fn add_request_template(
+        &self,
+        input: AddRequestTemplateInput,
+    ) -> ApiResult<AddRequestTemplateResponse> {
+        let template = self.request_template_service.add(input).await?;
+
+        Ok(AddRequestTemplateResponse {
+            template: template.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("edit_request_template", &result))]
+    async fn edit_request_template(
+        &self,
+        input: EditRequestTemplateInput,
+    ) -> ApiResult<EditRequestTemplateResponse> {
+        let template = self.request_template_service.edit(input)?;
+
+        Ok(EditRequestTemplateResponse {
+            template: template.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("remove_request_template", &result))]
+    async fn remove_request_template(
+        &self,
+        input: RemoveRequestTemplateInput,
+    ) -> ApiResult<RemoveRequestTemplateResponse> {
+        let template_id = HelperMapper::to_uuid(input.template_id)?;
+        let template = self
+            .request_template_service
+            .remove(template_id.as_bytes())?;
+
+        Ok(RemoveRequestTemplateResponse {
+            template: template.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn get_request_template(
+        &self,
+        input: GetRequestTemplateInput,
+    ) -> ApiResult<GetRequestTemplateResponse> {
+        let template_id = HelperMapper::to_uuid(input.template_id)?;
+        let template = self.request_template_service.get(template_id.as_bytes())?;
+
+        Ok(GetRequestTemplateResponse {
+            template: template.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn list_request_templates(
+        &self,
+        input: ListRequestTemplatesInput,
+    ) -> ApiResult<ListRequestTemplatesResponse> {
+        let result = self.request_template_service.list(input)?;
+
+        Ok(ListRequestTemplatesResponse {
+            templates: result.items.into_iter().map(Into::into).collect(),
+            next_offset: result.next_offset,
+            total: result.total,
+        })
+    }
+
+    /// The created request goes through the same policies as a request created directly.
+    #[with_middleware(guard = authorize(&call_context(), &CreateRequestFromTemplateInputRef(&input).to_resources()))]
+    #[with_middleware(tail = use_canister_call_metric("create_request_from_template", &result))]
+    async fn create_request_from_template(
+        &self,
+        input: CreateRequestFromTemplateInput,
+    ) -> ApiResult<CreateRequestResponse> {
+        let ctx = &call_context();
+        let create_request_input = self
+            .request_template_service
+            .to_create_request_input(input)?;
+        let request = self
+            .request_service
+            .create_request(create_request_input, ctx)
+            .await?;
+        let privileges = self
+            .request_service
+            .get_caller_privileges_for_request(&request.id, ctx)
+            .await?;
+        let additional_info = self
+            .request_service
+            .get_request_additional_info(&request, true)?;
+
+        Ok(CreateRequestResponse {
+            request: request.to_dto(),
+            privileges: privileges.into(),
+            additional_info: additional_info.into(),
+        })
+    }
+}
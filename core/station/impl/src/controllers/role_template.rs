@@ -0,0 +1,52 @@
+use crate::{
+    core::middlewares::{authorize, call_context},
+    mappers::HelperMapper,
+    models::resource::{PermissionResourceAction, Resource},
+    services::{RoleTemplateService, ROLE_TEMPLATE_SERVICE},
+};
+use ic_cdk_macros::query;
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::{GetRoleTemplateDiffInput, GetRoleTemplateDiffResponse};
+use std::sync::Arc;
+
+// Canister entrypoints for the controller.
+#[query(name = "get_role_template_diff")]
+async fn get_role_template_diff(
+    input: GetRoleTemplateDiffInput,
+) -> ApiResult<GetRoleTemplateDiffResponse> {
+    CONTROLLER.get_role_template_diff(input).await
+}
+
+// Controller initialization and implementation.
+lazy_static! {
+    static ref CONTROLLER: RoleTemplateController =
+        RoleTemplateController::new(Arc::clone(&ROLE_TEMPLATE_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct RoleTemplateController {
+    role_template_service: Arc<RoleTemplateService>,
+}
+
+impl RoleTemplateController {
+    pub fn new(role_template_service: Arc<RoleTemplateService>) -> Self {
+        Self {
+            role_template_service,
+        }
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Permission(PermissionResourceAction::Read)]))]
+    async fn get_role_template_diff(
+        &self,
+        input: GetRoleTemplateDiffInput,
+    ) -> ApiResult<GetRoleTemplateDiffResponse> {
+        let diff = self.role_template_service.get_role_template_diff(
+            HelperMapper::to_uuid(input.user_group_id)?.as_bytes(),
+            &input.template.into(),
+        )?;
+
+        Ok(GetRoleTemplateDiffResponse { diff: diff.into() })
+    }
+}
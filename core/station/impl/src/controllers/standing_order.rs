@@ -0,0 +1,51 @@
+use crate::{
+    core::middlewares::{authorize, call_context},
+    models::resource::{RequestResourceAction, Resource},
+    services::{StandingOrderService, STANDING_ORDER_SERVICE},
+};
+use ic_cdk_macros::query;
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::{ListUpcomingExecutionsInput, ListUpcomingExecutionsResponse};
+use std::sync::Arc;
+
+#[query(name = "list_upcoming_executions")]
+async fn list_upcoming_executions(
+    input: ListUpcomingExecutionsInput,
+) -> ApiResult<ListUpcomingExecutionsResponse> {
+    CONTROLLER.list_upcoming_executions(input).await
+}
+
+lazy_static! {
+    static ref CONTROLLER: StandingOrderController =
+        StandingOrderController::new(Arc::clone(&STANDING_ORDER_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct StandingOrderController {
+    standing_order_service: Arc<StandingOrderService>,
+}
+
+impl StandingOrderController {
+    pub fn new(standing_order_service: Arc<StandingOrderService>) -> Self {
+        Self {
+            standing_order_service,
+        }
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Request(RequestResourceAction::List)]))]
+    async fn list_upcoming_executions(
+        &self,
+        input: ListUpcomingExecutionsInput,
+    ) -> ApiResult<ListUpcomingExecutionsResponse> {
+        let ctx = call_context();
+        let executions = self
+            .standing_order_service
+            .list_upcoming_executions(input, &ctx)?;
+
+        Ok(ListUpcomingExecutionsResponse {
+            executions: executions.into_iter().map(Into::into).collect(),
+        })
+    }
+}
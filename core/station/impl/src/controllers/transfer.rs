@@ -4,12 +4,13 @@ use crate::{
     models::resource::Resource,
     services::TransferService,
 };
-use ic_cdk_macros::query;
+use ic_cdk_macros::{query, update};
 use lazy_static::lazy_static;
 use orbit_essentials::api::{ApiError, ApiResult};
 use orbit_essentials::with_middleware;
 use station_api::{
-    GetTransfersInput, GetTransfersResponse, ListAccountTransfersInput,
+    EstimateTransferInput, EstimateTransferResponse, GetTransfersInput, GetTransfersResponse,
+    ListAccountActivityInput, ListAccountActivityResponse, ListAccountTransfersInput,
     ListAccountTransfersResponse,
 };
 
@@ -26,6 +27,18 @@ async fn list_account_transfers(
     CONTROLLER.list_account_transfers(input).await
 }
 
+#[query(name = "list_account_activity")]
+async fn list_account_activity(
+    input: ListAccountActivityInput,
+) -> ApiResult<ListAccountActivityResponse> {
+    CONTROLLER.list_account_activity(input).await
+}
+
+#[update(name = "estimate_transfer")]
+async fn estimate_transfer(input: EstimateTransferInput) -> ApiResult<EstimateTransferResponse> {
+    CONTROLLER.estimate_transfer(input).await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
     static ref CONTROLLER: TransferController = TransferController::new(TransferService::default());
@@ -75,4 +88,29 @@ impl TransferController {
                 .collect(),
         })
     }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn list_account_activity(
+        &self,
+        input: ListAccountActivityInput,
+    ) -> ApiResult<ListAccountActivityResponse> {
+        let activity = self.transfer_service.list_account_activity(input)?;
+
+        Ok(ListAccountActivityResponse {
+            activity: activity.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn estimate_transfer(
+        &self,
+        input: EstimateTransferInput,
+    ) -> ApiResult<EstimateTransferResponse> {
+        let estimate = self
+            .transfer_service
+            .estimate_transfer(input, &call_context())
+            .await?;
+
+        Ok(estimate.into())
+    }
 }
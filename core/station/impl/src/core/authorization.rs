@@ -6,8 +6,12 @@ use super::{
 use crate::{
     errors::AuthorizationError,
     models::{
+        permission::{
+            AccessCheckOutcome, AccessDenialReason, AccessExplanation, AccessReason,
+            ResourceAccessCheck,
+        },
         resource::{RequestResourceAction, Resource, ResourceId, UserResourceAction},
-        User,
+        User, UserGroupId,
     },
     repositories::REQUEST_REPOSITORY,
     services::permission::PERMISSION_SERVICE,
@@ -27,37 +31,75 @@ impl Authorization {
         let resources = resource.to_expanded_list();
 
         // Checks if the caller has access to the requested resource.
-        resources.iter().any(|resource| {
-            let permission = PERMISSION_SERVICE.get_permission(resource);
+        resources
+            .iter()
+            .any(|resource| check_resource_access(ctx.user(), resource).is_allowed())
+    }
 
-            // Checks if the resource is public, if so, then the access is granted.
-            if permission.allowed_public() {
-                return true;
-            }
+    /// Explains why the user has or does not have access to the resource, following the same
+    /// rules that are used to authorize the calls of the user.
+    pub fn explain(user: &User, resource: &Resource) -> AccessExplanation {
+        let checks: Vec<ResourceAccessCheck> = resource
+            .to_expanded_list()
+            .into_iter()
+            .map(|expanded_resource| ResourceAccessCheck {
+                outcome: check_resource_access(Some(user), &expanded_resource),
+                resource: expanded_resource,
+            })
+            .collect();
+
+        AccessExplanation {
+            resource: resource.clone(),
+            allowed: checks.iter().any(|check| check.outcome.is_allowed()),
+            checks,
+        }
+    }
+}
 
-            if let Some(user) = ctx.user() {
-                // If the user is not active, then the access is denied.
-                if !user.is_active() {
-                    return false;
-                }
+/// Checks the access of the user to a single resource of the expanded list.
+fn check_resource_access(user: Option<&User>, resource: &Resource) -> AccessCheckOutcome {
+    let permission = PERMISSION_SERVICE.get_permission(resource);
 
-                // If the resource is available to authenticated users, then the access is granted.
-                if permission.allowed_authenticated() {
-                    return true;
-                }
+    // Checks if the resource is public, if so, then the access is granted.
+    if permission.allowed_public() {
+        return AccessCheckOutcome::Allowed(AccessReason::Public);
+    }
 
-                // Validades if the user has access to the resource based on the default rules (non-permission based).
-                if has_default_resource_access(user, resource) {
-                    return true;
-                }
+    let Some(user) = user else {
+        return AccessCheckOutcome::Denied(AccessDenialReason::NotAUser);
+    };
 
-                // Checks if the user has access to the resource based on the system permissions.
-                return permission.is_allowed(user);
-            }
+    // If the user is not active, then the access is denied.
+    if !user.is_active() {
+        return AccessCheckOutcome::Denied(AccessDenialReason::UserNotActive);
+    }
+
+    // If the resource is available to authenticated users, then the access is granted.
+    if permission.allowed_authenticated() {
+        return AccessCheckOutcome::Allowed(AccessReason::Authenticated);
+    }
+
+    // Validades if the user has access to the resource based on the default rules (non-permission based).
+    if has_default_resource_access(user, resource) {
+        return AccessCheckOutcome::Allowed(AccessReason::DefaultAccess);
+    }
+
+    // Checks if the user has access to the resource based on the system permissions.
+    if permission.allowed_users().contains(&user.id) {
+        return AccessCheckOutcome::Allowed(AccessReason::UserGrant);
+    }
+
+    let groups: Vec<UserGroupId> = permission
+        .allowed_user_groups()
+        .into_iter()
+        .filter(|group| user.groups.contains(group))
+        .collect();
 
-            false
-        })
+    if !groups.is_empty() {
+        return AccessCheckOutcome::Allowed(AccessReason::GroupGrant(groups));
     }
+
+    AccessCheckOutcome::Denied(AccessDenialReason::NoMatchingGrant)
 }
 
 /// Checks if the user had access to the resource based on default rules (non-permission based).
@@ -81,10 +123,18 @@ fn has_default_resource_access(user: &User, resource: &Resource) -> bool {
 
             validator.evaluate().unwrap_or(false)
         }
+        Resource::Request(RequestResourceAction::Cancel(ResourceId::Id(request_id))) => {
+            // The requester can withdraw their own request.
+            REQUEST_REPOSITORY.exists_requester(request_id, &user.id)
+        }
         Resource::User(UserResourceAction::Read(ResourceId::Id(user_id))) => {
             // The user has access to their own user record.
             *user_id == user.id
         }
+        Resource::User(UserResourceAction::Delegate(ResourceId::Id(user_id))) => {
+            // The user can delegate their own approval rights.
+            *user_id == user.id
+        }
         _ => false,
     }
 }
@@ -371,4 +421,73 @@ mod tests {
             &Resource::User(UserResourceAction::Read(ResourceId::Any))
         ));
     }
+
+    #[tokio::test]
+    async fn explain_reports_the_reason_of_each_check() {
+        let test_context = setup();
+        let permission = Permission::new(
+            Allow::user_groups(vec![test_context.finance_user_group.id]),
+            Resource::Account(AccountResourceAction::Read(ResourceId::Any)),
+        );
+
+        PERMISSION_REPOSITORY.insert(permission.key(), permission.to_owned());
+
+        let resource = Resource::Account(AccountResourceAction::Read(ResourceId::Id([1; 16])));
+        let explanation = Authorization::explain(&test_context.finance_user, &resource);
+
+        assert!(explanation.allowed);
+        assert_eq!(explanation.resource, resource);
+        assert_eq!(
+            explanation.checks,
+            vec![
+                ResourceAccessCheck {
+                    resource: resource.clone(),
+                    outcome: AccessCheckOutcome::Denied(AccessDenialReason::NoMatchingGrant),
+                },
+                ResourceAccessCheck {
+                    resource: Resource::Account(AccountResourceAction::Read(ResourceId::Any)),
+                    outcome: AccessCheckOutcome::Allowed(AccessReason::GroupGrant(vec![
+                        test_context.finance_user_group.id
+                    ])),
+                },
+            ]
+        );
+
+        let own_user = Resource::User(UserResourceAction::Read(ResourceId::Id(
+            test_context.finance_user.id,
+        )));
+
+        assert_eq!(
+            Authorization::explain(&test_context.finance_user, &own_user).checks[0].outcome,
+            AccessCheckOutcome::Allowed(AccessReason::DefaultAccess)
+        );
+    }
+
+    #[tokio::test]
+    async fn explain_denies_inactive_user() {
+        let mut test_context = setup();
+        let permission = Permission::new(
+            Allow::users(vec![test_context.finance_user.id]),
+            Resource::AddressBook(ResourceAction::Read(ResourceId::Any)),
+        );
+
+        PERMISSION_REPOSITORY.insert(permission.key(), permission.to_owned());
+
+        let resource = Resource::AddressBook(ResourceAction::Read(ResourceId::Any));
+
+        assert_eq!(
+            Authorization::explain(&test_context.finance_user, &resource).checks[0].outcome,
+            AccessCheckOutcome::Allowed(AccessReason::UserGrant)
+        );
+
+        test_context.finance_user.status = UserStatus::Inactive;
+
+        let explanation = Authorization::explain(&test_context.finance_user, &resource);
+
+        assert!(!explanation.allowed);
+        assert_eq!(
+            explanation.checks[0].outcome,
+            AccessCheckOutcome::Denied(AccessDenialReason::UserNotActive)
+        );
+    }
 }
@@ -48,6 +48,14 @@ lazy_static! {
             Allow::user_groups(vec![*ADMIN_GROUP_ID]),
             Resource::User(UserResourceAction::Update(ResourceId::Any)),
         ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::User(UserResourceAction::Remove(ResourceId::Any)),
+        ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::User(UserResourceAction::Delegate(ResourceId::Any)),
+        ),
         // user groups
         (
             Allow::user_groups(vec![*ADMIN_GROUP_ID]),
@@ -108,6 +116,27 @@ lazy_static! {
             Allow::user_groups(vec![*ADMIN_GROUP_ID]),
             Resource::Request(RequestResourceAction::Read(ResourceId::Any)),
         ),
+        // request templates
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::RequestTemplate(ResourceAction::Create),
+        ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::RequestTemplate(ResourceAction::List),
+        ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::RequestTemplate(ResourceAction::Read(ResourceId::Any)),
+        ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::RequestTemplate(ResourceAction::Update(ResourceId::Any)),
+        ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::RequestTemplate(ResourceAction::Delete(ResourceId::Any)),
+        ),
         // address book
         (
             Allow::user_groups(vec![*ADMIN_GROUP_ID]),
@@ -129,6 +158,27 @@ lazy_static! {
             Allow::user_groups(vec![*ADMIN_GROUP_ID]),
             Resource::AddressBook(ResourceAction::Delete(ResourceId::Any)),
         ),
+        // assets
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::Asset(ResourceAction::Create),
+        ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::Asset(ResourceAction::List),
+        ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::Asset(ResourceAction::Read(ResourceId::Any)),
+        ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::Asset(ResourceAction::Update(ResourceId::Any)),
+        ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::Asset(ResourceAction::Delete(ResourceId::Any)),
+        ),
         // accounts
         (
             Allow::user_groups(vec![*ADMIN_GROUP_ID]),
@@ -175,6 +225,10 @@ lazy_static! {
             RequestSpecifier::AddAccount,
             RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), 1)
         ),
+        (
+            RequestSpecifier::BatchTransfer(ResourceIds::Any),
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), 1)
+        ),
         // users
         (
             RequestSpecifier::AddUser,
@@ -184,6 +238,19 @@ lazy_static! {
             RequestSpecifier::EditUser(ResourceIds::Any),
             RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]),1)
         ),
+        (
+            RequestSpecifier::RemoveUser(ResourceIds::Any),
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), 1)
+        ),
+        // the new identity already proved that it is controlled by the user
+        (
+            RequestSpecifier::LinkUserIdentity(ResourceIds::Any),
+            RequestPolicyRule::AutoApproved
+        ),
+        (
+            RequestSpecifier::AddApprovalDelegation,
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), 1)
+        ),
         // address book
         (
             RequestSpecifier::AddAddressBookEntry,
@@ -204,6 +271,19 @@ lazy_static! {
             )
 
         ),
+        // assets
+        (
+            RequestSpecifier::AddAsset,
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), 1)
+        ),
+        (
+            RequestSpecifier::EditAsset(ResourceIds::Any),
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), 1)
+        ),
+        (
+            RequestSpecifier::RemoveAsset(ResourceIds::Any),
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), 1)
+        ),
         // permissions
         (
             RequestSpecifier::EditPermission(ResourceSpecifier::Any),
@@ -249,6 +329,10 @@ lazy_static! {
             )
 
         ),
+        (
+            RequestSpecifier::ApplyRoleTemplate(ResourceIds::Any),
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), 1)
+        ),
         // change canister
         (
             RequestSpecifier::ChangeCanister,
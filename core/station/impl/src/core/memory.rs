@@ -41,6 +41,17 @@ pub const OPERATION_TYPE_TO_REQUEST_ID_INDEX_MEMORY_ID: MemoryId = MemoryId::new
 pub const REQUEST_RESOURCE_INDEX_MEMORY_ID: MemoryId = MemoryId::new(30);
 pub const POLICY_RESOURCE_INDEX_MEMORY_ID: MemoryId = MemoryId::new(31);
 pub const REQUEST_EVALUATION_RESULT_MEMORY_ID: MemoryId = MemoryId::new(32);
+pub const ASSET_MEMORY_ID: MemoryId = MemoryId::new(33);
+pub const ALLOWANCE_MEMORY_ID: MemoryId = MemoryId::new(34);
+pub const STANDING_ORDER_MEMORY_ID: MemoryId = MemoryId::new(35);
+pub const INCOMING_TRANSFER_MEMORY_ID: MemoryId = MemoryId::new(36);
+pub const INCOMING_TRANSFER_ACCOUNT_INDEX_MEMORY_ID: MemoryId = MemoryId::new(37);
+pub const INCOMING_TRANSFER_SYNC_MEMORY_ID: MemoryId = MemoryId::new(38);
+pub const APPROVAL_DELEGATION_MEMORY_ID: MemoryId = MemoryId::new(39);
+pub const REQUEST_COMMENT_MEMORY_ID: MemoryId = MemoryId::new(40);
+pub const REQUEST_COMMENT_REQUEST_INDEX_MEMORY_ID: MemoryId = MemoryId::new(41);
+pub const REQUEST_TEMPLATE_MEMORY_ID: MemoryId = MemoryId::new(42);
+pub const IDENTITY_LINK_MEMORY_ID: MemoryId = MemoryId::new(43);
 
 thread_local! {
   /// Static configuration of the canister.
@@ -83,7 +94,7 @@ pub fn read_system_info() -> SystemInfo {
 pub fn write_system_info(config: SystemInfo) {
     CONFIG.with(|cell| {
         cell.borrow_mut()
-            .set(SystemState::Initialized(config))
+            .set(SystemState::Initialized(Box::new(config)))
             .expect("failed to write system information");
     });
 }
@@ -1,8 +1,5 @@
 //! Core utility features for the canister.
 
-mod assets;
-pub use assets::*;
-
 mod constants;
 pub use constants::*;
 
@@ -1,4 +1,4 @@
-use super::evaluation::Evaluate;
+use super::{evaluation::Evaluate, ic_cdk::next_time};
 use crate::{
     errors::EvaluateError,
     models::{
@@ -9,10 +9,12 @@ use crate::{
         request_specifier::{
             Match, RequestSpecifier, UserInvolvedInPolicyRuleForRequestResource, UserSpecifier,
         },
-        EvaluationStatus, Request, RequestId, RequestStatusCode, User, UserId, UserStatus,
+        BundleOperation, EvaluationStatus, Request, RequestId, RequestOperation, RequestStatusCode,
+        User, UserId, UserStatus,
     },
     repositories::{
-        request_policy::REQUEST_POLICY_REPOSITORY, REQUEST_REPOSITORY, USER_REPOSITORY,
+        request_policy::REQUEST_POLICY_REPOSITORY, APPROVAL_DELEGATION_REPOSITORY,
+        REQUEST_REPOSITORY, USER_REPOSITORY,
     },
 };
 use anyhow::Context;
@@ -37,19 +39,13 @@ impl RequestEvaluator {
             request,
         }
     }
-}
-
-impl Evaluate<RequestEvaluationResult> for RequestEvaluator {
-    fn evaluate(&self) -> Result<RequestEvaluationResult, EvaluateError> {
-        let matching_policies = self
-            .request
-            .operation
-            .to_resources()
-            .iter()
-            .flat_map(|resource| REQUEST_POLICY_REPOSITORY.find_by_resource(resource.to_owned()))
-            .collect::<Vec<_>>();
 
-        if matching_policies.is_empty() {
+    /// Evaluates the request against the given policy rules instead of the stored policies.
+    pub fn evaluate_rules(
+        &self,
+        rules: &[RequestPolicyRule],
+    ) -> Result<RequestEvaluationResult, EvaluateError> {
+        if rules.is_empty() {
             // Since requests handle security critical operations, we want to reject them by default if
             // they don't match any policy. Users need to explicitly add the necessary policies to evaluate them.
             return Ok(RequestEvaluationResult {
@@ -63,11 +59,11 @@ impl Evaluate<RequestEvaluationResult> for RequestEvaluator {
         let mut evaluation_statuses = Vec::new();
 
         // Evaluate all matching policies to get the full evaluation result.
-        for policy in matching_policies {
+        for rule in rules {
             // Evaluate the request policy rule.
             let evaluation_status = self
                 .policy_rule_evaluator
-                .evaluate((request.to_owned(), Arc::new(policy.rule)))
+                .evaluate((request.to_owned(), Arc::new(rule.to_owned())))
                 .context("failed to evaluate policy rule")?;
 
             evaluation_statuses.push(evaluation_status);
@@ -98,6 +94,70 @@ impl Evaluate<RequestEvaluationResult> for RequestEvaluator {
             policy_results: evaluation_statuses,
         })
     }
+
+    /// Evaluates every operation of the bundle against its own policies.
+    ///
+    /// The bundle is only approved when all of its operations are approved, so the strictest
+    /// policy among them applies, and it is rejected as soon as one of them is rejected.
+    fn evaluate_bundle(
+        &self,
+        bundle: &BundleOperation,
+    ) -> Result<RequestEvaluationResult, EvaluateError> {
+        let mut statuses = Vec::new();
+        let mut policy_results = Vec::new();
+
+        for member in bundle.to_member_requests(&self.request) {
+            let evaluation = RequestEvaluator::new(
+                self.request_matcher.to_owned(),
+                self.policy_rule_evaluator.to_owned(),
+                member,
+            )
+            .evaluate()?;
+
+            statuses.push(evaluation.status);
+            policy_results.extend(evaluation.policy_results);
+        }
+
+        Ok(RequestEvaluationResult {
+            request_id: self.request.id,
+            status: {
+                if statuses.is_empty()
+                    || statuses
+                        .iter()
+                        .any(|status| *status == EvaluationStatus::Rejected)
+                {
+                    EvaluationStatus::Rejected
+                } else if statuses
+                    .iter()
+                    .all(|status| *status == EvaluationStatus::Approved)
+                {
+                    EvaluationStatus::Approved
+                } else {
+                    EvaluationStatus::Pending
+                }
+            },
+            policy_results,
+        })
+    }
+}
+
+impl Evaluate<RequestEvaluationResult> for RequestEvaluator {
+    fn evaluate(&self) -> Result<RequestEvaluationResult, EvaluateError> {
+        if let RequestOperation::Bundle(bundle) = &self.request.operation {
+            return self.evaluate_bundle(bundle);
+        }
+
+        let matching_rules = self
+            .request
+            .operation
+            .to_resources()
+            .iter()
+            .flat_map(|resource| REQUEST_POLICY_REPOSITORY.find_by_resource(resource.to_owned()))
+            .map(|policy| policy.rule)
+            .collect::<Vec<_>>();
+
+        self.evaluate_rules(&matching_rules)
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -109,7 +169,8 @@ pub struct PossibleApprovers {
 
 /// Evaluates all possible approvers for the request.
 ///
-/// The possible approvers are the users that match the request's policies.
+/// The possible approvers are the users that match the request's policies and the delegates that
+/// can approve on their behalf.
 pub struct RequestPossibleApproversFinder<'p> {
     pub request_matcher: Arc<dyn Match<(Request, RequestSpecifier)>>,
     pub possible_approvers_policy_rule_evaluator: Arc<
@@ -140,28 +201,32 @@ impl<'p> RequestPossibleApproversFinder<'p> {
             request,
         }
     }
-}
 
-impl Evaluate<HashSet<UUID>> for RequestPossibleApproversFinder<'_> {
-    fn evaluate(&self) -> Result<HashSet<UUID>, EvaluateError> {
+    /// Finds the possible approvers of the request for the given policy rules instead of the stored policies.
+    pub fn find_for_rules(
+        &self,
+        rules: &[RequestPolicyRule],
+    ) -> Result<HashSet<UUID>, EvaluateError> {
+        let mut possible_approvers = self.find_direct_approvers_for_rules(rules)?;
+        let delegates = self.find_delegates(&possible_approvers)?;
+        possible_approvers.extend(delegates);
+
+        Ok(possible_approvers)
+    }
+
+    /// Finds the users that have approval rights of their own for the given policy rules, without
+    /// the delegates of the approvers.
+    pub fn find_direct_approvers_for_rules(
+        &self,
+        rules: &[RequestPolicyRule],
+    ) -> Result<HashSet<UUID>, EvaluateError> {
         let mut possible_approvers = HashSet::new();
         let mut matching_groups = HashSet::new();
-        let mut matching_policies = Vec::new();
-        for policy in REQUEST_POLICY_REPOSITORY.list() {
-            if self
-                .request_matcher
-                .is_match((self.request.to_owned(), policy.specifier.to_owned()))
-                .context("failed to match request")?
-            {
-                matching_policies.push(policy.to_owned());
-            }
-        }
 
-        for policy in matching_policies {
-            let result = self.possible_approvers_policy_rule_evaluator.evaluate((
-                Arc::new(self.request.to_owned()),
-                Arc::new(policy.rule.to_owned()),
-            ))?;
+        for rule in rules {
+            let result = self
+                .possible_approvers_policy_rule_evaluator
+                .evaluate((Arc::new(self.request.to_owned()), Arc::new(rule.to_owned())))?;
 
             if result.match_all {
                 return Ok(USER_REPOSITORY
@@ -204,6 +269,49 @@ impl Evaluate<HashSet<UUID>> for RequestPossibleApproversFinder<'_> {
 
         Ok(possible_approvers)
     }
+
+    /// Finds the delegates of the active delegations that the approvers gave and that cover the request.
+    fn find_delegates(&self, approvers: &HashSet<UUID>) -> Result<HashSet<UUID>, EvaluateError> {
+        let now = next_time();
+        let mut delegates = HashSet::new();
+
+        for approver_id in approvers {
+            for delegation in
+                APPROVAL_DELEGATION_REPOSITORY.find_active_by_delegator(approver_id, now)
+            {
+                if let Some(specifier) = &delegation.specifier {
+                    if !self
+                        .request_matcher
+                        .is_match((self.request.to_owned(), specifier.to_owned()))
+                        .context("failed to match request")?
+                    {
+                        continue;
+                    }
+                }
+
+                delegates.insert(delegation.delegate_id);
+            }
+        }
+
+        Ok(delegates)
+    }
+}
+
+impl Evaluate<HashSet<UUID>> for RequestPossibleApproversFinder<'_> {
+    fn evaluate(&self) -> Result<HashSet<UUID>, EvaluateError> {
+        let mut matching_rules = Vec::new();
+        for policy in REQUEST_POLICY_REPOSITORY.list() {
+            if self
+                .request_matcher
+                .is_match((self.request.to_owned(), policy.specifier.to_owned()))
+                .context("failed to match request")?
+            {
+                matching_rules.push(policy.rule);
+            }
+        }
+
+        self.find_for_rules(&matching_rules)
+    }
 }
 
 pub struct RequestPossibleApproversRequestPolicyRuleEvaluator;
@@ -239,9 +347,35 @@ impl
                     Ok(possible_approvers)
                 }
             },
-            RequestPolicyRule::AllowListed | RequestPolicyRule::AllowListedByMetadata(_) => {
+            RequestPolicyRule::WeightedQuorum(quorum) => {
+                for approver_weight in quorum.weights.iter() {
+                    if approver_weight.weight == 0 {
+                        continue;
+                    }
+
+                    match &approver_weight.approvers {
+                        UserSpecifier::Any => {
+                            possible_approvers.match_all = true;
+                            break;
+                        }
+                        UserSpecifier::Id(user_ids) => {
+                            possible_approvers.users.extend(user_ids.to_owned())
+                        }
+                        UserSpecifier::Group(group_ids) => {
+                            possible_approvers.groups.extend(group_ids.to_owned())
+                        }
+                    }
+                }
+
                 Ok(possible_approvers)
             }
+            RequestPolicyRule::AllowListed
+            | RequestPolicyRule::AllowListedByMetadata(_)
+            | RequestPolicyRule::AllowListedWithinLimit(_)
+            | RequestPolicyRule::AllowListedWithinRollingLimit(_)
+            | RequestPolicyRule::AmountAtMost(_)
+            | RequestPolicyRule::AmountAbove(_)
+            | RequestPolicyRule::TimeLock(_) => Ok(possible_approvers),
             RequestPolicyRule::And(criterias) | RequestPolicyRule::Or(criterias) => {
                 for criteria in criterias.iter() {
                     let result = self.evaluate((request.clone(), Arc::new(criteria.clone())));
@@ -287,6 +421,8 @@ impl
 /// - The request is not approved or rejected
 /// - There are matching policies for the request and the user is a part of the group that is allowed to approve
 /// - The user has not already approved on the request
+///
+/// Users can also approve on behalf of the users that delegated their approval rights to them.
 pub struct RequestApprovalRightsEvaluator {
     pub request_matcher: Arc<dyn Match<(Request, RequestSpecifier)>>,
     pub approval_rights_evaluator: Arc<ApprovalRightsEvaluate>,
@@ -314,11 +450,54 @@ impl RequestApprovalRightsEvaluator {
             request_id,
         }
     }
-}
 
-impl Evaluate<bool> for RequestApprovalRightsEvaluator {
-    fn evaluate(&self) -> Result<bool, EvaluateError> {
-        if REQUEST_REPOSITORY.exists_approver(&self.request_id, &self.approver_id)
+    /// Finds the users whose approval rights the approver can use on the request.
+    ///
+    /// The approver comes first when they have their own rights, followed by the delegators of the
+    /// active delegations that the approver received and that cover the request.
+    pub fn find_rights_owners(&self) -> Result<Vec<UserId>, EvaluateError> {
+        let mut owners = Vec::new();
+
+        if self.has_approval_rights(&self.approver_id)? {
+            owners.push(self.approver_id);
+        }
+
+        let delegations =
+            APPROVAL_DELEGATION_REPOSITORY.find_active_by_delegate(&self.approver_id, next_time());
+
+        if delegations.is_empty() {
+            return Ok(owners);
+        }
+
+        let Some(request) = REQUEST_REPOSITORY.get(&Request::key(self.request_id)) else {
+            return Ok(owners);
+        };
+
+        for delegation in delegations {
+            if owners.contains(&delegation.delegator_id) {
+                continue;
+            }
+
+            if let Some(specifier) = &delegation.specifier {
+                if !self
+                    .request_matcher
+                    .is_match((request.to_owned(), specifier.to_owned()))
+                    .context("failed to match request")?
+                {
+                    continue;
+                }
+            }
+
+            if self.has_approval_rights(&delegation.delegator_id)? {
+                owners.push(delegation.delegator_id);
+            }
+        }
+
+        Ok(owners)
+    }
+
+    fn has_approval_rights(&self, user_id: &UserId) -> Result<bool, EvaluateError> {
+        if REQUEST_REPOSITORY.exists_approver(&self.request_id, user_id)
             || !REQUEST_REPOSITORY.exists_status(&self.request_id, RequestStatusCode::Created)
         {
             return Ok(false);
@@ -333,7 +512,7 @@ impl Evaluate<bool> for RequestApprovalRightsEvaluator {
         for policy in matching_policies {
             if self.approval_rights_evaluator.evaluate((
                 Arc::new(self.request_id.to_owned()),
-                Arc::new(self.approver_id),
+                Arc::new(user_id.to_owned()),
                 Arc::new(policy.rule.to_owned()),
             ))? {
                 return Ok(true);
@@ -344,6 +523,12 @@ impl Evaluate<bool> for RequestApprovalRightsEvaluator {
     }
 }
 
+impl Evaluate<bool> for RequestApprovalRightsEvaluator {
+    fn evaluate(&self) -> Result<bool, EvaluateError> {
+        Ok(!self.find_rights_owners()?.is_empty())
+    }
+}
+
 pub struct RequesApprovalRightsRequestPolicyRuleEvaluator {
     pub approver_matcher: Arc<dyn Match<UserInvolvedInPolicyRuleForRequestResource>>,
 }
@@ -374,9 +559,33 @@ impl
 
                 Ok(can_approve)
             }
-            RequestPolicyRule::AllowListed | RequestPolicyRule::AllowListedByMetadata(_) => {
+            RequestPolicyRule::WeightedQuorum(quorum) => {
+                for approver_weight in quorum.weights.iter() {
+                    if approver_weight.weight > 0
+                        && self
+                            .approver_matcher
+                            .is_match(UserInvolvedInPolicyRuleForRequestResource {
+                                request_operation_resources: REQUEST_REPOSITORY
+                                    .get_resources(&request_id),
+                                policy_rule_user_specifier: approver_weight.approvers.to_owned(),
+                                user_id: approver_id.as_ref().to_owned(),
+                                request_id: request_id.as_ref().to_owned(),
+                            })
+                            .context("failed to match request approvers")?
+                    {
+                        return Ok(true);
+                    }
+                }
+
                 Ok(false)
             }
+            RequestPolicyRule::AllowListed
+            | RequestPolicyRule::AllowListedByMetadata(_)
+            | RequestPolicyRule::AllowListedWithinLimit(_)
+            | RequestPolicyRule::AllowListedWithinRollingLimit(_)
+            | RequestPolicyRule::AmountAtMost(_)
+            | RequestPolicyRule::AmountAbove(_)
+            | RequestPolicyRule::TimeLock(_) => Ok(false),
             RequestPolicyRule::And(criterias) | RequestPolicyRule::Or(criterias) => {
                 let request = &request_id;
                 let approver_id = &approver_id;
@@ -426,7 +635,8 @@ mod tests {
             user_test_utils::{self, mock_user},
             Account, AccountKey, AddUserGroupOperation, AddUserGroupOperationInput, Blockchain,
             BlockchainStandard, EvaluatedRequestPolicyRule, Metadata, MetadataItem, Percentage,
-            RequestOperation, RequestPolicy, RequestStatus, ADMIN_GROUP_ID,
+            RemoveUserGroupOperation, RemoveUserGroupOperationInput, RequestOperation,
+            RequestPolicy, RequestStatus, ADMIN_GROUP_ID, ICP_ASSET_ID,
         },
         repositories::{
             request_policy::REQUEST_POLICY_REPOSITORY, ACCOUNT_REPOSITORY,
@@ -523,6 +733,55 @@ mod tests {
         assert_eq!(result.status, EvaluationStatus::Pending);
     }
 
+    #[tokio::test]
+    async fn bundle_is_pending_until_all_operations_are_approved() {
+        let mut request = mock_request();
+        let user = user_test_utils::add_user(&[1; 16]);
+        user_test_utils::add_user(&[2; 16]);
+
+        request.operation = RequestOperation::Bundle(BundleOperation {
+            operations: vec![
+                RequestOperation::AddUserGroup(AddUserGroupOperation {
+                    user_group_id: None,
+                    input: AddUserGroupOperationInput {
+                        name: "test".to_string(),
+                    },
+                }),
+                RequestOperation::RemoveUserGroup(RemoveUserGroupOperation {
+                    input: RemoveUserGroupOperationInput {
+                        user_group_id: [3; 16],
+                    },
+                }),
+            ],
+        });
+        request.requested_by = user.id;
+        request.approvals = vec![mock_approved_with_user(user.id)];
+
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        let mut add_policy = mock_request_policy();
+        add_policy.specifier = RequestSpecifier::AddUserGroup;
+        add_policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Any, 1);
+        REQUEST_POLICY_REPOSITORY.insert(add_policy.id, add_policy.clone());
+
+        let mut remove_policy = mock_request_policy();
+        remove_policy.specifier = RequestSpecifier::RemoveUserGroup(ResourceIds::Any);
+        remove_policy.rule =
+            RequestPolicyRule::QuorumPercentage(UserSpecifier::Any, Percentage(100));
+        REQUEST_POLICY_REPOSITORY.insert(remove_policy.id, remove_policy.clone());
+
+        let evaluator = RequestEvaluator {
+            request: request.to_owned(),
+            request_matcher: REQUEST_MATCHER.to_owned(),
+            policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+        };
+
+        let result = evaluator.evaluate().unwrap();
+
+        assert_eq!(result.status, EvaluationStatus::Pending);
+        assert_eq!(result.policy_results.len(), 2);
+    }
+
     #[tokio::test]
     async fn is_rejected_when_approved_is_not_reached() {
         let mut request = mock_request();
@@ -653,6 +912,7 @@ mod tests {
             AccountKey { id: [1; 16] },
             Account {
                 id: [1; 16],
+                asset_id: *ICP_ASSET_ID,
                 blockchain: Blockchain::InternetComputer,
                 address: "a".to_owned(),
                 standard: BlockchainStandard::Native,
@@ -743,6 +1003,7 @@ mod tests {
                     request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
                     decision: RequestApprovalStatusDTO::Approved,
                     reason: None,
+                    on_behalf_of: None,
                 },
                 &call_context(),
             )
@@ -12,8 +12,8 @@ use crate::{
     },
     repositories::{
         permission::PERMISSION_REPOSITORY, request_policy::REQUEST_POLICY_REPOSITORY,
-        ACCOUNT_REPOSITORY, ADDRESS_BOOK_REPOSITORY, REQUEST_REPOSITORY, USER_GROUP_REPOSITORY,
-        USER_REPOSITORY,
+        ACCOUNT_REPOSITORY, ADDRESS_BOOK_REPOSITORY, ALLOWANCE_REPOSITORY, ASSET_REPOSITORY,
+        REQUEST_REPOSITORY, REQUEST_TEMPLATE_REPOSITORY, USER_GROUP_REPOSITORY, USER_REPOSITORY,
     },
     services::SYSTEM_SERVICE,
 };
@@ -142,6 +142,34 @@ impl EnsureIdExists<UUID> for EnsureAddressBookEntry {
 
 impl EnsureResourceIdExists for EnsureAddressBookEntry {}
 
+pub struct EnsureAsset {}
+
+impl EnsureIdExists<UUID> for EnsureAsset {
+    fn id_exists(id: &UUID) -> Result<(), RecordValidationError> {
+        ensure_entry_exists(ASSET_REPOSITORY.to_owned(), *id).ok_or(
+            RecordValidationError::NotFound {
+                model_name: "Asset".to_string(),
+                id: Uuid::from_bytes(*id).hyphenated().to_string(),
+            },
+        )
+    }
+}
+
+impl EnsureResourceIdExists for EnsureAsset {}
+
+pub struct EnsureAllowance {}
+
+impl EnsureIdExists<UUID> for EnsureAllowance {
+    fn id_exists(id: &UUID) -> Result<(), RecordValidationError> {
+        ensure_entry_exists(ALLOWANCE_REPOSITORY.to_owned(), *id).ok_or(
+            RecordValidationError::NotFound {
+                model_name: "Allowance".to_string(),
+                id: Uuid::from_bytes(*id).hyphenated().to_string(),
+            },
+        )
+    }
+}
+
 pub struct EnsureRequest {}
 
 impl EnsureIdExists<UUID> for EnsureRequest {
@@ -157,6 +185,21 @@ impl EnsureIdExists<UUID> for EnsureRequest {
 
 impl EnsureResourceIdExists for EnsureRequest {}
 
+pub struct EnsureRequestTemplate {}
+
+impl EnsureIdExists<UUID> for EnsureRequestTemplate {
+    fn id_exists(id: &UUID) -> Result<(), RecordValidationError> {
+        ensure_entry_exists(REQUEST_TEMPLATE_REPOSITORY.to_owned(), *id).ok_or(
+            RecordValidationError::NotFound {
+                model_name: "RequestTemplate".to_string(),
+                id: Uuid::from_bytes(*id).hyphenated().to_string(),
+            },
+        )
+    }
+}
+
+impl EnsureResourceIdExists for EnsureRequestTemplate {}
+
 pub struct EnsureRequestPolicy {}
 
 impl EnsureIdExists<UUID> for EnsureRequestPolicy {
@@ -24,6 +24,9 @@ pub enum AddressBookError {
         r#"The adress length is out of range, it must be between {min_length} and {max_length}."#
     )]
     InvalidAddressLength { min_length: u16, max_length: u16 },
+    /// The given address is not well formed for its blockchain and standard.
+    #[error(r#"The address `{address}` is invalid: {reason}"#)]
+    InvalidAddress { address: String, reason: String },
     /// The given blockchain is unknown to the system.
     #[error(r#"The given blockchain is unknown to the system."#)]
     UnknownBlockchain { blockchain: String },
@@ -67,6 +70,11 @@ impl DetailableError for AddressBookError {
                 details.insert("max_length".to_string(), max_length.to_string());
                 Some(details)
             }
+            AddressBookError::InvalidAddress { address, reason } => {
+                details.insert("address".to_string(), address.to_string());
+                details.insert("reason".to_string(), reason.to_string());
+                Some(details)
+            }
             AddressBookError::UnknownBlockchain { blockchain } => {
                 details.insert("blockchain".to_string(), blockchain.to_string());
                 Some(details)
@@ -0,0 +1,57 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for allowance errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum AllowanceError {
+    /// The requested allowance was not found.
+    #[error(r#"The allowance with id {id} was not found."#)]
+    NotFound { id: String },
+    /// Allowances can only be approved by accounts that hold tokens of an ICRC-2 capable ledger.
+    #[error(r#"The account standard {standard} does not support allowances."#)]
+    UnsupportedAccountStandard { standard: String },
+    /// The allowance was not granted by the given account.
+    #[error(r#"The allowance {allowance_id} does not belong to the account {account_id}."#)]
+    AccountMismatch {
+        allowance_id: String,
+        account_id: String,
+    },
+    /// The allowance has already been revoked.
+    #[error(r#"The allowance with id {id} has already been revoked."#)]
+    AlreadyRevoked { id: String },
+    /// The ledger has rejected the approval.
+    #[error(r#"The allowance approval has failed."#)]
+    ApproveFailed { info: String },
+    /// The allowance has failed validation.
+    #[error(r#"The allowance has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for AllowanceError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            AllowanceError::NotFound { id } | AllowanceError::AlreadyRevoked { id } => {
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
+            AllowanceError::UnsupportedAccountStandard { standard } => {
+                details.insert("standard".to_string(), standard.to_string());
+                Some(details)
+            }
+            AllowanceError::AccountMismatch {
+                allowance_id,
+                account_id,
+            } => {
+                details.insert("allowance_id".to_string(), allowance_id.to_string());
+                details.insert("account_id".to_string(), account_id.to_string());
+                Some(details)
+            }
+            AllowanceError::ApproveFailed { info } | AllowanceError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+        }
+    }
+}
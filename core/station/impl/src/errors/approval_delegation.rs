@@ -0,0 +1,51 @@
+use super::{RecordValidationError, ValidationError};
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for approval delegation errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum ApprovalDelegationError {
+    /// The user is not allowed to approve on behalf of the given user.
+    #[error(r#"The user has no delegation to approve on behalf of {delegator_id}."#)]
+    NotDelegated { delegator_id: String },
+    /// The approval delegation has failed validation.
+    #[error(r#"The approval delegation has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for ApprovalDelegationError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            ApprovalDelegationError::NotDelegated { delegator_id } => {
+                details.insert("delegator_id".to_string(), delegator_id.to_string());
+                Some(details)
+            }
+            ApprovalDelegationError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+        }
+    }
+}
+
+impl From<RecordValidationError> for ApprovalDelegationError {
+    fn from(err: RecordValidationError) -> ApprovalDelegationError {
+        match err {
+            RecordValidationError::NotFound { id, model_name } => {
+                ApprovalDelegationError::ValidationError {
+                    info: format!("Invalid UUID: {} {} not found", model_name, id),
+                }
+            }
+        }
+    }
+}
+
+impl From<ValidationError> for ApprovalDelegationError {
+    fn from(err: ValidationError) -> ApprovalDelegationError {
+        ApprovalDelegationError::ValidationError {
+            info: err.to_string(),
+        }
+    }
+}
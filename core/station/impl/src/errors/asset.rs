@@ -0,0 +1,86 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for asset errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum AssetError {
+    /// The requested asset was not found.
+    #[error(r#"The asset with id {id} was not found."#)]
+    NotFound { id: String },
+    /// An asset with the same symbol already exists for the given blockchain and standard.
+    #[error(r#"The asset {symbol} already exists for {blockchain} {standard}."#)]
+    AlreadyExists {
+        symbol: String,
+        blockchain: String,
+        standard: String,
+    },
+    /// The asset is still referenced by accounts and cannot be removed.
+    #[error(r#"The asset is still used by {accounts} account(s) and cannot be removed."#)]
+    AssetInUse { accounts: usize },
+    /// The asset symbol length is out of range.
+    #[error(r#"The asset symbol length is out of range, it must be between {min_length} and {max_length}."#)]
+    InvalidSymbolLength { min_length: u8, max_length: u8 },
+    /// The asset name length is out of range.
+    #[error(r#"The asset name length is out of range, it must be between {min_length} and {max_length}."#)]
+    InvalidNameLength { min_length: u8, max_length: u8 },
+    /// The blockchain does not support the given standard.
+    #[error(r#"The blockchain {blockchain} does not support the {standard} standard."#)]
+    UnsupportedStandard {
+        blockchain: String,
+        standard: String,
+    },
+    /// The asset has failed validation.
+    #[error(r#"The asset has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for AssetError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            AssetError::NotFound { id } => {
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
+            AssetError::AlreadyExists {
+                symbol,
+                blockchain,
+                standard,
+            } => {
+                details.insert("symbol".to_string(), symbol.to_string());
+                details.insert("blockchain".to_string(), blockchain.to_string());
+                details.insert("standard".to_string(), standard.to_string());
+                Some(details)
+            }
+            AssetError::AssetInUse { accounts } => {
+                details.insert("accounts".to_string(), accounts.to_string());
+                Some(details)
+            }
+            AssetError::InvalidSymbolLength {
+                min_length,
+                max_length,
+            }
+            | AssetError::InvalidNameLength {
+                min_length,
+                max_length,
+            } => {
+                details.insert("min_length".to_string(), min_length.to_string());
+                details.insert("max_length".to_string(), max_length.to_string());
+                Some(details)
+            }
+            AssetError::UnsupportedStandard {
+                blockchain,
+                standard,
+            } => {
+                details.insert("blockchain".to_string(), blockchain.to_string());
+                details.insert("standard".to_string(), standard.to_string());
+                Some(details)
+            }
+            AssetError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+        }
+    }
+}
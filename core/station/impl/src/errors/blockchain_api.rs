@@ -17,6 +17,9 @@ pub enum BlockchainApiError {
     /// The to address is invalid.
     #[error("The to address '{address}' is invalid: {error}")]
     InvalidToAddress { address: String, error: String },
+    /// The account metadata required by the blockchain api is missing or invalid.
+    #[error(r#"The account metadata `{key}` is invalid: {info}"#)]
+    InvalidAccountMetadata { key: String, info: String },
 }
 
 impl DetailableError for BlockchainApiError {
@@ -40,6 +43,11 @@ impl DetailableError for BlockchainApiError {
                 details.insert("error".to_string(), error.to_string());
                 Some(details)
             }
+            BlockchainApiError::InvalidAccountMetadata { key, info } => {
+                details.insert("key".to_string(), key.to_string());
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
         }
     }
 }
@@ -0,0 +1,61 @@
+use super::{RecordValidationError, ValidationError};
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for identity link errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum IdentityLinkError {
+    /// The identity link was not found.
+    #[error(r#"The identity link {id} was not found."#)]
+    NotFound { id: String },
+    /// The identity link can no longer be confirmed.
+    #[error(r#"The identity link {id} has expired."#)]
+    Expired { id: String },
+    /// Only the new identity of the link can confirm it.
+    #[error(r#"Only the identity that is being linked can confirm the link."#)]
+    NotNewIdentity,
+    /// The caller user can not link new identities.
+    #[error(r#"Only active users can link new identities."#)]
+    UserNotActive,
+    /// The identity link has failed validation.
+    #[error(r#"The identity link has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for IdentityLinkError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            IdentityLinkError::NotFound { id } | IdentityLinkError::Expired { id } => {
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
+            IdentityLinkError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<RecordValidationError> for IdentityLinkError {
+    fn from(err: RecordValidationError) -> IdentityLinkError {
+        match err {
+            RecordValidationError::NotFound { id, model_name } => {
+                IdentityLinkError::ValidationError {
+                    info: format!("Invalid UUID: {} {} not found", model_name, id),
+                }
+            }
+        }
+    }
+}
+
+impl From<ValidationError> for IdentityLinkError {
+    fn from(err: ValidationError) -> IdentityLinkError {
+        IdentityLinkError::ValidationError {
+            info: err.to_string(),
+        }
+    }
+}
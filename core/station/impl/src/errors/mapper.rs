@@ -17,12 +17,6 @@ pub enum MapperError {
         blockchain: String,
         supported_standards: Vec<String>,
     },
-    /// Cannot set the symbol for native assets.
-    #[error(r#"Cannot set the symbol for native assets."#)]
-    NativeAccountSymbolMetadataNotAllowed,
-    /// Accounts for non native assets are required to have a defined token symbol.
-    #[error(r#"Accounts for non native assets are required to have a defined token symbol."#)]
-    NonNativeAccountSymbolRequired,
     /// The provided format is not compatible with a UUID.
     #[error(r#"The provided format is not compatible with a UUID."#)]
     MalformedUuid {
@@ -100,7 +94,6 @@ impl DetailableError for MapperError {
                 details.insert("info".to_string(), info.to_string());
                 Some(details)
             }
-            _ => None,
         }
     }
 }
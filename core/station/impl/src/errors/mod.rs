@@ -7,6 +7,14 @@ pub use account::*;
 
 mod address_book;
 pub use address_book::*;
+mod allowance;
+pub use allowance::*;
+
+mod approval_delegation;
+pub use approval_delegation::*;
+
+mod asset;
+pub use asset::*;
 
 mod metadata;
 pub use metadata::*;
@@ -14,6 +22,12 @@ pub use metadata::*;
 mod user;
 pub use user::*;
 
+mod identity_link;
+pub use identity_link::*;
+
+mod recovery;
+pub use recovery::*;
+
 mod user_group;
 pub use user_group::*;
 
@@ -41,6 +55,15 @@ pub use request::*;
 mod request_execute;
 pub use request_execute::*;
 
+mod request_comment;
+pub use request_comment::*;
+
+mod request_template;
+pub use request_template::*;
+
+mod standing_order;
+pub use standing_order::*;
+
 mod evaluate;
 pub use evaluate::*;
 
@@ -65,5 +88,8 @@ pub use external_canister::*;
 mod request_policy;
 pub use request_policy::*;
 
+mod permission;
+pub use permission::*;
+
 mod validation;
 pub use validation::*;
@@ -0,0 +1,23 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for permission errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum PermissionError {
+    /// The change leaves no user with access to the resource.
+    #[error(r#"The change leaves no user with access to `{resource}`."#)]
+    LockOut { resource: String },
+}
+
+impl DetailableError for PermissionError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            PermissionError::LockOut { resource } => {
+                details.insert("resource".to_string(), resource.to_string());
+                Some(details)
+            }
+        }
+    }
+}
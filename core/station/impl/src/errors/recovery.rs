@@ -0,0 +1,47 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for station recovery errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum RecoveryError {
+    /// The station has no recovery guardians.
+    #[error(r#"The station has no recovery guardians configured."#)]
+    NotConfigured,
+    /// The caller is not a recovery guardian.
+    #[error(r#"Only recovery guardians can submit recovery actions."#)]
+    NotGuardian,
+    /// Another recovery action is already pending.
+    #[error(r#"Another recovery action is already pending until {expires_at}."#)]
+    RecoveryInProgress { expires_at: String },
+    /// There is no pending recovery.
+    #[error(r#"There is no pending recovery."#)]
+    NoRecoveryInProgress,
+    /// The recovery action failed to execute.
+    #[error(r#"The recovery action failed to execute: {reason}"#)]
+    ExecutionFailed { reason: String },
+    /// The recovery configuration has failed validation.
+    #[error(r#"The recovery configuration has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for RecoveryError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            RecoveryError::RecoveryInProgress { expires_at } => {
+                details.insert("expires_at".to_string(), expires_at.to_string());
+                Some(details)
+            }
+            RecoveryError::ExecutionFailed { reason } => {
+                details.insert("reason".to_string(), reason.to_string());
+                Some(details)
+            }
+            RecoveryError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+            _ => None,
+        }
+    }
+}
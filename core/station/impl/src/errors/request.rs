@@ -24,6 +24,12 @@ pub enum RequestError {
     /// You can't add your approval decision to the request.
     #[error(r#"You can't add your approval decision to the request."#)]
     ApprovalNotAllowed,
+    /// The approver has changed their decision too many times.
+    #[error(r#"The decision can't be changed more than {max_changes} times."#)]
+    ApprovalChangeLimitReached { max_changes: u8 },
+    /// Only the approvers of a time locked request can veto it before it is executed.
+    #[error(r#"You can't veto the request."#)]
+    VetoNotAllowed,
     /// Request execution failed due to {reason}.
     #[error(r#"Request execution failed due to `{reason}`."#)]
     ExecutionError { reason: String },
@@ -54,6 +60,10 @@ impl DetailableError for RequestError {
                 details.insert("max_len".to_string(), max_len.to_string());
                 Some(details)
             }
+            RequestError::ApprovalChangeLimitReached { max_changes } => {
+                details.insert("max_changes".to_string(), max_changes.to_string());
+                Some(details)
+            }
             RequestError::ValidationError { info } => {
                 details.insert("info".to_string(), info.to_string());
                 Some(details)
@@ -0,0 +1,53 @@
+use super::RecordValidationError;
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for request comment errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum RequestCommentError {
+    /// The requested comment was not found.
+    #[error(r#"The comment with id {id} was not found."#)]
+    NotFound { id: String },
+    /// Only the author of the comment can edit it.
+    #[error(r#"The comment with id {id} can only be edited by its author."#)]
+    NotAuthor { id: String },
+    /// The mentioned user can't read the request of the comment.
+    #[error(r#"The user with id {user_id} can't be mentioned since they don't have access to the request."#)]
+    MentionNotAllowed { user_id: String },
+    /// The comment has failed validation.
+    #[error(r#"The comment has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for RequestCommentError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            RequestCommentError::NotFound { id } | RequestCommentError::NotAuthor { id } => {
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
+            RequestCommentError::MentionNotAllowed { user_id } => {
+                details.insert("user_id".to_string(), user_id.to_string());
+                Some(details)
+            }
+            RequestCommentError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+        }
+    }
+}
+
+impl From<RecordValidationError> for RequestCommentError {
+    fn from(err: RecordValidationError) -> RequestCommentError {
+        match err {
+            RecordValidationError::NotFound { id, model_name } => {
+                RequestCommentError::ValidationError {
+                    info: format!("Invalid UUID: {} {} not found", model_name, id),
+                }
+            }
+        }
+    }
+}
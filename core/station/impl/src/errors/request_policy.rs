@@ -9,6 +9,15 @@ pub enum RequestPolicyError {
     /// The request policy has failed validation.
     #[error(r#"The request policy has failed validation."#)]
     ValidationError { info: String },
+    /// The request policy rule can never approve a request.
+    #[error(r#"The request policy rule can never approve a request: {info}"#)]
+    UnsatisfiableRule { info: String },
+    /// The change leaves no one able to approve the requests for the resource.
+    #[error(r#"The change leaves no one able to approve the requests for `{resource}`."#)]
+    LockOut { resource: String },
+    /// The change leaves the requests for the resource approved without any approval.
+    #[error(r#"The change leaves the requests for `{resource}` approved without any approval."#)]
+    ApprovedWithoutApprovers { resource: String },
 }
 
 impl DetailableError for RequestPolicyError {
@@ -19,6 +28,18 @@ impl DetailableError for RequestPolicyError {
                 details.insert("info".to_string(), info.to_string());
                 Some(details)
             }
+            RequestPolicyError::UnsatisfiableRule { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+            RequestPolicyError::LockOut { resource } => {
+                details.insert("resource".to_string(), resource.to_string());
+                Some(details)
+            }
+            RequestPolicyError::ApprovedWithoutApprovers { resource } => {
+                details.insert("resource".to_string(), resource.to_string());
+                Some(details)
+            }
         }
     }
 }
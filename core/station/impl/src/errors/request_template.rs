@@ -0,0 +1,54 @@
+use super::RecordValidationError;
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for request template errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum RequestTemplateError {
+    /// The requested template was not found.
+    #[error(r#"The request template with id {id} was not found."#)]
+    NotFound { id: String },
+    /// The template leaves the parameter open and it was not provided.
+    #[error(r#"The request template requires the parameter {name}."#)]
+    MissingParameter { name: String },
+    /// The template has a fixed value for the parameter, it cannot be provided.
+    #[error(r#"The request template has a fixed value for the parameter {name}."#)]
+    FixedParameter { name: String },
+    /// The template has failed validation.
+    #[error(r#"The request template has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for RequestTemplateError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            RequestTemplateError::NotFound { id } => {
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
+            RequestTemplateError::MissingParameter { name }
+            | RequestTemplateError::FixedParameter { name } => {
+                details.insert("name".to_string(), name.to_string());
+                Some(details)
+            }
+            RequestTemplateError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+        }
+    }
+}
+
+impl From<RecordValidationError> for RequestTemplateError {
+    fn from(err: RecordValidationError) -> RequestTemplateError {
+        match err {
+            RecordValidationError::NotFound { id, model_name } => {
+                RequestTemplateError::ValidationError {
+                    info: format!("Invalid UUID: {} {} not found", model_name, id),
+                }
+            }
+        }
+    }
+}
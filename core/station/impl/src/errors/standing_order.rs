@@ -0,0 +1,41 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for standing order errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum StandingOrderError {
+    /// The requested standing order was not found.
+    #[error(r#"The standing order of request {request_id} was not found."#)]
+    NotFound { request_id: String },
+    /// The standing order has already finished or was cancelled.
+    #[error(r#"The standing order of request {request_id} is no longer active."#)]
+    NotActive { request_id: String },
+    /// Recurring execution plans are only supported by some request operations.
+    #[error(r#"The operation {operation} does not support recurring execution."#)]
+    UnsupportedOperation { operation: String },
+    /// The standing order has failed validation.
+    #[error(r#"The standing order has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for StandingOrderError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            StandingOrderError::NotFound { request_id }
+            | StandingOrderError::NotActive { request_id } => {
+                details.insert("request_id".to_string(), request_id.to_string());
+                Some(details)
+            }
+            StandingOrderError::UnsupportedOperation { operation } => {
+                details.insert("operation".to_string(), operation.to_string());
+                Some(details)
+            }
+            StandingOrderError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+        }
+    }
+}
@@ -16,6 +16,11 @@ pub enum TransferError {
     /// Transfer execution failed due to {reason}.
     #[error(r#"Transfer execution failed due to `{reason}`."#)]
     ExecutionError { reason: String },
+    /// The account balance does not cover the amount and fee of the transfer.
+    #[error(
+        r#"The account balance of {balance} is insufficient, the transfer requires {required}."#
+    )]
+    InsufficientBalance { balance: String, required: String },
 }
 
 impl DetailableError for TransferError {
@@ -38,6 +43,11 @@ impl DetailableError for TransferError {
                 details.insert("reason".to_string(), reason.to_string());
                 Some(details)
             }
+            TransferError::InsufficientBalance { balance, required } => {
+                details.insert("balance".to_string(), balance.to_string());
+                details.insert("required".to_string(), required.to_string());
+                Some(details)
+            }
         }
     }
 }
@@ -79,6 +79,15 @@ pub enum UserError {
     // error for when non existent user group is getting added
     #[error(r#"The user group {group_id} does not exist."#)]
     UserGroupDoesNotExist { group_id: String },
+    /// The user was removed and can no longer be changed.
+    #[error(r#"The user {user} was removed and can no longer be changed."#)]
+    UserArchived {
+        /// The removed user.
+        user: String,
+    },
+    /// Users can only be archived by removing them.
+    #[error(r#"Users can only be archived by removing them."#)]
+    ArchivedStatusNotAllowed,
 }
 
 impl DetailableError for UserError {
@@ -129,6 +138,10 @@ impl DetailableError for UserError {
                 details.insert("user".to_string(), user.to_string());
                 Some(details)
             }
+            UserError::UserArchived { user } => {
+                details.insert("user".to_string(), user.to_string());
+                Some(details)
+            }
             _ => None,
         }
     }
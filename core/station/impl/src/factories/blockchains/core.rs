@@ -1,4 +1,4 @@
-use super::InternetComputer;
+use super::{InternetComputer, InternetComputerIcrc1};
 use crate::{
     errors::FactoryError,
     models::{Account, Blockchain, BlockchainStandard, Metadata, Transfer},
@@ -6,6 +6,7 @@ use crate::{
 use async_trait::async_trait;
 use num_bigint::BigUint;
 use orbit_essentials::api::ApiError;
+use orbit_essentials::types::Timestamp;
 use std::collections::HashMap;
 
 pub const TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY: &str = "block_height";
@@ -44,6 +45,25 @@ impl BlockchainTransactionSubmitted {
     }
 }
 
+/// A deposit of tokens into the account of the station.
+#[derive(Clone, Debug, Hash)]
+pub struct BlockchainDeposit {
+    /// The address that sent the tokens, `None` if the tokens were minted.
+    pub from_address: Option<String>,
+    pub amount: BigUint,
+}
+
+/// A transaction from the ledger history of an account of the station.
+#[derive(Clone, Debug, Hash)]
+pub struct BlockchainAccountTransaction {
+    /// The id of the transaction, ids grow with the history of the ledger.
+    pub tx_id: u64,
+    /// The time at which the transaction was recorded by the ledger.
+    pub timestamp: Timestamp,
+    /// Set if the transaction deposited tokens into the account.
+    pub deposit: Option<BlockchainDeposit>,
+}
+
 #[async_trait]
 pub trait BlockchainApi: Send + Sync {
     /// Generates a new address for the given account.
@@ -57,6 +77,9 @@ pub trait BlockchainApi: Send + Sync {
     /// Returns the decimals of the given account.
     async fn decimals(&self, account: &Account) -> Result<u32, ApiError>;
 
+    /// Returns the symbol of the token held by the given account, as advertised by its ledger.
+    async fn symbol(&self, account: &Account) -> Result<String, ApiError>;
+
     /// Returns the latest average transaction fee.
     async fn transaction_fee(
         &self,
@@ -66,6 +89,17 @@ pub trait BlockchainApi: Send + Sync {
     /// Returns the default network.
     fn default_network(&self) -> String;
 
+    /// Returns a page of the ledger history of the given account, most recent transactions first.
+    ///
+    /// The page starts right before the `before_tx_id` transaction if set, otherwise with the most
+    /// recent transaction. An empty page is returned if the history of the account is not available.
+    async fn account_transactions(
+        &self,
+        account: &Account,
+        before_tx_id: Option<u64>,
+        max_results: u64,
+    ) -> Result<Vec<BlockchainAccountTransaction>, ApiError>;
+
     /// Submits a transaction to the destination address.
     async fn submit_transaction(
         &self,
@@ -86,6 +120,9 @@ impl BlockchainApiFactory {
             (Blockchain::InternetComputer, BlockchainStandard::Native) => {
                 Ok(Box::new(InternetComputer::create()))
             }
+            (Blockchain::InternetComputer, BlockchainStandard::ICRC1) => {
+                Ok(Box::new(InternetComputerIcrc1::create()))
+            }
             (blockchain, standard) => Err(FactoryError::UnsupportedBlockchainAccount {
                 blockchain: blockchain.to_string(),
                 standard: standard.to_string(),
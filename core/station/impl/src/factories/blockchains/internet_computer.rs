@@ -1,5 +1,6 @@
 use super::{
-    BlockchainApi, BlockchainApiResult, BlockchainTransactionFee, BlockchainTransactionSubmitted,
+    BlockchainAccountTransaction, BlockchainApi, BlockchainApiResult, BlockchainDeposit,
+    BlockchainTransactionFee, BlockchainTransactionSubmitted,
     TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
     TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY,
 };
@@ -13,7 +14,7 @@ use crate::{
 };
 use async_trait::async_trait;
 use byteorder::{BigEndian, ByteOrder};
-use candid::Principal;
+use candid::{CandidType, Deserialize, Principal};
 use ic_ledger_types::{
     account_balance, query_blocks, transfer, AccountBalanceArgs, AccountIdentifier, GetBlocksArgs,
     Memo, QueryBlocksResponse, Subaccount, Timestamp, Tokens, Transaction, TransferArgs,
@@ -65,10 +66,62 @@ pub struct SubmitTransferResponse {
     pub transaction_hash: Option<String>,
 }
 
+/// The subset of the ICP index canister interface that is used to read the history of an account.
+#[derive(CandidType, Deserialize)]
+struct GetAccountIdentifierTransactionsArgs {
+    account_identifier: String,
+    start: Option<u64>,
+    max_results: u64,
+}
+
+#[derive(CandidType, Deserialize)]
+struct GetAccountIdentifierTransactionsResponse {
+    transactions: Vec<IcpIndexTransactionWithId>,
+}
+
+#[derive(CandidType, Deserialize)]
+struct GetAccountIdentifierTransactionsError {
+    message: String,
+}
+
+#[derive(CandidType, Deserialize)]
+struct IcpIndexTransactionWithId {
+    id: u64,
+    transaction: IcpIndexTransaction,
+}
+
+#[derive(CandidType, Deserialize)]
+struct IcpIndexTransaction {
+    operation: IcpIndexOperation,
+    created_at_time: Option<Timestamp>,
+    timestamp: Option<Timestamp>,
+}
+
+#[derive(CandidType, Deserialize)]
+enum IcpIndexOperation {
+    Transfer {
+        from: String,
+        to: String,
+        amount: Tokens,
+    },
+    TransferFrom {
+        from: String,
+        to: String,
+        amount: Tokens,
+    },
+    Mint {
+        to: String,
+        amount: Tokens,
+    },
+    Burn {},
+    Approve {},
+}
+
 impl InternetComputer {
     pub const BLOCKCHAIN: Blockchain = Blockchain::InternetComputer;
     pub const STANDARD: BlockchainStandard = BlockchainStandard::Native;
     pub const ICP_LEDGER_CANISTER_ID: &'static str = "ryjl3-tyaaa-aaaaa-aaaba-cai";
+    pub const ICP_INDEX_CANISTER_ID: &'static str = "qhbym-qaaaa-aaaaa-aaafq-cai";
     pub const DECIMALS: u32 = 8;
     pub const MAIN_NETWORK: InternetComputerNetwork = InternetComputerNetwork::Mainnet;
 
@@ -82,6 +135,10 @@ impl InternetComputer {
         Principal::from_text(Self::ICP_LEDGER_CANISTER_ID).unwrap()
     }
 
+    pub fn index_canister_id() -> Principal {
+        Principal::from_text(Self::ICP_INDEX_CANISTER_ID).unwrap()
+    }
+
     fn hash_transaction(transaction: &Transaction) -> Result<String, serde_cbor::Error> {
         let mut hasher = Sha256::new();
         hasher.update(&serde_cbor::ser::to_vec_packed(transaction)?);
@@ -91,7 +148,7 @@ impl InternetComputer {
     /// Generates the corresponded subaccount id for the given station_account id.
     ///
     /// The subaccount id is a 32 bytes array that is used to identify a station_account in the ICP ledger.
-    fn subaccount_from_station_account_id(&self, station_account_id: &AccountId) -> [u8; 32] {
+    pub(super) fn subaccount_from_station_account_id(station_account_id: &AccountId) -> [u8; 32] {
         let len = station_account_id.len();
         let mut subaccount_id = [0u8; 32];
         subaccount_id[0..len].copy_from_slice(&station_account_id[0..len]);
@@ -107,7 +164,7 @@ impl InternetComputer {
         &self,
         station_account_id: &AccountId,
     ) -> AccountIdentifier {
-        let subaccount = self.subaccount_from_station_account_id(station_account_id);
+        let subaccount = Self::subaccount_from_station_account_id(station_account_id);
 
         AccountIdentifier::new(&self.station_canister_id, &Subaccount(subaccount))
     }
@@ -143,6 +200,70 @@ impl InternetComputer {
         DEFAULT_FEE.e8s()
     }
 
+    /// Returns a page of the transactions of the given station_account from the ICP index canister,
+    /// most recent first.
+    pub async fn account_transactions(
+        &self,
+        station_account: &Account,
+        before_tx_id: Option<u64>,
+        max_results: u64,
+    ) -> BlockchainApiResult<Vec<BlockchainAccountTransaction>> {
+        let address = self.station_account_address(&station_account.id);
+        let (result,): (
+            Result<GetAccountIdentifierTransactionsResponse, GetAccountIdentifierTransactionsError>,
+        ) = ic_cdk::call(
+            Self::index_canister_id(),
+            "get_account_identifier_transactions",
+            (GetAccountIdentifierTransactionsArgs {
+                account_identifier: address.clone(),
+                start: before_tx_id,
+                max_results,
+            },),
+        )
+        .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        })?;
+
+        let response = result
+            .map_err(|err| BlockchainApiError::BlockchainNetworkError { info: err.message })?;
+
+        Ok(response
+            .transactions
+            .into_iter()
+            .map(|IcpIndexTransactionWithId { id, transaction }| {
+                let deposit = match transaction.operation {
+                    IcpIndexOperation::Transfer { from, to, amount }
+                    | IcpIndexOperation::TransferFrom { from, to, amount }
+                        if to == address =>
+                    {
+                        Some(BlockchainDeposit {
+                            from_address: Some(from),
+                            amount: BigUint::from(amount.e8s()),
+                        })
+                    }
+                    IcpIndexOperation::Mint { to, amount } if to == address => {
+                        Some(BlockchainDeposit {
+                            from_address: None,
+                            amount: BigUint::from(amount.e8s()),
+                        })
+                    }
+                    _ => None,
+                };
+
+                BlockchainAccountTransaction {
+                    tx_id: id,
+                    timestamp: transaction
+                        .timestamp
+                        .or(transaction.created_at_time)
+                        .map(|timestamp| timestamp.timestamp_nanos)
+                        .unwrap_or_default(),
+                    deposit,
+                }
+            })
+            .collect())
+    }
+
     pub fn decimals(&self) -> u32 {
         Self::DECIMALS
     }
@@ -175,9 +296,9 @@ impl InternetComputer {
                 created_at_time: Some(Timestamp {
                     timestamp_nanos: current_time,
                 }),
-                from_subaccount: Some(Subaccount(
-                    self.subaccount_from_station_account_id(&station_account.id),
-                )),
+                from_subaccount: Some(Subaccount(Self::subaccount_from_station_account_id(
+                    &station_account.id,
+                ))),
                 memo: Memo(memo),
                 to: to_address,
             },
@@ -264,6 +385,10 @@ impl BlockchainApi for InternetComputer {
         Ok(self.decimals())
     }
 
+    async fn symbol(&self, _station_account: &Account) -> BlockchainApiResult<String> {
+        Ok(Self::BLOCKCHAIN.native_symbol().to_string())
+    }
+
     async fn transaction_fee(
         &self,
         _station_account: &Account,
@@ -278,6 +403,16 @@ impl BlockchainApi for InternetComputer {
         Self::MAIN_NETWORK.to_string()
     }
 
+    async fn account_transactions(
+        &self,
+        station_account: &Account,
+        before_tx_id: Option<u64>,
+        max_results: u64,
+    ) -> BlockchainApiResult<Vec<BlockchainAccountTransaction>> {
+        self.account_transactions(station_account, before_tx_id, max_results)
+            .await
+    }
+
     async fn submit_transaction(
         &self,
         station_account: &Account,
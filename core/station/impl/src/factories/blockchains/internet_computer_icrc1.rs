@@ -0,0 +1,647 @@
+use super::{
+    BlockchainAccountTransaction, BlockchainApi, BlockchainApiResult, BlockchainDeposit,
+    BlockchainTransactionFee, BlockchainTransactionSubmitted, InternetComputer,
+    TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
+};
+use crate::{
+    core::ic_cdk::{api::id as station_canister_self_id, next_time},
+    errors::BlockchainApiError,
+    mappers::HelperMapper,
+    models::{
+        Account, AccountId, AllowanceSpender, Blockchain, BlockchainStandard, Metadata, Transfer,
+        ACCOUNT_METADATA_INDEX_CANISTER_ID_KEY, ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY,
+        METADATA_MEMO_KEY,
+    },
+};
+use async_trait::async_trait;
+use candid::{CandidType, Deserialize, Nat, Principal};
+use icrc_ledger_types::{
+    icrc::generic_metadata_value::MetadataValue,
+    icrc1::{
+        account::Account as Icrc1Account,
+        transfer::{Memo, TransferArg, TransferError as Icrc1TransferError},
+    },
+    icrc2::approve::{ApproveArgs, ApproveError as Icrc2ApproveError},
+    icrc3::transactions::Transaction as Icrc3Transaction,
+};
+use num_bigint::BigUint;
+use orbit_essentials::api::ApiError;
+use orbit_essentials::types::Timestamp;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// The ICRC-1 metadata key that holds the number of decimals used by the ledger.
+pub const ICRC1_METADATA_DECIMALS_KEY: &str = "icrc1:decimals";
+/// The ICRC-1 metadata key that holds the symbol of the token of the ledger.
+pub const ICRC1_METADATA_SYMBOL_KEY: &str = "icrc1:symbol";
+
+/// Blockchain api for the ICRC-1 token ledgers deployed on the Internet Computer (e.g. ckBTC, ckETH, SNS tokens).
+///
+/// The ledger canister id is not fixed for this standard, each account stores the ledger it holds tokens
+/// for in its metadata under the `ledger_canister_id` key.
+#[derive(Debug)]
+pub struct InternetComputerIcrc1 {
+    /// This canister id is used as the owner of all the ledger accounts of the station accounts.
+    station_canister_id: Principal,
+}
+
+pub struct SubmitIcrc1TransferResponse {
+    pub block_height: Nat,
+}
+
+/// The subset of the ICRC-1 index canister interface that is used to read the history of an account.
+#[derive(CandidType, Deserialize)]
+struct GetAccountTransactionsArgs {
+    account: Icrc1Account,
+    start: Option<Nat>,
+    max_results: Nat,
+}
+
+#[derive(CandidType, Deserialize)]
+struct GetTransactions {
+    transactions: Vec<TransactionWithId>,
+}
+
+#[derive(CandidType, Deserialize)]
+struct GetTransactionsErr {
+    message: String,
+}
+
+#[derive(CandidType, Deserialize)]
+struct TransactionWithId {
+    id: Nat,
+    transaction: Icrc3Transaction,
+}
+
+impl InternetComputerIcrc1 {
+    pub const BLOCKCHAIN: Blockchain = Blockchain::InternetComputer;
+    pub const STANDARD: BlockchainStandard = BlockchainStandard::ICRC1;
+
+    pub fn create() -> Self {
+        Self {
+            station_canister_id: station_canister_self_id(),
+        }
+    }
+
+    /// Returns the ledger canister id that is configured in the metadata of the given station_account.
+    pub fn ledger_canister_id(station_account: &Account) -> BlockchainApiResult<Principal> {
+        let ledger_canister_id = station_account
+            .metadata
+            .get(ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY)
+            .ok_or(BlockchainApiError::InvalidAccountMetadata {
+                key: ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY.to_string(),
+                info: "The ledger canister id is required for ICRC-1 accounts".to_string(),
+            })?;
+
+        let ledger_canister_id = Principal::from_text(&ledger_canister_id).map_err(|e| {
+            BlockchainApiError::InvalidAccountMetadata {
+                key: ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY.to_string(),
+                info: format!("Invalid ledger canister id `{}`: {}", ledger_canister_id, e),
+            }
+        })?;
+
+        Ok(ledger_canister_id)
+    }
+
+    /// Returns the index canister id that is configured in the metadata of the given station_account, if any.
+    pub fn index_canister_id(station_account: &Account) -> BlockchainApiResult<Option<Principal>> {
+        let Some(index_canister_id) = station_account
+            .metadata
+            .get(ACCOUNT_METADATA_INDEX_CANISTER_ID_KEY)
+        else {
+            return Ok(None);
+        };
+
+        let index_canister_id = Principal::from_text(&index_canister_id).map_err(|e| {
+            BlockchainApiError::InvalidAccountMetadata {
+                key: ACCOUNT_METADATA_INDEX_CANISTER_ID_KEY.to_string(),
+                info: format!("Invalid index canister id `{}`: {}", index_canister_id, e),
+            }
+        })?;
+
+        Ok(Some(index_canister_id))
+    }
+
+    /// Creates the ICRC-1 ledger account of the given station_account id, the owner is the station canister
+    /// and the subaccount is derived from the station_account uuid.
+    pub fn station_account_to_ledger_account(
+        &self,
+        station_account_id: &AccountId,
+    ) -> Icrc1Account {
+        Icrc1Account {
+            owner: self.station_canister_id,
+            subaccount: Some(InternetComputer::subaccount_from_station_account_id(
+                station_account_id,
+            )),
+        }
+    }
+
+    /// Generates the ICRC-1 textual representation of the ledger account for the given station_account id.
+    ///
+    /// This address is used for token transfers.
+    pub fn station_account_address(&self, station_account_id: &AccountId) -> String {
+        self.station_account_to_ledger_account(station_account_id)
+            .to_string()
+    }
+
+    /// Returns the latest balance of the given station_account.
+    pub async fn balance(&self, station_account: &Account) -> BlockchainApiResult<Nat> {
+        let ledger_canister_id = Self::ledger_canister_id(station_account)?;
+        let (balance,): (Nat,) = ic_cdk::call(
+            ledger_canister_id,
+            "icrc1_balance_of",
+            (self.station_account_to_ledger_account(&station_account.id),),
+        )
+        .await
+        .map_err(|_| BlockchainApiError::FetchBalanceFailed {
+            account_id: Uuid::from_bytes(station_account.id)
+                .hyphenated()
+                .to_string(),
+        })?;
+
+        Ok(balance)
+    }
+
+    /// Returns the fee that the ledger currently charges for each transfer.
+    pub async fn transaction_fee(&self, station_account: &Account) -> BlockchainApiResult<Nat> {
+        let ledger_canister_id = Self::ledger_canister_id(station_account)?;
+        let (fee,): (Nat,) = ic_cdk::call(ledger_canister_id, "icrc1_fee", ())
+            .await
+            .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+                info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+            })?;
+
+        Ok(fee)
+    }
+
+    /// Returns a page of the transactions of the given station_account from the index canister of the
+    /// ledger, most recent first.
+    ///
+    /// Accounts without an index canister in their metadata have no history available.
+    pub async fn account_transactions(
+        &self,
+        station_account: &Account,
+        before_tx_id: Option<u64>,
+        max_results: u64,
+    ) -> BlockchainApiResult<Vec<BlockchainAccountTransaction>> {
+        let Some(index_canister_id) = Self::index_canister_id(station_account)? else {
+            return Ok(Vec::new());
+        };
+
+        let ledger_account = self.station_account_to_ledger_account(&station_account.id);
+        let (result,): (Result<GetTransactions, GetTransactionsErr>,) = ic_cdk::call(
+            index_canister_id,
+            "get_account_transactions",
+            (GetAccountTransactionsArgs {
+                account: ledger_account,
+                start: before_tx_id.map(Nat::from),
+                max_results: Nat::from(max_results),
+            },),
+        )
+        .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        })?;
+
+        let response = result
+            .map_err(|err| BlockchainApiError::BlockchainNetworkError { info: err.message })?;
+
+        response
+            .transactions
+            .into_iter()
+            .map(
+                |TransactionWithId { id, transaction }| -> BlockchainApiResult<BlockchainAccountTransaction> {
+                let deposit = match (transaction.mint, transaction.transfer) {
+                    (Some(mint), _) if mint.to == ledger_account => Some(BlockchainDeposit {
+                        from_address: None,
+                        amount: mint.amount.0,
+                    }),
+                    (_, Some(transfer)) if transfer.to == ledger_account => {
+                        Some(BlockchainDeposit {
+                            from_address: Some(transfer.from.to_string()),
+                            amount: transfer.amount.0,
+                        })
+                    }
+                    _ => None,
+                };
+
+                Ok(BlockchainAccountTransaction {
+                    tx_id: HelperMapper::nat_to_u64(id)?,
+                    timestamp: transaction.timestamp,
+                    deposit,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the metadata advertised by the ledger of the account.
+    async fn ledger_metadata(
+        &self,
+        station_account: &Account,
+    ) -> BlockchainApiResult<Vec<(String, MetadataValue)>> {
+        let ledger_canister_id = Self::ledger_canister_id(station_account)?;
+        let (metadata,): (Vec<(String, MetadataValue)>,) =
+            ic_cdk::call(ledger_canister_id, "icrc1_metadata", ())
+                .await
+                .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+                    info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+                })?;
+
+        Ok(metadata)
+    }
+
+    /// Returns the decimals that are advertised in the ledger metadata.
+    pub async fn decimals(&self, station_account: &Account) -> BlockchainApiResult<u32> {
+        let metadata = self.ledger_metadata(station_account).await?;
+
+        Self::decimals_from_metadata(metadata)
+    }
+
+    /// Returns the symbol that is advertised in the ledger metadata.
+    pub async fn symbol(&self, station_account: &Account) -> BlockchainApiResult<String> {
+        let metadata = self.ledger_metadata(station_account).await?;
+
+        Self::symbol_from_metadata(metadata)
+    }
+
+    fn decimals_from_metadata(metadata: Vec<(String, MetadataValue)>) -> BlockchainApiResult<u32> {
+        let decimals = metadata
+            .into_iter()
+            .find_map(|(key, value)| match (key.as_str(), value) {
+                (ICRC1_METADATA_DECIMALS_KEY, MetadataValue::Nat(decimals)) => Some(decimals),
+                _ => None,
+            })
+            .ok_or(BlockchainApiError::BlockchainNetworkError {
+                info: format!(
+                    "The ledger metadata is missing the `{}` entry",
+                    ICRC1_METADATA_DECIMALS_KEY
+                ),
+            })?;
+
+        let decimals = HelperMapper::nat_to_u64(decimals)?;
+
+        Ok(decimals as u32)
+    }
+
+    fn symbol_from_metadata(metadata: Vec<(String, MetadataValue)>) -> BlockchainApiResult<String> {
+        metadata
+            .into_iter()
+            .find_map(|(key, value)| match (key.as_str(), value) {
+                (ICRC1_METADATA_SYMBOL_KEY, MetadataValue::Text(symbol)) => Some(symbol),
+                _ => None,
+            })
+            .ok_or(
+                BlockchainApiError::BlockchainNetworkError {
+                    info: format!(
+                        "The ledger metadata is missing the `{}` entry",
+                        ICRC1_METADATA_SYMBOL_KEY
+                    ),
+                }
+                .into(),
+            )
+    }
+
+    pub async fn submit_transfer(
+        &self,
+        station_account: Account,
+        station_transfer: Transfer,
+    ) -> Result<SubmitIcrc1TransferResponse, ApiError> {
+        let ledger_canister_id = Self::ledger_canister_id(&station_account)?;
+        let current_time = next_time();
+        let memo = match station_transfer.metadata_map().get(METADATA_MEMO_KEY) {
+            Some(memo) => Memo::from(HelperMapper::to_u64(memo)?),
+            None => Memo::from(station_transfer.id.to_vec()),
+        };
+        let to_address = Icrc1Account::from_str(&station_transfer.to_address).map_err(|error| {
+            BlockchainApiError::InvalidToAddress {
+                address: station_transfer.to_address.clone(),
+                error: error.to_string(),
+            }
+        })?;
+
+        let (result,): (Result<Nat, Icrc1TransferError>,) = ic_cdk::call(
+            ledger_canister_id,
+            "icrc1_transfer",
+            (TransferArg {
+                from_subaccount: Some(InternetComputer::subaccount_from_station_account_id(
+                    &station_account.id,
+                )),
+                to: to_address,
+                fee: Some(station_transfer.fee.clone()),
+                created_at_time: Some(current_time),
+                memo: Some(memo),
+                amount: station_transfer.amount.clone(),
+            },),
+        )
+        .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        })?;
+
+        let block_height = result.map_err(|err| BlockchainApiError::TransactionSubmitFailed {
+            info: match err {
+                Icrc1TransferError::BadFee { expected_fee } => {
+                    format!("Bad fee, expected: {}", expected_fee)
+                }
+                Icrc1TransferError::BadBurn { min_burn_amount } => {
+                    format!("Bad burn, min_burn_amount: {}", min_burn_amount)
+                }
+                Icrc1TransferError::InsufficientFunds { balance } => {
+                    format!("Insufficient balance, balance: {}", balance)
+                }
+                Icrc1TransferError::TooOld => "Tx too old".to_string(),
+                Icrc1TransferError::CreatedInFuture { ledger_time } => {
+                    format!("Tx created in future, ledger_time: {}", ledger_time)
+                }
+                Icrc1TransferError::TemporarilyUnavailable => {
+                    "Ledger temporarily unavailable".to_string()
+                }
+                Icrc1TransferError::Duplicate { duplicate_of } => {
+                    format!("Tx duplicate, duplicate_of: {}", duplicate_of)
+                }
+                Icrc1TransferError::GenericError {
+                    error_code,
+                    message,
+                } => {
+                    format!("Generic error, code: {}, message: {}", error_code, message)
+                }
+            },
+        })?;
+
+        Ok(SubmitIcrc1TransferResponse { block_height })
+    }
+
+    /// Sets the ICRC-2 allowance of the spender on the ledger account of the given station_account.
+    ///
+    /// The ledger replaces any previous allowance of the spender, hence an amount of zero revokes it.
+    /// Returns the `icrc2_approve` arguments that grant the spender an allowance over the tokens of
+    /// the ledger account of the station account.
+    fn approve_args(
+        station_account: &Account,
+        spender: &AllowanceSpender,
+        amount: Nat,
+        expected_allowance: Option<Nat>,
+        expires_at: Option<Timestamp>,
+        fee: Option<Nat>,
+    ) -> ApproveArgs {
+        ApproveArgs {
+            from_subaccount: Some(InternetComputer::subaccount_from_station_account_id(
+                &station_account.id,
+            )),
+            spender: spender.to_icrc1_account(),
+            amount,
+            expected_allowance,
+            expires_at,
+            fee,
+            memo: None,
+            created_at_time: Some(next_time()),
+        }
+    }
+
+    pub async fn submit_approve(
+        &self,
+        station_account: &Account,
+        spender: &AllowanceSpender,
+        amount: Nat,
+        expected_allowance: Option<Nat>,
+        expires_at: Option<Timestamp>,
+        fee: Option<Nat>,
+    ) -> BlockchainApiResult<Nat> {
+        let ledger_canister_id = Self::ledger_canister_id(station_account)?;
+        let (result,): (Result<Nat, Icrc2ApproveError>,) = ic_cdk::call(
+            ledger_canister_id,
+            "icrc2_approve",
+            (Self::approve_args(
+                station_account,
+                spender,
+                amount,
+                expected_allowance,
+                expires_at,
+                fee,
+            ),),
+        )
+        .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        })?;
+
+        let block_height = result.map_err(|err| BlockchainApiError::TransactionSubmitFailed {
+            info: match err {
+                Icrc2ApproveError::BadFee { expected_fee } => {
+                    format!("Bad fee, expected: {}", expected_fee)
+                }
+                Icrc2ApproveError::InsufficientFunds { balance } => {
+                    format!("Insufficient balance, balance: {}", balance)
+                }
+                Icrc2ApproveError::AllowanceChanged { current_allowance } => {
+                    format!(
+                        "Allowance changed, current_allowance: {}",
+                        current_allowance
+                    )
+                }
+                Icrc2ApproveError::Expired { ledger_time } => {
+                    format!("Approval expired, ledger_time: {}", ledger_time)
+                }
+                Icrc2ApproveError::TooOld => "Tx too old".to_string(),
+                Icrc2ApproveError::CreatedInFuture { ledger_time } => {
+                    format!("Tx created in future, ledger_time: {}", ledger_time)
+                }
+                Icrc2ApproveError::Duplicate { duplicate_of } => {
+                    format!("Tx duplicate, duplicate_of: {}", duplicate_of)
+                }
+                Icrc2ApproveError::TemporarilyUnavailable => {
+                    "Ledger temporarily unavailable".to_string()
+                }
+                Icrc2ApproveError::GenericError {
+                    error_code,
+                    message,
+                } => {
+                    format!("Generic error, code: {}, message: {}", error_code, message)
+                }
+            },
+        })?;
+
+        Ok(block_height)
+    }
+}
+
+#[async_trait]
+impl BlockchainApi for InternetComputerIcrc1 {
+    async fn generate_address(&self, station_account: &Account) -> BlockchainApiResult<String> {
+        // Makes sure that the account is configured with a valid ledger before handing out an address.
+        Self::ledger_canister_id(station_account)?;
+
+        Ok(self.station_account_address(&station_account.id))
+    }
+
+    async fn balance(&self, station_account: &Account) -> BlockchainApiResult<BigUint> {
+        let balance = self.balance(station_account).await?;
+
+        Ok(balance.0)
+    }
+
+    async fn decimals(&self, station_account: &Account) -> BlockchainApiResult<u32> {
+        self.decimals(station_account).await
+    }
+
+    async fn symbol(&self, station_account: &Account) -> BlockchainApiResult<String> {
+        self.symbol(station_account).await
+    }
+
+    async fn account_transactions(
+        &self,
+        station_account: &Account,
+        before_tx_id: Option<u64>,
+        max_results: u64,
+    ) -> BlockchainApiResult<Vec<BlockchainAccountTransaction>> {
+        self.account_transactions(station_account, before_tx_id, max_results)
+            .await
+    }
+
+    async fn transaction_fee(
+        &self,
+        station_account: &Account,
+    ) -> BlockchainApiResult<BlockchainTransactionFee> {
+        let fee = self.transaction_fee(station_account).await?;
+
+        Ok(BlockchainTransactionFee {
+            fee: fee.0,
+            metadata: Metadata::default(),
+        })
+    }
+
+    fn default_network(&self) -> String {
+        InternetComputer::MAIN_NETWORK.to_string()
+    }
+
+    async fn submit_transaction(
+        &self,
+        station_account: &Account,
+        transfer: &Transfer,
+    ) -> BlockchainApiResult<BlockchainTransactionSubmitted> {
+        let transfer_response = self
+            .submit_transfer(station_account.clone(), transfer.clone())
+            .await?;
+
+        Ok(BlockchainTransactionSubmitted {
+            details: vec![(
+                TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY.to_string(),
+                transfer_response.block_height.to_string(),
+            )],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::account_test_utils::mock_account;
+
+    #[test]
+    fn station_account_address_is_icrc1_textual_account() {
+        let icrc1 = InternetComputerIcrc1::create();
+        let account = mock_account();
+
+        let address = icrc1.station_account_address(&account.id);
+        let ledger_account = Icrc1Account::from_str(&address).unwrap();
+
+        assert_eq!(ledger_account.owner, station_canister_self_id());
+        assert_eq!(
+            ledger_account.subaccount,
+            Some(InternetComputer::subaccount_from_station_account_id(
+                &account.id
+            ))
+        );
+    }
+
+    #[test]
+    fn ledger_canister_id_is_read_from_account_metadata() {
+        let mut account = mock_account();
+        account.metadata = Metadata::new(
+            vec![(
+                ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY.to_string(),
+                "mxzaz-hqaaa-aaaar-qaada-cai".to_string(),
+            )]
+            .into_iter()
+            .collect(),
+        );
+
+        assert_eq!(
+            InternetComputerIcrc1::ledger_canister_id(&account).unwrap(),
+            Principal::from_text("mxzaz-hqaaa-aaaar-qaada-cai").unwrap()
+        );
+    }
+
+    #[test]
+    fn decimals_and_symbol_are_read_from_ledger_metadata() {
+        let metadata = vec![
+            (
+                ICRC1_METADATA_SYMBOL_KEY.to_string(),
+                MetadataValue::Text("ckBTC".to_string()),
+            ),
+            (
+                ICRC1_METADATA_DECIMALS_KEY.to_string(),
+                MetadataValue::Nat(Nat::from(8u64)),
+            ),
+        ];
+
+        assert_eq!(
+            InternetComputerIcrc1::decimals_from_metadata(metadata.clone()).unwrap(),
+            8
+        );
+        assert_eq!(
+            InternetComputerIcrc1::symbol_from_metadata(metadata).unwrap(),
+            "ckBTC"
+        );
+    }
+
+    #[test]
+    fn missing_ledger_metadata_entries_fail() {
+        let metadata = vec![(
+            ICRC1_METADATA_SYMBOL_KEY.to_string(),
+            MetadataValue::Nat(Nat::from(8u64)),
+        )];
+
+        assert!(InternetComputerIcrc1::decimals_from_metadata(metadata.clone()).is_err());
+        assert!(InternetComputerIcrc1::symbol_from_metadata(metadata).is_err());
+    }
+
+    #[test]
+    fn approve_args_grant_the_allowance_from_the_station_account() {
+        let account = mock_account();
+        let spender = AllowanceSpender {
+            owner: Principal::from_text("mxzaz-hqaaa-aaaar-qaada-cai").unwrap(),
+            subaccount: Some([7; 32]),
+        };
+
+        let args = InternetComputerIcrc1::approve_args(
+            &account,
+            &spender,
+            Nat::from(100u64),
+            Some(Nat::from(50u64)),
+            Some(1_000),
+            None,
+        );
+
+        assert_eq!(
+            args.from_subaccount,
+            Some(InternetComputer::subaccount_from_station_account_id(
+                &account.id
+            ))
+        );
+        assert_eq!(args.spender.owner, spender.owner);
+        assert_eq!(args.spender.subaccount, Some([7; 32]));
+        assert_eq!(args.amount, Nat::from(100u64));
+        assert_eq!(args.expected_allowance, Some(Nat::from(50u64)));
+        assert_eq!(args.expires_at, Some(1_000));
+        assert!(args.created_at_time.is_some());
+    }
+
+    #[test]
+    fn ledger_canister_id_is_required() {
+        let mut account = mock_account();
+        account.metadata = Metadata::default();
+
+        assert!(InternetComputerIcrc1::ledger_canister_id(&account).is_err());
+    }
+}
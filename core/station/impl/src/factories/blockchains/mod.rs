@@ -3,3 +3,6 @@ pub use core::*;
 
 mod internet_computer;
 pub use internet_computer::*;
+
+mod internet_computer_icrc1;
+pub use internet_computer_icrc1::*;
@@ -1,7 +1,10 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{AddAddressBookEntryOperation, Request, RequestExecutionPlan, RequestOperation},
+    models::{
+        validate_blockchain_address, AddAddressBookEntryOperation,
+        AddAddressBookEntryOperationInput, Request, RequestExecutionPlan, RequestOperation,
+    },
     services::ADDRESS_BOOK_SERVICE,
 };
 use async_trait::async_trait;
@@ -18,13 +21,24 @@ impl Create<station_api::AddAddressBookEntryOperationInput> for AddAddressBookEn
         input: station_api::CreateRequestInput,
         operation_input: station_api::AddAddressBookEntryOperationInput,
     ) -> Result<Request, RequestError> {
+        let operation_input: AddAddressBookEntryOperationInput = operation_input.into();
+
+        validate_blockchain_address(
+            &operation_input.blockchain,
+            &operation_input.standard,
+            &operation_input.address,
+        )
+        .map_err(|reason| RequestError::ValidationError {
+            info: format!("Invalid address `{}`: {}", operation_input.address, reason),
+        })?;
+
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::AddAddressBookEntry(AddAddressBookEntryOperation {
                 address_book_entry_id: None,
-                input: operation_input.into(),
+                input: operation_input,
             }),
             input
                 .execution_plan
@@ -0,0 +1,98 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    mappers::HelperMapper,
+    models::{
+        AddApprovalDelegationOperation, AddApprovalDelegationOperationInput, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
+    services::APPROVAL_DELEGATION_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::model::ModelValidator;
+use orbit_essentials::types::UUID;
+use orbit_essentials::utils::rfc3339_to_timestamp;
+
+pub struct AddApprovalDelegationRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::AddApprovalDelegationOperationInput>
+    for AddApprovalDelegationRequestCreate
+{
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::AddApprovalDelegationOperationInput,
+    ) -> Result<Request, RequestError> {
+        let delegator_id = HelperMapper::to_uuid(operation_input.delegator_id).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid delegator_id: {}", e),
+            }
+        })?;
+        let delegate_id = HelperMapper::to_uuid(operation_input.delegate_id).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid delegate_id: {}", e),
+            }
+        })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::AddApprovalDelegation(AddApprovalDelegationOperation {
+                delegation_id: None,
+                input: AddApprovalDelegationOperationInput {
+                    delegator_id: *delegator_id.as_bytes(),
+                    delegate_id: *delegate_id.as_bytes(),
+                    specifier: operation_input.specifier.map(Into::into),
+                    expires_at: rfc3339_to_timestamp(operation_input.expires_at.as_str()),
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Approval delegation".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct AddApprovalDelegationRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o AddApprovalDelegationOperation,
+}
+
+impl<'p, 'o> AddApprovalDelegationRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o AddApprovalDelegationOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for AddApprovalDelegationRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let delegation = APPROVAL_DELEGATION_SERVICE
+            .add_delegation(self.operation.input.clone())
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to add approval delegation: {}", e),
+            })?;
+
+        let mut operation = self.request.operation.clone();
+
+        if let RequestOperation::AddApprovalDelegation(ref mut op) = operation {
+            op.delegation_id = Some(delegation.id);
+        }
+
+        Ok(RequestExecuteStage::Completed(operation))
+    }
+}
@@ -0,0 +1,92 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    mappers::blockchain::BlockchainMapper,
+    models::{
+        AddAssetOperation, AddAssetOperationInput, Request, RequestExecutionPlan, RequestOperation,
+    },
+    services::ASSET_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct AddAssetRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::AddAssetOperationInput> for AddAssetRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::AddAssetOperationInput,
+    ) -> Result<Request, RequestError> {
+        let blockchain =
+            BlockchainMapper::to_blockchain(operation_input.blockchain).map_err(|e| {
+                RequestError::ValidationError {
+                    info: format!("Invalid blockchain: {}", e),
+                }
+            })?;
+        let standard =
+            BlockchainMapper::to_blockchain_standard(operation_input.standard).map_err(|e| {
+                RequestError::ValidationError {
+                    info: format!("Invalid blockchain standard: {}", e),
+                }
+            })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::AddAsset(AddAssetOperation {
+                asset_id: None,
+                input: AddAssetOperationInput {
+                    blockchain,
+                    standard,
+                    symbol: operation_input.symbol,
+                    name: operation_input.name,
+                    metadata: operation_input.metadata.into(),
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "Asset creation".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct AddAssetRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o AddAssetOperation,
+}
+
+impl<'p, 'o> AddAssetRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o AddAssetOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for AddAssetRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let asset = ASSET_SERVICE
+            .create(self.operation.input.clone())
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to create asset: {}", e),
+            })?;
+
+        let mut operation = self.request.operation.clone();
+
+        if let RequestOperation::AddAsset(ref mut op) = operation {
+            op.asset_id = Some(asset.id);
+        }
+
+        Ok(RequestExecuteStage::Completed(operation))
+    }
+}
@@ -3,8 +3,11 @@ use std::sync::Arc;
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{AddRequestPolicyOperation, Request, RequestExecutionPlan, RequestOperation},
-    services::RequestPolicyService,
+    models::{
+        AddRequestPolicyOperation, AddRequestPolicyOperationInput, DraftRequestPolicy, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
+    services::{RequestPolicyService, REQUEST_POLICY_SERVICE},
 };
 use async_trait::async_trait;
 use orbit_essentials::types::UUID;
@@ -20,13 +23,28 @@ impl Create<station_api::AddRequestPolicyOperationInput> for AddRequestPolicyReq
         input: station_api::CreateRequestInput,
         operation_input: station_api::AddRequestPolicyOperationInput,
     ) -> Result<Request, RequestError> {
+        let operation_input = AddRequestPolicyOperationInput::from(operation_input);
+        let warnings = REQUEST_POLICY_SERVICE
+            .validate_policy_change(
+                None,
+                Some(&DraftRequestPolicy {
+                    policy_id: None,
+                    specifier: operation_input.specifier.clone(),
+                    rule: operation_input.rule.clone(),
+                }),
+            )
+            .map_err(|e| RequestError::ValidationError {
+                info: e.to_string(),
+            })?;
+
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::AddRequestPolicy(AddRequestPolicyOperation {
                 policy_id: None,
-                input: operation_input.into(),
+                input: operation_input,
+                warnings: warnings.iter().map(ToString::to_string).collect(),
             }),
             input
                 .execution_plan
@@ -65,6 +83,20 @@ impl<'p, 'o> AddRequestPolicyRequestExecute<'p, 'o> {
 #[async_trait]
 impl Execute for AddRequestPolicyRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        // the policies may have changed since the request was created
+        self.policy_service
+            .validate_policy_change(
+                None,
+                Some(&DraftRequestPolicy {
+                    policy_id: None,
+                    specifier: self.operation.input.specifier.clone(),
+                    rule: self.operation.input.rule.clone(),
+                }),
+            )
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to create request policy: {}", e),
+            })?;
+
         let policy = self
             .policy_service
             .add_request_policy(self.operation.input.to_owned())
@@ -86,7 +118,10 @@ impl Execute for AddRequestPolicyRequestExecute<'_, '_> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{repositories::REQUEST_REPOSITORY, services::REQUEST_POLICY_SERVICE};
+    use crate::{
+        models::RequestPolicyLintWarning, repositories::REQUEST_REPOSITORY,
+        services::REQUEST_POLICY_SERVICE,
+    };
     use orbit_essentials::repository::Repository;
 
     #[tokio::test]
@@ -114,6 +149,37 @@ mod tests {
         assert_eq!(request.title, "Request policy creation".to_string());
     }
 
+    #[tokio::test]
+    async fn create_request_keeps_the_policy_warnings() {
+        let operation_input = station_api::AddRequestPolicyOperationInput {
+            rule: station_api::RequestPolicyRuleDTO::AnyOf(vec![
+                station_api::RequestPolicyRuleDTO::AutoApproved,
+                station_api::RequestPolicyRuleDTO::Quorum(station_api::QuorumDTO {
+                    approvers: station_api::UserSpecifierDTO::Id(vec![]),
+                    min_approved: 1,
+                }),
+            ]),
+            specifier: station_api::RequestSpecifierDTO::AddRequestPolicy,
+        };
+        let mut request_input = add_request_policy_test_utils::mock_request_api_input();
+        request_input.operation =
+            station_api::RequestOperationInput::AddRequestPolicy(operation_input.clone());
+
+        let request = AddRequestPolicyRequestCreate {}
+            .create([0; 16], [1; 16], request_input, operation_input)
+            .await
+            .unwrap();
+
+        let RequestOperation::AddRequestPolicy(operation) = request.operation else {
+            panic!("unexpected operation");
+        };
+
+        assert_eq!(
+            operation.warnings,
+            vec![RequestPolicyLintWarning::EmptyUserSpecifier.to_string()]
+        );
+    }
+
     #[tokio::test]
     async fn test_execute_request_completed() {
         let request_id = [0u8; 16];
@@ -0,0 +1,74 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{ApplyRoleTemplateOperation, Request, RequestExecutionPlan, RequestOperation},
+    services::ROLE_TEMPLATE_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct ApplyRoleTemplateRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::ApplyRoleTemplateOperationInput> for ApplyRoleTemplateRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::ApplyRoleTemplateOperationInput,
+    ) -> Result<Request, RequestError> {
+        let operation = ApplyRoleTemplateOperation::from(operation_input);
+
+        // checks that the group exists and that the policy changes of the template are valid
+        ROLE_TEMPLATE_SERVICE
+            .get_role_template_diff(&operation.input.user_group_id, &operation.input.template)
+            .map_err(|e| RequestError::ValidationError {
+                info: e.to_string(),
+            })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::ApplyRoleTemplate(operation),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Role template application".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct ApplyRoleTemplateRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o ApplyRoleTemplateOperation,
+}
+
+impl<'p, 'o> ApplyRoleTemplateRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o ApplyRoleTemplateOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for ApplyRoleTemplateRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        ROLE_TEMPLATE_SERVICE
+            .apply_role_template(self.operation.input.clone())
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to apply role template: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
@@ -0,0 +1,101 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    mappers::HelperMapper,
+    models::{
+        AllowanceSpender, ApproveAllowanceOperation, ApproveAllowanceOperationInput, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
+    services::ALLOWANCE_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::model::ModelValidator;
+use orbit_essentials::types::UUID;
+use orbit_essentials::utils::rfc3339_to_timestamp;
+
+pub struct ApproveAllowanceRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::ApproveAllowanceOperationInput> for ApproveAllowanceRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::ApproveAllowanceOperationInput,
+    ) -> Result<Request, RequestError> {
+        let from_account_id =
+            HelperMapper::to_uuid(operation_input.from_account_id).map_err(|e| {
+                RequestError::ValidationError {
+                    info: format!("Invalid from_account_id: {}", e),
+                }
+            })?;
+        let spender = AllowanceSpender::try_from(operation_input.spender).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid spender: {}", e),
+            }
+        })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::ApproveAllowance(ApproveAllowanceOperation {
+                allowance_id: None,
+                input: ApproveAllowanceOperationInput {
+                    from_account_id: *from_account_id.as_bytes(),
+                    spender,
+                    amount: operation_input.amount,
+                    expected_allowance: operation_input.expected_allowance,
+                    expires_at: operation_input
+                        .expires_at
+                        .map(|dt| rfc3339_to_timestamp(dt.as_str())),
+                    fee: operation_input.fee,
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Allowance approval".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct ApproveAllowanceRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o ApproveAllowanceOperation,
+}
+
+impl<'p, 'o> ApproveAllowanceRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o ApproveAllowanceOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for ApproveAllowanceRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let allowance = ALLOWANCE_SERVICE
+            .approve(self.request.id, self.operation.input.to_owned())
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to approve allowance: {}", e),
+            })?;
+
+        let mut operation = self.request.operation.clone();
+
+        if let RequestOperation::ApproveAllowance(ref mut operation) = operation {
+            operation.allowance_id = Some(allowance.id);
+        }
+
+        Ok(RequestExecuteStage::Completed(operation))
+    }
+}
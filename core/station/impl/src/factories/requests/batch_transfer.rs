@@ -0,0 +1,319 @@
+use super::{
+    transfer::{transfer_operation_input, validate_transfer_destination},
+    Create, Execute, RequestExecuteStage,
+};
+use crate::{
+    core::generate_uuid_v4,
+    errors::{RequestError, RequestExecuteError},
+    factories::blockchains::BlockchainApiFactory,
+    mappers::HelperMapper,
+    models::{
+        Account, AccountId, BatchTransferOperation, BatchTransferOperationInput, Request,
+        RequestExecutionPlan, RequestOperation, Transfer,
+    },
+    repositories::ACCOUNT_REPOSITORY,
+    services::TransferService,
+};
+use async_trait::async_trait;
+use orbit_essentials::model::ModelValidator;
+use orbit_essentials::repository::Repository;
+use orbit_essentials::types::UUID;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+pub struct BatchTransferRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::BatchTransferOperationInput> for BatchTransferRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::BatchTransferOperationInput,
+    ) -> Result<Request, RequestError> {
+        if operation_input.transfers.is_empty()
+            || operation_input.transfers.len() > BatchTransferOperationInput::MAX_TRANSFERS
+        {
+            Err(RequestError::ValidationError {
+                info: format!(
+                    "A batch transfer must have between 1 and {} transfers, got {}",
+                    BatchTransferOperationInput::MAX_TRANSFERS,
+                    operation_input.transfers.len()
+                ),
+            })?;
+        }
+
+        let mut transfers = Vec::with_capacity(operation_input.transfers.len());
+        for (row, transfer) in operation_input.transfers.into_iter().enumerate() {
+            let from_account_id =
+                HelperMapper::to_uuid(transfer.from_account_id.clone()).map_err(|e| {
+                    RequestError::ValidationError {
+                        info: format!("Invalid from_account_id in row {}: {}", row, e),
+                    }
+                })?;
+
+            transfers.push(transfer_operation_input(
+                *from_account_id.as_bytes(),
+                transfer,
+            ));
+        }
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::BatchTransfer(BatchTransferOperation {
+                transfer_ids: vec![],
+                input: BatchTransferOperationInput { transfers },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "Batch transfer".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        if let RequestOperation::BatchTransfer(operation) = &request.operation {
+            for transfer in &operation.input.transfers {
+                validate_transfer_destination(transfer)?;
+            }
+        }
+
+        Ok(request)
+    }
+}
+
+pub struct BatchTransferRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o BatchTransferOperation,
+    transfer_service: TransferService,
+}
+
+impl<'p, 'o> BatchTransferRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o BatchTransferOperation) -> Self {
+        Self {
+            request,
+            operation,
+            transfer_service: TransferService::default(),
+        }
+    }
+
+    async fn default_fee(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<candid::Nat, RequestExecuteError> {
+        let account = ACCOUNT_REPOSITORY.get(&Account::key(*account_id)).ok_or(
+            RequestExecuteError::Failed {
+                reason: format!(
+                    "Account {} does not exist.",
+                    Uuid::from_bytes(*account_id).hyphenated()
+                ),
+            },
+        )?;
+
+        let blockchain_api = BlockchainApiFactory::build(&account.blockchain, &account.standard)
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to build blockchain api: {}", e),
+            })?;
+
+        let transaction_fee = blockchain_api
+            .transaction_fee(&account)
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to fetch transaction fee: {}", e),
+            })?;
+
+        Ok(candid::Nat(transaction_fee.fee))
+    }
+}
+
+#[async_trait]
+impl Execute for BatchTransferRequestExecute<'_, '_> {
+    /// Creates one transfer per row, which are then submitted by the transfer execution job.
+    ///
+    /// All the rows are validated before any transfer is created, so that a batch is either
+    /// fully handed over to the job or not at all.
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let mut default_fees: BTreeMap<AccountId, candid::Nat> = BTreeMap::new();
+        let mut transfers = Vec::with_capacity(self.operation.input.transfers.len());
+
+        for (row, input) in self.operation.input.transfers.iter().enumerate() {
+            let fee = match &input.fee {
+                Some(fee) => fee.clone(),
+                None => match default_fees.get(&input.from_account_id) {
+                    Some(fee) => fee.clone(),
+                    None => {
+                        let fee = self.default_fee(&input.from_account_id).await?;
+                        default_fees.insert(input.from_account_id, fee.clone());
+
+                        fee
+                    }
+                },
+            };
+
+            let transfer = Transfer::new(
+                self.request.id,
+                *generate_uuid_v4().await.as_bytes(),
+                self.request.requested_by,
+                input.from_account_id,
+                input.to.clone(),
+                input.metadata.clone(),
+                input.amount.clone(),
+                fee,
+                input.network.clone(),
+            );
+
+            transfer
+                .validate()
+                .map_err(|e| RequestExecuteError::Failed {
+                    reason: format!("Failed to validate transfer in row {}: {}", row, e),
+                })?;
+
+            transfers.push(transfer);
+        }
+
+        let mut operation = self.operation.clone();
+        for transfer in transfers {
+            operation.transfer_ids.push(transfer.id);
+
+            self.transfer_service.add_transfer(transfer).map_err(|e| {
+                RequestExecuteError::Failed {
+                    reason: format!("Failed to add transfer: {}", e),
+                }
+            })?;
+        }
+
+        Ok(RequestExecuteStage::Processing(
+            RequestOperation::BatchTransfer(operation),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mappers::authorization::CreateRequestInputRef,
+        models::{
+            account_test_utils::add_account,
+            resource::{AccountResourceAction, Resource, ResourceId},
+            user_test_utils::add_user,
+        },
+    };
+
+    fn mock_request_api_input(
+        transfers: Vec<station_api::TransferOperationInput>,
+    ) -> (
+        station_api::CreateRequestInput,
+        station_api::BatchTransferOperationInput,
+    ) {
+        let operation_input = station_api::BatchTransferOperationInput { transfers };
+
+        (
+            station_api::CreateRequestInput {
+                operation: station_api::RequestOperationInput::BatchTransfer(
+                    operation_input.clone(),
+                ),
+                title: None,
+                summary: None,
+                execution_plan: None,
+            },
+            operation_input,
+        )
+    }
+
+    fn mock_transfer_api_input() -> station_api::TransferOperationInput {
+        station_api::TransferOperationInput {
+            from_account_id: Uuid::from_bytes([0; 16]).hyphenated().to_string(),
+            to: "0x1234".to_string(),
+            amount: candid::Nat::from(1u64),
+            fee: None,
+            metadata: vec![],
+            network: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn fail_create_empty_batch() {
+        let (request_input, operation_input) = mock_request_api_input(vec![]);
+
+        let result = BatchTransferRequestCreate {}
+            .create([0; 16], [1; 16], request_input, operation_input)
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn fail_create_batch_over_limit() {
+        let (request_input, operation_input) =
+            mock_request_api_input(vec![
+                mock_transfer_api_input();
+                BatchTransferOperationInput::MAX_TRANSFERS + 1
+            ]);
+
+        let result = BatchTransferRequestCreate {}
+            .create([0; 16], [1; 16], request_input, operation_input)
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn create_batch_keeps_the_metadata_and_network_of_the_rows() {
+        add_user(&[1; 16]);
+        let account = add_account(&[3; 16]);
+        let mut default_transfer = mock_transfer_api_input();
+        default_transfer.from_account_id = Uuid::from_bytes(account.id).hyphenated().to_string();
+        default_transfer.to =
+            "7f2e1b156f29b09f8efe9248c4e2d1f754434887a4bae0317e06c7d3442e7922".to_string();
+        let mut transfer = default_transfer.clone();
+        transfer.metadata = vec![station_api::MetadataDTO {
+            key: "memo".to_string(),
+            value: "invoice-1".to_string(),
+        }];
+        transfer.network = Some(station_api::NetworkDTO {
+            id: "testnet".to_string(),
+            name: "Testnet".to_string(),
+        });
+        let (request_input, operation_input) =
+            mock_request_api_input(vec![transfer, default_transfer]);
+
+        let request = BatchTransferRequestCreate {}
+            .create([0; 16], [1; 16], request_input, operation_input)
+            .await
+            .unwrap();
+
+        let RequestOperation::BatchTransfer(operation) = request.operation else {
+            panic!("unexpected operation");
+        };
+        let rows = operation.input.transfers;
+        assert_eq!(rows[0].metadata.get("memo"), Some("invoice-1".to_string()));
+        assert_eq!(rows[0].network, "testnet");
+        assert!(rows[1].metadata.as_btreemap().is_empty());
+        assert_eq!(rows[1].network, "mainnet");
+    }
+
+    #[test]
+    fn batch_requires_transfer_access_to_every_source_account() {
+        let mut other_account_transfer = mock_transfer_api_input();
+        other_account_transfer.from_account_id = Uuid::from_bytes([2; 16]).hyphenated().to_string();
+        let (request_input, _) = mock_request_api_input(vec![
+            mock_transfer_api_input(),
+            other_account_transfer,
+            mock_transfer_api_input(),
+        ]);
+
+        assert_eq!(
+            CreateRequestInputRef(&request_input).to_resources(),
+            vec![
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Id([0; 16]))),
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Id([2; 16]))),
+            ]
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use super::{Create, Execute, RequestExecuteStage, RequestFactory};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{BundleOperation, Request, RequestExecutionPlan, RequestOperation},
+};
+use async_trait::async_trait;
+use orbit_essentials::model::ModelValidator;
+use orbit_essentials::types::UUID;
+use station_api::{CreateRequestInput, RequestOperationInput};
+
+pub struct BundleRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::BundleOperationInput> for BundleRequestCreate {
+    /// Each operation of the bundle is created by its own factory, so it goes through the same
+    /// validations as if it was requested on its own.
+    ///
+    /// The operations are validated against the current state of the station, not the state left
+    /// by the earlier operations of the bundle, so they cannot refer to what the bundle creates.
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: CreateRequestInput,
+        operation_input: station_api::BundleOperationInput,
+    ) -> Result<Request, RequestError> {
+        let mut operations = Vec::new();
+
+        for operation in operation_input.operations {
+            if let RequestOperationInput::Bundle(_) = operation {
+                return Err(RequestError::ValidationError {
+                    info: "Nested bundles are not supported".to_string(),
+                });
+            }
+
+            let member = RequestFactory::create_request(
+                requested_by_user,
+                CreateRequestInput {
+                    operation,
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                },
+            )
+            .await?;
+
+            operations.push(member.operation);
+        }
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::Bundle(BundleOperation { operations }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "Bundle".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct BundleRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o BundleOperation,
+}
+
+impl<'p, 'o> BundleRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o BundleOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for BundleRequestExecute<'_, '_> {
+    /// Executes the operations in order and stops at the first one that fails, the operations that
+    /// were already executed are not reverted.
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let mut operations = Vec::new();
+
+        for (position, member) in self
+            .operation
+            .to_member_requests(self.request)
+            .iter()
+            .enumerate()
+        {
+            let reason = match RequestFactory::executor(member).execute().await {
+                Ok(RequestExecuteStage::Completed(operation)) => {
+                    operations.push(operation);
+
+                    continue;
+                }
+                Ok(RequestExecuteStage::Processing(_)) => {
+                    "the operation did not complete".to_string()
+                }
+                Err(RequestExecuteError::Failed { reason }) => reason,
+                Err(e) => e.to_string(),
+            };
+
+            return Err(RequestExecuteError::Failed {
+                reason: format!(
+                    "operation {} ({}) of the bundle failed after {} completed: {}",
+                    position + 1,
+                    member.operation,
+                    operations.len(),
+                    reason
+                ),
+            });
+        }
+
+        Ok(RequestExecuteStage::Completed(RequestOperation::Bundle(
+            BundleOperation { operations },
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        mappers::authorization::CreateRequestInputRef,
+        models::{
+            resource::{RequestResourceAction, Resource},
+            user_test_utils,
+        },
+        repositories::USER_GROUP_REPOSITORY,
+    };
+    use orbit_essentials::repository::Repository;
+    use station_api::AddUserGroupOperationInput;
+    use uuid::Uuid;
+
+    async fn create_bundle(group_names: &[&str]) -> Request {
+        let requester = user_test_utils::add_user(&[1; 16]);
+        let input = station_api::BundleOperationInput {
+            operations: group_names
+                .iter()
+                .map(|name| {
+                    RequestOperationInput::AddUserGroup(AddUserGroupOperationInput {
+                        name: name.to_string(),
+                    })
+                })
+                .collect(),
+        };
+
+        BundleRequestCreate {}
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                requester.id,
+                CreateRequestInput {
+                    operation: RequestOperationInput::Bundle(input.clone()),
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                },
+                input,
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn executes_operations_in_order() {
+        test_utils::init_canister_system();
+
+        let request = create_bundle(&["finance", "operations"]).await;
+        let RequestOperation::Bundle(operation) = &request.operation else {
+            panic!("Invalid operation");
+        };
+
+        let result = BundleRequestExecute::new(&request, operation)
+            .execute()
+            .await
+            .unwrap();
+
+        let RequestExecuteStage::Completed(RequestOperation::Bundle(executed)) = result else {
+            panic!("Invalid execution stage");
+        };
+
+        assert_eq!(executed.operations.len(), 2);
+        for member in executed.operations {
+            let RequestOperation::AddUserGroup(add_user_group) = member else {
+                panic!("Invalid operation");
+            };
+
+            assert!(USER_GROUP_REPOSITORY
+                .get(&add_user_group.user_group_id.unwrap())
+                .is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn stops_at_the_first_failure() {
+        test_utils::init_canister_system();
+
+        let request = create_bundle(&["treasury", "treasury", "audit"]).await;
+        let RequestOperation::Bundle(operation) = &request.operation else {
+            panic!("Invalid operation");
+        };
+
+        let result = BundleRequestExecute::new(&request, operation)
+            .execute()
+            .await;
+
+        let Err(RequestExecuteError::Failed { reason }) = result else {
+            panic!("The bundle should have failed");
+        };
+
+        assert!(reason.starts_with("operation 2 (add_user_group)"));
+        assert!(!USER_GROUP_REPOSITORY
+            .list()
+            .iter()
+            .any(|group| group.name == "audit"));
+    }
+
+    #[tokio::test]
+    async fn fail_create_nested_bundle() {
+        let input = station_api::BundleOperationInput {
+            operations: vec![RequestOperationInput::Bundle(
+                station_api::BundleOperationInput { operations: vec![] },
+            )],
+        };
+
+        let result = BundleRequestCreate {}
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                CreateRequestInput {
+                    operation: RequestOperationInput::Bundle(input.clone()),
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                },
+                input,
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn fail_create_empty_bundle() {
+        let input = station_api::BundleOperationInput { operations: vec![] };
+        let create_input = CreateRequestInput {
+            operation: RequestOperationInput::Bundle(input.clone()),
+            title: None,
+            summary: None,
+            execution_plan: None,
+        };
+
+        // empty bundles get to the factory instead of trapping in the authorization guard
+        assert_eq!(
+            CreateRequestInputRef(&create_input).to_resources(),
+            vec![Resource::Request(RequestResourceAction::List)]
+        );
+
+        let result = BundleRequestCreate {}
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                create_input,
+                input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+}
@@ -0,0 +1,159 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    mappers::HelperMapper,
+    models::{
+        CancelStandingOrderOperation, CancelStandingOrderOperationInput, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
+    services::STANDING_ORDER_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::model::ModelValidator;
+use orbit_essentials::types::UUID;
+
+pub struct CancelStandingOrderRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::CancelStandingOrderOperationInput> for CancelStandingOrderRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::CancelStandingOrderOperationInput,
+    ) -> Result<Request, RequestError> {
+        let standing_order_request_id =
+            HelperMapper::to_uuid(operation_input.request_id).map_err(|e| {
+                RequestError::ValidationError {
+                    info: format!("Invalid request_id: {}", e),
+                }
+            })?;
+        let standing_order = STANDING_ORDER_SERVICE
+            .get(standing_order_request_id.as_bytes())
+            .map_err(|e| RequestError::ValidationError {
+                info: e.to_string(),
+            })?;
+
+        if !standing_order.is_active() {
+            Err(RequestError::ValidationError {
+                info: "The standing order is no longer active".to_string(),
+            })?;
+        }
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::CancelStandingOrder(CancelStandingOrderOperation {
+                input: CancelStandingOrderOperationInput {
+                    request_id: standing_order.request_id,
+                    from_account_id: standing_order.account_id,
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Standing order cancellation".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct CancelStandingOrderRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o CancelStandingOrderOperation,
+}
+
+impl<'p, 'o> CancelStandingOrderRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o CancelStandingOrderOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for CancelStandingOrderRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        STANDING_ORDER_SERVICE
+            .cancel(&self.operation.input.request_id, &self.request.id)
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to cancel standing order: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{standing_order_test_utils::mock_standing_order, StandingOrderStatus},
+        repositories::STANDING_ORDER_REPOSITORY,
+    };
+    use orbit_essentials::repository::Repository;
+    use uuid::Uuid;
+
+    fn mock_request_api_input(
+        request_id: UUID,
+    ) -> (
+        station_api::CreateRequestInput,
+        station_api::CancelStandingOrderOperationInput,
+    ) {
+        let operation_input = station_api::CancelStandingOrderOperationInput {
+            request_id: Uuid::from_bytes(request_id).hyphenated().to_string(),
+        };
+
+        (
+            station_api::CreateRequestInput {
+                operation: station_api::RequestOperationInput::CancelStandingOrder(
+                    operation_input.clone(),
+                ),
+                title: None,
+                summary: None,
+                execution_plan: None,
+            },
+            operation_input,
+        )
+    }
+
+    #[tokio::test]
+    async fn fail_create_for_unknown_standing_order() {
+        test_utils::init_canister_system();
+
+        let (request_input, operation_input) = mock_request_api_input([9; 16]);
+
+        let result = CancelStandingOrderRequestCreate {}
+            .create([0; 16], [1; 16], request_input, operation_input)
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn fail_create_for_finished_standing_order() {
+        test_utils::init_canister_system();
+
+        let mut standing_order = mock_standing_order();
+        standing_order.status = StandingOrderStatus::Finished { finished_at: 1 };
+        STANDING_ORDER_REPOSITORY.insert(standing_order.request_id, standing_order.clone());
+
+        let (request_input, operation_input) = mock_request_api_input(standing_order.request_id);
+
+        let result = CancelStandingOrderRequestCreate {}
+            .create([0; 16], [1; 16], request_input, operation_input)
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,80 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    mappers::HelperMapper,
+    models::{
+        EditAssetOperation, EditAssetOperationInput, Request, RequestExecutionPlan,
+        RequestOperation,
+    },
+    services::ASSET_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct EditAssetRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::EditAssetOperationInput> for EditAssetRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::EditAssetOperationInput,
+    ) -> Result<Request, RequestError> {
+        let asset_id = HelperMapper::to_uuid(operation_input.asset_id).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid asset id: {}", e),
+            }
+        })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::EditAsset(EditAssetOperation {
+                input: EditAssetOperationInput {
+                    asset_id: *asset_id.as_bytes(),
+                    name: operation_input.name,
+                    symbol: operation_input.symbol,
+                    change_metadata: operation_input.change_metadata.map(|m| m.into()),
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "Asset update".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct EditAssetRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o EditAssetOperation,
+}
+
+impl<'p, 'o> EditAssetRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o EditAssetOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for EditAssetRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        ASSET_SERVICE
+            .edit(self.operation.input.to_owned())
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to update asset: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
@@ -1,8 +1,11 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{EditPermissionOperation, Request, RequestExecutionPlan, RequestOperation},
-    services::permission::PermissionService,
+    models::{
+        EditPermissionOperation, EditPermissionOperationInput, Request, RequestExecutionPlan,
+        RequestOperation,
+    },
+    services::permission::{PermissionService, PERMISSION_SERVICE},
 };
 use async_trait::async_trait;
 use orbit_essentials::types::UUID;
@@ -19,12 +22,19 @@ impl Create<station_api::EditPermissionOperationInput> for EditPermissionRequest
         input: station_api::CreateRequestInput,
         operation_input: station_api::EditPermissionOperationInput,
     ) -> Result<Request, RequestError> {
+        let operation_input = EditPermissionOperationInput::from(operation_input);
+        PERMISSION_SERVICE
+            .validate_permission_change(&operation_input)
+            .map_err(|e| RequestError::ValidationError {
+                info: e.to_string(),
+            })?;
+
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::EditPermission(EditPermissionOperation {
-                input: operation_input.into(),
+                input: operation_input,
             }),
             input
                 .execution_plan
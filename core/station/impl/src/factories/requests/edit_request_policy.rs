@@ -2,8 +2,8 @@ use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
     models::{
-        EditRequestPolicyOperation, EditRequestPolicyOperationInput, Request, RequestExecutionPlan,
-        RequestOperation,
+        DraftRequestPolicy, EditRequestPolicyOperation, EditRequestPolicyOperationInput, Request,
+        RequestExecutionPlan, RequestOperation,
     },
     services::{RequestPolicyService, REQUEST_POLICY_SERVICE},
 };
@@ -24,7 +24,7 @@ impl Create<station_api::EditRequestPolicyOperationInput> for EditRequestPolicyR
         operation_input: station_api::EditRequestPolicyOperationInput,
     ) -> Result<Request, RequestError> {
         let operation_input = EditRequestPolicyOperationInput::from(operation_input);
-        REQUEST_POLICY_SERVICE
+        let policy = REQUEST_POLICY_SERVICE
             .get_request_policy(&operation_input.policy_id)
             .map_err(|_| RequestError::ValidationError {
                 info: format!(
@@ -33,12 +33,29 @@ impl Create<station_api::EditRequestPolicyOperationInput> for EditRequestPolicyR
                 ),
             })?;
 
+        let warnings = REQUEST_POLICY_SERVICE
+            .validate_policy_change(
+                Some(&policy.id),
+                Some(&DraftRequestPolicy {
+                    policy_id: Some(policy.id),
+                    specifier: operation_input
+                        .specifier
+                        .clone()
+                        .unwrap_or(policy.specifier),
+                    rule: operation_input.rule.clone().unwrap_or(policy.rule),
+                }),
+            )
+            .map_err(|e| RequestError::ValidationError {
+                info: e.to_string(),
+            })?;
+
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::EditRequestPolicy(EditRequestPolicyOperation {
                 input: operation_input,
+                warnings: warnings.iter().map(ToString::to_string).collect(),
             }),
             input
                 .execution_plan
@@ -77,6 +94,28 @@ impl<'p, 'o> EditRequestPolicyRequestExecute<'p, 'o> {
 #[async_trait]
 impl Execute for EditRequestPolicyRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let input = &self.operation.input;
+        let policy = self
+            .policy_service
+            .get_request_policy(&input.policy_id)
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to update request policy: {}", e),
+            })?;
+
+        // the policies may have changed since the request was created
+        self.policy_service
+            .validate_policy_change(
+                Some(&policy.id),
+                Some(&DraftRequestPolicy {
+                    policy_id: Some(policy.id),
+                    specifier: input.specifier.clone().unwrap_or(policy.specifier),
+                    rule: input.rule.clone().unwrap_or(policy.rule),
+                }),
+            )
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to update request policy: {}", e),
+            })?;
+
         self.policy_service
             .edit_request_policy(self.operation.input.to_owned())
             .await
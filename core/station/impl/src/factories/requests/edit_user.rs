@@ -1,8 +1,8 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{EditUserOperation, Request, RequestExecutionPlan, RequestOperation},
-    services::USER_SERVICE,
+    models::{EditUserOperation, Request, RequestExecutionPlan, RequestOperation, UserStatus},
+    services::{REQUEST_SERVICE, USER_SERVICE},
 };
 use async_trait::async_trait;
 use orbit_essentials::types::UUID;
@@ -51,6 +51,16 @@ impl<'p, 'o> EditUserRequestExecute<'p, 'o> {
 #[async_trait]
 impl Execute for EditUserRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        // users that are no longer active can't approve, so their open requests are evaluated again
+        let affected_requests = match &self.operation.input.status {
+            Some(status) if *status != UserStatus::Active => {
+                REQUEST_SERVICE
+                    .find_open_requests_approvable_by(&self.operation.input.user_id)
+                    .await
+            }
+            _ => Vec::new(),
+        };
+
         USER_SERVICE
             .edit_user(self.operation.input.clone())
             .await
@@ -58,6 +68,13 @@ impl Execute for EditUserRequestExecute<'_, '_> {
                 reason: format!("Failed to edit user: {}", e),
             })?;
 
+        REQUEST_SERVICE
+            .reevaluate_requests(&affected_requests)
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to reevaluate the requests of the user: {}", e),
+            })?;
+
         Ok(RequestExecuteStage::Completed(
             self.request.operation.clone(),
         ))
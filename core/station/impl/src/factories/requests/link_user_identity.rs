@@ -0,0 +1,35 @@
+use super::{Execute, RequestExecuteStage};
+use crate::{
+    errors::RequestExecuteError,
+    models::{LinkUserIdentityOperation, Request},
+    services::USER_SERVICE,
+};
+use async_trait::async_trait;
+
+/// The link requests are only created by the identity link flow once the new identity confirmed
+/// it, so there is no creator that accepts them from the request input.
+pub struct LinkUserIdentityRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o LinkUserIdentityOperation,
+}
+
+impl<'p, 'o> LinkUserIdentityRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o LinkUserIdentityOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for LinkUserIdentityRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        USER_SERVICE
+            .link_identity(self.operation.input.clone())
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to link the identity to the user: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
@@ -1,11 +1,14 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{ManageSystemInfoOperation, Request, RequestExecutionPlan, RequestOperation},
+    models::{
+        ChangeRecoveryConfig, ManageSystemInfoOperation, ManageSystemInfoOperationInput, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
     services::SYSTEM_SERVICE,
 };
 use async_trait::async_trait;
-use orbit_essentials::types::UUID;
+use orbit_essentials::{model::ModelValidator, types::UUID};
 
 pub struct ManageSystemInfoRequestCreate {}
 
@@ -18,12 +21,22 @@ impl Create<station_api::ManageSystemInfoOperationInput> for ManageSystemInfoReq
         input: station_api::CreateRequestInput,
         operation_input: station_api::ManageSystemInfoOperationInput,
     ) -> Result<Request, RequestError> {
+        let operation_input = ManageSystemInfoOperationInput::from(operation_input);
+
+        if let Some(ChangeRecoveryConfig::Set(config)) = &operation_input.recovery_config {
+            config
+                .validate()
+                .map_err(|e| RequestError::ValidationError {
+                    info: e.to_string(),
+                })?;
+        }
+
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::ManageSystemInfo(ManageSystemInfoOperation {
-                input: operation_input.into(),
+                input: operation_input,
             }),
             input
                 .execution_plan
@@ -95,6 +108,7 @@ mod tests {
             RequestOperation::ManageSystemInfo(ManageSystemInfoOperation {
                 input: ManageSystemInfoOperationInput {
                     name: Some("name".to_string()),
+                    recovery_config: None,
                 },
             })
         );
@@ -145,6 +159,7 @@ mod mnanage_system_info_test_utils {
     pub fn mock_manage_system_info_api_input() -> station_api::ManageSystemInfoOperationInput {
         station_api::ManageSystemInfoOperationInput {
             name: Some("name".to_string()),
+            recovery_config: None,
         }
     }
 
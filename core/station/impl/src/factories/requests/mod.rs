@@ -14,31 +14,52 @@ use std::sync::Arc;
 
 mod add_account;
 mod add_address_book_entry;
+mod add_approval_delegation;
+mod add_asset;
 mod add_request_policy;
 mod add_user;
 mod add_user_group;
+mod apply_role_template;
+mod approve_allowance;
+mod batch_transfer;
+mod bundle;
 mod call_canister;
+mod cancel_standing_order;
 mod change_canister;
 mod create_canister;
 mod edit_account;
 mod edit_address_book_entry;
+mod edit_asset;
 mod edit_permission;
 mod edit_request_policy;
 mod edit_user;
 mod edit_user_group;
+mod link_user_identity;
 mod manage_system_info;
 mod remove_address_book_entry;
+mod remove_asset;
 mod remove_request_policy;
+mod remove_user;
 mod remove_user_group;
+mod revoke_allowance;
 mod transfer;
 
 use self::{
     add_account::{AddAccountRequestCreate, AddAccountRequestExecute},
     add_address_book_entry::{AddAddressBookEntryRequestCreate, AddAddressBookEntryRequestExecute},
+    add_approval_delegation::{
+        AddApprovalDelegationRequestCreate, AddApprovalDelegationRequestExecute,
+    },
+    add_asset::{AddAssetRequestCreate, AddAssetRequestExecute},
     add_request_policy::{AddRequestPolicyRequestCreate, AddRequestPolicyRequestExecute},
     add_user::{AddUserRequestCreate, AddUserRequestExecute},
     add_user_group::{AddUserGroupRequestCreate, AddUserGroupRequestExecute},
+    apply_role_template::{ApplyRoleTemplateRequestCreate, ApplyRoleTemplateRequestExecute},
+    approve_allowance::{ApproveAllowanceRequestCreate, ApproveAllowanceRequestExecute},
+    batch_transfer::{BatchTransferRequestCreate, BatchTransferRequestExecute},
+    bundle::{BundleRequestCreate, BundleRequestExecute},
     call_canister::{CallExternalCanisterRequestCreate, CallExternalCanisterRequestExecute},
+    cancel_standing_order::{CancelStandingOrderRequestCreate, CancelStandingOrderRequestExecute},
     change_canister::{
         ChangeCanisterRequestCreate, ChangeCanisterRequestExecute,
         ChangeExternalCanisterRequestCreate, ChangeExternalCanisterRequestExecute,
@@ -48,15 +69,20 @@ use self::{
     edit_address_book_entry::{
         EditAddressBookEntryRequestCreate, EditAddressBookEntryRequestExecute,
     },
+    edit_asset::{EditAssetRequestCreate, EditAssetRequestExecute},
     edit_permission::{EditPermissionRequestCreate, EditPermissionRequestExecute},
     edit_request_policy::{EditRequestPolicyRequestCreate, EditRequestPolicyRequestExecute},
     edit_user::{EditUserRequestCreate, EditUserRequestExecute},
     edit_user_group::{EditUserGroupRequestCreate, EditUserGroupRequestExecute},
+    link_user_identity::LinkUserIdentityRequestExecute,
     remove_address_book_entry::{
         RemoveAddressBookEntryRequestCreate, RemoveAddressBookEntryRequestExecute,
     },
+    remove_asset::{RemoveAssetRequestCreate, RemoveAssetRequestExecute},
     remove_request_policy::{RemoveRequestPolicyRequestCreate, RemoveRequestPolicyRequestExecute},
+    remove_user::{RemoveUserRequestCreate, RemoveUserRequestExecute},
     remove_user_group::{RemoveUserGroupRequestCreate, RemoveUserGroupRequestExecute},
+    revoke_allowance::{RevokeAllowanceRequestCreate, RevokeAllowanceRequestExecute},
     transfer::{TransferRequestCreate, TransferRequestExecute},
 };
 
@@ -102,6 +128,12 @@ impl RequestFactory {
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
             }
+            RequestOperationInput::BatchTransfer(operation) => {
+                let creator = Box::new(BatchTransferRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
             RequestOperationInput::AddAccount(operation) => {
                 let creator = Box::new(AddAccountRequestCreate {});
                 creator
@@ -132,6 +164,48 @@ impl RequestFactory {
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
             }
+            RequestOperationInput::AddAsset(operation) => {
+                let creator = Box::new(AddAssetRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::EditAsset(operation) => {
+                let creator = Box::new(EditAssetRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::RemoveAsset(operation) => {
+                let creator = Box::new(RemoveAssetRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::ApproveAllowance(operation) => {
+                let creator = Box::new(ApproveAllowanceRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::RevokeAllowance(operation) => {
+                let creator = Box::new(RevokeAllowanceRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::CancelStandingOrder(operation) => {
+                let creator = Box::new(CancelStandingOrderRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::AddApprovalDelegation(operation) => {
+                let creator = Box::new(AddApprovalDelegationRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
             RequestOperationInput::AddUserGroup(operation) => {
                 let creator = Box::new(AddUserGroupRequestCreate {});
                 creator
@@ -150,6 +224,12 @@ impl RequestFactory {
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
             }
+            RequestOperationInput::ApplyRoleTemplate(operation) => {
+                let creator = Box::new(ApplyRoleTemplateRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
             RequestOperationInput::AddUser(operation) => {
                 let creator = Box::new(AddUserRequestCreate {});
                 creator
@@ -162,6 +242,12 @@ impl RequestFactory {
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
             }
+            RequestOperationInput::RemoveUser(operation) => {
+                let creator = Box::new(RemoveUserRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
             RequestOperationInput::ChangeCanister(operation) => {
                 let creator = Box::new(ChangeCanisterRequestCreate {});
                 creator
@@ -218,6 +304,12 @@ impl RequestFactory {
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
             }
+            RequestOperationInput::Bundle(operation) => {
+                let creator = Box::new(BundleRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
         }
     }
 
@@ -226,6 +318,9 @@ impl RequestFactory {
             RequestOperation::Transfer(operation) => {
                 Box::new(TransferRequestExecute::new(request, operation))
             }
+            RequestOperation::BatchTransfer(operation) => {
+                Box::new(BatchTransferRequestExecute::new(request, operation))
+            }
             RequestOperation::AddAccount(operation) => {
                 Box::new(AddAccountRequestExecute::new(request, operation))
             }
@@ -241,6 +336,27 @@ impl RequestFactory {
             RequestOperation::RemoveAddressBookEntry(operation) => Box::new(
                 RemoveAddressBookEntryRequestExecute::new(request, operation),
             ),
+            RequestOperation::AddAsset(operation) => {
+                Box::new(AddAssetRequestExecute::new(request, operation))
+            }
+            RequestOperation::EditAsset(operation) => {
+                Box::new(EditAssetRequestExecute::new(request, operation))
+            }
+            RequestOperation::RemoveAsset(operation) => {
+                Box::new(RemoveAssetRequestExecute::new(request, operation))
+            }
+            RequestOperation::ApproveAllowance(operation) => {
+                Box::new(ApproveAllowanceRequestExecute::new(request, operation))
+            }
+            RequestOperation::RevokeAllowance(operation) => {
+                Box::new(RevokeAllowanceRequestExecute::new(request, operation))
+            }
+            RequestOperation::CancelStandingOrder(operation) => {
+                Box::new(CancelStandingOrderRequestExecute::new(request, operation))
+            }
+            RequestOperation::AddApprovalDelegation(operation) => {
+                Box::new(AddApprovalDelegationRequestExecute::new(request, operation))
+            }
             RequestOperation::AddUserGroup(operation) => {
                 Box::new(AddUserGroupRequestExecute::new(request, operation))
             }
@@ -250,12 +366,21 @@ impl RequestFactory {
             RequestOperation::RemoveUserGroup(operation) => {
                 Box::new(RemoveUserGroupRequestExecute::new(request, operation))
             }
+            RequestOperation::ApplyRoleTemplate(operation) => {
+                Box::new(ApplyRoleTemplateRequestExecute::new(request, operation))
+            }
             RequestOperation::AddUser(operation) => {
                 Box::new(AddUserRequestExecute::new(request, operation))
             }
             RequestOperation::EditUser(operation) => {
                 Box::new(EditUserRequestExecute::new(request, operation))
             }
+            RequestOperation::RemoveUser(operation) => {
+                Box::new(RemoveUserRequestExecute::new(request, operation))
+            }
+            RequestOperation::LinkUserIdentity(operation) => {
+                Box::new(LinkUserIdentityRequestExecute::new(request, operation))
+            }
             RequestOperation::ChangeCanister(operation) => {
                 Box::new(ChangeCanisterRequestExecute::new(
                     request,
@@ -316,6 +441,9 @@ impl RequestFactory {
             RequestOperation::ManageSystemInfo(operation) => Box::new(
                 manage_system_info::ManageSystemInfoRequestExecute::new(request, operation),
             ),
+            RequestOperation::Bundle(operation) => {
+                Box::new(BundleRequestExecute::new(request, operation))
+            }
         }
     }
 }
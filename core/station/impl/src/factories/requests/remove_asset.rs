@@ -0,0 +1,77 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    mappers::HelperMapper,
+    models::{
+        RemoveAssetOperation, RemoveAssetOperationInput, Request, RequestExecutionPlan,
+        RequestOperation,
+    },
+    services::ASSET_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct RemoveAssetRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::RemoveAssetOperationInput> for RemoveAssetRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::RemoveAssetOperationInput,
+    ) -> Result<Request, RequestError> {
+        let asset_id = HelperMapper::to_uuid(operation_input.asset_id).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid asset id: {}", e),
+            }
+        })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::RemoveAsset(RemoveAssetOperation {
+                input: RemoveAssetOperationInput {
+                    asset_id: *asset_id.as_bytes(),
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "Asset removal".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct RemoveAssetRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o RemoveAssetOperation,
+}
+
+impl<'p, 'o> RemoveAssetRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o RemoveAssetOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for RemoveAssetRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        ASSET_SERVICE
+            .remove(self.operation.input.to_owned())
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to remove asset: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
@@ -33,6 +33,12 @@ impl Create<station_api::RemoveRequestPolicyOperationInput> for RemoveRequestPol
                 ),
             })?;
 
+        REQUEST_POLICY_SERVICE
+            .validate_policy_change(Some(&operation_input.policy_id), None)
+            .map_err(|e| RequestError::ValidationError {
+                info: e.to_string(),
+            })?;
+
         let request = Request::new(
             request_id,
             requested_by_user,
@@ -77,6 +83,13 @@ impl<'p, 'o> RemoveRequestPolicyRequestExecute<'p, 'o> {
 #[async_trait]
 impl Execute for RemoveRequestPolicyRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        // the policies may have changed since the request was created
+        self.policy_service
+            .validate_policy_change(Some(&self.operation.input.policy_id), None)
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to remove request policy: {}", e),
+            })?;
+
         self.policy_service
             .remove_request_policy(&self.operation.input.policy_id)
             .await
@@ -94,7 +107,9 @@ impl Execute for RemoveRequestPolicyRequestExecute<'_, '_> {
 mod tests {
     use super::*;
     use crate::{
-        models::request_policy_test_utils::mock_request_policy,
+        models::{
+            request_policy_test_utils::mock_request_policy, request_specifier::RequestSpecifier,
+        },
         repositories::{request_policy::REQUEST_POLICY_REPOSITORY, REQUEST_REPOSITORY},
     };
     use orbit_essentials::repository::Repository;
@@ -235,6 +250,60 @@ mod tests {
             );
         }
     }
+
+    #[tokio::test]
+    async fn test_execute_request_should_fail_when_it_locks_out() {
+        let request_id = [0u8; 16];
+        let requested_by_user = [1u8; 16];
+        let operation_input =
+            remove_request_policy_test_utils::mock_remove_request_policy_api_input();
+        let mut request_input = remove_request_policy_test_utils::mock_request_api_input();
+        request_input.operation =
+            station_api::RequestOperationInput::RemoveRequestPolicy(operation_input.clone());
+
+        let mut policy = mock_request_policy();
+        policy.id = *Uuid::from_str(&operation_input.policy_id)
+            .unwrap()
+            .as_bytes();
+        policy.specifier = RequestSpecifier::ChangeCanister;
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.to_owned());
+
+        let mut fallback_policy = mock_request_policy();
+        fallback_policy.specifier = RequestSpecifier::ChangeCanister;
+        REQUEST_POLICY_REPOSITORY.insert(fallback_policy.id, fallback_policy.to_owned());
+
+        let creator = Box::new(RemoveRequestPolicyRequestCreate {});
+        let request = creator
+            .create(
+                request_id,
+                requested_by_user,
+                request_input,
+                operation_input,
+            )
+            .await
+            .unwrap();
+
+        // the fallback policy is removed while the request is pending
+        REQUEST_POLICY_REPOSITORY.remove(&fallback_policy.id);
+
+        if let RequestOperation::RemoveRequestPolicy(operation) = &request.operation {
+            let stage = RemoveRequestPolicyRequestExecute::new(
+                &request,
+                operation,
+                Arc::clone(&REQUEST_POLICY_SERVICE),
+            )
+            .execute()
+            .await;
+
+            assert!(stage.is_err());
+            assert!(REQUEST_POLICY_REPOSITORY.get(&policy.id).is_some());
+        } else {
+            panic!(
+                "Expected RemoveRequestPolicy operation, got {:?}",
+                request.operation
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,225 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{
+        RemoveUserOperation, RemoveUserOperationInput, Request, RequestExecutionPlan,
+        RequestOperation,
+    },
+    services::{
+        permission::PERMISSION_SERVICE, APPROVAL_DELEGATION_SERVICE, REQUEST_POLICY_SERVICE,
+        REQUEST_SERVICE, USER_SERVICE,
+    },
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+use uuid::Uuid;
+
+pub struct RemoveUserRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::RemoveUserOperationInput> for RemoveUserRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::RemoveUserOperationInput,
+    ) -> Result<Request, RequestError> {
+        let operation_input = RemoveUserOperationInput::from(operation_input);
+        let user = USER_SERVICE
+            .get_user(&operation_input.user_id)
+            .map_err(|_| RequestError::ValidationError {
+                info: format!(
+                    "User with id {} does not exist",
+                    Uuid::from_bytes(operation_input.user_id).hyphenated()
+                ),
+            })?;
+
+        if user.is_archived() {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "User with id {} was already removed",
+                    Uuid::from_bytes(user.id).hyphenated()
+                ),
+            });
+        }
+
+        PERMISSION_SERVICE
+            .validate_user_removal(&user.id)
+            .map_err(|e| RequestError::ValidationError {
+                info: e.to_string(),
+            })?;
+
+        REQUEST_POLICY_SERVICE
+            .validate_user_removal(&user.id)
+            .map_err(|e| RequestError::ValidationError {
+                info: e.to_string(),
+            })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::RemoveUser(RemoveUserOperation {
+                input: operation_input,
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "User removal".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct RemoveUserRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o RemoveUserOperation,
+}
+
+impl<'p, 'o> RemoveUserRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o RemoveUserOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for RemoveUserRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let user_id = self.operation.input.user_id;
+
+        // must be looked up before the user is archived, since only active users can approve
+        let affected_requests = REQUEST_SERVICE
+            .find_open_requests_approvable_by(&user_id)
+            .await;
+
+        USER_SERVICE
+            .archive_user(&user_id)
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to remove user: {}", e),
+            })?;
+
+        PERMISSION_SERVICE.remove_user_from_permissions(&user_id);
+        REQUEST_POLICY_SERVICE.remove_user_from_policies(&user_id);
+        APPROVAL_DELEGATION_SERVICE.remove_user_delegations(&user_id);
+
+        REQUEST_SERVICE
+            .reevaluate_requests(&affected_requests)
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!(
+                    "Failed to reevaluate the requests of the removed user: {}",
+                    e
+                ),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            permission::{Allow, Permission},
+            request_policy_rule::RequestPolicyRule,
+            request_policy_test_utils::mock_request_policy,
+            request_specifier::{RequestSpecifier, UserSpecifier},
+            request_test_utils::mock_request,
+            resource::{Resource, ResourceAction, ResourceId},
+            user_test_utils::add_user,
+            AddUserGroupOperation, AddUserGroupOperationInput, Percentage, RequestApprovalStatus,
+            RequestStatus, UserStatus,
+        },
+        repositories::{
+            permission::PERMISSION_REPOSITORY, request_policy::REQUEST_POLICY_REPOSITORY,
+            REQUEST_REPOSITORY, USER_REPOSITORY,
+        },
+    };
+    use orbit_essentials::{model::ModelKey, repository::Repository};
+
+    #[tokio::test]
+    async fn remove_user_cascades_to_permissions_policies_and_open_requests() {
+        test_utils::init_canister_system();
+
+        let departed = add_user(&[1; 16]);
+        let remaining = add_user(&[2; 16]);
+
+        let resource = Resource::AddressBook(ResourceAction::Read(ResourceId::Any));
+        let permission = Permission::new(
+            Allow::users(vec![departed.id, remaining.id]),
+            resource.clone(),
+        );
+        PERMISSION_REPOSITORY.insert(permission.key(), permission.to_owned());
+
+        let mut policy = mock_request_policy();
+        policy.specifier = RequestSpecifier::AddUserGroup;
+        policy.rule = RequestPolicyRule::QuorumPercentage(
+            UserSpecifier::Id(vec![departed.id, remaining.id]),
+            Percentage(100),
+        );
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.to_owned());
+
+        // the remaining user already approved, which reaches the quorum once the other leaves
+        let mut open_request = mock_request();
+        open_request.status = RequestStatus::Created;
+        open_request.operation = RequestOperation::AddUserGroup(AddUserGroupOperation {
+            user_group_id: None,
+            input: AddUserGroupOperationInput {
+                name: "Finance".to_string(),
+            },
+        });
+        open_request.approvals = vec![];
+        open_request
+            .add_approval(remaining.id, RequestApprovalStatus::Approved, None)
+            .unwrap();
+        REQUEST_REPOSITORY.insert(open_request.to_key(), open_request.to_owned());
+
+        let mut request = mock_request();
+        request.operation = RequestOperation::RemoveUser(RemoveUserOperation {
+            input: RemoveUserOperationInput {
+                user_id: departed.id,
+            },
+        });
+        let RequestOperation::RemoveUser(operation) = &request.operation else {
+            unreachable!()
+        };
+
+        RemoveUserRequestExecute::new(&request, operation)
+            .execute()
+            .await
+            .unwrap();
+
+        let tombstone = USER_REPOSITORY.get(&departed.to_key()).unwrap();
+        assert_eq!(tombstone.status, UserStatus::Archived);
+        assert_eq!(tombstone.name, departed.name);
+        assert!(tombstone.identities.is_empty());
+
+        assert_eq!(
+            PERMISSION_REPOSITORY.get(&resource).unwrap().allow.users,
+            vec![remaining.id]
+        );
+        assert_eq!(
+            REQUEST_POLICY_REPOSITORY.get(&policy.id).unwrap().rule,
+            RequestPolicyRule::QuorumPercentage(
+                UserSpecifier::Id(vec![remaining.id]),
+                Percentage(100),
+            )
+        );
+        // the approved request is scheduled for execution right away
+        assert!(matches!(
+            REQUEST_REPOSITORY
+                .get(&open_request.to_key())
+                .unwrap()
+                .status,
+            RequestStatus::Scheduled { .. }
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    mappers::HelperMapper,
+    models::{
+        Request, RequestExecutionPlan, RequestOperation, RevokeAllowanceOperation,
+        RevokeAllowanceOperationInput,
+    },
+    services::ALLOWANCE_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::model::ModelValidator;
+use orbit_essentials::types::UUID;
+
+pub struct RevokeAllowanceRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::RevokeAllowanceOperationInput> for RevokeAllowanceRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::RevokeAllowanceOperationInput,
+    ) -> Result<Request, RequestError> {
+        let from_account_id =
+            HelperMapper::to_uuid(operation_input.from_account_id).map_err(|e| {
+                RequestError::ValidationError {
+                    info: format!("Invalid from_account_id: {}", e),
+                }
+            })?;
+        let allowance_id = HelperMapper::to_uuid(operation_input.allowance_id).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid allowance_id: {}", e),
+            }
+        })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::RevokeAllowance(RevokeAllowanceOperation {
+                input: RevokeAllowanceOperationInput {
+                    from_account_id: *from_account_id.as_bytes(),
+                    allowance_id: *allowance_id.as_bytes(),
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Allowance revocation".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct RevokeAllowanceRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o RevokeAllowanceOperation,
+}
+
+impl<'p, 'o> RevokeAllowanceRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o RevokeAllowanceOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for RevokeAllowanceRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        ALLOWANCE_SERVICE
+            .revoke(self.operation.input.to_owned())
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to revoke allowance: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
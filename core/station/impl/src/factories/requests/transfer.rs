@@ -5,8 +5,8 @@ use crate::{
     factories::blockchains::BlockchainApiFactory,
     mappers::HelperMapper,
     models::{
-        Account, Metadata, Request, RequestExecutionPlan, RequestOperation, Transfer,
-        TransferOperation, TransferOperationInput,
+        validate_blockchain_address, Account, Metadata, Request, RequestExecutionPlan,
+        RequestOperation, Transfer, TransferOperation, TransferOperationInput,
     },
     repositories::ACCOUNT_REPOSITORY,
     services::TransferService,
@@ -21,6 +21,42 @@ fn get_account(from_account_id: &UUID) -> Option<Account> {
     ACCOUNT_REPOSITORY.get(&Account::key(*from_account_id))
 }
 
+/// Maps the transfer of the api input that is sent from the given account, keeping its metadata and
+/// network.
+pub fn transfer_operation_input(
+    from_account_id: UUID,
+    input: station_api::TransferOperationInput,
+) -> TransferOperationInput {
+    TransferOperationInput {
+        from_account_id,
+        to: input.to,
+        amount: input.amount,
+        fee: input.fee,
+        metadata: Metadata::from(input.metadata),
+        network: match input.network {
+            Some(network) => network.id,
+            None => "mainnet".to_string(),
+        },
+    }
+}
+
+/// Checks that the destination of the transfer is well formed for the blockchain and standard of
+/// the account it is sent from.
+pub fn validate_transfer_destination(
+    transfer: &TransferOperationInput,
+) -> Result<(), RequestError> {
+    let Some(account) = get_account(&transfer.from_account_id) else {
+        // the existence of the account is checked by the request validation
+        return Ok(());
+    };
+
+    validate_blockchain_address(&account.blockchain, &account.standard, &transfer.to).map_err(
+        |reason| RequestError::ValidationError {
+            info: format!("Invalid destination address `{}`: {}", transfer.to, reason),
+        },
+    )
+}
+
 pub struct TransferRequestCreate {}
 
 #[async_trait]
@@ -32,11 +68,9 @@ impl Create<station_api::TransferOperationInput> for TransferRequestCreate {
         input: station_api::CreateRequestInput,
         operation_input: station_api::TransferOperationInput,
     ) -> Result<Request, RequestError> {
-        let from_account_id =
-            HelperMapper::to_uuid(operation_input.from_account_id).map_err(|e| {
-                RequestError::ValidationError {
-                    info: format!("Invalid from_account_id: {}", e),
-                }
+        let from_account_id = HelperMapper::to_uuid(operation_input.from_account_id.clone())
+            .map_err(|e| RequestError::ValidationError {
+                info: format!("Invalid from_account_id: {}", e),
             })?;
         let request = Request::new(
             request_id,
@@ -44,19 +78,7 @@ impl Create<station_api::TransferOperationInput> for TransferRequestCreate {
             Request::default_expiration_dt_ns(),
             RequestOperation::Transfer(TransferOperation {
                 transfer_id: None,
-                input: TransferOperationInput {
-                    from_account_id: *from_account_id.as_bytes(),
-                    to: operation_input.to,
-                    amount: operation_input.amount,
-                    fee: operation_input.fee,
-                    // todo: add metadata mapping
-                    metadata: Metadata::default(),
-                    // todo: add network mapping
-                    network: match operation_input.network {
-                        Some(network) => network.id,
-                        None => "mainnet".to_string(),
-                    },
-                },
+                input: transfer_operation_input(*from_account_id.as_bytes(), operation_input),
             }),
             input
                 .execution_plan
@@ -68,6 +90,10 @@ impl Create<station_api::TransferOperationInput> for TransferRequestCreate {
 
         request.validate()?;
 
+        if let RequestOperation::Transfer(operation) = &request.operation {
+            validate_transfer_destination(&operation.input)?;
+        }
+
         Ok(request)
     }
 }
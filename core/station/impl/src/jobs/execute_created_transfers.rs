@@ -7,7 +7,8 @@ use crate::{
         TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY,
     },
     models::{
-        Account, Request, RequestOperation, RequestStatus, Transfer, TransferId, TransferStatus,
+        Account, Request, RequestExecutionPlan, RequestId, RequestOperation, RequestStatus,
+        Transfer, TransferId, TransferStatus,
     },
     repositories::{AccountRepository, RequestRepository, TransferRepository},
     services::RequestService,
@@ -16,7 +17,7 @@ use async_trait::async_trait;
 use futures::future;
 
 use orbit_essentials::repository::Repository;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use uuid::Uuid;
 
@@ -107,6 +108,9 @@ impl Job {
         let results = future::join_all(calls).await;
         let transfers = transfers.clone();
 
+        // batch transfer requests are only updated once all of their transfers are final
+        let mut batch_request_ids: BTreeSet<RequestId> = BTreeSet::new();
+
         for (pos, result) in results.iter().enumerate() {
             match result {
                 Ok((transfer, details)) => {
@@ -128,6 +132,16 @@ impl Job {
                         .insert(transfer.to_key(), transfer.to_owned());
 
                     if let Some(request) = requests.get(&transfer.id) {
+                        // the request of a standing order outlives its transfers
+                        if let RequestExecutionPlan::Recurring(_) = request.execution_plan {
+                            continue;
+                        }
+
+                        if let RequestOperation::BatchTransfer(_) = request.operation {
+                            batch_request_ids.insert(request.id);
+                            continue;
+                        }
+
                         let mut request = request.clone();
 
                         if let RequestOperation::Transfer(transfer_operation) =
@@ -160,6 +174,16 @@ impl Job {
                         .insert(transfer.to_key(), transfer.to_owned());
 
                     if let Some(request) = requests.get(&transfer.id) {
+                        // the request of a standing order outlives its transfers
+                        if let RequestExecutionPlan::Recurring(_) = request.execution_plan {
+                            continue;
+                        }
+
+                        if let RequestOperation::BatchTransfer(_) = request.operation {
+                            batch_request_ids.insert(request.id);
+                            continue;
+                        }
+
                         let mut request = request.clone();
                         request.status = RequestStatus::Failed {
                             reason: Some(e.to_string()),
@@ -179,9 +203,82 @@ impl Job {
             }
         }
 
+        for request_id in batch_request_ids.iter() {
+            self.update_batch_transfer_request(request_id).await;
+        }
+
         processing_all_transfers
     }
 
+    /// Updates the status of a batch transfer request once all of its transfers are final.
+    ///
+    /// The request is completed if all of its transfers are completed, otherwise it fails with
+    /// the reason of each failed row so that the partial execution is visible on the request.
+    async fn update_batch_transfer_request(&self, request_id: &RequestId) {
+        let mut request = match self.request_repository.get(&Request::key(*request_id)) {
+            Some(request) => request,
+            None => {
+                print(format!(
+                    "Error: batch transfer request {} not found",
+                    Uuid::from_bytes(*request_id).hyphenated()
+                ));
+
+                return;
+            }
+        };
+
+        let (failed_rows, total_rows) = match &request.operation {
+            RequestOperation::BatchTransfer(operation) => {
+                if operation.transfer_ids.len() != operation.input.transfers.len() {
+                    return;
+                }
+
+                let mut failed_rows = Vec::new();
+                for (row, transfer_id) in operation.transfer_ids.iter().enumerate() {
+                    match self
+                        .transfer_repository
+                        .get(&Transfer::key(*transfer_id))
+                        .map(|transfer| transfer.status)
+                    {
+                        Some(TransferStatus::Completed { .. }) => (),
+                        Some(TransferStatus::Failed { reason }) => {
+                            failed_rows.push(format!("row {}: {}", row, reason));
+                        }
+                        // the batch is still being processed
+                        _ => return,
+                    }
+                }
+
+                (failed_rows, operation.transfer_ids.len())
+            }
+            _ => return,
+        };
+
+        let request_updated_time = next_time();
+
+        request.status = if failed_rows.is_empty() {
+            RequestStatus::Completed {
+                completed_at: request_updated_time,
+            }
+        } else {
+            RequestStatus::Failed {
+                reason: Some(format!(
+                    "{} of {} transfers failed: {}",
+                    failed_rows.len(),
+                    total_rows,
+                    failed_rows.join("; ")
+                )),
+            }
+        };
+        request.last_modification_timestamp = request_updated_time;
+        self.request_repository
+            .insert(request.to_key(), request.to_owned());
+
+        if !failed_rows.is_empty() {
+            self.request_service.failed_request_hook(&request).await;
+        }
+    }
+
     /// Executes a single transfer.
     ///
     /// This function will handle the submission of the transfer to the blockchain.
@@ -1,11 +1,11 @@
-use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use super::{execute_standing_orders, scheduler::Scheduler, JobType, ScheduledJob};
 use crate::{
     core::ic_cdk::next_time,
     errors::RequestExecuteError,
     factories::requests::{RequestExecuteStage, RequestFactory},
-    models::{Request, RequestStatus},
+    models::{Request, RequestExecutionPlan, RequestStatus},
     repositories::RequestRepository,
-    services::RequestService,
+    services::{RequestService, STANDING_ORDER_SERVICE},
 };
 use async_trait::async_trait;
 use futures::future;
@@ -94,6 +94,32 @@ impl Job {
     ///
     /// This function will handle the request execution for the given operation type.
     async fn execute_request(&self, mut request: Request) -> Result<Request, RequestExecuteError> {
+        // recurring requests are executed by their standing order
+        if let RequestExecutionPlan::Recurring(_) = request.execution_plan {
+            let standing_order = STANDING_ORDER_SERVICE.start(&request).map_err(|e| {
+                RequestExecuteError::Failed {
+                    reason: format!("Failed to create standing order: {}", e),
+                }
+            })?;
+
+            let request_execution_time = next_time();
+            request.status = match standing_order.next_execution_at {
+                Some(next_execution_at) => {
+                    execute_standing_orders::schedule_standing_order_execution(next_execution_at);
+
+                    RequestStatus::Processing {
+                        started_at: request_execution_time,
+                    }
+                }
+                None => RequestStatus::Completed {
+                    completed_at: request_execution_time,
+                },
+            };
+            request.last_modification_timestamp = request_execution_time;
+
+            return Ok(request);
+        }
+
         let executor = RequestFactory::executor(&request);
 
         let execute_state = executor.execute().await?;
@@ -125,3 +151,7 @@ impl Job {
 pub fn schedule_request_execution(at_ns: u64) {
     Scheduler::schedule::<Job>(at_ns);
 }
+
+pub fn cancel_scheduled_request_execution(at_ns: u64) {
+    Scheduler::cancel_scheduled_timer::<Job>(at_ns);
+}
@@ -0,0 +1,61 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::ic_cdk::{api::print, next_time},
+    services::STANDING_ORDER_SERVICE,
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Default)]
+pub struct Job {}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::ExecuteStandingOrders;
+    async fn run() -> bool {
+        Self::default().execute_standing_orders().await
+    }
+}
+
+/// This job is responsible for creating the transfers of the standing orders that are due.
+impl Job {
+    pub const MAX_BATCH_SIZE: usize = 20;
+
+    /// Executes the next occurrence of the standing orders that are due and schedules the job
+    /// for their following occurrence.
+    ///
+    /// This function will process a maximum of `MAX_BATCH_SIZE` standing orders at once.
+    async fn execute_standing_orders(&self) -> bool {
+        let current_time = next_time();
+        let mut standing_orders = STANDING_ORDER_SERVICE.find_due(current_time);
+
+        let processing_all_standing_orders = standing_orders.len() <= Self::MAX_BATCH_SIZE;
+
+        // truncate the list to avoid processing too many standing orders at once
+        standing_orders.truncate(Self::MAX_BATCH_SIZE);
+
+        for standing_order in standing_orders {
+            match STANDING_ORDER_SERVICE
+                .execute_next_occurrence(&standing_order.request_id)
+                .await
+            {
+                Ok(standing_order) => {
+                    if let Some(next_execution_at) = standing_order.next_execution_at {
+                        schedule_standing_order_execution(next_execution_at);
+                    }
+                }
+                Err(e) => print(format!(
+                    "Error: failed to execute standing order {}: {}",
+                    Uuid::from_bytes(standing_order.request_id).hyphenated(),
+                    e
+                )),
+            }
+        }
+
+        processing_all_standing_orders
+    }
+}
+
+pub fn schedule_standing_order_execution(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
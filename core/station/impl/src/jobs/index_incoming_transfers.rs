@@ -0,0 +1,55 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::ic_cdk::{api::print, next_time},
+    repositories::ACCOUNT_REPOSITORY,
+    services::INCOMING_TRANSFER_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::repository::Repository;
+use uuid::Uuid;
+
+#[derive(Debug, Default)]
+pub struct Job {}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::IndexIncomingTransfers;
+    async fn run() -> bool {
+        Self::default().index_incoming_transfers().await
+    }
+}
+
+/// This job is responsible for indexing the deposits into the station accounts from their ledger history.
+impl Job {
+    /// The time between two runs once all the accounts are synced (5 minutes).
+    pub const INDEXING_INTERVAL_NS: u64 = 5 * 60 * 1_000_000_000;
+
+    /// Indexes the next page of the ledger history of each account.
+    ///
+    /// The job runs again right away while there are accounts with older transactions left to index,
+    /// otherwise it is scheduled for the next interval.
+    async fn index_incoming_transfers(&self) -> bool {
+        let mut all_accounts_synced = true;
+
+        for account in ACCOUNT_REPOSITORY.list() {
+            match INCOMING_TRANSFER_SERVICE.index_account(&account).await {
+                Ok(synced) => all_accounts_synced &= synced,
+                Err(e) => print(format!(
+                    "Error: failed to index the incoming transfers of account {}: {}",
+                    Uuid::from_bytes(account.id).hyphenated(),
+                    e
+                )),
+            }
+        }
+
+        if all_accounts_synced {
+            schedule_incoming_transfers_indexing(next_time() + Self::INDEXING_INTERVAL_NS);
+        }
+
+        all_accounts_synced
+    }
+}
+
+pub fn schedule_incoming_transfers_indexing(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
@@ -2,12 +2,13 @@
 //!
 //! The jobs are registered in the `register_jobs` function and are executed based on the defined timer intervals.
 use std::cell::RefCell;
+use std::cmp;
 use std::collections::HashMap;
 
 use crate::core::ic_cdk::next_time;
 use crate::core::ic_timers::TimerId;
 use crate::models::{RequestExecutionPlan, RequestStatusCode};
-use crate::repositories::TRANSFER_REPOSITORY;
+use crate::repositories::{STANDING_ORDER_REPOSITORY, TRANSFER_REPOSITORY};
 use crate::{
     core::observer::Observer,
     models::{Request, RequestStatus, Transfer, TransferStatus},
@@ -19,6 +20,8 @@ use orbit_essentials::repository::Repository;
 mod cancel_expired_requests;
 mod execute_created_transfers;
 mod execute_scheduled_requests;
+mod execute_standing_orders;
+mod index_incoming_transfers;
 mod scheduler;
 
 #[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
@@ -26,6 +29,8 @@ pub enum JobType {
     CancelExpiredRequests,
     ExecuteScheduledRequests,
     ExecuteCreatedTransfers,
+    ExecuteStandingOrders,
+    IndexIncomingTransfers,
 }
 
 #[async_trait]
@@ -177,6 +182,16 @@ fn schedule_request_for_execution(request: &Request) -> u64 {
     let scheduled_at = match &request.execution_plan {
         RequestExecutionPlan::Immediate => request_processing_time,
         RequestExecutionPlan::Scheduled { execution_time } => *execution_time,
+        // the standing order is created right away, its executions follow the plan
+        RequestExecutionPlan::Recurring(_) => request_processing_time,
+    };
+    // time locked requests wait for the delay to pass so that the approvers can still veto them
+    let scheduled_at = match request.time_lock_ns() {
+        Some(delay_ns) => cmp::max(
+            scheduled_at,
+            request_processing_time.saturating_add(delay_ns),
+        ),
+        None => scheduled_at,
     };
 
     let mut request = request.clone();
@@ -211,15 +226,22 @@ pub fn jobs_observe_insert_request(observer: &mut Observer<(Request, Option<Requ
                 execute_scheduled_requests::schedule_request_execution(scheduled_at);
             }
         }
-        RequestStatus::Rejected | RequestStatus::Cancelled { .. } => {
-            if let Some(Request {
+        RequestStatus::Rejected | RequestStatus::Cancelled { .. } => match prev {
+            Some(Request {
                 status: RequestStatus::Created,
                 ..
-            }) = prev
-            {
+            }) => {
                 cancel_expired_requests::cancel_scheduled_expiration(request.expiration_dt);
             }
-        }
+            // vetoed or cancelled before its execution, the execution timer is no longer needed
+            Some(Request {
+                status: RequestStatus::Scheduled { scheduled_at },
+                ..
+            }) => {
+                execute_scheduled_requests::cancel_scheduled_request_execution(*scheduled_at);
+            }
+            _ => {}
+        },
         RequestStatus::Scheduled { .. } => {
             // do nothing, these will exectuted by the timers already set when the request was approved
         }
@@ -284,6 +306,16 @@ pub fn initialize_job_timers() {
         // kick off execution timer for Transfers, once is enough
         execute_created_transfers::schedule_process_transfers(next_time());
     }
+
+    // start indexing the deposits into the accounts, the job keeps itself scheduled
+    index_incoming_transfers::schedule_incoming_transfers_indexing(next_time());
+
+    // start the execution timer for the next occurrence of each active standing order
+    for standing_order in STANDING_ORDER_REPOSITORY.find_active() {
+        if let Some(next_execution_at) = standing_order.next_execution_at {
+            execute_standing_orders::schedule_standing_order_execution(next_execution_at);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -444,6 +476,39 @@ mod test {
             .is_none());
     }
 
+    #[tokio::test]
+    async fn test_cancelled_request_removes_execution_timer() {
+        let scheduled_at = time() + Duration::from_secs(60 * 60).as_nanos() as u64;
+        let scheduled_coarse = to_coarse_time(
+            scheduled_at,
+            execute_scheduled_requests::Job::JOB_TOLERANCE_NS,
+        );
+
+        let mut request = Request {
+            status: RequestStatus::Scheduled { scheduled_at },
+            ..mock_request()
+        };
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+        execute_scheduled_requests::schedule_request_execution(scheduled_at);
+
+        assert_eq!(
+            JobStateDatabase::get_time_job_maps()
+                .get(&execute_scheduled_requests::Job::JOB_TYPE)
+                .expect("Job not scheduled at all")
+                .get(&scheduled_coarse)
+                .expect("Job not scheduled at this time")
+                .1,
+            1
+        );
+
+        request.status = RequestStatus::Cancelled { reason: None };
+        REQUEST_REPOSITORY.insert(request.to_key(), request);
+
+        assert!(JobStateDatabase::get_time_job_maps()
+            .get(&execute_scheduled_requests::Job::JOB_TYPE)
+            .is_none());
+    }
+
     #[tokio::test]
     async fn test_transfer_schedule_execution() {
         let transfer = mock_transfer();
@@ -528,8 +593,8 @@ mod test {
         // initialize the job timers
         crate::jobs::initialize_job_timers();
 
-        // all 3 job types should have timers set
-        assert_eq!(JobStateDatabase::get_time_job_maps().len(), 3);
+        // all 3 job types and the incoming transfers indexing should have timers set
+        assert_eq!(JobStateDatabase::get_time_job_maps().len(), 4);
 
         // 2 requests are scheduled for expiration
         assert_eq!(
@@ -1,9 +1,8 @@
 use crate::{
     core::ic_cdk::next_time,
-    errors::MapperError,
     models::{
         Account, AccountBalance, AccountCallerPrivileges, AccountId, AddAccountOperationInput,
-        BlockchainStandard, ACCOUNT_METADATA_SYMBOL_KEY,
+        Asset, ChangeMetadata,
     },
     repositories::request_policy::REQUEST_POLICY_REPOSITORY,
 };
@@ -20,6 +19,7 @@ impl AccountMapper {
         AccountDTO {
             id: Uuid::from_bytes(account.id).hyphenated().to_string(),
             name: account.name,
+            asset_id: Uuid::from_bytes(account.asset_id).hyphenated().to_string(),
             decimals: account.decimals,
             balance: match account.balance {
                 Some(balance) => Some(AccountBalanceInfoDTO {
@@ -64,57 +64,36 @@ impl AccountMapper {
         }
     }
 
+    /// Creates a new account from the user input, the blockchain, standard and symbol are taken from
+    /// the asset the account holds.
+    ///
+    /// The asset metadata (e.g. the ledger canister id) takes precedence over the metadata provided by the user.
     pub fn from_create_input(
         input: AddAccountOperationInput,
+        asset: Asset,
         account_id: AccountId,
         address: Option<String>,
-    ) -> Result<Account, MapperError> {
-        if !input
-            .blockchain
-            .supported_standards()
-            .contains(&input.standard)
-        {
-            return Err(MapperError::UnsupportedBlockchainStandard {
-                blockchain: input.blockchain.to_string(),
-                supported_standards: input
-                    .blockchain
-                    .supported_standards()
-                    .iter()
-                    .map(|s| s.to_string())
-                    .collect(),
-            });
-        }
-
-        let symbol = match input.standard {
-            BlockchainStandard::Native => {
-                if input.metadata.get(ACCOUNT_METADATA_SYMBOL_KEY).is_some() {
-                    return Err(MapperError::NativeAccountSymbolMetadataNotAllowed);
-                }
-
-                input.blockchain.native_symbol().to_string()
-            }
-            _ => input
-                .metadata
-                .get(ACCOUNT_METADATA_SYMBOL_KEY)
-                .ok_or(MapperError::NonNativeAccountSymbolRequired)?,
-        };
+    ) -> Account {
+        let mut metadata = input.metadata;
+        metadata.change(ChangeMetadata::OverrideSpecifiedBy(
+            asset.metadata.as_btreemap().to_owned(),
+        ));
 
-        let new_account = Account {
+        Account {
             id: account_id,
-            blockchain: input.blockchain,
-            standard: input.standard,
+            asset_id: asset.id,
+            blockchain: asset.blockchain,
+            standard: asset.standard,
             name: input.name,
             address: address.unwrap_or("".to_string()),
             decimals: 0,
-            symbol,
+            symbol: asset.symbol,
             transfer_request_policy_id: None,
             configs_request_policy_id: None,
             balance: None,
-            metadata: input.metadata,
+            metadata,
             last_modification_timestamp: next_time(),
-        };
-
-        Ok(new_account)
+        }
     }
 
     pub fn to_balance_dto(
@@ -0,0 +1,124 @@
+use crate::{
+    errors::AllowanceError,
+    models::{
+        Account, Allowance, AllowanceSpender, AllowanceStatus, ApproveAllowanceOperation,
+        ApproveAllowanceOperationInput, RevokeAllowanceOperation, RevokeAllowanceOperationInput,
+    },
+};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use uuid::Uuid;
+
+impl TryFrom<station_api::AllowanceSpenderDTO> for AllowanceSpender {
+    type Error = AllowanceError;
+
+    fn try_from(spender: station_api::AllowanceSpenderDTO) -> Result<Self, Self::Error> {
+        let subaccount = match spender.subaccount {
+            Some(subaccount) => {
+                Some(<[u8; 32]>::try_from(subaccount.as_slice()).map_err(|_| {
+                    AllowanceError::ValidationError {
+                        info: format!(
+                            "The spender subaccount must be 32 bytes long, got {}",
+                            subaccount.len()
+                        ),
+                    }
+                })?)
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            owner: spender.owner,
+            subaccount,
+        })
+    }
+}
+
+impl From<AllowanceSpender> for station_api::AllowanceSpenderDTO {
+    fn from(spender: AllowanceSpender) -> Self {
+        Self {
+            owner: spender.owner,
+            subaccount: spender.subaccount.map(|subaccount| subaccount.to_vec()),
+        }
+    }
+}
+
+impl From<AllowanceStatus> for station_api::AllowanceStatusDTO {
+    fn from(status: AllowanceStatus) -> Self {
+        match status {
+            AllowanceStatus::Active => station_api::AllowanceStatusDTO::Active,
+            AllowanceStatus::Revoked { .. } => station_api::AllowanceStatusDTO::Revoked,
+        }
+    }
+}
+
+impl From<Allowance> for station_api::AllowanceDTO {
+    fn from(allowance: Allowance) -> Self {
+        Self {
+            id: Uuid::from_bytes(allowance.id).hyphenated().to_string(),
+            account_id: Uuid::from_bytes(allowance.account_id)
+                .hyphenated()
+                .to_string(),
+            request_id: Uuid::from_bytes(allowance.request_id)
+                .hyphenated()
+                .to_string(),
+            spender: allowance.spender.into(),
+            amount: allowance.amount,
+            expires_at: allowance.expires_at.map(|dt| timestamp_to_rfc3339(&dt)),
+            status: allowance.status.into(),
+            created_at: timestamp_to_rfc3339(&allowance.created_timestamp),
+            last_modification_timestamp: timestamp_to_rfc3339(
+                &allowance.last_modification_timestamp,
+            ),
+        }
+    }
+}
+
+impl From<ApproveAllowanceOperationInput> for station_api::ApproveAllowanceOperationInput {
+    fn from(input: ApproveAllowanceOperationInput) -> Self {
+        Self {
+            from_account_id: Uuid::from_bytes(input.from_account_id)
+                .hyphenated()
+                .to_string(),
+            spender: input.spender.into(),
+            amount: input.amount,
+            expected_allowance: input.expected_allowance,
+            expires_at: input.expires_at.map(|dt| timestamp_to_rfc3339(&dt)),
+            fee: input.fee,
+        }
+    }
+}
+
+impl From<RevokeAllowanceOperationInput> for station_api::RevokeAllowanceOperationInput {
+    fn from(input: RevokeAllowanceOperationInput) -> Self {
+        Self {
+            from_account_id: Uuid::from_bytes(input.from_account_id)
+                .hyphenated()
+                .to_string(),
+            allowance_id: Uuid::from_bytes(input.allowance_id)
+                .hyphenated()
+                .to_string(),
+        }
+    }
+}
+
+impl ApproveAllowanceOperation {
+    pub fn to_dto(
+        self,
+        account: Option<Account>,
+        allowance: Option<Allowance>,
+    ) -> station_api::ApproveAllowanceOperationDTO {
+        station_api::ApproveAllowanceOperationDTO {
+            from_account: account.map(|account| account.to_dto()),
+            allowance: allowance.map(Into::into),
+            input: self.input.into(),
+        }
+    }
+}
+
+impl From<RevokeAllowanceOperation> for station_api::RevokeAllowanceOperationDTO {
+    fn from(operation: RevokeAllowanceOperation) -> Self {
+        Self {
+            input: operation.input.into(),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::models::{
+    AddApprovalDelegationOperation, AddApprovalDelegationOperationInput, ApprovalDelegation,
+};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use uuid::Uuid;
+
+impl From<ApprovalDelegation> for station_api::ApprovalDelegationDTO {
+    fn from(delegation: ApprovalDelegation) -> Self {
+        Self {
+            id: Uuid::from_bytes(delegation.id).hyphenated().to_string(),
+            delegator_id: Uuid::from_bytes(delegation.delegator_id)
+                .hyphenated()
+                .to_string(),
+            delegate_id: Uuid::from_bytes(delegation.delegate_id)
+                .hyphenated()
+                .to_string(),
+            specifier: delegation.specifier.map(Into::into),
+            expires_at: timestamp_to_rfc3339(&delegation.expires_at),
+            created_at: timestamp_to_rfc3339(&delegation.created_timestamp),
+        }
+    }
+}
+
+impl From<AddApprovalDelegationOperationInput>
+    for station_api::AddApprovalDelegationOperationInput
+{
+    fn from(input: AddApprovalDelegationOperationInput) -> Self {
+        Self {
+            delegator_id: Uuid::from_bytes(input.delegator_id)
+                .hyphenated()
+                .to_string(),
+            delegate_id: Uuid::from_bytes(input.delegate_id).hyphenated().to_string(),
+            specifier: input.specifier.map(Into::into),
+            expires_at: timestamp_to_rfc3339(&input.expires_at),
+        }
+    }
+}
+
+impl AddApprovalDelegationOperation {
+    pub fn to_dto(
+        self,
+        delegation: Option<ApprovalDelegation>,
+    ) -> station_api::AddApprovalDelegationOperationDTO {
+        station_api::AddApprovalDelegationOperationDTO {
+            delegation: delegation.map(Into::into),
+            input: self.input.into(),
+        }
+    }
+}
@@ -1,13 +1,110 @@
-use crate::models::Asset;
+use super::HelperMapper;
+use crate::models::{
+    AddAssetOperation, AddAssetOperationInput, Asset, AssetCallerPrivileges, EditAssetOperation,
+    EditAssetOperationInput, RemoveAssetOperation, RemoveAssetOperationInput,
+};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use uuid::Uuid;
 
 impl From<Asset> for station_api::AssetDTO {
     fn from(asset: Asset) -> Self {
         station_api::AssetDTO {
+            id: Uuid::from_bytes(asset.id).hyphenated().to_string(),
             blockchain: asset.blockchain.to_string(),
             symbol: asset.symbol.to_string(),
             standard: asset.standard.to_string(),
             name: asset.name,
             metadata: asset.metadata.into_vec_dto(),
+            last_modification_timestamp: timestamp_to_rfc3339(&asset.last_modification_timestamp),
+        }
+    }
+}
+
+impl From<AssetCallerPrivileges> for station_api::AssetCallerPrivilegesDTO {
+    fn from(privileges: AssetCallerPrivileges) -> Self {
+        Self {
+            id: Uuid::from_bytes(privileges.id).hyphenated().to_string(),
+            can_edit: privileges.can_edit,
+            can_delete: privileges.can_delete,
+        }
+    }
+}
+
+impl From<AddAssetOperationInput> for station_api::AddAssetOperationInput {
+    fn from(input: AddAssetOperationInput) -> Self {
+        Self {
+            blockchain: input.blockchain.to_string(),
+            standard: input.standard.to_string(),
+            symbol: input.symbol,
+            name: input.name,
+            metadata: input.metadata.into_vec_dto(),
+        }
+    }
+}
+
+impl From<station_api::EditAssetOperationInput> for EditAssetOperationInput {
+    fn from(input: station_api::EditAssetOperationInput) -> Self {
+        Self {
+            asset_id: *HelperMapper::to_uuid(input.asset_id)
+                .expect("Invalid UUID")
+                .as_bytes(),
+            name: input.name,
+            symbol: input.symbol,
+            change_metadata: input.change_metadata.map(Into::into),
+        }
+    }
+}
+
+impl From<EditAssetOperationInput> for station_api::EditAssetOperationInput {
+    fn from(input: EditAssetOperationInput) -> Self {
+        Self {
+            asset_id: Uuid::from_bytes(input.asset_id).hyphenated().to_string(),
+            name: input.name,
+            symbol: input.symbol,
+            change_metadata: input.change_metadata.map(Into::into),
+        }
+    }
+}
+
+impl From<station_api::RemoveAssetOperationInput> for RemoveAssetOperationInput {
+    fn from(input: station_api::RemoveAssetOperationInput) -> Self {
+        Self {
+            asset_id: *HelperMapper::to_uuid(input.asset_id)
+                .expect("Invalid UUID")
+                .as_bytes(),
+        }
+    }
+}
+
+impl From<RemoveAssetOperationInput> for station_api::RemoveAssetOperationInput {
+    fn from(input: RemoveAssetOperationInput) -> Self {
+        Self {
+            asset_id: Uuid::from_bytes(input.asset_id).hyphenated().to_string(),
+        }
+    }
+}
+
+impl AddAssetOperation {
+    pub fn to_dto(self, asset: Option<Asset>) -> station_api::AddAssetOperationDTO {
+        station_api::AddAssetOperationDTO {
+            asset: asset.map(|asset| asset.into()),
+            input: self.input.into(),
+        }
+    }
+}
+
+impl From<EditAssetOperation> for station_api::EditAssetOperationDTO {
+    fn from(operation: EditAssetOperation) -> Self {
+        Self {
+            input: operation.input.into(),
+        }
+    }
+}
+
+impl From<RemoveAssetOperation> for station_api::RemoveAssetOperationDTO {
+    fn from(operation: RemoveAssetOperation) -> Self {
+        Self {
+            input: operation.input.into(),
         }
     }
 }
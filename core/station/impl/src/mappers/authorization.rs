@@ -9,15 +9,19 @@ use crate::{
             PermissionResourceAction, RequestResourceAction, Resource, ResourceAction, ResourceId,
             SystemResourceAction, UserResourceAction,
         },
-        CanisterMethod, Transfer,
+        CanisterMethod, RequestTemplateOperation, Transfer,
+    },
+    repositories::{
+        REQUEST_COMMENT_REPOSITORY, REQUEST_TEMPLATE_REPOSITORY, STANDING_ORDER_REPOSITORY,
+        TRANSFER_REPOSITORY,
     },
-    repositories::TRANSFER_REPOSITORY,
 };
 use orbit_essentials::repository::Repository;
 use orbit_essentials::types::UUID;
 use station_api::{RequestOperationInput, UserPrivilege};
+use std::collections::BTreeSet;
 
-pub const USER_PRIVILEGES: [UserPrivilege; 16] = [
+pub const USER_PRIVILEGES: [UserPrivilege; 20] = [
     UserPrivilege::Capabilities,
     UserPrivilege::SystemInfo,
     UserPrivilege::ManageSystemInfo,
@@ -32,6 +36,10 @@ pub const USER_PRIVILEGES: [UserPrivilege; 16] = [
     UserPrivilege::AddUserGroup,
     UserPrivilege::ListAddressBookEntries,
     UserPrivilege::AddAddressBookEntry,
+    UserPrivilege::ListAssets,
+    UserPrivilege::AddAsset,
+    UserPrivilege::ListRequestTemplates,
+    UserPrivilege::AddRequestTemplate,
     UserPrivilege::ChangeCanister,
     UserPrivilege::ListRequests,
 ];
@@ -52,6 +60,10 @@ impl From<UserPrivilege> for Resource {
             UserPrivilege::AddUserGroup => Resource::UserGroup(ResourceAction::Create),
             UserPrivilege::ListAddressBookEntries => Resource::AddressBook(ResourceAction::List),
             UserPrivilege::AddAddressBookEntry => Resource::AddressBook(ResourceAction::Create),
+            UserPrivilege::ListAssets => Resource::Asset(ResourceAction::List),
+            UserPrivilege::AddAsset => Resource::Asset(ResourceAction::Create),
+            UserPrivilege::ListRequestTemplates => Resource::RequestTemplate(ResourceAction::List),
+            UserPrivilege::AddRequestTemplate => Resource::RequestTemplate(ResourceAction::Create),
             UserPrivilege::ChangeCanister => {
                 Resource::ChangeCanister(ChangeCanisterResourceAction::Create)
             }
@@ -83,6 +95,26 @@ impl From<&station_api::ListAccountTransfersInput> for Resource {
     }
 }
 
+impl From<&station_api::EstimateTransferInput> for Resource {
+    fn from(input: &station_api::EstimateTransferInput) -> Self {
+        Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+            *HelperMapper::to_uuid(input.transfer.from_account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::ListAccountActivityInput> for Resource {
+    fn from(input: &station_api::ListAccountActivityInput) -> Self {
+        Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
 impl From<&station_api::GetUserInput> for Resource {
     fn from(input: &station_api::GetUserInput) -> Self {
         Resource::User(UserResourceAction::Read(ResourceId::Id(
@@ -103,6 +135,84 @@ impl From<&station_api::GetRequestInput> for Resource {
     }
 }
 
+impl From<&station_api::AddRequestCommentInput> for Resource {
+    fn from(input: &station_api::AddRequestCommentInput) -> Self {
+        Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.request_id.to_owned())
+                .expect("Invalid request id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::ListRequestCommentsInput> for Resource {
+    fn from(input: &station_api::ListRequestCommentsInput) -> Self {
+        Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.request_id.to_owned())
+                .expect("Invalid request id")
+                .as_bytes(),
+        )))
+    }
+}
+
+// the request is looked up from the comment, unknown comments require access to all requests
+impl From<&station_api::EditRequestCommentInput> for Resource {
+    fn from(input: &station_api::EditRequestCommentInput) -> Self {
+        let comment_id = *HelperMapper::to_uuid(input.comment_id.to_owned())
+            .expect("Invalid comment id")
+            .as_bytes();
+
+        match REQUEST_COMMENT_REPOSITORY.get(&comment_id) {
+            Some(comment) => Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+                comment.request_id,
+            ))),
+            None => Resource::Request(RequestResourceAction::Read(ResourceId::Any)),
+        }
+    }
+}
+
+impl From<&station_api::AddRequestTemplateInput> for Resource {
+    fn from(_: &station_api::AddRequestTemplateInput) -> Self {
+        Resource::RequestTemplate(ResourceAction::Create)
+    }
+}
+
+impl From<&station_api::EditRequestTemplateInput> for Resource {
+    fn from(input: &station_api::EditRequestTemplateInput) -> Self {
+        Resource::RequestTemplate(ResourceAction::Update(ResourceId::Id(
+            *HelperMapper::to_uuid(input.template_id.to_owned())
+                .expect("Invalid request template id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::RemoveRequestTemplateInput> for Resource {
+    fn from(input: &station_api::RemoveRequestTemplateInput) -> Self {
+        Resource::RequestTemplate(ResourceAction::Delete(ResourceId::Id(
+            *HelperMapper::to_uuid(input.template_id.to_owned())
+                .expect("Invalid request template id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::GetRequestTemplateInput> for Resource {
+    fn from(input: &station_api::GetRequestTemplateInput) -> Self {
+        Resource::RequestTemplate(ResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.template_id.to_owned())
+                .expect("Invalid request template id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::ListRequestTemplatesInput> for Resource {
+    fn from(_: &station_api::ListRequestTemplatesInput) -> Self {
+        Resource::RequestTemplate(ResourceAction::List)
+    }
+}
+
 impl From<&station_api::GetRequestPolicyInput> for Resource {
     fn from(input: &station_api::GetRequestPolicyInput) -> Self {
         Resource::RequestPolicy(ResourceAction::Read(ResourceId::Id(
@@ -133,6 +243,36 @@ impl From<&station_api::SubmitRequestApprovalInput> for Resource {
     }
 }
 
+impl From<&station_api::ChangeRequestApprovalInput> for Resource {
+    fn from(input: &station_api::ChangeRequestApprovalInput) -> Self {
+        Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.request_id.to_owned())
+                .expect("Invalid request id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::VetoRequestInput> for Resource {
+    fn from(input: &station_api::VetoRequestInput) -> Self {
+        Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.request_id.to_owned())
+                .expect("Invalid request id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::CancelRequestInput> for Resource {
+    fn from(input: &station_api::CancelRequestInput) -> Self {
+        Resource::Request(RequestResourceAction::Cancel(ResourceId::Id(
+            *HelperMapper::to_uuid(input.request_id.to_owned())
+                .expect("Invalid request id")
+                .as_bytes(),
+        )))
+    }
+}
+
 impl From<&station_api::GetAddressBookEntryInputDTO> for Resource {
     fn from(input: &station_api::GetAddressBookEntryInputDTO) -> Self {
         Resource::AddressBook(ResourceAction::Read(ResourceId::Id(
@@ -143,9 +283,83 @@ impl From<&station_api::GetAddressBookEntryInputDTO> for Resource {
     }
 }
 
-impl From<&station_api::CreateRequestInput> for Resource {
-    fn from(input: &station_api::CreateRequestInput) -> Self {
-        match &input.operation {
+impl From<&station_api::ListAccountAllowancesInput> for Resource {
+    fn from(input: &station_api::ListAccountAllowancesInput) -> Self {
+        Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::GetAssetInput> for Resource {
+    fn from(input: &station_api::GetAssetInput) -> Self {
+        Resource::Asset(ResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.asset_id.to_owned())
+                .expect("Invalid asset id")
+                .as_bytes(),
+        )))
+    }
+}
+
+pub(crate) struct CreateRequestInputRef<'a>(pub &'a station_api::CreateRequestInput);
+
+impl CreateRequestInputRef<'_> {
+    /// Returns the resources that the caller must have access to in order to create the request.
+    ///
+    /// Batch transfers require the transfer permission of every source account and bundles require
+    /// the permissions of every operation that they contain.
+    pub fn to_resources(&self) -> Vec<Resource> {
+        let resource = match &self.0.operation {
+            // empty bundles are rejected when the request is created, since they don't change
+            // anything they only require the access that every participant of requests has
+            RequestOperationInput::Bundle(input) if input.operations.is_empty() => {
+                Resource::Request(RequestResourceAction::List)
+            }
+            RequestOperationInput::Bundle(input) => {
+                let mut resources = Vec::new();
+                for operation in &input.operations {
+                    let member = station_api::CreateRequestInput {
+                        operation: operation.to_owned(),
+                        title: None,
+                        summary: None,
+                        execution_plan: None,
+                    };
+
+                    for resource in CreateRequestInputRef(&member).to_resources() {
+                        if !resources.contains(&resource) {
+                            resources.push(resource);
+                        }
+                    }
+                }
+
+                return resources;
+            }
+            // empty batches are rejected when the request is created
+            RequestOperationInput::BatchTransfer(input) if input.transfers.is_empty() => {
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Any))
+            }
+            RequestOperationInput::BatchTransfer(input) => {
+                let account_ids = input
+                    .transfers
+                    .iter()
+                    .map(|transfer| {
+                        *HelperMapper::to_uuid(transfer.from_account_id.to_owned())
+                            .expect("Invalid account id")
+                            .as_bytes()
+                    })
+                    .collect::<BTreeSet<UUID>>();
+
+                return account_ids
+                    .into_iter()
+                    .map(|account_id| {
+                        Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                            account_id,
+                        )))
+                    })
+                    .collect();
+            }
             RequestOperationInput::AddAccount(_) => {
                 Resource::Account(AccountResourceAction::Create)
             }
@@ -188,6 +402,13 @@ impl From<&station_api::CreateRequestInput> for Resource {
                         .as_bytes(),
                 )))
             }
+            RequestOperationInput::RemoveUser(input) => {
+                Resource::User(UserResourceAction::Remove(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.user_id.to_owned())
+                        .expect("Invalid user id")
+                        .as_bytes(),
+                )))
+            }
             RequestOperationInput::AddUserGroup(_) => Resource::UserGroup(ResourceAction::Create),
             RequestOperationInput::EditUserGroup(input) => {
                 Resource::UserGroup(ResourceAction::Update(ResourceId::Id(
@@ -225,7 +446,8 @@ impl From<&station_api::CreateRequestInput> for Resource {
                     },
                 ))
             }
-            RequestOperationInput::EditPermission(_) => {
+            RequestOperationInput::EditPermission(_)
+            | RequestOperationInput::ApplyRoleTemplate(_) => {
                 Resource::Permission(PermissionResourceAction::Update)
             }
             RequestOperationInput::AddRequestPolicy(_) => {
@@ -248,7 +470,85 @@ impl From<&station_api::CreateRequestInput> for Resource {
             RequestOperationInput::ManageSystemInfo(_) => {
                 Resource::System(SystemResourceAction::ManageSystemInfo)
             }
-        }
+            RequestOperationInput::AddAsset(_) => Resource::Asset(ResourceAction::Create),
+            RequestOperationInput::EditAsset(input) => {
+                Resource::Asset(ResourceAction::Update(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.asset_id.to_owned())
+                        .expect("Invalid asset id")
+                        .as_bytes(),
+                )))
+            }
+            RequestOperationInput::RemoveAsset(input) => {
+                Resource::Asset(ResourceAction::Delete(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.asset_id.to_owned())
+                        .expect("Invalid asset id")
+                        .as_bytes(),
+                )))
+            }
+            RequestOperationInput::ApproveAllowance(input) => {
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.from_account_id.to_owned())
+                        .expect("Invalid account id")
+                        .as_bytes(),
+                )))
+            }
+            RequestOperationInput::RevokeAllowance(input) => {
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.from_account_id.to_owned())
+                        .expect("Invalid account id")
+                        .as_bytes(),
+                )))
+            }
+            // the account is looked up from the standing order, unknown ones require access to all
+            RequestOperationInput::CancelStandingOrder(input) => {
+                let request_id = *HelperMapper::to_uuid(input.request_id.to_owned())
+                    .expect("Invalid request id")
+                    .as_bytes();
+
+                match STANDING_ORDER_REPOSITORY.get(&request_id) {
+                    Some(standing_order) => Resource::Account(AccountResourceAction::Transfer(
+                        ResourceId::Id(standing_order.account_id),
+                    )),
+                    None => Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+                }
+            }
+            RequestOperationInput::AddApprovalDelegation(input) => {
+                Resource::User(UserResourceAction::Delegate(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.delegator_id.to_owned())
+                        .expect("Invalid user id")
+                        .as_bytes(),
+                )))
+            }
+        };
+
+        vec![resource]
+    }
+}
+
+pub(crate) struct CreateRequestFromTemplateInputRef<'a>(
+    pub &'a station_api::CreateRequestFromTemplateInput,
+);
+
+impl CreateRequestFromTemplateInputRef<'_> {
+    /// Returns the resources that the caller must have access to in order to instantiate the
+    /// template, which are the template itself and the resource of the request it creates.
+    pub fn to_resources(&self) -> Vec<Resource> {
+        let template_id = *HelperMapper::to_uuid(self.0.template_id.to_owned())
+            .expect("Invalid request template id")
+            .as_bytes();
+        let operation_resource = match REQUEST_TEMPLATE_REPOSITORY.get(&template_id) {
+            Some(template) => match template.operation {
+                RequestTemplateOperation::Transfer(transfer) => Resource::Account(
+                    AccountResourceAction::Transfer(ResourceId::Id(transfer.from_account_id)),
+                ),
+            },
+            None => Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+        };
+
+        vec![
+            Resource::RequestTemplate(ResourceAction::Read(ResourceId::Id(template_id))),
+            operation_resource,
+        ]
     }
 }
 
@@ -0,0 +1,16 @@
+use crate::models::IdentityLink;
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use uuid::Uuid;
+
+impl From<IdentityLink> for station_api::IdentityLinkDTO {
+    fn from(link: IdentityLink) -> Self {
+        Self {
+            id: Uuid::from_bytes(link.id).hyphenated().to_string(),
+            user_id: Uuid::from_bytes(link.user_id).hyphenated().to_string(),
+            new_identity: link.new_identity,
+            retire_current_identity: link.retire_current_identity,
+            expires_at: timestamp_to_rfc3339(&link.expires_at),
+            created_at: timestamp_to_rfc3339(&link.created_timestamp),
+        }
+    }
+}
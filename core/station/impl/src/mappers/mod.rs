@@ -8,6 +8,12 @@ pub mod asset;
 
 pub mod address_book;
 
+pub mod allowance;
+
+pub mod approval_delegation;
+
+pub mod standing_order;
+
 pub mod blockchain;
 
 pub mod request_policy_rule;
@@ -33,14 +39,24 @@ mod request_approval_status;
 
 mod request_approval;
 
+mod request_comment;
+
+mod request_template;
+
 mod helper;
 pub use helper::*;
 
 mod user;
 pub use user::*;
 
+mod identity_link;
+
+mod recovery;
+
 mod user_group;
 
+mod role_template;
+
 mod user_status;
 
 mod transfer;
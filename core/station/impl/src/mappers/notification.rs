@@ -7,6 +7,9 @@ pub enum NotificationMapperError {
     RequestNotFound {
         request_id: UUID,
     },
+    RequestCommentNotFound {
+        comment_id: UUID,
+    },
     InvalidRequestStatus {
         expected: RequestStatusCode,
         found: RequestStatusCode,
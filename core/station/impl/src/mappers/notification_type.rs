@@ -1,13 +1,14 @@
 use crate::models::{RequestOperation, RequestOperationType, RequestStatus, RequestStatusCode};
-use crate::repositories::REQUEST_EVALUATION_RESULT_REPOSITORY;
+use crate::repositories::{REQUEST_COMMENT_REPOSITORY, REQUEST_EVALUATION_RESULT_REPOSITORY};
 use crate::{
     models::{NotificationType, Request},
     repositories::REQUEST_REPOSITORY,
 };
-use orbit_essentials::repository::Repository;
+use orbit_essentials::{repository::Repository, utils::timestamp_to_rfc3339};
 use station_api::{
-    NotificationTypeDTO, RequestCreatedNotificationDTO, RequestFailedNotificationDTO,
-    RequestRejectedNotificationDTO,
+    NotificationTypeDTO, RequestCancelledNotificationDTO, RequestCommentMentionNotificationDTO,
+    RequestCreatedNotificationDTO, RequestFailedNotificationDTO, RequestRejectedNotificationDTO,
+    RequestTimeLockedNotificationDTO,
 };
 use uuid::Uuid;
 
@@ -63,6 +64,59 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     })?,
                 }
             }
+            NotificationType::RequestTimeLocked(ctx) => {
+                let request = REQUEST_REPOSITORY
+                    .get(&Request::key(ctx.request_id))
+                    .ok_or(NotificationMapperError::RequestNotFound {
+                        request_id: ctx.request_id,
+                    })?;
+
+                NotificationTypeDTO::RequestTimeLocked(RequestTimeLockedNotificationDTO {
+                    request_id: Uuid::from_bytes(ctx.request_id).to_string(),
+                    operation_type: RequestOperationType::from(request.operation).into(),
+                    unlocks_at: timestamp_to_rfc3339(&ctx.unlocks_at),
+                })
+            }
+            NotificationType::RequestCancelled(ctx) => {
+                let request = REQUEST_REPOSITORY
+                    .get(&Request::key(ctx.request_id))
+                    .ok_or(NotificationMapperError::RequestNotFound {
+                        request_id: ctx.request_id,
+                    })?;
+
+                match request.status {
+                    RequestStatus::Cancelled { reason } => {
+                        NotificationTypeDTO::RequestCancelled(RequestCancelledNotificationDTO {
+                            request_id: Uuid::from_bytes(ctx.request_id).to_string(),
+                            operation_type: RequestOperationType::from(request.operation).into(),
+                            reason,
+                        })
+                    }
+                    status => Err(NotificationMapperError::InvalidRequestStatus {
+                        expected: RequestStatusCode::Cancelled,
+                        found: status.to_type(),
+                    })?,
+                }
+            }
+            NotificationType::RequestCommentMention(ctx) => {
+                let request = REQUEST_REPOSITORY
+                    .get(&Request::key(ctx.request_id))
+                    .ok_or(NotificationMapperError::RequestNotFound {
+                        request_id: ctx.request_id,
+                    })?;
+                let comment = REQUEST_COMMENT_REPOSITORY.get(&ctx.comment_id).ok_or(
+                    NotificationMapperError::RequestCommentNotFound {
+                        comment_id: ctx.comment_id,
+                    },
+                )?;
+
+                NotificationTypeDTO::RequestCommentMention(RequestCommentMentionNotificationDTO {
+                    request_id: Uuid::from_bytes(ctx.request_id).to_string(),
+                    operation_type: RequestOperationType::from(request.operation).into(),
+                    comment_id: Uuid::from_bytes(ctx.comment_id).to_string(),
+                    author_id: Uuid::from_bytes(comment.author_id).to_string(),
+                })
+            }
             NotificationType::RequestCreated(ctx) => {
                 let request = REQUEST_REPOSITORY
                     .get(&Request::key(ctx.request_id))
@@ -72,12 +126,28 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
 
                 let account_id = match &request.operation {
                     RequestOperation::Transfer(operation) => Some(operation.input.from_account_id),
+                    RequestOperation::BatchTransfer(operation) => operation
+                        .input
+                        .transfers
+                        .first()
+                        .map(|transfer| transfer.from_account_id),
                     RequestOperation::EditAccount(operation) => Some(operation.input.account_id),
+                    RequestOperation::ApproveAllowance(operation) => {
+                        Some(operation.input.from_account_id)
+                    }
+                    RequestOperation::RevokeAllowance(operation) => {
+                        Some(operation.input.from_account_id)
+                    }
+                    RequestOperation::CancelStandingOrder(operation) => {
+                        Some(operation.input.from_account_id)
+                    }
                     RequestOperation::AddAccount(_)
                     | RequestOperation::AddAddressBookEntry(_)
                     | RequestOperation::EditAddressBookEntry(_)
                     | RequestOperation::RemoveAddressBookEntry(_)
                     | RequestOperation::EditUser(_)
+                    | RequestOperation::RemoveUser(_)
+                    | RequestOperation::LinkUserIdentity(_)
                     | RequestOperation::AddRequestPolicy(_)
                     | RequestOperation::AddUser(_)
                     | RequestOperation::AddUserGroup(_)
@@ -86,15 +156,23 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     | RequestOperation::EditUserGroup(_)
                     | RequestOperation::RemoveRequestPolicy(_)
                     | RequestOperation::RemoveUserGroup(_)
+                    | RequestOperation::ApplyRoleTemplate(_)
                     | RequestOperation::ManageSystemInfo(_)
                     | RequestOperation::ChangeCanister(_)
                     | RequestOperation::ChangeExternalCanister(_)
                     | RequestOperation::CreateExternalCanister(_)
-                    | RequestOperation::CallExternalCanister(_) => None,
+                    | RequestOperation::CallExternalCanister(_)
+                    | RequestOperation::AddAsset(_)
+                    | RequestOperation::EditAsset(_)
+                    | RequestOperation::RemoveAsset(_)
+                    | RequestOperation::AddApprovalDelegation(_)
+                    | RequestOperation::Bundle(_) => None,
                 };
 
                 let user_id: Option<[u8; 16]> = match &request.operation {
                     RequestOperation::EditUser(operation) => Some(operation.input.user_id),
+                    RequestOperation::RemoveUser(operation) => Some(operation.input.user_id),
+                    RequestOperation::LinkUserIdentity(operation) => Some(operation.input.user_id),
                     RequestOperation::AddAccount(_)
                     | RequestOperation::AddAddressBookEntry(_)
                     | RequestOperation::AddRequestPolicy(_)
@@ -108,12 +186,22 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     | RequestOperation::EditUserGroup(_)
                     | RequestOperation::RemoveRequestPolicy(_)
                     | RequestOperation::RemoveUserGroup(_)
+                    | RequestOperation::ApplyRoleTemplate(_)
                     | RequestOperation::Transfer(_)
+                    | RequestOperation::BatchTransfer(_)
                     | RequestOperation::ManageSystemInfo(_)
                     | RequestOperation::ChangeCanister(_)
                     | RequestOperation::ChangeExternalCanister(_)
                     | RequestOperation::CreateExternalCanister(_)
-                    | RequestOperation::CallExternalCanister(_) => None,
+                    | RequestOperation::CallExternalCanister(_)
+                    | RequestOperation::AddAsset(_)
+                    | RequestOperation::EditAsset(_)
+                    | RequestOperation::RemoveAsset(_)
+                    | RequestOperation::ApproveAllowance(_)
+                    | RequestOperation::RevokeAllowance(_)
+                    | RequestOperation::CancelStandingOrder(_)
+                    | RequestOperation::AddApprovalDelegation(_)
+                    | RequestOperation::Bundle(_) => None,
                 };
 
                 NotificationTypeDTO::RequestCreated(RequestCreatedNotificationDTO {
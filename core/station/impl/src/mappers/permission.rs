@@ -1,6 +1,9 @@
 use super::HelperMapper;
 use crate::models::{
-    permission::{Allow, AuthScope, Permission},
+    permission::{
+        AccessCheckOutcome, AccessDenialReason, AccessExplanation, AccessReason, Allow, AuthScope,
+        Permission, ResourceAccessCheck,
+    },
     resource::ResourceIds,
 };
 use orbit_essentials::types::UUID;
@@ -108,3 +111,64 @@ impl From<Permission> for station_api::PermissionDTO {
         }
     }
 }
+
+impl From<AccessReason> for station_api::AccessReasonDTO {
+    fn from(reason: AccessReason) -> Self {
+        match reason {
+            AccessReason::Public => station_api::AccessReasonDTO::Public,
+            AccessReason::Authenticated => station_api::AccessReasonDTO::Authenticated,
+            AccessReason::DefaultAccess => station_api::AccessReasonDTO::DefaultAccess,
+            AccessReason::UserGrant => station_api::AccessReasonDTO::UserGrant,
+            AccessReason::GroupGrant(groups) => station_api::AccessReasonDTO::GroupGrant(
+                groups
+                    .iter()
+                    .map(|id| Uuid::from_bytes(*id).hyphenated().to_string())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<AccessDenialReason> for station_api::AccessDenialReasonDTO {
+    fn from(reason: AccessDenialReason) -> Self {
+        match reason {
+            AccessDenialReason::NotAUser => station_api::AccessDenialReasonDTO::NotAUser,
+            AccessDenialReason::UserNotActive => station_api::AccessDenialReasonDTO::UserNotActive,
+            AccessDenialReason::NoMatchingGrant => {
+                station_api::AccessDenialReasonDTO::NoMatchingGrant
+            }
+        }
+    }
+}
+
+impl From<AccessCheckOutcome> for station_api::AccessCheckOutcomeDTO {
+    fn from(outcome: AccessCheckOutcome) -> Self {
+        match outcome {
+            AccessCheckOutcome::Allowed(reason) => {
+                station_api::AccessCheckOutcomeDTO::Allowed(reason.into())
+            }
+            AccessCheckOutcome::Denied(reason) => {
+                station_api::AccessCheckOutcomeDTO::Denied(reason.into())
+            }
+        }
+    }
+}
+
+impl From<ResourceAccessCheck> for station_api::ResourceAccessCheckDTO {
+    fn from(check: ResourceAccessCheck) -> Self {
+        station_api::ResourceAccessCheckDTO {
+            resource: check.resource.into(),
+            outcome: check.outcome.into(),
+        }
+    }
+}
+
+impl From<AccessExplanation> for station_api::AccessExplanationDTO {
+    fn from(explanation: AccessExplanation) -> Self {
+        station_api::AccessExplanationDTO {
+            resource: explanation.resource.into(),
+            allowed: explanation.allowed,
+            checks: explanation.checks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
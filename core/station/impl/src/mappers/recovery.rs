@@ -0,0 +1,93 @@
+use crate::models::{
+    ChangeRecoveryConfig, RecoveryAction, RecoveryAdmin, RecoveryAttempt, RecoveryConfig,
+};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+
+impl From<RecoveryConfig> for station_api::RecoveryConfigDTO {
+    fn from(config: RecoveryConfig) -> Self {
+        Self {
+            guardians: config.guardians,
+            threshold: config.threshold,
+            delay_seconds: config.delay_seconds,
+        }
+    }
+}
+
+impl From<station_api::RecoveryConfigDTO> for RecoveryConfig {
+    fn from(config: station_api::RecoveryConfigDTO) -> Self {
+        Self {
+            guardians: config.guardians,
+            threshold: config.threshold,
+            delay_seconds: config.delay_seconds,
+        }
+    }
+}
+
+impl From<ChangeRecoveryConfig> for station_api::ChangeRecoveryConfigDTO {
+    fn from(change: ChangeRecoveryConfig) -> Self {
+        match change {
+            ChangeRecoveryConfig::Set(config) => {
+                station_api::ChangeRecoveryConfigDTO::Set(config.into())
+            }
+            ChangeRecoveryConfig::Remove => station_api::ChangeRecoveryConfigDTO::Remove,
+        }
+    }
+}
+
+impl From<station_api::ChangeRecoveryConfigDTO> for ChangeRecoveryConfig {
+    fn from(change: station_api::ChangeRecoveryConfigDTO) -> Self {
+        match change {
+            station_api::ChangeRecoveryConfigDTO::Set(config) => {
+                ChangeRecoveryConfig::Set(config.into())
+            }
+            station_api::ChangeRecoveryConfigDTO::Remove => ChangeRecoveryConfig::Remove,
+        }
+    }
+}
+
+impl From<RecoveryAction> for station_api::RecoveryActionDTO {
+    fn from(action: RecoveryAction) -> Self {
+        match action {
+            RecoveryAction::InstallAdminIdentity(admin) => {
+                station_api::RecoveryActionDTO::InstallAdminIdentity(
+                    station_api::RecoveryAdminDTO {
+                        name: admin.name,
+                        identity: admin.identity,
+                    },
+                )
+            }
+            RecoveryAction::ReenableAdminGroup => {
+                station_api::RecoveryActionDTO::ReenableAdminGroup
+            }
+        }
+    }
+}
+
+impl From<station_api::RecoveryActionDTO> for RecoveryAction {
+    fn from(action: station_api::RecoveryActionDTO) -> Self {
+        match action {
+            station_api::RecoveryActionDTO::InstallAdminIdentity(admin) => {
+                RecoveryAction::InstallAdminIdentity(RecoveryAdmin {
+                    name: admin.name,
+                    identity: admin.identity,
+                })
+            }
+            station_api::RecoveryActionDTO::ReenableAdminGroup => {
+                RecoveryAction::ReenableAdminGroup
+            }
+        }
+    }
+}
+
+impl From<RecoveryAttempt> for station_api::RecoveryAttemptDTO {
+    fn from(attempt: RecoveryAttempt) -> Self {
+        Self {
+            action: attempt.action.into(),
+            approvals: attempt.approvals,
+            started_at: timestamp_to_rfc3339(&attempt.started_at),
+            executable_at: attempt
+                .executable_at
+                .map(|executable_at| timestamp_to_rfc3339(&executable_at)),
+        }
+    }
+}
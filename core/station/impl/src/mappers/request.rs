@@ -34,6 +34,7 @@ impl Request {
             expiration_dt,
             execution_plan,
             approvals: vec![],
+            approval_history: vec![],
             created_timestamp: now,
             last_modification_timestamp: now,
         }
@@ -55,6 +56,7 @@ impl Request {
                 .iter()
                 .map(|approval| approval.to_owned().into())
                 .collect(),
+            approval_history: self.approval_history.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -66,6 +68,7 @@ impl From<RequestExecutionScheduleDTO> for RequestExecutionPlan {
             RequestExecutionScheduleDTO::Scheduled { execution_time } => Self::Scheduled {
                 execution_time: rfc3339_to_timestamp(&execution_time),
             },
+            RequestExecutionScheduleDTO::Recurring(schedule) => Self::Recurring(schedule.into()),
         }
     }
 }
@@ -77,6 +80,7 @@ impl From<RequestExecutionPlan> for RequestExecutionScheduleDTO {
             RequestExecutionPlan::Scheduled { execution_time } => Self::Scheduled {
                 execution_time: timestamp_to_rfc3339(&execution_time),
             },
+            RequestExecutionPlan::Recurring(plan) => Self::Recurring(plan.into()),
         }
     }
 }
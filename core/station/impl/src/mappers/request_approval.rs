@@ -1,6 +1,6 @@
-use crate::models::RequestApproval;
+use crate::models::{RequestApproval, RequestApprovalChange};
 use orbit_essentials::utils::timestamp_to_rfc3339;
-use station_api::RequestApprovalDTO;
+use station_api::{RequestApprovalChangeDTO, RequestApprovalDTO};
 use uuid::Uuid;
 
 impl From<RequestApproval> for RequestApprovalDTO {
@@ -9,9 +9,25 @@ impl From<RequestApproval> for RequestApprovalDTO {
             approver_id: Uuid::from_bytes(approval.approver_id)
                 .hyphenated()
                 .to_string(),
+            delegate_id: approval
+                .delegate_id
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
             decided_at: timestamp_to_rfc3339(&approval.decided_dt),
             status: approval.status.into(),
             status_reason: approval.status_reason,
         }
     }
 }
+
+impl From<RequestApprovalChange> for RequestApprovalChangeDTO {
+    fn from(change: RequestApprovalChange) -> Self {
+        Self {
+            approver_id: Uuid::from_bytes(change.approver_id)
+                .hyphenated()
+                .to_string(),
+            decided_at: timestamp_to_rfc3339(&change.decided_dt),
+            status: change.status.map(Into::into),
+            status_reason: change.status_reason,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::models::RequestComment;
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::RequestCommentDTO;
+use uuid::Uuid;
+
+impl From<RequestComment> for RequestCommentDTO {
+    fn from(comment: RequestComment) -> Self {
+        Self {
+            id: Uuid::from_bytes(comment.id).hyphenated().to_string(),
+            request_id: Uuid::from_bytes(comment.request_id)
+                .hyphenated()
+                .to_string(),
+            author_id: Uuid::from_bytes(comment.author_id).hyphenated().to_string(),
+            content: comment.content,
+            mentions: comment
+                .mentions
+                .into_iter()
+                .map(|user_id| Uuid::from_bytes(user_id).hyphenated().to_string())
+                .collect(),
+            created_at: timestamp_to_rfc3339(&comment.created_timestamp),
+            last_modification_timestamp: timestamp_to_rfc3339(&comment.last_modification_timestamp),
+        }
+    }
+}
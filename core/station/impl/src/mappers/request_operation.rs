@@ -9,28 +9,34 @@ use crate::{
             ResourceId, SystemResourceAction, UserResourceAction,
         },
         Account, AddAccountOperation, AddAccountOperationInput, AddAddressBookEntryOperation,
-        AddAddressBookEntryOperationInput, AddRequestPolicyOperation,
-        AddRequestPolicyOperationInput, AddUserOperation, AddUserOperationInput, AddressBookEntry,
-        CallExternalCanisterOperation, CallExternalCanisterOperationInput, CanisterInstallMode,
-        CanisterInstallModeArgs, CanisterMethod, CanisterReinstallModeArgs,
-        CanisterUpgradeModeArgs, ChangeCanisterOperation, ChangeCanisterOperationInput,
-        ChangeCanisterTarget, ChangeExternalCanisterOperation,
+        AddAddressBookEntryOperationInput, AddApprovalDelegationOperation,
+        AddRequestPolicyOperation, AddRequestPolicyOperationInput, AddUserOperation,
+        AddUserOperationInput, AddressBookEntry, ApproveAllowanceOperation, BatchTransferOperation,
+        BundleOperation, CallExternalCanisterOperation, CallExternalCanisterOperationInput,
+        CancelStandingOrderOperation, CanisterInstallMode, CanisterInstallModeArgs, CanisterMethod,
+        CanisterReinstallModeArgs, CanisterUpgradeModeArgs, ChangeCanisterOperation,
+        ChangeCanisterOperationInput, ChangeCanisterTarget, ChangeExternalCanisterOperation,
         ChangeExternalCanisterOperationInput, CreateExternalCanisterOperation,
         CreateExternalCanisterOperationInput, EditAccountOperation, EditAccountOperationInput,
-        EditAddressBookEntryOperation, EditPermissionOperation, EditPermissionOperationInput,
-        EditRequestPolicyOperation, EditRequestPolicyOperationInput, EditUserGroupOperation,
-        EditUserOperation, EditUserOperationInput, ManageSystemInfoOperation,
-        ManageSystemInfoOperationInput, RemoveAddressBookEntryOperation,
-        RemoveRequestPolicyOperation, RemoveRequestPolicyOperationInput, RemoveUserGroupOperation,
-        RequestOperation, TransferOperation, User,
+        EditAddressBookEntryOperation, EditAssetOperation, EditPermissionOperation,
+        EditPermissionOperationInput, EditRequestPolicyOperation, EditRequestPolicyOperationInput,
+        EditUserGroupOperation, EditUserOperation, EditUserOperationInput,
+        LinkUserIdentityOperation, ManageSystemInfoOperation, ManageSystemInfoOperationInput,
+        RemoveAddressBookEntryOperation, RemoveAssetOperation, RemoveRequestPolicyOperation,
+        RemoveRequestPolicyOperationInput, RemoveUserGroupOperation, RemoveUserOperation,
+        RemoveUserOperationInput, RequestOperation, RevokeAllowanceOperation, Transfer,
+        TransferOperation, TransferOperationInput, TransferStatus, User,
     },
     repositories::{
-        AccountRepository, AddressBookRepository, UserRepository, USER_GROUP_REPOSITORY,
+        AccountRepository, AddressBookRepository, UserRepository, ALLOWANCE_REPOSITORY,
+        APPROVAL_DELEGATION_REPOSITORY, ASSET_REPOSITORY, STANDING_ORDER_REPOSITORY,
+        TRANSFER_REPOSITORY, USER_GROUP_REPOSITORY,
     },
 };
 use orbit_essentials::repository::Repository;
 use station_api::{
     AddAccountOperationDTO, AddAddressBookEntryOperationDTO, AddUserOperationDTO,
+    BatchTransferOperationDTO, BatchTransferRowDTO, BatchTransferSummaryDTO, BundleOperationDTO,
     CallExternalCanisterOperationDTO, CanisterMethodDTO, ChangeCanisterOperationDTO,
     ChangeCanisterTargetDTO, ChangeExternalCanisterOperationDTO,
     CreateExternalCanisterOperationDTO, EditAccountOperationDTO, EditAddressBookEntryOperationDTO,
@@ -39,6 +45,24 @@ use station_api::{
 };
 use uuid::Uuid;
 
+impl From<TransferOperationInput> for station_api::TransferOperationInput {
+    fn from(input: TransferOperationInput) -> Self {
+        station_api::TransferOperationInput {
+            from_account_id: Uuid::from_bytes(input.from_account_id)
+                .hyphenated()
+                .to_string(),
+            amount: input.amount,
+            to: input.to,
+            fee: input.fee,
+            metadata: input.metadata.into_vec_dto(),
+            network: Some(NetworkDTO {
+                id: input.network.clone(),
+                name: input.network,
+            }),
+        }
+    }
+}
+
 impl TransferOperation {
     pub fn to_dto(self, account: Option<Account>) -> TransferOperationDTO {
         TransferOperationDTO {
@@ -47,19 +71,7 @@ impl TransferOperation {
                 id: self.input.network.clone(),
                 name: self.input.network.clone(),
             },
-            input: station_api::TransferOperationInput {
-                from_account_id: Uuid::from_bytes(self.input.from_account_id)
-                    .hyphenated()
-                    .to_string(),
-                amount: self.input.amount,
-                to: self.input.to,
-                fee: self.input.fee,
-                metadata: self.input.metadata.into_vec_dto(),
-                network: Some(NetworkDTO {
-                    id: self.input.network.clone(),
-                    name: self.input.network.clone(),
-                }),
-            },
+            input: self.input.into(),
             transfer_id: self
                 .transfer_id
                 .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
@@ -67,14 +79,103 @@ impl TransferOperation {
     }
 }
 
+impl BatchTransferOperation {
+    pub const CSV_HEADER: &'static str =
+        "row,from_account_id,to,amount,fee,status,transfer_id,reason";
+
+    /// Maps the operation to its DTO, the transfers must follow the order of the input rows.
+    pub fn to_dto(self, transfers: Vec<Option<Transfer>>) -> BatchTransferOperationDTO {
+        let mut summary = BatchTransferSummaryDTO {
+            total: self.input.transfers.len() as u64,
+            pending: 0,
+            completed: 0,
+            failed: 0,
+            csv: Self::CSV_HEADER.to_string(),
+        };
+        let mut rows = Vec::with_capacity(self.input.transfers.len());
+
+        for (row, input) in self.input.transfers.iter().enumerate() {
+            let transfer = transfers.get(row).cloned().flatten();
+            let (status, reason) = match transfer.as_ref().map(|transfer| &transfer.status) {
+                Some(TransferStatus::Completed { .. }) => {
+                    summary.completed += 1;
+                    ("completed".to_string(), String::new())
+                }
+                Some(TransferStatus::Failed { reason }) => {
+                    summary.failed += 1;
+                    ("failed".to_string(), reason.clone())
+                }
+                Some(status) => {
+                    summary.pending += 1;
+                    (status.to_string(), String::new())
+                }
+                None => {
+                    summary.pending += 1;
+                    ("pending".to_string(), String::new())
+                }
+            };
+            let transfer_id = transfer
+                .as_ref()
+                .map(|transfer| Uuid::from_bytes(transfer.id).hyphenated().to_string());
+            let fee = match &transfer {
+                Some(transfer) => Some(transfer.fee.clone()),
+                None => input.fee.clone(),
+            };
+
+            summary.csv.push('\n');
+            summary.csv.push_str(
+                &[
+                    row.to_string(),
+                    Uuid::from_bytes(input.from_account_id)
+                        .hyphenated()
+                        .to_string(),
+                    input.to.clone(),
+                    input.amount.0.to_string(),
+                    fee.map(|fee| fee.0.to_string()).unwrap_or_default(),
+                    status,
+                    transfer_id.clone().unwrap_or_default(),
+                    reason,
+                ]
+                .iter()
+                .map(|field| escape_csv_field(field))
+                .collect::<Vec<_>>()
+                .join(","),
+            );
+
+            rows.push(BatchTransferRowDTO {
+                transfer_id,
+                status: transfer.map(|transfer| transfer.status.into()),
+            });
+        }
+
+        BatchTransferOperationDTO {
+            input: station_api::BatchTransferOperationInput {
+                transfers: self.input.transfers.into_iter().map(Into::into).collect(),
+            },
+            rows,
+            summary,
+        }
+    }
+}
+
+/// Quotes the field if it contains characters that have a meaning in CSV.
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
 impl AddAccountOperation {
     pub fn to_dto(self, account: Option<Account>) -> AddAccountOperationDTO {
         AddAccountOperationDTO {
             account: account.map(|account: Account| account.to_dto()),
             input: station_api::AddAccountOperationInput {
                 name: self.input.name,
-                blockchain: self.input.blockchain.to_string(),
-                standard: self.input.standard.to_string(),
+                asset_id: Uuid::from_bytes(self.input.asset_id)
+                    .hyphenated()
+                    .to_string(),
                 metadata: self.input.metadata.into_vec_dto(),
                 read_permission: self.input.read_permission.into(),
                 transfer_permission: self.input.transfer_permission.into(),
@@ -103,10 +204,9 @@ impl From<station_api::AddAccountOperationInput> for AddAccountOperationInput {
     fn from(input: station_api::AddAccountOperationInput) -> AddAccountOperationInput {
         AddAccountOperationInput {
             name: input.name,
-            blockchain: BlockchainMapper::to_blockchain(input.blockchain.clone())
-                .expect("Invalid blockchain"),
-            standard: BlockchainMapper::to_blockchain_standard(input.standard)
-                .expect("Invalid blockchain standard"),
+            asset_id: *HelperMapper::to_uuid(input.asset_id)
+                .expect("Invalid asset id")
+                .as_bytes(),
             metadata: input.metadata.into(),
             read_permission: input.read_permission.into(),
             configs_permission: input.configs_permission.into(),
@@ -267,6 +367,32 @@ impl From<EditUserOperation> for EditUserOperationDTO {
     }
 }
 
+impl From<RemoveUserOperation> for station_api::RemoveUserOperationDTO {
+    fn from(operation: RemoveUserOperation) -> station_api::RemoveUserOperationDTO {
+        station_api::RemoveUserOperationDTO {
+            input: station_api::RemoveUserOperationInput {
+                user_id: Uuid::from_bytes(operation.input.user_id)
+                    .hyphenated()
+                    .to_string(),
+            },
+        }
+    }
+}
+
+impl From<LinkUserIdentityOperation> for station_api::LinkUserIdentityOperationDTO {
+    fn from(operation: LinkUserIdentityOperation) -> station_api::LinkUserIdentityOperationDTO {
+        station_api::LinkUserIdentityOperationDTO {
+            input: station_api::LinkUserIdentityOperationInput {
+                user_id: Uuid::from_bytes(operation.input.user_id)
+                    .hyphenated()
+                    .to_string(),
+                identity: operation.input.identity,
+                retired_identity: operation.input.retired_identity,
+            },
+        }
+    }
+}
+
 impl From<station_api::AddUserOperationInput> for AddUserOperationInput {
     fn from(input: station_api::AddUserOperationInput) -> AddUserOperationInput {
         AddUserOperationInput {
@@ -286,6 +412,16 @@ impl From<station_api::AddUserOperationInput> for AddUserOperationInput {
     }
 }
 
+impl From<station_api::RemoveUserOperationInput> for RemoveUserOperationInput {
+    fn from(input: station_api::RemoveUserOperationInput) -> RemoveUserOperationInput {
+        RemoveUserOperationInput {
+            user_id: *HelperMapper::to_uuid(input.user_id)
+                .expect("Invalid user id")
+                .as_bytes(),
+        }
+    }
+}
+
 impl From<station_api::EditUserOperationInput> for EditUserOperationInput {
     fn from(input: station_api::EditUserOperationInput) -> EditUserOperationInput {
         EditUserOperationInput {
@@ -602,6 +738,7 @@ impl From<AddRequestPolicyOperation> for station_api::AddRequestPolicyOperationD
                 .policy_id
                 .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
             input: operation.input.into(),
+            warnings: operation.warnings,
         }
     }
 }
@@ -636,6 +773,7 @@ impl From<EditRequestPolicyOperation> for station_api::EditRequestPolicyOperatio
     fn from(operation: EditRequestPolicyOperation) -> station_api::EditRequestPolicyOperationDTO {
         station_api::EditRequestPolicyOperationDTO {
             input: operation.input.into(),
+            warnings: operation.warnings,
         }
     }
 }
@@ -674,13 +812,19 @@ impl From<RemoveRequestPolicyOperation> for station_api::RemoveRequestPolicyOper
 
 impl From<ManageSystemInfoOperationInput> for station_api::ManageSystemInfoOperationInput {
     fn from(input: ManageSystemInfoOperationInput) -> station_api::ManageSystemInfoOperationInput {
-        station_api::ManageSystemInfoOperationInput { name: input.name }
+        station_api::ManageSystemInfoOperationInput {
+            name: input.name,
+            recovery_config: input.recovery_config.map(Into::into),
+        }
     }
 }
 
 impl From<station_api::ManageSystemInfoOperationInput> for ManageSystemInfoOperationInput {
     fn from(input: station_api::ManageSystemInfoOperationInput) -> ManageSystemInfoOperationInput {
-        ManageSystemInfoOperationInput { name: input.name }
+        ManageSystemInfoOperationInput {
+            name: input.name,
+            recovery_config: input.recovery_config.map(Into::into),
+        }
     }
 }
 
@@ -709,6 +853,15 @@ impl From<RequestOperation> for RequestOperationDTO {
 
                 RequestOperationDTO::Transfer(Box::new(operation.to_dto(account)))
             }
+            RequestOperation::BatchTransfer(operation) => {
+                let transfers = operation
+                    .transfer_ids
+                    .iter()
+                    .map(|id| TRANSFER_REPOSITORY.get(&Transfer::key(*id)))
+                    .collect();
+
+                RequestOperationDTO::BatchTransfer(Box::new(operation.to_dto(transfers)))
+            }
             RequestOperation::AddAccount(operation) => {
                 let account = operation
                     .account_id
@@ -744,6 +897,12 @@ impl From<RequestOperation> for RequestOperationDTO {
             RequestOperation::EditUser(operation) => {
                 RequestOperationDTO::EditUser(Box::new(operation.into()))
             }
+            RequestOperation::RemoveUser(operation) => {
+                RequestOperationDTO::RemoveUser(Box::new(operation.into()))
+            }
+            RequestOperation::LinkUserIdentity(operation) => {
+                RequestOperationDTO::LinkUserIdentity(Box::new(operation.into()))
+            }
             RequestOperation::AddUserGroup(operation) => {
                 let user_group = operation
                     .user_group_id
@@ -757,6 +916,9 @@ impl From<RequestOperation> for RequestOperationDTO {
             RequestOperation::RemoveUserGroup(operation) => {
                 RequestOperationDTO::RemoveUserGroup(Box::new(operation.into()))
             }
+            RequestOperation::ApplyRoleTemplate(operation) => {
+                RequestOperationDTO::ApplyRoleTemplate(Box::new(operation.into()))
+            }
             RequestOperation::ChangeCanister(operation) => {
                 RequestOperationDTO::ChangeCanister(Box::new(operation.into()))
             }
@@ -784,6 +946,48 @@ impl From<RequestOperation> for RequestOperationDTO {
             RequestOperation::ManageSystemInfo(operation) => {
                 RequestOperationDTO::ManageSystemInfo(Box::new(operation.into()))
             }
+            RequestOperation::AddAsset(operation) => {
+                let asset = operation.asset_id.and_then(|id| ASSET_REPOSITORY.get(&id));
+
+                RequestOperationDTO::AddAsset(Box::new(operation.to_dto(asset)))
+            }
+            RequestOperation::EditAsset(operation) => {
+                RequestOperationDTO::EditAsset(Box::new(operation.into()))
+            }
+            RequestOperation::RemoveAsset(operation) => {
+                RequestOperationDTO::RemoveAsset(Box::new(operation.into()))
+            }
+            RequestOperation::ApproveAllowance(operation) => {
+                let account = AccountRepository::default()
+                    .get(&Account::key(operation.input.from_account_id));
+                let allowance = operation
+                    .allowance_id
+                    .and_then(|id| ALLOWANCE_REPOSITORY.get(&id));
+
+                RequestOperationDTO::ApproveAllowance(Box::new(
+                    operation.to_dto(account, allowance),
+                ))
+            }
+            RequestOperation::RevokeAllowance(operation) => {
+                RequestOperationDTO::RevokeAllowance(Box::new(operation.into()))
+            }
+            RequestOperation::CancelStandingOrder(operation) => {
+                let standing_order = STANDING_ORDER_REPOSITORY.get(&operation.input.request_id);
+
+                RequestOperationDTO::CancelStandingOrder(Box::new(operation.to_dto(standing_order)))
+            }
+            RequestOperation::AddApprovalDelegation(operation) => {
+                let delegation = operation
+                    .delegation_id
+                    .and_then(|id| APPROVAL_DELEGATION_REPOSITORY.get(&id));
+
+                RequestOperationDTO::AddApprovalDelegation(Box::new(operation.to_dto(delegation)))
+            }
+            RequestOperation::Bundle(operation) => {
+                RequestOperationDTO::Bundle(Box::new(BundleOperationDTO {
+                    operations: operation.operations.into_iter().map(Into::into).collect(),
+                }))
+            }
         }
     }
 }
@@ -797,13 +1001,14 @@ impl RequestOperation {
             RequestOperation::AddAddressBookEntry(_) => {
                 vec![Resource::AddressBook(ResourceAction::Create)]
             }
+            RequestOperation::AddAsset(_) => vec![Resource::Asset(ResourceAction::Create)],
             RequestOperation::AddUser(_) => vec![Resource::User(UserResourceAction::Create)],
             RequestOperation::AddUserGroup(_) => vec![Resource::UserGroup(ResourceAction::Create)],
 
             RequestOperation::AddRequestPolicy(_) => {
                 vec![Resource::RequestPolicy(ResourceAction::Create)]
             }
-            RequestOperation::EditPermission(_) => {
+            RequestOperation::EditPermission(_) | RequestOperation::ApplyRoleTemplate(_) => {
                 vec![Resource::Permission(PermissionResourceAction::Update)]
             }
 
@@ -815,6 +1020,61 @@ impl RequestOperation {
                     Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
                 ]
             }
+            RequestOperation::BatchTransfer(BatchTransferOperation { input, .. }) => input
+                .from_account_ids()
+                .into_iter()
+                .map(|account_id| {
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(account_id)))
+                })
+                .chain(std::iter::once(Resource::Account(
+                    AccountResourceAction::Transfer(ResourceId::Any),
+                )))
+                .collect(),
+            RequestOperation::ApproveAllowance(ApproveAllowanceOperation { input, .. }) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                        input.from_account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+                ]
+            }
+            RequestOperation::RevokeAllowance(RevokeAllowanceOperation { input }) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                        input.from_account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+                ]
+            }
+            RequestOperation::CancelStandingOrder(CancelStandingOrderOperation { input }) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                        input.from_account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+                ]
+            }
+            RequestOperation::AddApprovalDelegation(AddApprovalDelegationOperation {
+                input,
+                ..
+            }) => {
+                vec![
+                    Resource::User(UserResourceAction::Delegate(ResourceId::Id(
+                        input.delegator_id,
+                    ))),
+                    Resource::User(UserResourceAction::Delegate(ResourceId::Any)),
+                ]
+            }
+            RequestOperation::Bundle(BundleOperation { operations }) => {
+                let mut resources = Vec::new();
+                for resource in operations.iter().flat_map(|member| member.to_resources()) {
+                    if !resources.contains(&resource) {
+                        resources.push(resource);
+                    }
+                }
+
+                resources
+            }
 
             RequestOperation::EditAccount(EditAccountOperation { input }) => {
                 vec![
@@ -842,12 +1102,36 @@ impl RequestOperation {
                     Resource::AddressBook(ResourceAction::Delete(ResourceId::Any)),
                 ]
             }
+            RequestOperation::EditAsset(EditAssetOperation { input }) => {
+                vec![
+                    Resource::Asset(ResourceAction::Update(ResourceId::Id(input.asset_id))),
+                    Resource::Asset(ResourceAction::Update(ResourceId::Any)),
+                ]
+            }
+            RequestOperation::RemoveAsset(RemoveAssetOperation { input }) => {
+                vec![
+                    Resource::Asset(ResourceAction::Delete(ResourceId::Id(input.asset_id))),
+                    Resource::Asset(ResourceAction::Delete(ResourceId::Any)),
+                ]
+            }
             RequestOperation::EditUser(EditUserOperation { input }) => {
                 vec![
                     Resource::User(UserResourceAction::Update(ResourceId::Id(input.user_id))),
                     Resource::User(UserResourceAction::Update(ResourceId::Any)),
                 ]
             }
+            RequestOperation::RemoveUser(RemoveUserOperation { input }) => {
+                vec![
+                    Resource::User(UserResourceAction::Remove(ResourceId::Id(input.user_id))),
+                    Resource::User(UserResourceAction::Remove(ResourceId::Any)),
+                ]
+            }
+            RequestOperation::LinkUserIdentity(LinkUserIdentityOperation { input }) => {
+                vec![
+                    Resource::User(UserResourceAction::Update(ResourceId::Id(input.user_id))),
+                    Resource::User(UserResourceAction::Update(ResourceId::Any)),
+                ]
+            }
             RequestOperation::EditUserGroup(EditUserGroupOperation { input }) => {
                 vec![
                     Resource::UserGroup(ResourceAction::Update(ResourceId::Id(
@@ -909,7 +1193,7 @@ impl RequestOperation {
                     )),
                 ]
             }
-            RequestOperation::EditRequestPolicy(EditRequestPolicyOperation { input }) => {
+            RequestOperation::EditRequestPolicy(EditRequestPolicyOperation { input, .. }) => {
                 vec![
                     Resource::RequestPolicy(ResourceAction::Update(ResourceId::Id(
                         input.policy_id,
@@ -7,6 +7,7 @@ impl From<RequestOperationTypeDTO> for RequestOperationType {
     fn from(dto: RequestOperationTypeDTO) -> Self {
         match dto {
             RequestOperationTypeDTO::Transfer => RequestOperationType::Transfer,
+            RequestOperationTypeDTO::BatchTransfer => RequestOperationType::BatchTransfer,
             RequestOperationTypeDTO::AddAccount => RequestOperationType::AddAccount,
             RequestOperationTypeDTO::EditAccount => RequestOperationType::EditAccount,
             RequestOperationTypeDTO::AddAddressBookEntry => {
@@ -20,9 +21,12 @@ impl From<RequestOperationTypeDTO> for RequestOperationType {
             }
             RequestOperationTypeDTO::AddUser => RequestOperationType::AddUser,
             RequestOperationTypeDTO::EditUser => RequestOperationType::EditUser,
+            RequestOperationTypeDTO::RemoveUser => RequestOperationType::RemoveUser,
+            RequestOperationTypeDTO::LinkUserIdentity => RequestOperationType::LinkUserIdentity,
             RequestOperationTypeDTO::AddUserGroup => RequestOperationType::AddUserGroup,
             RequestOperationTypeDTO::EditUserGroup => RequestOperationType::EditUserGroup,
             RequestOperationTypeDTO::RemoveUserGroup => RequestOperationType::RemoveUserGroup,
+            RequestOperationTypeDTO::ApplyRoleTemplate => RequestOperationType::ApplyRoleTemplate,
             RequestOperationTypeDTO::ChangeCanister => RequestOperationType::ChangeCanister,
             RequestOperationTypeDTO::ChangeExternalCanister => {
                 RequestOperationType::ChangeExternalCanister
@@ -40,6 +44,18 @@ impl From<RequestOperationTypeDTO> for RequestOperationType {
                 RequestOperationType::RemoveRequestPolicy
             }
             RequestOperationTypeDTO::ManageSystemInfo => RequestOperationType::ManageSystemInfo,
+            RequestOperationTypeDTO::AddAsset => RequestOperationType::AddAsset,
+            RequestOperationTypeDTO::EditAsset => RequestOperationType::EditAsset,
+            RequestOperationTypeDTO::RemoveAsset => RequestOperationType::RemoveAsset,
+            RequestOperationTypeDTO::ApproveAllowance => RequestOperationType::ApproveAllowance,
+            RequestOperationTypeDTO::RevokeAllowance => RequestOperationType::RevokeAllowance,
+            RequestOperationTypeDTO::CancelStandingOrder => {
+                RequestOperationType::CancelStandingOrder
+            }
+            RequestOperationTypeDTO::AddApprovalDelegation => {
+                RequestOperationType::AddApprovalDelegation
+            }
+            RequestOperationTypeDTO::Bundle => RequestOperationType::Bundle,
         }
     }
 }
@@ -48,6 +64,7 @@ impl From<RequestOperationType> for RequestOperationTypeDTO {
     fn from(operation_type: RequestOperationType) -> Self {
         match operation_type {
             RequestOperationType::Transfer => RequestOperationTypeDTO::Transfer,
+            RequestOperationType::BatchTransfer => RequestOperationTypeDTO::BatchTransfer,
             RequestOperationType::AddAccount => RequestOperationTypeDTO::AddAccount,
             RequestOperationType::EditAccount => RequestOperationTypeDTO::EditAccount,
             RequestOperationType::AddAddressBookEntry => {
@@ -61,9 +78,12 @@ impl From<RequestOperationType> for RequestOperationTypeDTO {
             }
             RequestOperationType::AddUser => RequestOperationTypeDTO::AddUser,
             RequestOperationType::EditUser => RequestOperationTypeDTO::EditUser,
+            RequestOperationType::RemoveUser => RequestOperationTypeDTO::RemoveUser,
+            RequestOperationType::LinkUserIdentity => RequestOperationTypeDTO::LinkUserIdentity,
             RequestOperationType::AddUserGroup => RequestOperationTypeDTO::AddUserGroup,
             RequestOperationType::EditUserGroup => RequestOperationTypeDTO::EditUserGroup,
             RequestOperationType::RemoveUserGroup => RequestOperationTypeDTO::RemoveUserGroup,
+            RequestOperationType::ApplyRoleTemplate => RequestOperationTypeDTO::ApplyRoleTemplate,
             RequestOperationType::ChangeCanister => RequestOperationTypeDTO::ChangeCanister,
             RequestOperationType::ChangeExternalCanister => {
                 RequestOperationTypeDTO::ChangeExternalCanister
@@ -81,6 +101,18 @@ impl From<RequestOperationType> for RequestOperationTypeDTO {
                 RequestOperationTypeDTO::RemoveRequestPolicy
             }
             RequestOperationType::ManageSystemInfo => RequestOperationTypeDTO::ManageSystemInfo,
+            RequestOperationType::AddAsset => RequestOperationTypeDTO::AddAsset,
+            RequestOperationType::EditAsset => RequestOperationTypeDTO::EditAsset,
+            RequestOperationType::RemoveAsset => RequestOperationTypeDTO::RemoveAsset,
+            RequestOperationType::ApproveAllowance => RequestOperationTypeDTO::ApproveAllowance,
+            RequestOperationType::RevokeAllowance => RequestOperationTypeDTO::RevokeAllowance,
+            RequestOperationType::CancelStandingOrder => {
+                RequestOperationTypeDTO::CancelStandingOrder
+            }
+            RequestOperationType::AddApprovalDelegation => {
+                RequestOperationTypeDTO::AddApprovalDelegation
+            }
+            RequestOperationType::Bundle => RequestOperationTypeDTO::Bundle,
         }
     }
 }
@@ -89,6 +121,7 @@ impl From<RequestOperation> for RequestOperationType {
     fn from(operation: RequestOperation) -> Self {
         match operation {
             RequestOperation::Transfer(_) => RequestOperationType::Transfer,
+            RequestOperation::BatchTransfer(_) => RequestOperationType::BatchTransfer,
             RequestOperation::AddAccount(_) => RequestOperationType::AddAccount,
             RequestOperation::EditAccount(_) => RequestOperationType::EditAccount,
             RequestOperation::AddAddressBookEntry(_) => RequestOperationType::AddAddressBookEntry,
@@ -98,9 +131,12 @@ impl From<RequestOperation> for RequestOperationType {
             }
             RequestOperation::AddUser(_) => RequestOperationType::AddUser,
             RequestOperation::EditUser(_) => RequestOperationType::EditUser,
+            RequestOperation::RemoveUser(_) => RequestOperationType::RemoveUser,
+            RequestOperation::LinkUserIdentity(_) => RequestOperationType::LinkUserIdentity,
             RequestOperation::AddUserGroup(_) => RequestOperationType::AddUserGroup,
             RequestOperation::EditUserGroup(_) => RequestOperationType::EditUserGroup,
             RequestOperation::RemoveUserGroup(_) => RequestOperationType::RemoveUserGroup,
+            RequestOperation::ApplyRoleTemplate(_) => RequestOperationType::ApplyRoleTemplate,
             RequestOperation::ChangeCanister(_) => RequestOperationType::ChangeCanister,
             RequestOperation::ChangeExternalCanister(_) => {
                 RequestOperationType::ChangeExternalCanister
@@ -114,6 +150,16 @@ impl From<RequestOperation> for RequestOperationType {
             RequestOperation::EditRequestPolicy(_) => RequestOperationType::EditRequestPolicy,
             RequestOperation::RemoveRequestPolicy(_) => RequestOperationType::RemoveRequestPolicy,
             RequestOperation::ManageSystemInfo(_) => RequestOperationType::ManageSystemInfo,
+            RequestOperation::AddAsset(_) => RequestOperationType::AddAsset,
+            RequestOperation::EditAsset(_) => RequestOperationType::EditAsset,
+            RequestOperation::RemoveAsset(_) => RequestOperationType::RemoveAsset,
+            RequestOperation::ApproveAllowance(_) => RequestOperationType::ApproveAllowance,
+            RequestOperation::RevokeAllowance(_) => RequestOperationType::RevokeAllowance,
+            RequestOperation::CancelStandingOrder(_) => RequestOperationType::CancelStandingOrder,
+            RequestOperation::AddApprovalDelegation(_) => {
+                RequestOperationType::AddApprovalDelegation
+            }
+            RequestOperation::Bundle(_) => RequestOperationType::Bundle,
         }
     }
 }
@@ -132,6 +178,18 @@ impl RequestOperation {
                     true
                 }
             }
+            (
+                RequestOperation::BatchTransfer(operation),
+                ListRequestsOperationTypeDTO::BatchTransfer(from_account_id),
+            ) => {
+                if let Some(account_id) = from_account_id {
+                    HelperMapper::to_uuid(account_id.clone())
+                        .map(|uuid| operation.input.from_account_ids().contains(uuid.as_bytes()))
+                        .unwrap_or(false)
+                } else {
+                    true
+                }
+            }
             (RequestOperation::AddAccount(_), ListRequestsOperationTypeDTO::AddAccount) => true,
             (RequestOperation::EditAccount(_), ListRequestsOperationTypeDTO::EditAccount) => true,
             (
@@ -148,6 +206,11 @@ impl RequestOperation {
             ) => true,
             (RequestOperation::AddUser(_), ListRequestsOperationTypeDTO::AddUser) => true,
             (RequestOperation::EditUser(_), ListRequestsOperationTypeDTO::EditUser) => true,
+            (RequestOperation::RemoveUser(_), ListRequestsOperationTypeDTO::RemoveUser) => true,
+            (
+                RequestOperation::LinkUserIdentity(_),
+                ListRequestsOperationTypeDTO::LinkUserIdentity,
+            ) => true,
             (RequestOperation::AddUserGroup(_), ListRequestsOperationTypeDTO::AddUserGroup) => true,
             (RequestOperation::EditUserGroup(_), ListRequestsOperationTypeDTO::EditUserGroup) => {
                 true
@@ -156,6 +219,10 @@ impl RequestOperation {
                 RequestOperation::RemoveUserGroup(_),
                 ListRequestsOperationTypeDTO::RemoveUserGroup,
             ) => true,
+            (
+                RequestOperation::ApplyRoleTemplate(_),
+                ListRequestsOperationTypeDTO::ApplyRoleTemplate,
+            ) => true,
             (RequestOperation::ChangeCanister(_), ListRequestsOperationTypeDTO::ChangeCanister) => {
                 true
             }
@@ -202,6 +269,52 @@ impl RequestOperation {
                 RequestOperation::ManageSystemInfo(_),
                 ListRequestsOperationTypeDTO::ManageSystemInfo,
             ) => true,
+            (RequestOperation::AddAsset(_), ListRequestsOperationTypeDTO::AddAsset) => true,
+            (RequestOperation::EditAsset(_), ListRequestsOperationTypeDTO::EditAsset) => true,
+            (RequestOperation::RemoveAsset(_), ListRequestsOperationTypeDTO::RemoveAsset) => true,
+            (
+                RequestOperation::ApproveAllowance(operation),
+                ListRequestsOperationTypeDTO::ApproveAllowance(from_account_id),
+            ) => {
+                if let Some(account_id) = from_account_id {
+                    HelperMapper::to_uuid(account_id.clone()).map(|uuid| *uuid.as_bytes())
+                        == Ok(operation.input.from_account_id)
+                } else {
+                    true
+                }
+            }
+            (
+                RequestOperation::RevokeAllowance(operation),
+                ListRequestsOperationTypeDTO::RevokeAllowance(from_account_id),
+            ) => {
+                if let Some(account_id) = from_account_id {
+                    HelperMapper::to_uuid(account_id.clone()).map(|uuid| *uuid.as_bytes())
+                        == Ok(operation.input.from_account_id)
+                } else {
+                    true
+                }
+            }
+            (
+                RequestOperation::CancelStandingOrder(operation),
+                ListRequestsOperationTypeDTO::CancelStandingOrder(from_account_id),
+            ) => {
+                if let Some(account_id) = from_account_id {
+                    HelperMapper::to_uuid(account_id.clone()).map(|uuid| *uuid.as_bytes())
+                        == Ok(operation.input.from_account_id)
+                } else {
+                    true
+                }
+            }
+            (
+                RequestOperation::AddApprovalDelegation(_),
+                ListRequestsOperationTypeDTO::AddApprovalDelegation,
+            ) => true,
+            (RequestOperation::Bundle(_), ListRequestsOperationTypeDTO::Bundle) => true,
+            // bundles also match the types of their operations
+            (RequestOperation::Bundle(bundle), operation_type) => bundle
+                .operations
+                .iter()
+                .any(|member| member.is_of_type(operation_type)),
             _ => false,
         }
     }
@@ -217,6 +330,13 @@ impl From<station_api::ListRequestsOperationTypeDTO> for RequestOperationFilterT
                         .as_bytes()
                 }))
             }
+            station_api::ListRequestsOperationTypeDTO::BatchTransfer(from_account_id) => {
+                RequestOperationFilterType::BatchTransfer(from_account_id.map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                }))
+            }
             station_api::ListRequestsOperationTypeDTO::AddAccount => {
                 RequestOperationFilterType::AddAccount
             }
@@ -238,6 +358,12 @@ impl From<station_api::ListRequestsOperationTypeDTO> for RequestOperationFilterT
             station_api::ListRequestsOperationTypeDTO::EditUser => {
                 RequestOperationFilterType::EditUser
             }
+            station_api::ListRequestsOperationTypeDTO::RemoveUser => {
+                RequestOperationFilterType::RemoveUser
+            }
+            station_api::ListRequestsOperationTypeDTO::LinkUserIdentity => {
+                RequestOperationFilterType::LinkUserIdentity
+            }
             station_api::ListRequestsOperationTypeDTO::AddUserGroup => {
                 RequestOperationFilterType::AddUserGroup
             }
@@ -247,6 +373,9 @@ impl From<station_api::ListRequestsOperationTypeDTO> for RequestOperationFilterT
             station_api::ListRequestsOperationTypeDTO::RemoveUserGroup => {
                 RequestOperationFilterType::RemoveUserGroup
             }
+            station_api::ListRequestsOperationTypeDTO::ApplyRoleTemplate => {
+                RequestOperationFilterType::ApplyRoleTemplate
+            }
             station_api::ListRequestsOperationTypeDTO::ChangeCanister => {
                 RequestOperationFilterType::ChangeCanister
             }
@@ -274,6 +403,40 @@ impl From<station_api::ListRequestsOperationTypeDTO> for RequestOperationFilterT
             station_api::ListRequestsOperationTypeDTO::ManageSystemInfo => {
                 RequestOperationFilterType::ManageSystemInfo
             }
+            station_api::ListRequestsOperationTypeDTO::AddAsset => {
+                RequestOperationFilterType::AddAsset
+            }
+            station_api::ListRequestsOperationTypeDTO::EditAsset => {
+                RequestOperationFilterType::EditAsset
+            }
+            station_api::ListRequestsOperationTypeDTO::RemoveAsset => {
+                RequestOperationFilterType::RemoveAsset
+            }
+            station_api::ListRequestsOperationTypeDTO::ApproveAllowance(from_account_id) => {
+                RequestOperationFilterType::ApproveAllowance(from_account_id.map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                }))
+            }
+            station_api::ListRequestsOperationTypeDTO::RevokeAllowance(from_account_id) => {
+                RequestOperationFilterType::RevokeAllowance(from_account_id.map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                }))
+            }
+            station_api::ListRequestsOperationTypeDTO::CancelStandingOrder(from_account_id) => {
+                RequestOperationFilterType::CancelStandingOrder(from_account_id.map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                }))
+            }
+            station_api::ListRequestsOperationTypeDTO::AddApprovalDelegation => {
+                RequestOperationFilterType::AddApprovalDelegation
+            }
+            station_api::ListRequestsOperationTypeDTO::Bundle => RequestOperationFilterType::Bundle,
         }
     }
 }
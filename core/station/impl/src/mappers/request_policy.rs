@@ -1,4 +1,5 @@
 use super::HelperMapper;
+use crate::errors::MapperError;
 use crate::models::{
     request_policy_rule::RequestPolicyRule,
     request_specifier::{RequestSpecifier, ResourceSpecifier, UserSpecifier},
@@ -7,12 +8,17 @@ use crate::models::{
         PermissionResourceAction, Resource, ResourceAction, ResourceId, ResourceIds,
         SystemResourceAction, UserResourceAction,
     },
-    EvaluatedRequestPolicyRule, EvaluationStatus, Percentage, RequestEvaluationResult,
-    RequestPolicy, RequestPolicyCallerPrivileges, RequestPolicyRuleResult,
+    ApproverWeight, DraftRequestPolicy, EvaluatedRequestPolicyRule, EvaluationStatus, Percentage,
+    RequestEvaluationResult, RequestPolicy, RequestPolicyCallerPrivileges, RequestPolicyRuleResult,
+    RequestPolicySimulation, RollingTransferLimit, TimeLock, TransferLimit, TransferLimitScope,
+    WeightedQuorum,
 };
 use station_api::{
-    EvaluatedRequestPolicyRuleDTO, EvaluationStatusDTO, QuorumDTO, QuorumPercentageDTO,
-    RequestEvaluationResultDTO, RequestPolicyRuleDTO, RequestPolicyRuleResultDTO, UserSpecifierDTO,
+    ApproverWeightDTO, DraftRequestPolicyDTO, EvaluatedRequestPolicyRuleDTO, EvaluationStatusDTO,
+    QuorumDTO, QuorumPercentageDTO, RequestEvaluationResultDTO, RequestPolicyRuleDTO,
+    RequestPolicyRuleResultDTO, RollingTransferLimitDTO, SimulateRequestResponse,
+    SimulatedRequestPolicyResultDTO, TimeLockDTO, TransferLimitDTO, TransferLimitScopeDTO,
+    UserSpecifierDTO, WeightedQuorumDTO,
 };
 use uuid::Uuid;
 
@@ -32,10 +38,34 @@ impl From<RequestPolicyRule> for RequestPolicyRuleDTO {
                     min_approved,
                 })
             }
+            RequestPolicyRule::WeightedQuorum(quorum) => {
+                RequestPolicyRuleDTO::WeightedQuorum(WeightedQuorumDTO {
+                    weights: quorum
+                        .weights
+                        .into_iter()
+                        .map(|approver_weight| ApproverWeightDTO {
+                            approvers: approver_weight.approvers.into(),
+                            weight: approver_weight.weight,
+                        })
+                        .collect(),
+                    min_weight: quorum.min_weight,
+                })
+            }
             RequestPolicyRule::AllowListedByMetadata(metadata) => {
                 RequestPolicyRuleDTO::AllowListedByMetadata(metadata.into())
             }
             RequestPolicyRule::AllowListed => RequestPolicyRuleDTO::AllowListed,
+            RequestPolicyRule::AllowListedWithinLimit(limit) => {
+                RequestPolicyRuleDTO::AllowListedWithinLimit(limit.into())
+            }
+            RequestPolicyRule::AllowListedWithinRollingLimit(limit) => {
+                RequestPolicyRuleDTO::AllowListedWithinRollingLimit(limit.into())
+            }
+            RequestPolicyRule::AmountAtMost(amount) => RequestPolicyRuleDTO::AmountAtMost(amount),
+            RequestPolicyRule::AmountAbove(amount) => RequestPolicyRuleDTO::AmountAbove(amount),
+            RequestPolicyRule::TimeLock(time_lock) => RequestPolicyRuleDTO::TimeLock(TimeLockDTO {
+                delay_seconds: time_lock.delay_ns / 1_000_000_000,
+            }),
             RequestPolicyRule::Or(policy_rules) => {
                 RequestPolicyRuleDTO::AnyOf(policy_rules.into_iter().map(Into::into).collect())
             }
@@ -60,10 +90,34 @@ impl From<RequestPolicyRuleDTO> for RequestPolicyRule {
             RequestPolicyRuleDTO::Quorum(config) => {
                 RequestPolicyRule::Quorum(config.approvers.into(), config.min_approved)
             }
+            RequestPolicyRuleDTO::WeightedQuorum(config) => {
+                RequestPolicyRule::WeightedQuorum(WeightedQuorum {
+                    weights: config
+                        .weights
+                        .into_iter()
+                        .map(|approver_weight| ApproverWeight {
+                            approvers: approver_weight.approvers.into(),
+                            weight: approver_weight.weight,
+                        })
+                        .collect(),
+                    min_weight: config.min_weight,
+                })
+            }
             RequestPolicyRuleDTO::AllowListedByMetadata(metadata) => {
                 RequestPolicyRule::AllowListedByMetadata(metadata.into())
             }
             RequestPolicyRuleDTO::AllowListed => RequestPolicyRule::AllowListed,
+            RequestPolicyRuleDTO::AllowListedWithinLimit(limit) => {
+                RequestPolicyRule::AllowListedWithinLimit(limit.into())
+            }
+            RequestPolicyRuleDTO::AllowListedWithinRollingLimit(limit) => {
+                RequestPolicyRule::AllowListedWithinRollingLimit(limit.into())
+            }
+            RequestPolicyRuleDTO::AmountAtMost(amount) => RequestPolicyRule::AmountAtMost(amount),
+            RequestPolicyRuleDTO::AmountAbove(amount) => RequestPolicyRule::AmountAbove(amount),
+            RequestPolicyRuleDTO::TimeLock(time_lock) => RequestPolicyRule::TimeLock(TimeLock {
+                delay_ns: time_lock.delay_seconds.saturating_mul(1_000_000_000),
+            }),
             RequestPolicyRuleDTO::AnyOf(policy_rules) => {
                 RequestPolicyRule::Or(policy_rules.into_iter().map(Into::into).collect())
             }
@@ -77,6 +131,52 @@ impl From<RequestPolicyRuleDTO> for RequestPolicyRule {
     }
 }
 
+impl From<TransferLimit> for TransferLimitDTO {
+    fn from(limit: TransferLimit) -> Self {
+        TransferLimitDTO {
+            scope: match limit.scope {
+                TransferLimitScope::PerAccount => TransferLimitScopeDTO::PerAccount,
+                TransferLimitScope::PerAddressBookEntry => {
+                    TransferLimitScopeDTO::PerAddressBookEntry
+                }
+            },
+            max_amount: limit.max_amount,
+        }
+    }
+}
+
+impl From<TransferLimitDTO> for TransferLimit {
+    fn from(dto: TransferLimitDTO) -> Self {
+        TransferLimit {
+            scope: match dto.scope {
+                TransferLimitScopeDTO::PerAccount => TransferLimitScope::PerAccount,
+                TransferLimitScopeDTO::PerAddressBookEntry => {
+                    TransferLimitScope::PerAddressBookEntry
+                }
+            },
+            max_amount: dto.max_amount,
+        }
+    }
+}
+
+impl From<RollingTransferLimit> for RollingTransferLimitDTO {
+    fn from(limit: RollingTransferLimit) -> Self {
+        RollingTransferLimitDTO {
+            limit: limit.limit.into(),
+            window_seconds: limit.window_ns / 1_000_000_000,
+        }
+    }
+}
+
+impl From<RollingTransferLimitDTO> for RollingTransferLimit {
+    fn from(dto: RollingTransferLimitDTO) -> Self {
+        RollingTransferLimit {
+            limit: dto.limit.into(),
+            window_ns: dto.window_seconds.saturating_mul(1_000_000_000),
+        }
+    }
+}
+
 impl From<RequestPolicyRuleResult> for RequestPolicyRuleResultDTO {
     fn from(value: RequestPolicyRuleResult) -> Self {
         RequestPolicyRuleResultDTO {
@@ -135,12 +235,49 @@ impl From<EvaluatedRequestPolicyRule> for EvaluatedRequestPolicyRuleDTO {
                     .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
                     .collect(),
             },
+            EvaluatedRequestPolicyRule::WeightedQuorum {
+                min_weight,
+                total_possible_weight,
+                approved_weight,
+                remaining_weight,
+                approvers,
+            } => EvaluatedRequestPolicyRuleDTO::WeightedQuorum {
+                min_weight,
+                total_possible_weight,
+                approved_weight,
+                remaining_weight,
+                approvers: approvers
+                    .into_iter()
+                    .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
+                    .collect(),
+            },
             EvaluatedRequestPolicyRule::AllowListedByMetadata { metadata } => {
                 EvaluatedRequestPolicyRuleDTO::AllowListedByMetadata {
                     metadata: metadata.into(),
                 }
             }
             EvaluatedRequestPolicyRule::AllowListed => EvaluatedRequestPolicyRuleDTO::AllowListed,
+            EvaluatedRequestPolicyRule::AllowListedWithinLimit { limit } => {
+                EvaluatedRequestPolicyRuleDTO::AllowListedWithinLimit {
+                    limit: limit.into(),
+                }
+            }
+            EvaluatedRequestPolicyRule::AllowListedWithinRollingLimit { limit } => {
+                EvaluatedRequestPolicyRuleDTO::AllowListedWithinRollingLimit {
+                    limit: limit.into(),
+                }
+            }
+            EvaluatedRequestPolicyRule::AmountAtMost { amount } => {
+                EvaluatedRequestPolicyRuleDTO::AmountAtMost { amount }
+            }
+            EvaluatedRequestPolicyRule::AmountAbove { amount } => {
+                EvaluatedRequestPolicyRuleDTO::AmountAbove { amount }
+            }
+            EvaluatedRequestPolicyRule::TimeLock { delay_ns } => {
+                EvaluatedRequestPolicyRuleDTO::TimeLock {
+                    delay_seconds: delay_ns / 1_000_000_000,
+                }
+            }
             EvaluatedRequestPolicyRule::Or(policy_rules) => EvaluatedRequestPolicyRuleDTO::AnyOf(
                 policy_rules.into_iter().map(Into::into).collect(),
             ),
@@ -222,6 +359,54 @@ impl RequestPolicy {
     }
 }
 
+impl TryFrom<DraftRequestPolicyDTO> for DraftRequestPolicy {
+    type Error = MapperError;
+
+    fn try_from(dto: DraftRequestPolicyDTO) -> Result<Self, Self::Error> {
+        Ok(DraftRequestPolicy {
+            policy_id: match dto.policy_id {
+                Some(policy_id) => Some(*HelperMapper::to_uuid(policy_id)?.as_bytes()),
+                None => None,
+            },
+            specifier: dto.specifier.into(),
+            rule: dto.rule.into(),
+        })
+    }
+}
+
+impl From<RequestPolicySimulation> for SimulateRequestResponse {
+    fn from(simulation: RequestPolicySimulation) -> Self {
+        let result_reasons = simulation.evaluation.get_status_reason();
+        let mut possible_approvers = simulation
+            .possible_approvers
+            .into_iter()
+            .collect::<Vec<_>>();
+        possible_approvers.sort();
+
+        SimulateRequestResponse {
+            status: simulation.evaluation.status.into(),
+            policy_results: simulation
+                .matched_policies
+                .into_iter()
+                .zip(simulation.evaluation.policy_results)
+                .map(|(policy, result)| SimulatedRequestPolicyResultDTO {
+                    policy_id: policy
+                        .policy_id
+                        .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+                    specifier: policy.specifier.into(),
+                    result: result.into(),
+                })
+                .collect(),
+            result_reasons,
+            possible_approvers: possible_approvers
+                .into_iter()
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
+                .collect(),
+            auto_approved: simulation.auto_approved,
+        }
+    }
+}
+
 impl From<RequestSpecifier> for station_api::RequestSpecifierDTO {
     fn from(specifier: RequestSpecifier) -> Self {
         match specifier {
@@ -233,6 +418,12 @@ impl From<RequestSpecifier> for station_api::RequestSpecifierDTO {
             RequestSpecifier::EditUser(user) => {
                 station_api::RequestSpecifierDTO::EditUser(user.into())
             }
+            RequestSpecifier::RemoveUser(user) => {
+                station_api::RequestSpecifierDTO::RemoveUser(user.into())
+            }
+            RequestSpecifier::LinkUserIdentity(user) => {
+                station_api::RequestSpecifierDTO::LinkUserIdentity(user.into())
+            }
             RequestSpecifier::AddAddressBookEntry => {
                 station_api::RequestSpecifierDTO::AddAddressBookEntry
             }
@@ -245,6 +436,9 @@ impl From<RequestSpecifier> for station_api::RequestSpecifierDTO {
             RequestSpecifier::Transfer(account) => {
                 station_api::RequestSpecifierDTO::Transfer(account.into())
             }
+            RequestSpecifier::BatchTransfer(account) => {
+                station_api::RequestSpecifierDTO::BatchTransfer(account.into())
+            }
             RequestSpecifier::ChangeCanister => station_api::RequestSpecifierDTO::ChangeCanister,
             RequestSpecifier::ChangeExternalCanister(target) => {
                 station_api::RequestSpecifierDTO::ChangeExternalCanister(target.into())
@@ -274,9 +468,22 @@ impl From<RequestSpecifier> for station_api::RequestSpecifierDTO {
             RequestSpecifier::RemoveUserGroup(group) => {
                 station_api::RequestSpecifierDTO::RemoveUserGroup(group.into())
             }
+            RequestSpecifier::ApplyRoleTemplate(group) => {
+                station_api::RequestSpecifierDTO::ApplyRoleTemplate(group.into())
+            }
             RequestSpecifier::ManageSystemInfo => {
                 station_api::RequestSpecifierDTO::ManageSystemInfo
             }
+            RequestSpecifier::AddAsset => station_api::RequestSpecifierDTO::AddAsset,
+            RequestSpecifier::EditAsset(asset) => {
+                station_api::RequestSpecifierDTO::EditAsset(asset.into())
+            }
+            RequestSpecifier::RemoveAsset(asset) => {
+                station_api::RequestSpecifierDTO::RemoveAsset(asset.into())
+            }
+            RequestSpecifier::AddApprovalDelegation => {
+                station_api::RequestSpecifierDTO::AddApprovalDelegation
+            }
         }
     }
 }
@@ -292,6 +499,12 @@ impl From<station_api::RequestSpecifierDTO> for RequestSpecifier {
             station_api::RequestSpecifierDTO::EditUser(user) => {
                 RequestSpecifier::EditUser(user.into())
             }
+            station_api::RequestSpecifierDTO::RemoveUser(user) => {
+                RequestSpecifier::RemoveUser(user.into())
+            }
+            station_api::RequestSpecifierDTO::LinkUserIdentity(user) => {
+                RequestSpecifier::LinkUserIdentity(user.into())
+            }
             station_api::RequestSpecifierDTO::AddAddressBookEntry => {
                 RequestSpecifier::AddAddressBookEntry
             }
@@ -304,6 +517,9 @@ impl From<station_api::RequestSpecifierDTO> for RequestSpecifier {
             station_api::RequestSpecifierDTO::Transfer(transfer_specifier) => {
                 RequestSpecifier::Transfer(transfer_specifier.into())
             }
+            station_api::RequestSpecifierDTO::BatchTransfer(transfer_specifier) => {
+                RequestSpecifier::BatchTransfer(transfer_specifier.into())
+            }
             station_api::RequestSpecifierDTO::ChangeCanister => RequestSpecifier::ChangeCanister,
             station_api::RequestSpecifierDTO::ChangeExternalCanister(target) => {
                 RequestSpecifier::ChangeExternalCanister(target.into())
@@ -333,9 +549,22 @@ impl From<station_api::RequestSpecifierDTO> for RequestSpecifier {
             station_api::RequestSpecifierDTO::RemoveUserGroup(group) => {
                 RequestSpecifier::RemoveUserGroup(group.into())
             }
+            station_api::RequestSpecifierDTO::ApplyRoleTemplate(group) => {
+                RequestSpecifier::ApplyRoleTemplate(group.into())
+            }
             station_api::RequestSpecifierDTO::ManageSystemInfo => {
                 RequestSpecifier::ManageSystemInfo
             }
+            station_api::RequestSpecifierDTO::AddAsset => RequestSpecifier::AddAsset,
+            station_api::RequestSpecifierDTO::EditAsset(asset) => {
+                RequestSpecifier::EditAsset(asset.into())
+            }
+            station_api::RequestSpecifierDTO::RemoveAsset(asset) => {
+                RequestSpecifier::RemoveAsset(asset.into())
+            }
+            station_api::RequestSpecifierDTO::AddApprovalDelegation => {
+                RequestSpecifier::AddApprovalDelegation
+            }
         }
     }
 }
@@ -359,7 +588,8 @@ impl RequestSpecifier {
                 vec![Resource::System(SystemResourceAction::ManageSystemInfo)]
             }
 
-            RequestSpecifier::Transfer(account_specifier) => match account_specifier {
+            RequestSpecifier::Transfer(account_specifier)
+            | RequestSpecifier::BatchTransfer(account_specifier) => match account_specifier {
                 ResourceIds::Any => vec![Resource::Account(AccountResourceAction::Transfer(
                     ResourceId::Any,
                 ))],
@@ -383,6 +613,26 @@ impl RequestSpecifier {
                     .collect::<_>(),
             },
 
+            RequestSpecifier::RemoveUser(user_spec) => match user_spec {
+                ResourceIds::Any => {
+                    vec![Resource::User(UserResourceAction::Remove(ResourceId::Any))]
+                }
+                ResourceIds::Ids(ids) => ids
+                    .iter()
+                    .map(|id| Resource::User(UserResourceAction::Remove(ResourceId::Id(*id))))
+                    .collect::<_>(),
+            },
+
+            RequestSpecifier::LinkUserIdentity(user_spec) => match user_spec {
+                ResourceIds::Any => {
+                    vec![Resource::User(UserResourceAction::Update(ResourceId::Any))]
+                }
+                ResourceIds::Ids(ids) => ids
+                    .iter()
+                    .map(|id| Resource::User(UserResourceAction::Update(ResourceId::Id(*id))))
+                    .collect::<_>(),
+            },
+
             RequestSpecifier::EditAccount(resource_ids) => match resource_ids {
                 ResourceIds::Any => vec![Resource::Account(AccountResourceAction::Update(
                     ResourceId::Any,
@@ -414,6 +664,26 @@ impl RequestSpecifier {
                     .map(|id| Resource::AddressBook(ResourceAction::Delete(ResourceId::Id(*id))))
                     .collect::<_>(),
             },
+            RequestSpecifier::AddAsset => vec![Resource::Asset(ResourceAction::Create)],
+            RequestSpecifier::AddApprovalDelegation => {
+                vec![Resource::User(UserResourceAction::Delegate(
+                    ResourceId::Any,
+                ))]
+            }
+            RequestSpecifier::EditAsset(resource_ids) => match resource_ids {
+                ResourceIds::Any => vec![Resource::Asset(ResourceAction::Update(ResourceId::Any))],
+                ResourceIds::Ids(ids) => ids
+                    .iter()
+                    .map(|id| Resource::Asset(ResourceAction::Update(ResourceId::Id(*id))))
+                    .collect::<_>(),
+            },
+            RequestSpecifier::RemoveAsset(resource_ids) => match resource_ids {
+                ResourceIds::Any => vec![Resource::Asset(ResourceAction::Delete(ResourceId::Any))],
+                ResourceIds::Ids(ids) => ids
+                    .iter()
+                    .map(|id| Resource::Asset(ResourceAction::Delete(ResourceId::Id(*id))))
+                    .collect::<_>(),
+            },
             RequestSpecifier::ChangeCanister => vec![Resource::ChangeCanister(
                 ChangeCanisterResourceAction::Create,
             )],
@@ -432,6 +702,9 @@ impl RequestSpecifier {
                     ExternalCanisterResourceAction::Call(target.clone()),
                 )]
             }
+            RequestSpecifier::ApplyRoleTemplate(_) => {
+                vec![Resource::Permission(PermissionResourceAction::Update)]
+            }
             RequestSpecifier::EditPermission(resource_specifier) => match resource_specifier {
                 ResourceSpecifier::Any => {
                     vec![Resource::Permission(PermissionResourceAction::Update)]
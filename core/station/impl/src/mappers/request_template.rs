@@ -0,0 +1,59 @@
+use super::HelperMapper;
+use crate::{
+    errors::MapperError,
+    models::{RequestTemplate, RequestTemplateOperation, TransferRequestTemplate},
+};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{RequestTemplateDTO, RequestTemplateOperationDTO, TransferRequestTemplateDTO};
+use uuid::Uuid;
+
+impl From<RequestTemplate> for RequestTemplateDTO {
+    fn from(template: RequestTemplate) -> Self {
+        Self {
+            id: Uuid::from_bytes(template.id).hyphenated().to_string(),
+            name: template.name,
+            description: template.description,
+            title: template.title,
+            summary: template.summary,
+            operation: template.operation.into(),
+            created_at: timestamp_to_rfc3339(&template.created_timestamp),
+            last_modification_timestamp: timestamp_to_rfc3339(
+                &template.last_modification_timestamp,
+            ),
+        }
+    }
+}
+
+impl From<RequestTemplateOperation> for RequestTemplateOperationDTO {
+    fn from(operation: RequestTemplateOperation) -> Self {
+        match operation {
+            RequestTemplateOperation::Transfer(transfer) => {
+                RequestTemplateOperationDTO::Transfer(TransferRequestTemplateDTO {
+                    from_account_id: Uuid::from_bytes(transfer.from_account_id)
+                        .hyphenated()
+                        .to_string(),
+                    to: transfer.to,
+                    amount: transfer.amount,
+                    metadata: transfer.metadata.into(),
+                })
+            }
+        }
+    }
+}
+
+impl TryFrom<RequestTemplateOperationDTO> for RequestTemplateOperation {
+    type Error = MapperError;
+
+    fn try_from(dto: RequestTemplateOperationDTO) -> Result<Self, Self::Error> {
+        Ok(match dto {
+            RequestTemplateOperationDTO::Transfer(transfer) => {
+                RequestTemplateOperation::Transfer(TransferRequestTemplate {
+                    from_account_id: *HelperMapper::to_uuid(transfer.from_account_id)?.as_bytes(),
+                    to: transfer.to,
+                    amount: transfer.amount,
+                    metadata: transfer.metadata.into(),
+                })
+            }
+        })
+    }
+}
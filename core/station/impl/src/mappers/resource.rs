@@ -22,7 +22,11 @@ impl From<station_api::ResourceDTO> for Resource {
                 Resource::RequestPolicy(action.into())
             }
             station_api::ResourceDTO::UserGroup(action) => Resource::UserGroup(action.into()),
+            station_api::ResourceDTO::RequestTemplate(action) => {
+                Resource::RequestTemplate(action.into())
+            }
             station_api::ResourceDTO::AddressBook(action) => Resource::AddressBook(action.into()),
+            station_api::ResourceDTO::Asset(action) => Resource::Asset(action.into()),
             station_api::ResourceDTO::ChangeCanister(action) => {
                 Resource::ChangeCanister(action.into())
             }
@@ -45,7 +49,11 @@ impl From<Resource> for station_api::ResourceDTO {
                 station_api::ResourceDTO::RequestPolicy(action.into())
             }
             Resource::UserGroup(action) => station_api::ResourceDTO::UserGroup(action.into()),
+            Resource::RequestTemplate(action) => {
+                station_api::ResourceDTO::RequestTemplate(action.into())
+            }
             Resource::AddressBook(action) => station_api::ResourceDTO::AddressBook(action.into()),
+            Resource::Asset(action) => station_api::ResourceDTO::Asset(action.into()),
             Resource::ChangeCanister(action) => {
                 station_api::ResourceDTO::ChangeCanister(action.into())
             }
@@ -131,6 +139,10 @@ impl From<station_api::UserResourceActionDTO> for UserResourceAction {
             station_api::UserResourceActionDTO::Create => UserResourceAction::Create,
             station_api::UserResourceActionDTO::Read(id) => UserResourceAction::Read(id.into()),
             station_api::UserResourceActionDTO::Update(id) => UserResourceAction::Update(id.into()),
+            station_api::UserResourceActionDTO::Remove(id) => UserResourceAction::Remove(id.into()),
+            station_api::UserResourceActionDTO::Delegate(id) => {
+                UserResourceAction::Delegate(id.into())
+            }
         }
     }
 }
@@ -142,6 +154,10 @@ impl From<UserResourceAction> for station_api::UserResourceActionDTO {
             UserResourceAction::Create => station_api::UserResourceActionDTO::Create,
             UserResourceAction::Read(id) => station_api::UserResourceActionDTO::Read(id.into()),
             UserResourceAction::Update(id) => station_api::UserResourceActionDTO::Update(id.into()),
+            UserResourceAction::Remove(id) => station_api::UserResourceActionDTO::Remove(id.into()),
+            UserResourceAction::Delegate(id) => {
+                station_api::UserResourceActionDTO::Delegate(id.into())
+            }
         }
     }
 }
@@ -446,6 +462,9 @@ impl From<station_api::RequestResourceActionDTO> for RequestResourceAction {
             station_api::RequestResourceActionDTO::Read(id) => {
                 RequestResourceAction::Read(id.into())
             }
+            station_api::RequestResourceActionDTO::Cancel(id) => {
+                RequestResourceAction::Cancel(id.into())
+            }
         }
     }
 }
@@ -457,6 +476,9 @@ impl From<RequestResourceAction> for station_api::RequestResourceActionDTO {
             RequestResourceAction::Read(id) => {
                 station_api::RequestResourceActionDTO::Read(id.into())
             }
+            RequestResourceAction::Cancel(id) => {
+                station_api::RequestResourceActionDTO::Cancel(id.into())
+            }
         }
     }
 }
@@ -0,0 +1,96 @@
+use super::HelperMapper;
+use crate::models::{
+    ApplyRoleTemplateOperation, ApplyRoleTemplateOperationInput, RoleTemplate, RoleTemplateDiff,
+};
+use uuid::Uuid;
+
+impl From<RoleTemplate> for station_api::RoleTemplateDTO {
+    fn from(template: RoleTemplate) -> Self {
+        match template {
+            RoleTemplate::Viewer => station_api::RoleTemplateDTO::Viewer,
+            RoleTemplate::Operator => station_api::RoleTemplateDTO::Operator,
+            RoleTemplate::Treasurer => station_api::RoleTemplateDTO::Treasurer,
+            RoleTemplate::Auditor => station_api::RoleTemplateDTO::Auditor,
+            RoleTemplate::Admin => station_api::RoleTemplateDTO::Admin,
+        }
+    }
+}
+
+impl From<station_api::RoleTemplateDTO> for RoleTemplate {
+    fn from(template: station_api::RoleTemplateDTO) -> Self {
+        match template {
+            station_api::RoleTemplateDTO::Viewer => RoleTemplate::Viewer,
+            station_api::RoleTemplateDTO::Operator => RoleTemplate::Operator,
+            station_api::RoleTemplateDTO::Treasurer => RoleTemplate::Treasurer,
+            station_api::RoleTemplateDTO::Auditor => RoleTemplate::Auditor,
+            station_api::RoleTemplateDTO::Admin => RoleTemplate::Admin,
+        }
+    }
+}
+
+impl From<station_api::ApplyRoleTemplateOperationInput> for ApplyRoleTemplateOperationInput {
+    fn from(input: station_api::ApplyRoleTemplateOperationInput) -> Self {
+        Self {
+            user_group_id: *HelperMapper::to_uuid(input.user_group_id)
+                .expect("Invalid UUID")
+                .as_bytes(),
+            template: input.template.into(),
+        }
+    }
+}
+
+impl From<ApplyRoleTemplateOperationInput> for station_api::ApplyRoleTemplateOperationInput {
+    fn from(input: ApplyRoleTemplateOperationInput) -> Self {
+        Self {
+            user_group_id: Uuid::from_bytes(input.user_group_id)
+                .hyphenated()
+                .to_string(),
+            template: input.template.into(),
+        }
+    }
+}
+
+impl From<ApplyRoleTemplateOperation> for station_api::ApplyRoleTemplateOperationDTO {
+    fn from(operation: ApplyRoleTemplateOperation) -> Self {
+        Self {
+            input: operation.input.into(),
+        }
+    }
+}
+
+impl From<station_api::ApplyRoleTemplateOperationInput> for ApplyRoleTemplateOperation {
+    fn from(input: station_api::ApplyRoleTemplateOperationInput) -> Self {
+        Self {
+            input: input.into(),
+        }
+    }
+}
+
+impl From<RoleTemplateDiff> for station_api::RoleTemplateDiffDTO {
+    fn from(diff: RoleTemplateDiff) -> Self {
+        Self {
+            added_permissions: diff.added_permissions.into_iter().map(Into::into).collect(),
+            unchanged_permissions: diff
+                .unchanged_permissions
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            added_request_policies: diff
+                .added_request_policies
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            unchanged_request_policies: diff
+                .unchanged_request_policies
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            skipped_request_policies: diff
+                .skipped_request_policies
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            warnings: diff.warnings.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
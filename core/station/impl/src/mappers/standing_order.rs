@@ -0,0 +1,139 @@
+use crate::models::{
+    CancelStandingOrderOperation, CancelStandingOrderOperationInput, RecurrenceInterval,
+    RecurringExecutionPlan, StandingOrder, StandingOrderFailure, StandingOrderStatus,
+    UpcomingExecution,
+};
+use orbit_essentials::utils::{rfc3339_to_timestamp, timestamp_to_rfc3339};
+use station_api::{RecurrenceIntervalDTO, RecurringExecutionScheduleDTO};
+use uuid::Uuid;
+
+impl From<RecurrenceIntervalDTO> for RecurrenceInterval {
+    fn from(dto: RecurrenceIntervalDTO) -> Self {
+        match dto {
+            RecurrenceIntervalDTO::Minutes(every) => RecurrenceInterval::Minutes(every),
+            RecurrenceIntervalDTO::Hours(every) => RecurrenceInterval::Hours(every),
+            RecurrenceIntervalDTO::Days(every) => RecurrenceInterval::Days(every),
+            RecurrenceIntervalDTO::Weeks(every) => RecurrenceInterval::Weeks(every),
+            RecurrenceIntervalDTO::Months(every) => RecurrenceInterval::Months(every),
+        }
+    }
+}
+
+impl From<RecurrenceInterval> for RecurrenceIntervalDTO {
+    fn from(interval: RecurrenceInterval) -> Self {
+        match interval {
+            RecurrenceInterval::Minutes(every) => RecurrenceIntervalDTO::Minutes(every),
+            RecurrenceInterval::Hours(every) => RecurrenceIntervalDTO::Hours(every),
+            RecurrenceInterval::Days(every) => RecurrenceIntervalDTO::Days(every),
+            RecurrenceInterval::Weeks(every) => RecurrenceIntervalDTO::Weeks(every),
+            RecurrenceInterval::Months(every) => RecurrenceIntervalDTO::Months(every),
+        }
+    }
+}
+
+impl From<RecurringExecutionScheduleDTO> for RecurringExecutionPlan {
+    fn from(dto: RecurringExecutionScheduleDTO) -> Self {
+        Self {
+            interval: dto.interval.into(),
+            start_at: rfc3339_to_timestamp(&dto.start_at),
+            end_at: dto.end_at.map(|dt| rfc3339_to_timestamp(&dt)),
+            max_occurrences: dto.max_occurrences,
+        }
+    }
+}
+
+impl From<RecurringExecutionPlan> for RecurringExecutionScheduleDTO {
+    fn from(plan: RecurringExecutionPlan) -> Self {
+        Self {
+            interval: plan.interval.into(),
+            start_at: timestamp_to_rfc3339(&plan.start_at),
+            end_at: plan.end_at.map(|dt| timestamp_to_rfc3339(&dt)),
+            max_occurrences: plan.max_occurrences,
+        }
+    }
+}
+
+impl From<StandingOrderStatus> for station_api::StandingOrderStatusDTO {
+    fn from(status: StandingOrderStatus) -> Self {
+        match status {
+            StandingOrderStatus::Active => station_api::StandingOrderStatusDTO::Active,
+            StandingOrderStatus::Finished { .. } => station_api::StandingOrderStatusDTO::Finished,
+            StandingOrderStatus::Cancelled { .. } => station_api::StandingOrderStatusDTO::Cancelled,
+        }
+    }
+}
+
+impl From<StandingOrderFailure> for station_api::StandingOrderFailureDTO {
+    fn from(failure: StandingOrderFailure) -> Self {
+        Self {
+            occurrence: failure.occurrence,
+            reason: failure.reason,
+            failed_at: timestamp_to_rfc3339(&failure.failed_at),
+        }
+    }
+}
+
+impl From<StandingOrder> for station_api::StandingOrderDTO {
+    fn from(standing_order: StandingOrder) -> Self {
+        Self {
+            request_id: Uuid::from_bytes(standing_order.request_id)
+                .hyphenated()
+                .to_string(),
+            account_id: Uuid::from_bytes(standing_order.account_id)
+                .hyphenated()
+                .to_string(),
+            schedule: standing_order.plan.into(),
+            next_execution_at: standing_order
+                .next_execution_at
+                .map(|dt| timestamp_to_rfc3339(&dt)),
+            transfer_ids: standing_order
+                .transfer_ids
+                .iter()
+                .map(|id| Uuid::from_bytes(*id).hyphenated().to_string())
+                .collect(),
+            last_failure: standing_order.last_failure.map(Into::into),
+            status: standing_order.status.into(),
+            created_at: timestamp_to_rfc3339(&standing_order.created_timestamp),
+            last_modification_timestamp: timestamp_to_rfc3339(
+                &standing_order.last_modification_timestamp,
+            ),
+        }
+    }
+}
+
+impl From<CancelStandingOrderOperationInput> for station_api::CancelStandingOrderOperationInput {
+    fn from(input: CancelStandingOrderOperationInput) -> Self {
+        Self {
+            request_id: Uuid::from_bytes(input.request_id).hyphenated().to_string(),
+        }
+    }
+}
+
+impl CancelStandingOrderOperation {
+    pub fn to_dto(
+        self,
+        standing_order: Option<StandingOrder>,
+    ) -> station_api::CancelStandingOrderOperationDTO {
+        station_api::CancelStandingOrderOperationDTO {
+            standing_order: standing_order.map(Into::into),
+            input: self.input.into(),
+        }
+    }
+}
+
+impl From<UpcomingExecution> for station_api::UpcomingExecutionDTO {
+    fn from(execution: UpcomingExecution) -> Self {
+        Self {
+            request_id: Uuid::from_bytes(execution.request_id)
+                .hyphenated()
+                .to_string(),
+            occurrence: execution.occurrence,
+            execution_time: timestamp_to_rfc3339(&execution.execution_time),
+            from_account_id: Uuid::from_bytes(execution.from_account_id)
+                .hyphenated()
+                .to_string(),
+            to: execution.to,
+            amount: execution.amount,
+        }
+    }
+}
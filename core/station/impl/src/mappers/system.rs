@@ -10,6 +10,8 @@ impl SystemInfo {
             cycles: *cycles,
             version: version.to_string(),
             raw_rand_successful: raw_rand_successful(),
+            recovery_config: self.get_recovery_config().cloned().map(Into::into),
+            pending_recovery: self.get_recovery_attempt().cloned().map(Into::into),
         }
     }
 }
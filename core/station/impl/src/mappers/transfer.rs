@@ -1,6 +1,9 @@
-use crate::models::Transfer;
+use crate::models::{AccountActivity, IncomingTransfer, Transfer, TransferEstimate};
 use orbit_essentials::utils::timestamp_to_rfc3339;
-use station_api::{NetworkDTO, TransferDTO, TransferListItemDTO};
+use station_api::{
+    AccountActivityDTO, EstimateTransferResponse, IncomingTransferDTO, NetworkDTO, TransferDTO,
+    TransferListItemDTO,
+};
 use uuid::Uuid;
 
 #[derive(Default, Clone, Debug)]
@@ -51,3 +54,45 @@ impl Transfer {
         TransferMapper::to_list_item_dto(self.clone())
     }
 }
+
+impl From<TransferEstimate> for EstimateTransferResponse {
+    fn from(estimate: TransferEstimate) -> Self {
+        EstimateTransferResponse {
+            fee: estimate.fee,
+            balance: estimate.balance,
+            evaluation_result: estimate.evaluation_result.into(),
+            possible_approvers: estimate
+                .possible_approvers
+                .into_iter()
+                .map(|user_id| Uuid::from_bytes(user_id).hyphenated().to_string())
+                .collect(),
+        }
+    }
+}
+
+impl From<IncomingTransfer> for IncomingTransferDTO {
+    fn from(incoming_transfer: IncomingTransfer) -> Self {
+        IncomingTransferDTO {
+            account_id: Uuid::from_bytes(incoming_transfer.account_id)
+                .hyphenated()
+                .to_string(),
+            tx_id: incoming_transfer.tx_id,
+            from: incoming_transfer.from_address,
+            amount: incoming_transfer.amount,
+            timestamp: timestamp_to_rfc3339(&incoming_transfer.timestamp),
+        }
+    }
+}
+
+impl From<AccountActivity> for AccountActivityDTO {
+    fn from(activity: AccountActivity) -> Self {
+        match activity {
+            AccountActivity::Outgoing(transfer) => {
+                AccountActivityDTO::Outgoing(TransferMapper::to_list_item_dto(transfer))
+            }
+            AccountActivity::Incoming(incoming_transfer) => {
+                AccountActivityDTO::Incoming(incoming_transfer.into())
+            }
+        }
+    }
+}
@@ -118,6 +118,7 @@ impl From<UserCallerPrivileges> for station_api::UserCallerPrivilegesDTO {
         station_api::UserCallerPrivilegesDTO {
             id: Uuid::from_bytes(privileges.id).hyphenated().to_string(),
             can_edit: privileges.can_edit,
+            can_remove: privileges.can_remove,
         }
     }
 }
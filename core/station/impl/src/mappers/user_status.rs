@@ -6,6 +6,8 @@ impl From<UserStatus> for UserStatusDTO {
         match status {
             UserStatus::Active => UserStatusDTO::Active,
             UserStatus::Inactive => UserStatusDTO::Inactive,
+            UserStatus::Suspended => UserStatusDTO::Suspended,
+            UserStatus::Archived => UserStatusDTO::Archived,
         }
     }
 }
@@ -15,6 +17,8 @@ impl From<UserStatusDTO> for UserStatus {
         match status {
             UserStatusDTO::Active => UserStatus::Active,
             UserStatusDTO::Inactive => UserStatus::Inactive,
+            UserStatusDTO::Suspended => UserStatus::Suspended,
+            UserStatusDTO::Archived => UserStatus::Archived,
         }
     }
 }
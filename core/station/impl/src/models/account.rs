@@ -1,4 +1,4 @@
-use super::{AccountBalance, Blockchain, BlockchainStandard};
+use super::{legacy_asset_id, AccountBalance, AssetId, Blockchain, BlockchainStandard};
 use crate::errors::AccountError;
 use crate::models::Metadata;
 use crate::repositories::request_policy::REQUEST_POLICY_REPOSITORY;
@@ -11,8 +11,12 @@ use orbit_essentials::{
 };
 use std::{collections::HashMap, hash::Hash};
 
-/// The account metadata key for the asset symbol;
-pub const ACCOUNT_METADATA_SYMBOL_KEY: &str = "symbol";
+/// The account metadata key for the ledger canister id of ICRC-1 accounts;
+pub const ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY: &str = "ledger_canister_id";
+
+/// The account metadata key for the index canister id of ICRC-1 accounts, which is optional and
+/// enables indexing the deposits into the account;
+pub const ACCOUNT_METADATA_INDEX_CANISTER_ID_KEY: &str = "index_canister_id";
 
 /// The account id, which is a UUID.
 pub type AccountId = UUID;
@@ -20,12 +24,17 @@ pub type AccountId = UUID;
 /// Represents a account in the system.
 ///
 /// A account can be associated with one or more users and can only hold one type of asset,
-/// which is defined by the blockchain, standard and symbol.
+/// which is referenced by its asset id, the blockchain, standard and symbol are copied from the asset.
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Account {
     /// The account id, which is a UUID.
     pub id: AccountId,
+    /// The id of the asset that the account holds.
+    ///
+    /// Accounts created before assets were managed at runtime default to the ICP asset.
+    #[serde(default = "legacy_asset_id")]
+    pub asset_id: AssetId,
     /// The blockchain type (e.g. `icp`, `eth`, `btc`)
     pub blockchain: Blockchain,
     /// The account address (e.g. `0x1234`, etc.)
@@ -151,6 +160,30 @@ impl Account {
 mod tests {
     use super::account_test_utils::mock_account;
     use super::*;
+    use crate::models::ICP_ASSET_ID;
+    use ic_stable_structures::Storable;
+
+    #[test]
+    fn account_without_asset_id_defaults_to_icp() {
+        let mut account = mock_account();
+        account.asset_id = [1; 16];
+
+        let serde_cbor::Value::Map(mut fields) =
+            serde_cbor::from_slice(&account.to_bytes()).unwrap()
+        else {
+            panic!("accounts are serialized as maps");
+        };
+        fields.remove(&serde_cbor::Value::Text("asset_id".to_string()));
+
+        let legacy_account = Account::from_bytes(
+            serde_cbor::to_vec(&serde_cbor::Value::Map(fields))
+                .unwrap()
+                .into(),
+        );
+
+        assert_eq!(legacy_account.asset_id, *ICP_ASSET_ID);
+        assert_eq!(legacy_account.id, account.id);
+    }
 
     #[test]
     fn fail_symbol_validation_too_short() {
@@ -271,6 +304,7 @@ mod tests {
 #[cfg(test)]
 pub mod account_test_utils {
     use super::*;
+    use crate::models::ICP_ASSET_ID;
     use crate::repositories::ACCOUNT_REPOSITORY;
     use orbit_essentials::repository::Repository;
     use uuid::Uuid;
@@ -278,6 +312,7 @@ pub mod account_test_utils {
     pub fn mock_account() -> Account {
         Account {
             id: *Uuid::new_v4().as_bytes(),
+            asset_id: *ICP_ASSET_ID,
             address: "0x1234".to_string(),
             balance: None,
             blockchain: Blockchain::InternetComputer,
@@ -1,4 +1,4 @@
-use super::{Blockchain, BlockchainStandard};
+use super::{validate_blockchain_address, Blockchain, BlockchainStandard};
 use crate::errors::AddressBookError;
 use crate::models::Metadata;
 use candid::{CandidType, Deserialize};
@@ -7,7 +7,7 @@ use orbit_essentials::{
     model::{ModelValidator, ModelValidatorResult},
     types::{Timestamp, UUID},
 };
-use std::{collections::HashMap, hash::Hash};
+use std::{collections::HashMap, hash::Hash, str::FromStr};
 
 /// The address book entry id, which is a UUID.
 pub type AddressBookEntryId = UUID;
@@ -78,6 +78,8 @@ impl ModelValidator<AddressBookError> for AddressBookEntry {
 impl AddressBookEntry {
     pub const ADDRESS_RANGE: (u16, u16) = (1, 255);
     pub const ADDRESS_OWNER_RANGE: (u16, u16) = (1, 255);
+    /// The metadata key of the transfer limit of the entry, used by the transfer limit policy rules.
+    pub const TRANSFER_LIMIT_METADATA_KEY: &'static str = "transfer_limit";
 
     /// Creates a new address_book_entry key from the given key components.
     pub fn key(id: AddressBookEntryId) -> AddressBookEntryKey {
@@ -91,6 +93,26 @@ impl AddressBookEntry {
     pub fn metadata_map(&self) -> HashMap<String, String> {
         self.metadata.map()
     }
+
+    /// Returns the transfer limit of the entry, if it has a valid one in its metadata.
+    pub fn transfer_limit(&self) -> Option<candid::Nat> {
+        self.metadata
+            .get(Self::TRANSFER_LIMIT_METADATA_KEY)
+            .and_then(|limit| candid::Nat::from_str(&limit).ok())
+    }
+
+    /// Checks that the address is well formed for the blockchain and standard of the entry.
+    ///
+    /// Not part of the model validation so that entries created before the check was introduced
+    /// can still be edited.
+    pub fn validate_blockchain_address(&self) -> ModelValidatorResult<AddressBookError> {
+        validate_blockchain_address(&self.blockchain, &self.standard, &self.address).map_err(
+            |reason| AddressBookError::InvalidAddress {
+                address: self.address.clone(),
+                reason,
+            },
+        )
+    }
 }
 
 #[derive(CandidType, Deserialize, Debug, Clone)]
@@ -195,6 +217,20 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn fail_address_with_invalid_checksum() {
+        let mut address_book_entry = mock_address_book_entry();
+        address_book_entry.address =
+            "7f2e1b156f29b09f8efe9248c4e2d1f754434887a4bae0317e06c7d3442e7923".to_string();
+
+        let result = address_book_entry.validate_blockchain_address();
+
+        assert!(matches!(
+            result.unwrap_err(),
+            AddressBookError::InvalidAddress { .. }
+        ));
+    }
 }
 
 #[cfg(test)]
@@ -208,7 +244,7 @@ pub mod address_book_entry_test_utils {
         AddressBookEntry {
             id: *Uuid::new_v4().as_bytes(),
             address_owner: "foo".to_string(),
-            address: "0x1234".to_string(),
+            address: "7f2e1b156f29b09f8efe9248c4e2d1f754434887a4bae0317e06c7d3442e7922".to_string(),
             blockchain: Blockchain::InternetComputer,
             standard: BlockchainStandard::Native,
             metadata: Metadata::mock(),
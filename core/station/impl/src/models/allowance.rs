@@ -0,0 +1,162 @@
+use super::AccountId;
+use crate::core::validation::{EnsureAccount, EnsureIdExists, EnsureRequest};
+use crate::errors::{AllowanceError, RecordValidationError};
+use candid::Principal;
+use icrc_ledger_types::icrc1::account::Account as Icrc1Account;
+use orbit_essentials::storable;
+use orbit_essentials::{
+    model::{ModelValidator, ModelValidatorResult},
+    types::{Timestamp, UUID},
+};
+
+/// The allowance id, which is a UUID.
+pub type AllowanceId = UUID;
+
+/// The ICRC-1 account that is allowed to spend tokens on behalf of a station account.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AllowanceSpender {
+    pub owner: Principal,
+    pub subaccount: Option<[u8; 32]>,
+}
+
+impl AllowanceSpender {
+    /// Returns the ICRC-1 account of the spender.
+    pub fn to_icrc1_account(&self) -> Icrc1Account {
+        Icrc1Account {
+            owner: self.owner,
+            subaccount: self.subaccount,
+        }
+    }
+
+    /// Returns the ICRC-1 textual representation of the spender account, which is the same format
+    /// used by the address book for ICRC-1 addresses.
+    pub fn to_address(&self) -> String {
+        self.to_icrc1_account().to_string()
+    }
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AllowanceStatus {
+    Active,
+    Revoked { revoked_at: Timestamp },
+}
+
+/// Represents the spending rights that a station account has granted on its ledger through ICRC-2.
+///
+/// The allowance is recorded when the request that approved it is executed, approving a new
+/// allowance for the same spender replaces the amount of the active one.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Allowance {
+    /// The allowance id, which is a UUID.
+    pub id: AllowanceId,
+    /// The request that last approved the allowance.
+    pub request_id: UUID,
+    /// The station account that granted the allowance.
+    pub account_id: AccountId,
+    /// The account that is allowed to spend the tokens.
+    pub spender: AllowanceSpender,
+    /// The amount of tokens that the spender can transfer.
+    pub amount: candid::Nat,
+    /// The time at which the allowance expires, if any.
+    pub expires_at: Option<Timestamp>,
+    pub status: AllowanceStatus,
+    pub created_timestamp: Timestamp,
+    /// The last time the record was updated or created.
+    pub last_modification_timestamp: Timestamp,
+}
+
+impl Allowance {
+    pub fn is_active(&self) -> bool {
+        matches!(self.status, AllowanceStatus::Active)
+    }
+}
+
+fn validate_expiration(allowance: &Allowance) -> ModelValidatorResult<AllowanceError> {
+    if let Some(expires_at) = allowance.expires_at {
+        if expires_at <= allowance.last_modification_timestamp {
+            return Err(AllowanceError::ValidationError {
+                info: "The allowance expiration must be in the future".to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+impl ModelValidator<AllowanceError> for Allowance {
+    fn validate(&self) -> ModelValidatorResult<AllowanceError> {
+        validate_expiration(self)?;
+
+        EnsureAccount::id_exists(&self.account_id).map_err(|err| match err {
+            RecordValidationError::NotFound { id, .. } => AllowanceError::ValidationError {
+                info: format!("The account_id {} does not exist", id),
+            },
+        })?;
+
+        EnsureRequest::id_exists(&self.request_id).map_err(|err| match err {
+            RecordValidationError::NotFound { id, .. } => AllowanceError::ValidationError {
+                info: format!("The request_id {} does not exist", id),
+            },
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::allowance_test_utils::mock_allowance;
+    use super::*;
+
+    #[test]
+    fn fail_allowance_expired_on_creation() {
+        let mut allowance = mock_allowance();
+        allowance.last_modification_timestamp = 10;
+        allowance.expires_at = Some(10);
+
+        assert!(validate_expiration(&allowance).is_err());
+
+        allowance.expires_at = Some(11);
+
+        assert!(validate_expiration(&allowance).is_ok());
+    }
+
+    #[test]
+    fn spender_address_is_icrc1_textual_account() {
+        let mut allowance = mock_allowance();
+        allowance.spender.subaccount = Some([1; 32]);
+
+        let address = allowance.spender.to_address();
+
+        assert_eq!(
+            address.parse::<Icrc1Account>().unwrap(),
+            allowance.spender.to_icrc1_account()
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod allowance_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_allowance() -> Allowance {
+        Allowance {
+            id: *Uuid::new_v4().as_bytes(),
+            request_id: *Uuid::new_v4().as_bytes(),
+            account_id: *Uuid::new_v4().as_bytes(),
+            spender: AllowanceSpender {
+                owner: Principal::from_slice(&[1; 29]),
+                subaccount: None,
+            },
+            amount: candid::Nat::from(100_000_000u64),
+            expires_at: None,
+            status: AllowanceStatus::Active,
+            created_timestamp: 0,
+            last_modification_timestamp: 0,
+        }
+    }
+}
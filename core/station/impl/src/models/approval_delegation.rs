@@ -0,0 +1,123 @@
+use super::{request_specifier::RequestSpecifier, UserId};
+use crate::core::validation::{EnsureIdExists, EnsureUser};
+use crate::errors::ApprovalDelegationError;
+use orbit_essentials::storable;
+use orbit_essentials::{
+    model::{ModelValidator, ModelValidatorResult},
+    types::{Timestamp, UUID},
+};
+
+/// The approval delegation id, which is a UUID.
+pub type ApprovalDelegationId = UUID;
+
+/// Represents the approval rights that a user has handed over to another user for a limited time.
+///
+/// Until the delegation expires the delegate can approve requests on behalf of the delegator, the
+/// decisions are counted as the ones of the delegator.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ApprovalDelegation {
+    /// The approval delegation id, which is a UUID.
+    pub id: ApprovalDelegationId,
+    /// The user whose approval rights are delegated.
+    pub delegator_id: UserId,
+    /// The user that can approve on behalf of the delegator.
+    pub delegate_id: UserId,
+    /// Limits the delegation to the requests that match the specifier, if set.
+    pub specifier: Option<RequestSpecifier>,
+    /// The time at which the delegation expires.
+    pub expires_at: Timestamp,
+    pub created_timestamp: Timestamp,
+}
+
+impl ApprovalDelegation {
+    pub fn is_active_at(&self, now: Timestamp) -> bool {
+        now < self.expires_at
+    }
+}
+
+fn validate_delegation_users(
+    delegation: &ApprovalDelegation,
+) -> ModelValidatorResult<ApprovalDelegationError> {
+    if delegation.delegator_id == delegation.delegate_id {
+        return Err(ApprovalDelegationError::ValidationError {
+            info: "Users cannot delegate their approval rights to themselves".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_expiration(
+    delegation: &ApprovalDelegation,
+) -> ModelValidatorResult<ApprovalDelegationError> {
+    if delegation.expires_at <= delegation.created_timestamp {
+        return Err(ApprovalDelegationError::ValidationError {
+            info: "The delegation expiration must be in the future".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+impl ModelValidator<ApprovalDelegationError> for ApprovalDelegation {
+    fn validate(&self) -> ModelValidatorResult<ApprovalDelegationError> {
+        validate_delegation_users(self)?;
+        validate_expiration(self)?;
+
+        EnsureUser::id_exists(&self.delegator_id)?;
+        EnsureUser::id_exists(&self.delegate_id)?;
+
+        if let Some(specifier) = &self.specifier {
+            specifier.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::approval_delegation_test_utils::mock_approval_delegation;
+    use super::*;
+
+    #[test]
+    fn fail_delegation_to_self() {
+        let mut delegation = mock_approval_delegation();
+        delegation.delegate_id = delegation.delegator_id;
+
+        assert!(validate_delegation_users(&delegation).is_err());
+    }
+
+    #[test]
+    fn fail_delegation_expired_on_creation() {
+        let mut delegation = mock_approval_delegation();
+        delegation.created_timestamp = 10;
+        delegation.expires_at = 10;
+
+        assert!(validate_expiration(&delegation).is_err());
+
+        delegation.expires_at = 11;
+
+        assert!(validate_expiration(&delegation).is_ok());
+        assert!(delegation.is_active_at(10));
+        assert!(!delegation.is_active_at(11));
+    }
+}
+
+#[cfg(test)]
+pub mod approval_delegation_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_approval_delegation() -> ApprovalDelegation {
+        ApprovalDelegation {
+            id: *Uuid::new_v4().as_bytes(),
+            delegator_id: *Uuid::new_v4().as_bytes(),
+            delegate_id: *Uuid::new_v4().as_bytes(),
+            specifier: None,
+            expires_at: u64::MAX,
+            created_timestamp: 0,
+        }
+    }
+}
@@ -1,9 +1,38 @@
-use super::{Blockchain, BlockchainStandard};
+use super::{
+    Blockchain, BlockchainStandard, ACCOUNT_METADATA_INDEX_CANISTER_ID_KEY,
+    ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY,
+};
+use crate::errors::AssetError;
 use crate::models::Metadata;
-use std::hash::{Hash, Hasher};
+use crate::repositories::ASSET_REPOSITORY;
+use candid::{CandidType, Deserialize, Principal};
+use orbit_essentials::storable;
+use orbit_essentials::{
+    model::{ModelValidator, ModelValidatorResult},
+    types::{Timestamp, UUID},
+};
+use uuid::Uuid;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// The id of the native ICP asset that is registered when the station is initialized.
+pub const ICP_ASSET_ID: &UUID = Uuid::from_u128(302240678275694148452353).as_bytes();
+
+/// The asset id, which is a UUID.
+pub type AssetId = UUID;
+
+/// The asset of records that were stored before assets were managed at runtime, when the station
+/// only supported ICP.
+pub fn legacy_asset_id() -> AssetId {
+    *ICP_ASSET_ID
+}
+
+/// Represents an asset that is supported by the station (e.g. `ICP`, `ckBTC`, `ETH`, etc.).
+///
+/// Accounts reference the asset they hold by its id.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Asset {
+    /// The asset id, which is a UUID.
+    pub id: AssetId,
     /// The blockchain identifier (e.g., `ethereum`, `bitcoin`, `icp`, etc.)
     pub blockchain: Blockchain,
     // The asset standard that is supported (e.g. `erc20`, `native`, etc.), canonically
@@ -15,20 +44,257 @@ pub struct Asset {
     pub name: String,
     /// The asset metadata (e.g. `{"logo": "https://example.com/logo.png"}`),
     /// also, in the case of non-native assets, it can contain other required
-    /// information (e.g. `{"address": "0x1234"}`).
+    /// information (e.g. `{"ledger_canister_id": "mxzaz-hqaaa-aaaar-qaada-cai"}`).
     pub metadata: Metadata,
+    /// The last time the record was updated or created.
+    pub last_modification_timestamp: Timestamp,
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone)]
+pub struct AssetCallerPrivileges {
+    pub id: UUID,
+    pub can_edit: bool,
+    pub can_delete: bool,
+}
+
+impl Asset {
+    pub const SYMBOL_RANGE: (u8, u8) = (1, 8);
+    pub const NAME_RANGE: (u8, u8) = (1, 100);
+}
+
+fn validate_symbol(symbol: &str) -> ModelValidatorResult<AssetError> {
+    if (symbol.len() < Asset::SYMBOL_RANGE.0 as usize)
+        || (symbol.len() > Asset::SYMBOL_RANGE.1 as usize)
+    {
+        return Err(AssetError::InvalidSymbolLength {
+            min_length: Asset::SYMBOL_RANGE.0,
+            max_length: Asset::SYMBOL_RANGE.1,
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_name(name: &str) -> ModelValidatorResult<AssetError> {
+    if (name.len() < Asset::NAME_RANGE.0 as usize) || (name.len() > Asset::NAME_RANGE.1 as usize) {
+        return Err(AssetError::InvalidNameLength {
+            min_length: Asset::NAME_RANGE.0,
+            max_length: Asset::NAME_RANGE.1,
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_standard(
+    blockchain: &Blockchain,
+    standard: &BlockchainStandard,
+) -> ModelValidatorResult<AssetError> {
+    if !blockchain.supported_standards().contains(standard) {
+        return Err(AssetError::UnsupportedStandard {
+            blockchain: blockchain.to_string(),
+            standard: standard.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_standard_metadata(asset: &Asset) -> ModelValidatorResult<AssetError> {
+    if let BlockchainStandard::ICRC1 = asset.standard {
+        let ledger_canister_id = asset
+            .metadata
+            .get(ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY)
+            .ok_or(AssetError::ValidationError {
+                info: format!(
+                    "The `{}` metadata is required for ICRC-1 assets",
+                    ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY
+                ),
+            })?;
+
+        Principal::from_text(&ledger_canister_id).map_err(|e| AssetError::ValidationError {
+            info: format!("Invalid ledger canister id `{}`: {}", ledger_canister_id, e),
+        })?;
+
+        if let Some(index_canister_id) = asset.metadata.get(ACCOUNT_METADATA_INDEX_CANISTER_ID_KEY)
+        {
+            Principal::from_text(&index_canister_id).map_err(|e| AssetError::ValidationError {
+                info: format!("Invalid index canister id `{}`: {}", index_canister_id, e),
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_unique_symbol(asset: &Asset) -> ModelValidatorResult<AssetError> {
+    if let Some(existing_asset) =
+        ASSET_REPOSITORY.find_by_symbol(&asset.blockchain, &asset.standard, &asset.symbol)
+    {
+        if existing_asset.id != asset.id {
+            return Err(AssetError::AlreadyExists {
+                symbol: asset.symbol.to_string(),
+                blockchain: asset.blockchain.to_string(),
+                standard: asset.standard.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+impl ModelValidator<AssetError> for Asset {
+    fn validate(&self) -> ModelValidatorResult<AssetError> {
+        self.metadata.validate()?;
+        validate_symbol(&self.symbol)?;
+        validate_name(&self.name)?;
+        validate_standard(&self.blockchain, &self.standard)?;
+        validate_standard_metadata(self)?;
+        validate_unique_symbol(self)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::asset_test_utils::mock_asset;
+    use super::*;
+    use orbit_essentials::repository::Repository;
+
+    #[test]
+    fn fail_asset_symbol_length() {
+        let mut asset = mock_asset();
+        asset.symbol = String::new();
+
+        assert_eq!(
+            validate_symbol(&asset.symbol).unwrap_err(),
+            AssetError::InvalidSymbolLength {
+                min_length: Asset::SYMBOL_RANGE.0,
+                max_length: Asset::SYMBOL_RANGE.1
+            }
+        );
+
+        asset.symbol = "a".repeat(Asset::SYMBOL_RANGE.1 as usize + 1);
+
+        assert!(validate_symbol(&asset.symbol).is_err());
+    }
+
+    #[test]
+    fn fail_asset_name_length() {
+        let mut asset = mock_asset();
+        asset.name = "a".repeat(Asset::NAME_RANGE.1 as usize + 1);
+
+        assert_eq!(
+            validate_name(&asset.name).unwrap_err(),
+            AssetError::InvalidNameLength {
+                min_length: Asset::NAME_RANGE.0,
+                max_length: Asset::NAME_RANGE.1
+            }
+        );
+    }
+
+    #[test]
+    fn fail_unsupported_standard() {
+        let result = validate_standard(&Blockchain::Bitcoin, &BlockchainStandard::ICRC1);
+
+        assert_eq!(
+            result.unwrap_err(),
+            AssetError::UnsupportedStandard {
+                blockchain: "btc".to_string(),
+                standard: "icrc1".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn fail_icrc1_asset_without_ledger_canister_id() {
+        let mut asset = mock_asset();
+        asset.standard = BlockchainStandard::ICRC1;
+        asset.metadata = Metadata::default();
+
+        assert!(validate_standard_metadata(&asset).is_err());
+
+        asset.metadata = Metadata::new(
+            vec![(
+                ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY.to_string(),
+                "mxzaz-hqaaa-aaaar-qaada-cai".to_string(),
+            )]
+            .into_iter()
+            .collect(),
+        );
+
+        assert!(validate_standard_metadata(&asset).is_ok());
+    }
+
+    #[test]
+    fn fail_icrc1_asset_with_invalid_index_canister_id() {
+        let mut asset = mock_asset();
+        asset.standard = BlockchainStandard::ICRC1;
+        asset.metadata = Metadata::new(
+            vec![
+                (
+                    ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY.to_string(),
+                    "mxzaz-hqaaa-aaaar-qaada-cai".to_string(),
+                ),
+                (
+                    ACCOUNT_METADATA_INDEX_CANISTER_ID_KEY.to_string(),
+                    "not-a-principal".to_string(),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        assert!(validate_standard_metadata(&asset).is_err());
+    }
+
+    #[test]
+    fn fail_non_unique_asset_symbol() {
+        let asset = mock_asset();
+        let mut other_asset = mock_asset();
+        other_asset.id = [1; 16];
+
+        ASSET_REPOSITORY.insert(asset.id, asset.clone());
+
+        assert!(validate_unique_symbol(&asset).is_ok());
+        assert_eq!(
+            validate_unique_symbol(&other_asset).unwrap_err(),
+            AssetError::AlreadyExists {
+                symbol: asset.symbol.to_string(),
+                blockchain: asset.blockchain.to_string(),
+                standard: asset.standard.to_string(),
+            }
+        );
+    }
 }
 
-impl Hash for Asset {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.blockchain.hash(state);
-        self.standard.hash(state);
-        self.symbol.hash(state);
-        self.name.hash(state);
+#[cfg(test)]
+pub mod asset_test_utils {
+    use super::*;
+    use orbit_essentials::repository::Repository;
+
+    pub fn mock_asset() -> Asset {
+        Asset {
+            id: [0; 16],
+            blockchain: Blockchain::InternetComputer,
+            standard: BlockchainStandard::Native,
+            symbol: "ICP".to_string(),
+            name: "Internet Computer".to_string(),
+            metadata: Metadata::default(),
+            last_modification_timestamp: 0,
+        }
+    }
+
+    pub fn add_asset(symbol: &str) -> Asset {
+        let mut asset = mock_asset();
+        asset.id = *Uuid::new_v4().as_bytes();
+        asset.symbol = symbol.to_string();
+
+        asset.validate().unwrap();
+
+        ASSET_REPOSITORY.insert(asset.id, asset.clone());
 
-        // For HashMap we need to sort the keys first to ensure that the hash is stable.
-        let mut keys: Vec<&String> = self.metadata.keys();
-        keys.sort();
-        keys.hash(state);
+        asset
     }
 }
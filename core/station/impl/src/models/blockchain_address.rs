@@ -0,0 +1,356 @@
+use super::{Blockchain, BlockchainStandard};
+use ic_ledger_types::AccountIdentifier;
+use icrc_ledger_types::icrc1::account::Account as Icrc1Account;
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+use tiny_keccak::{Hasher, Keccak};
+
+/// Validates that the address is well formed for the given blockchain and standard.
+///
+/// The checksum of the address is verified whenever its format has one, so that typos are caught
+/// before a transfer is submitted to the network. Returns the reason why the address is invalid.
+pub fn validate_blockchain_address(
+    blockchain: &Blockchain,
+    standard: &BlockchainStandard,
+    address: &str,
+) -> Result<(), String> {
+    match (blockchain, standard) {
+        (Blockchain::InternetComputer, BlockchainStandard::Native) => {
+            validate_icp_account_identifier(address)
+        }
+        (Blockchain::InternetComputer, BlockchainStandard::ICRC1) => {
+            validate_icrc1_account(address)
+        }
+        (Blockchain::Ethereum, BlockchainStandard::Native)
+        | (Blockchain::Ethereum, BlockchainStandard::ERC20) => validate_ethereum_address(address),
+        (Blockchain::Bitcoin, BlockchainStandard::Native) => validate_bitcoin_address(address),
+        _ => Err(format!(
+            "the standard `{}` is not supported by the blockchain `{}`",
+            standard, blockchain
+        )),
+    }
+}
+
+/// The account identifier is the hex encoding of a CRC32 checksum followed by the 28 bytes hash.
+fn validate_icp_account_identifier(address: &str) -> Result<(), String> {
+    if address.len() != 64 {
+        return Err("the account identifier must be 64 hex characters long".to_string());
+    }
+
+    AccountIdentifier::from_hex(address)
+        .map(|_| ())
+        .map_err(|error| format!("invalid account identifier: {}", error))
+}
+
+/// The textual encoding of an ICRC-1 account is the principal, optionally followed by the checksum
+/// and the hex encoded subaccount (e.g. `<principal>-<checksum>.<subaccount>`).
+fn validate_icrc1_account(address: &str) -> Result<(), String> {
+    Icrc1Account::from_str(address)
+        .map(|_| ())
+        .map_err(|error| format!("invalid ICRC-1 account: {}", error))
+}
+
+/// Ethereum addresses are 20 bytes encoded as hex with the `0x` prefix, mixed case addresses must
+/// match their EIP-55 checksum.
+fn validate_ethereum_address(address: &str) -> Result<(), String> {
+    let hex_address = address
+        .strip_prefix("0x")
+        .ok_or_else(|| "the address must start with `0x`".to_string())?;
+
+    if hex_address.len() != 40 || !hex_address.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err("the address must be 40 hex characters long".to_string());
+    }
+
+    let has_lowercase = hex_address.chars().any(|c| c.is_ascii_lowercase());
+    let has_uppercase = hex_address.chars().any(|c| c.is_ascii_uppercase());
+    if !has_lowercase || !has_uppercase {
+        // addresses in a single case carry no checksum
+        return Ok(());
+    }
+
+    let mut hash = [0u8; 32];
+    let mut hasher = Keccak::v256();
+    hasher.update(hex_address.to_ascii_lowercase().as_bytes());
+    hasher.finalize(&mut hash);
+
+    for (i, c) in hex_address.chars().enumerate() {
+        if !c.is_ascii_alphabetic() {
+            continue;
+        }
+
+        let nibble = if i % 2 == 0 {
+            hash[i / 2] >> 4
+        } else {
+            hash[i / 2] & 0x0f
+        };
+
+        if (nibble >= 8) != c.is_ascii_uppercase() {
+            return Err("the address does not match its EIP-55 checksum".to_string());
+        }
+    }
+
+    Ok(())
+}
+
+/// Bitcoin addresses are either segwit addresses encoded with bech32/bech32m or legacy addresses
+/// encoded with base58check.
+fn validate_bitcoin_address(address: &str) -> Result<(), String> {
+    let lowercase_address = address.to_ascii_lowercase();
+    if ["bc1", "tb1", "bcrt1"]
+        .iter()
+        .any(|prefix| lowercase_address.starts_with(prefix))
+    {
+        return validate_bitcoin_segwit_address(address);
+    }
+
+    validate_bitcoin_base58_address(address)
+}
+
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc830a3;
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    let mut checksum: u32 = 1;
+    for value in values {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x1ffffff) << 5) ^ (*value as u32);
+        for (i, generator) in BECH32_GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+
+    checksum
+}
+
+fn validate_bitcoin_segwit_address(address: &str) -> Result<(), String> {
+    if address.len() > 90 {
+        return Err("the segwit address is too long".to_string());
+    }
+
+    if address.chars().any(|c| c.is_ascii_lowercase())
+        && address.chars().any(|c| c.is_ascii_uppercase())
+    {
+        return Err("the segwit address must not be mixed case".to_string());
+    }
+
+    let address = address.to_ascii_lowercase();
+    let (hrp, data) = address
+        .rsplit_once('1')
+        .ok_or_else(|| "the segwit address has no separator".to_string())?;
+
+    let data = data
+        .chars()
+        .map(|c| {
+            BECH32_CHARSET
+                .find(c)
+                .map(|position| position as u8)
+                .ok_or_else(|| format!("the segwit address has an invalid character `{}`", c))
+        })
+        .collect::<Result<Vec<u8>, String>>()?;
+
+    if data.len() < 7 {
+        return Err("the segwit address is too short".to_string());
+    }
+
+    let mut values = hrp.bytes().map(|c| c >> 5).collect::<Vec<u8>>();
+    values.push(0);
+    values.extend(hrp.bytes().map(|c| c & 31));
+    values.extend(&data);
+
+    let witness_version = data[0];
+    let expected_checksum = match witness_version {
+        0 => BECH32_CONST,
+        1..=16 => BECH32M_CONST,
+        _ => return Err("the segwit address has an invalid witness version".to_string()),
+    };
+
+    if bech32_polymod(&values) != expected_checksum {
+        return Err("the segwit address does not match its checksum".to_string());
+    }
+
+    // converts the 5 bit groups of the witness program to bytes
+    let mut program = Vec::new();
+    let mut accumulator: u32 = 0;
+    let mut bits = 0;
+    for value in &data[1..data.len() - 6] {
+        // only the bits that are not yet converted are kept
+        accumulator = ((accumulator << 5) | (*value as u32)) & 0xfff;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            program.push(((accumulator >> bits) & 0xff) as u8);
+        }
+    }
+
+    if bits >= 5 || (accumulator & ((1 << bits) - 1)) != 0 {
+        return Err("the segwit address has an invalid padding".to_string());
+    }
+
+    if program.len() < 2
+        || program.len() > 40
+        || (witness_version == 0 && program.len() != 20 && program.len() != 32)
+    {
+        return Err("the segwit address has an invalid witness program length".to_string());
+    }
+
+    Ok(())
+}
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The version bytes of the P2PKH and P2SH addresses of the mainnet and the testnet.
+const BITCOIN_BASE58_VERSIONS: [u8; 4] = [0x00, 0x05, 0x6f, 0xc4];
+
+fn validate_bitcoin_base58_address(address: &str) -> Result<(), String> {
+    // the decoded bytes are kept in little endian order while the number is being built
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in address.chars() {
+        let mut carry = BASE58_ALPHABET
+            .find(c)
+            .ok_or_else(|| format!("the address has an invalid character `{}`", c))?
+            as u32;
+
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    let leading_zeros = address.chars().take_while(|c| *c == '1').count();
+    bytes.extend(std::iter::repeat(0).take(leading_zeros));
+    bytes.reverse();
+
+    if bytes.len() != 25 {
+        return Err("the address must decode to 25 bytes".to_string());
+    }
+
+    if !BITCOIN_BASE58_VERSIONS.contains(&bytes[0]) {
+        return Err("the address has an unknown version".to_string());
+    }
+
+    let checksum = Sha256::digest(Sha256::digest(&bytes[..21]));
+    if checksum[..4] != bytes[21..] {
+        return Err("the address does not match its checksum".to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::icp_account_identifier(
+        Blockchain::InternetComputer,
+        BlockchainStandard::Native,
+        "7f2e1b156f29b09f8efe9248c4e2d1f754434887a4bae0317e06c7d3442e7922"
+    )]
+    #[case::icrc1_account(
+        Blockchain::InternetComputer,
+        BlockchainStandard::ICRC1,
+        "ryjl3-tyaaa-aaaaa-aaaba-cai"
+    )]
+    #[case::icrc1_account_with_subaccount(
+        Blockchain::InternetComputer,
+        BlockchainStandard::ICRC1,
+        "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-dfxgiyy.102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20"
+    )]
+    #[case::eth_checksum_address(
+        Blockchain::Ethereum,
+        BlockchainStandard::Native,
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+    )]
+    #[case::eth_lowercase_address(
+        Blockchain::Ethereum,
+        BlockchainStandard::ERC20,
+        "0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359"
+    )]
+    #[case::btc_p2pkh(
+        Blockchain::Bitcoin,
+        BlockchainStandard::Native,
+        "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"
+    )]
+    #[case::btc_p2sh(
+        Blockchain::Bitcoin,
+        BlockchainStandard::Native,
+        "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy"
+    )]
+    #[case::btc_segwit_v0(
+        Blockchain::Bitcoin,
+        BlockchainStandard::Native,
+        "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"
+    )]
+    #[case::btc_taproot(
+        Blockchain::Bitcoin,
+        BlockchainStandard::Native,
+        "bc1p5d7rjq7g6rdk2yhzks9smlaqtedr4dekq08ge8ztwac72sfr9rusxg3297"
+    )]
+    fn valid_addresses(
+        #[case] blockchain: Blockchain,
+        #[case] standard: BlockchainStandard,
+        #[case] address: &str,
+    ) {
+        validate_blockchain_address(&blockchain, &standard, address).unwrap();
+    }
+
+    #[rstest]
+    #[case::icp_account_identifier_with_typo(
+        Blockchain::InternetComputer,
+        BlockchainStandard::Native,
+        "7f2e1b156f29b09f8efe9248c4e2d1f754434887a4bae0317e06c7d3442e7923"
+    )]
+    #[case::icp_account_identifier_too_short(
+        Blockchain::InternetComputer,
+        BlockchainStandard::Native,
+        "0x1234"
+    )]
+    #[case::icrc1_account_with_wrong_checksum(
+        Blockchain::InternetComputer,
+        BlockchainStandard::ICRC1,
+        "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-aaaaaaa.102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20"
+    )]
+    #[case::eth_wrong_checksum(
+        Blockchain::Ethereum,
+        BlockchainStandard::Native,
+        "0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+    )]
+    #[case::eth_without_prefix(
+        Blockchain::Ethereum,
+        BlockchainStandard::Native,
+        "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+    )]
+    #[case::btc_p2pkh_with_typo(
+        Blockchain::Bitcoin,
+        BlockchainStandard::Native,
+        "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3"
+    )]
+    #[case::btc_segwit_with_typo(
+        Blockchain::Bitcoin,
+        BlockchainStandard::Native,
+        "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdr"
+    )]
+    #[case::unsupported_standard(
+        Blockchain::Bitcoin,
+        BlockchainStandard::ERC20,
+        "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"
+    )]
+    fn invalid_addresses(
+        #[case] blockchain: Blockchain,
+        #[case] standard: BlockchainStandard,
+        #[case] address: &str,
+    ) {
+        validate_blockchain_address(&blockchain, &standard, address).unwrap_err();
+    }
+}
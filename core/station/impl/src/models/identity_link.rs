@@ -0,0 +1,131 @@
+use super::UserId;
+use crate::core::validation::{EnsureIdExists, EnsureUser};
+use crate::errors::IdentityLinkError;
+use candid::Principal;
+use orbit_essentials::storable;
+use orbit_essentials::{
+    model::{ModelValidator, ModelValidatorResult},
+    types::{Timestamp, UUID},
+};
+
+/// The identity link id, which is a UUID.
+pub type IdentityLinkId = UUID;
+
+/// Represents a new identity that a user wants to add, waiting for the new identity to confirm it.
+///
+/// The link is started by the user from one of its existing identities and only completes once the
+/// new identity confirms it before it expires, which proves that the user controls both identities.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IdentityLink {
+    /// The identity link id, which is a UUID.
+    pub id: IdentityLinkId,
+    /// The user that the new identity is linked to.
+    pub user_id: UserId,
+    /// The identity that started the link.
+    pub current_identity: Principal,
+    /// The identity that needs to confirm the link.
+    pub new_identity: Principal,
+    /// Whether the current identity is removed from the user once the link completes.
+    pub retire_current_identity: bool,
+    /// The time after which the link can no longer be confirmed.
+    pub expires_at: Timestamp,
+    pub created_timestamp: Timestamp,
+}
+
+impl IdentityLink {
+    /// The time window in which the new identity has to confirm the link, in nanoseconds.
+    pub const CONFIRMATION_WINDOW_NS: u64 = 10 * 60 * 1_000_000_000;
+
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        now >= self.expires_at
+    }
+}
+
+fn validate_identities(link: &IdentityLink) -> ModelValidatorResult<IdentityLinkError> {
+    if link.new_identity == Principal::anonymous() {
+        return Err(IdentityLinkError::ValidationError {
+            info: "The anonymous identity cannot be linked to a user".to_string(),
+        });
+    }
+
+    if link.new_identity == link.current_identity {
+        return Err(IdentityLinkError::ValidationError {
+            info: "The new identity must be different from the current identity".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_expiration(link: &IdentityLink) -> ModelValidatorResult<IdentityLinkError> {
+    if link.expires_at <= link.created_timestamp {
+        return Err(IdentityLinkError::ValidationError {
+            info: "The link expiration must be in the future".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+impl ModelValidator<IdentityLinkError> for IdentityLink {
+    fn validate(&self) -> ModelValidatorResult<IdentityLinkError> {
+        validate_identities(self)?;
+        validate_expiration(self)?;
+
+        EnsureUser::id_exists(&self.user_id)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::identity_link_test_utils::mock_identity_link;
+    use super::*;
+
+    #[test]
+    fn fail_linking_anonymous_or_same_identity() {
+        let mut link = mock_identity_link();
+        link.new_identity = Principal::anonymous();
+
+        assert!(validate_identities(&link).is_err());
+
+        link.new_identity = link.current_identity;
+
+        assert!(validate_identities(&link).is_err());
+    }
+
+    #[test]
+    fn link_expires_at_the_end_of_the_window() {
+        let mut link = mock_identity_link();
+        link.created_timestamp = 10;
+        link.expires_at = 10;
+
+        assert!(validate_expiration(&link).is_err());
+
+        link.expires_at = 11;
+
+        assert!(validate_expiration(&link).is_ok());
+        assert!(!link.is_expired_at(10));
+        assert!(link.is_expired_at(11));
+    }
+}
+
+#[cfg(test)]
+pub mod identity_link_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_identity_link() -> IdentityLink {
+        IdentityLink {
+            id: *Uuid::new_v4().as_bytes(),
+            user_id: *Uuid::new_v4().as_bytes(),
+            current_identity: Principal::from_slice(&[1; 29]),
+            new_identity: Principal::from_slice(&[2; 29]),
+            retire_current_identity: false,
+            expires_at: u64::MAX,
+            created_timestamp: 0,
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use super::{AccountId, Transfer};
+use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
+use std::hash::Hash;
+
+/// The id of a transaction in the ledger history of the account, as reported by its index.
+pub type LedgerTransactionId = u64;
+
+/// Represents a deposit into a station account that was found in the ledger history of the account.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IncomingTransfer {
+    /// The account that received the tokens.
+    pub account_id: AccountId,
+    /// The id of the ledger transaction that deposited the tokens.
+    pub tx_id: LedgerTransactionId,
+    /// The address that sent the tokens, `None` if the tokens were minted.
+    pub from_address: Option<String>,
+    pub amount: candid::Nat,
+    /// The time at which the transaction was recorded by the ledger.
+    pub timestamp: Timestamp,
+    /// The time at which the transaction was indexed by the station.
+    pub created_timestamp: Timestamp,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IncomingTransferKey {
+    pub account_id: AccountId,
+    pub tx_id: LedgerTransactionId,
+}
+
+impl IncomingTransfer {
+    pub fn key(account_id: AccountId, tx_id: LedgerTransactionId) -> IncomingTransferKey {
+        IncomingTransferKey { account_id, tx_id }
+    }
+
+    pub fn to_key(&self) -> IncomingTransferKey {
+        Self::key(self.account_id, self.tx_id)
+    }
+}
+
+/// The progress of indexing the ledger history of an account.
+///
+/// The history is walked from the most recent transaction backwards, one page at a time, until the
+/// transactions that were already indexed are reached. The walk is then completed and the most recent
+/// transaction it saw becomes the new synced position.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IncomingTransferSyncState {
+    pub account_id: AccountId,
+    /// All the transactions up to this one are indexed.
+    pub synced_tx_id: Option<LedgerTransactionId>,
+    /// The most recent transaction of the walk in progress.
+    pub walk_top_tx_id: Option<LedgerTransactionId>,
+    /// The oldest transaction of the walk in progress, the next page starts right before it.
+    pub walk_cursor_tx_id: Option<LedgerTransactionId>,
+    /// The last time the record was updated or created.
+    pub last_modification_timestamp: Timestamp,
+}
+
+impl IncomingTransferSyncState {
+    pub fn new(account_id: AccountId) -> Self {
+        Self {
+            account_id,
+            synced_tx_id: None,
+            walk_top_tx_id: None,
+            walk_cursor_tx_id: None,
+            last_modification_timestamp: 0,
+        }
+    }
+
+    /// Returns true if the walk reached the transactions that were already indexed.
+    pub fn is_synced(&self) -> bool {
+        self.walk_cursor_tx_id.is_none()
+    }
+
+    /// Returns true if the transaction was already indexed by a previous walk.
+    pub fn is_indexed(&self, tx_id: LedgerTransactionId) -> bool {
+        self.synced_tx_id
+            .is_some_and(|synced_tx_id| tx_id <= synced_tx_id)
+    }
+
+    /// Moves the walk in progress past the given transaction.
+    pub fn advance(&mut self, tx_id: LedgerTransactionId) {
+        self.walk_top_tx_id = Some(
+            self.walk_top_tx_id
+                .map_or(tx_id, |walk_top_tx_id| walk_top_tx_id.max(tx_id)),
+        );
+        self.walk_cursor_tx_id = Some(tx_id);
+    }
+
+    /// Completes the walk in progress, its most recent transaction becomes the synced position.
+    pub fn complete_walk(&mut self) {
+        self.synced_tx_id = self.walk_top_tx_id.or(self.synced_tx_id);
+        self.walk_top_tx_id = None;
+        self.walk_cursor_tx_id = None;
+    }
+}
+
+/// An entry of the activity feed of an account, which merges the transfers made by the station with
+/// the deposits found in the ledger history of the account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountActivity {
+    Outgoing(Transfer),
+    Incoming(IncomingTransfer),
+}
+
+impl AccountActivity {
+    /// The time used to sort the feed.
+    pub fn timestamp(&self) -> Timestamp {
+        match self {
+            AccountActivity::Outgoing(transfer) => transfer.created_timestamp,
+            AccountActivity::Incoming(incoming_transfer) => incoming_transfer.timestamp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walk_becomes_synced_once_completed() {
+        let mut state = IncomingTransferSyncState::new([1; 16]);
+
+        state.advance(10);
+        state.advance(9);
+
+        assert!(!state.is_synced());
+        assert!(!state.is_indexed(10));
+
+        state.complete_walk();
+
+        assert!(state.is_synced());
+        assert!(state.is_indexed(10));
+        assert!(!state.is_indexed(11));
+    }
+
+    #[test]
+    fn empty_walk_keeps_the_synced_position() {
+        let mut state = IncomingTransferSyncState::new([1; 16]);
+        state.advance(5);
+        state.complete_walk();
+
+        state.complete_walk();
+
+        assert_eq!(state.synced_tx_id, Some(5));
+    }
+}
+
+#[cfg(test)]
+pub mod incoming_transfer_test_utils {
+    use super::*;
+    use num_bigint::BigUint;
+
+    pub fn mock_incoming_transfer() -> IncomingTransfer {
+        IncomingTransfer {
+            account_id: [1; 16],
+            tx_id: 1,
+            from_address: Some("0x1234".to_string()),
+            amount: candid::Nat(BigUint::from(100u32)),
+            timestamp: 0,
+            created_timestamp: 0,
+        }
+    }
+}
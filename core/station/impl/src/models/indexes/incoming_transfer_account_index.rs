@@ -0,0 +1,49 @@
+use crate::models::{AccountId, IncomingTransfer, LedgerTransactionId};
+use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
+use std::hash::Hash;
+
+/// Represents an index of the incoming transfers of an account by their ledger time.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IncomingTransferAccountIndex {
+    /// The account that received the transfer.
+    pub account_id: AccountId,
+    /// The time at which the transfer was recorded by the ledger.
+    pub timestamp: Timestamp,
+    /// The ledger transaction of the transfer.
+    pub tx_id: LedgerTransactionId,
+}
+
+#[derive(Clone, Debug)]
+pub struct IncomingTransferAccountIndexCriteria {
+    pub account_id: AccountId,
+    pub from_dt: Option<Timestamp>,
+    pub to_dt: Option<Timestamp>,
+}
+
+impl IncomingTransfer {
+    pub fn to_index_by_account(&self) -> IncomingTransferAccountIndex {
+        IncomingTransferAccountIndex {
+            account_id: self.account_id,
+            timestamp: self.timestamp,
+            tx_id: self.tx_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::incoming_transfer_test_utils::mock_incoming_transfer;
+
+    #[test]
+    fn test_incoming_transfer_to_account_index_association() {
+        let incoming_transfer = mock_incoming_transfer();
+
+        let index = incoming_transfer.to_index_by_account();
+
+        assert_eq!(index.account_id, incoming_transfer.account_id);
+        assert_eq!(index.timestamp, incoming_transfer.timestamp);
+        assert_eq!(index.tx_id, incoming_transfer.tx_id);
+    }
+}
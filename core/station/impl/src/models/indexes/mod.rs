@@ -1,9 +1,11 @@
 pub mod address_book_index;
 pub mod address_book_standard_index;
+pub mod incoming_transfer_account_index;
 pub mod name_to_account_id_index;
 pub mod name_to_user_id_index;
 pub mod notification_user_index;
 pub mod request_approver_index;
+pub mod request_comment_request_index;
 pub mod request_creation_time_index;
 pub mod request_expiration_time_index;
 pub mod request_key_creation_time_index;
@@ -65,6 +65,7 @@ mod tests {
         request.approvals = vec![
             RequestApproval {
                 approver_id: [1; 16],
+                delegate_id: None,
                 status_reason: None,
                 decided_dt: 0,
                 last_modification_timestamp: 0,
@@ -72,6 +73,7 @@ mod tests {
             },
             RequestApproval {
                 approver_id: [2; 16],
+                delegate_id: None,
                 status_reason: None,
                 decided_dt: 0,
                 last_modification_timestamp: 0,
@@ -0,0 +1,46 @@
+use crate::models::{RequestComment, RequestCommentId, RequestId};
+use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
+
+/// Index of request comments by the request they belong to.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestCommentRequestIndex {
+    /// The request that the comment belongs to.
+    pub request_id: RequestId,
+    /// The time when the comment was created.
+    pub created_at: Timestamp,
+    /// The comment id, which is a UUID.
+    pub comment_id: RequestCommentId,
+}
+
+#[derive(Clone, Debug)]
+pub struct RequestCommentRequestIndexCriteria {
+    pub request_id: RequestId,
+}
+
+impl RequestComment {
+    pub fn to_index_by_request(&self) -> RequestCommentRequestIndex {
+        RequestCommentRequestIndex {
+            request_id: self.request_id.to_owned(),
+            created_at: self.created_timestamp,
+            comment_id: self.id.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::request_comment_test_utils::mock_request_comment;
+
+    #[test]
+    fn correct_request_comment_index_mapping() {
+        let comment = mock_request_comment();
+
+        let index = comment.to_index_by_request();
+
+        assert_eq!(index.comment_id, comment.id);
+        assert_eq!(index.request_id, comment.request_id);
+        assert_eq!(index.created_at, comment.created_timestamp);
+    }
+}
@@ -36,6 +36,27 @@ impl Request {
                     },
                 ]
             }
+            RequestOperation::BatchTransfer(operation) => {
+                let mut indexes = vec![RequestOperationTypeIndex {
+                    operation_type: RequestOperationFilterType::BatchTransfer(None),
+                    request_id: self.id,
+                }];
+
+                indexes.extend(
+                    operation
+                        .input
+                        .from_account_ids()
+                        .into_iter()
+                        .map(|account_id| RequestOperationTypeIndex {
+                            operation_type: RequestOperationFilterType::BatchTransfer(Some(
+                                account_id,
+                            )),
+                            request_id: self.id,
+                        }),
+                );
+
+                indexes
+            }
             RequestOperation::AddAccount(_) => vec![RequestOperationTypeIndex {
                 operation_type: RequestOperationFilterType::AddAccount,
                 request_id: self.id,
@@ -52,6 +73,14 @@ impl Request {
                 operation_type: RequestOperationFilterType::EditUser,
                 request_id: self.id,
             }],
+            RequestOperation::RemoveUser(_) => vec![RequestOperationTypeIndex {
+                operation_type: RequestOperationFilterType::RemoveUser,
+                request_id: self.id,
+            }],
+            RequestOperation::LinkUserIdentity(_) => vec![RequestOperationTypeIndex {
+                operation_type: RequestOperationFilterType::LinkUserIdentity,
+                request_id: self.id,
+            }],
             RequestOperation::AddUserGroup(_) => vec![RequestOperationTypeIndex {
                 operation_type: RequestOperationFilterType::AddUserGroup,
                 request_id: self.id,
@@ -64,6 +93,10 @@ impl Request {
                 operation_type: RequestOperationFilterType::RemoveUserGroup,
                 request_id: self.id,
             }],
+            RequestOperation::ApplyRoleTemplate(_) => vec![RequestOperationTypeIndex {
+                operation_type: RequestOperationFilterType::ApplyRoleTemplate,
+                request_id: self.id,
+            }],
             RequestOperation::ChangeCanister(_) => vec![RequestOperationTypeIndex {
                 operation_type: RequestOperationFilterType::ChangeCanister,
                 request_id: self.id,
@@ -128,6 +161,81 @@ impl Request {
                 operation_type: RequestOperationFilterType::ManageSystemInfo,
                 request_id: self.id,
             }],
+            RequestOperation::AddAsset(_) => vec![RequestOperationTypeIndex {
+                operation_type: RequestOperationFilterType::AddAsset,
+                request_id: self.id,
+            }],
+            RequestOperation::EditAsset(_) => vec![RequestOperationTypeIndex {
+                operation_type: RequestOperationFilterType::EditAsset,
+                request_id: self.id,
+            }],
+            RequestOperation::RemoveAsset(_) => vec![RequestOperationTypeIndex {
+                operation_type: RequestOperationFilterType::RemoveAsset,
+                request_id: self.id,
+            }],
+            RequestOperation::ApproveAllowance(operation) => {
+                vec![
+                    RequestOperationTypeIndex {
+                        operation_type: RequestOperationFilterType::ApproveAllowance(None),
+                        request_id: self.id,
+                    },
+                    RequestOperationTypeIndex {
+                        operation_type: RequestOperationFilterType::ApproveAllowance(Some(
+                            operation.input.from_account_id,
+                        )),
+                        request_id: self.id,
+                    },
+                ]
+            }
+            RequestOperation::RevokeAllowance(operation) => {
+                vec![
+                    RequestOperationTypeIndex {
+                        operation_type: RequestOperationFilterType::RevokeAllowance(None),
+                        request_id: self.id,
+                    },
+                    RequestOperationTypeIndex {
+                        operation_type: RequestOperationFilterType::RevokeAllowance(Some(
+                            operation.input.from_account_id,
+                        )),
+                        request_id: self.id,
+                    },
+                ]
+            }
+            RequestOperation::CancelStandingOrder(operation) => {
+                vec![
+                    RequestOperationTypeIndex {
+                        operation_type: RequestOperationFilterType::CancelStandingOrder(None),
+                        request_id: self.id,
+                    },
+                    RequestOperationTypeIndex {
+                        operation_type: RequestOperationFilterType::CancelStandingOrder(Some(
+                            operation.input.from_account_id,
+                        )),
+                        request_id: self.id,
+                    },
+                ]
+            }
+            RequestOperation::AddApprovalDelegation(_) => vec![RequestOperationTypeIndex {
+                operation_type: RequestOperationFilterType::AddApprovalDelegation,
+                request_id: self.id,
+            }],
+            // bundles are also found when filtering by the types of their operations
+            RequestOperation::Bundle(operation) => {
+                let mut indexes = vec![RequestOperationTypeIndex {
+                    operation_type: RequestOperationFilterType::Bundle,
+                    request_id: self.id,
+                }];
+
+                for member in operation.to_member_requests(self) {
+                    for index in member.to_index_by_operation_types() {
+                        if !indexes.contains(&index) {
+                            indexes.push(index);
+                        }
+                    }
+                }
+
+                indexes
+            }
         }
     }
 }
@@ -137,8 +245,8 @@ mod tests {
     use crate::models::{
         indexes::request_operation_type_index::RequestOperationTypeIndex,
         request_operation_filter_type::RequestOperationFilterType,
-        request_test_utils::mock_request, Metadata, RequestOperation, TransferOperation,
-        TransferOperationInput,
+        request_test_utils::mock_request, BatchTransferOperation, BatchTransferOperationInput,
+        Metadata, RequestOperation, TransferOperation, TransferOperationInput,
     };
     use ic_stable_structures::Storable;
     use num_bigint::BigUint;
@@ -184,4 +292,33 @@ mod tests {
             .iter()
             .any(|i| i.operation_type == RequestOperationFilterType::Transfer(Some(account_id))));
     }
+
+    #[test]
+    fn valid_batch_transfer_indexes() {
+        let mut request = mock_request();
+        let transfer = |from_account_id| TransferOperationInput {
+            amount: candid::Nat(BigUint::from(100u32)),
+            fee: None,
+            metadata: Metadata::default(),
+            network: "mainnet".to_string(),
+            to: "0x1234".to_string(),
+            from_account_id,
+        };
+        request.operation = RequestOperation::BatchTransfer(BatchTransferOperation {
+            transfer_ids: vec![],
+            input: BatchTransferOperationInput {
+                transfers: vec![transfer([0; 16]), transfer([1; 16]), transfer([0; 16])],
+            },
+        });
+
+        let indexes = request.to_index_by_operation_types();
+
+        assert_eq!(indexes.len(), 3);
+        assert!(indexes
+            .iter()
+            .any(|i| i.operation_type == RequestOperationFilterType::BatchTransfer(None)));
+        assert!(indexes
+            .iter()
+            .any(|i| i.operation_type == RequestOperationFilterType::BatchTransfer(Some([1; 16]))));
+    }
 }
@@ -56,6 +56,7 @@ mod tests {
         request.approvals = vec![
             RequestApproval {
                 approver_id: [1; 16],
+                delegate_id: None,
                 status_reason: None,
                 decided_dt: 0,
                 last_modification_timestamp: 0,
@@ -63,6 +64,7 @@ mod tests {
             },
             RequestApproval {
                 approver_id: [2; 16],
+                delegate_id: None,
                 status_reason: None,
                 decided_dt: 0,
                 last_modification_timestamp: 0,
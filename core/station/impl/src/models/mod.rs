@@ -7,6 +7,11 @@ pub use account::*;
 
 pub mod address_book;
 pub use address_book::*;
+pub mod allowance;
+pub use allowance::*;
+
+pub mod approval_delegation;
+pub use approval_delegation::*;
 
 pub mod blockchain;
 pub use blockchain::*;
@@ -14,15 +19,27 @@ pub use blockchain::*;
 pub mod blockchain_standard;
 pub use blockchain_standard::*;
 
+pub mod blockchain_address;
+pub use blockchain_address::*;
+
 pub mod metadata;
 pub use metadata::*;
 
 pub mod user;
 pub use user::*;
 
+pub mod identity_link;
+pub use identity_link::*;
+
+pub mod recovery;
+pub use recovery::*;
+
 pub mod user_group;
 pub use user_group::*;
 
+pub mod role_template;
+pub use role_template::*;
+
 pub mod user_status;
 pub use user_status::*;
 
@@ -32,6 +49,9 @@ pub use account_balance::*;
 pub mod transfer;
 pub use transfer::*;
 
+pub mod incoming_transfer;
+pub use incoming_transfer::*;
+
 pub mod notification;
 pub use notification::*;
 
@@ -47,6 +67,12 @@ pub use request_approval::*;
 pub mod request_approval_status;
 pub use request_approval_status::*;
 
+pub mod request_comment;
+pub use request_comment::*;
+
+pub mod request_template;
+pub use request_template::*;
+
 pub mod request_policy;
 pub use request_policy::*;
 
@@ -70,6 +96,9 @@ pub use request_policy_rule::*;
 pub mod request;
 pub use request::*;
 
+pub mod standing_order;
+pub use standing_order::*;
+
 pub mod asset;
 pub use asset::*;
 
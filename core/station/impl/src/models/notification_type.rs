@@ -1,8 +1,10 @@
 use orbit_essentials::storable;
-use orbit_essentials::types::UUID;
+use orbit_essentials::types::{Timestamp, UUID};
 use station_api::{
+    REQUEST_CANCELLED_NOTIFICATION_TYPE, REQUEST_COMMENT_MENTION_NOTIFICATION_TYPE,
     REQUEST_CREATED_NOTIFICATION_TYPE, REQUEST_FAILED_NOTIFICATION_TYPE,
-    REQUEST_REJECTED_NOTIFICATION_TYPE, SYSTEM_MESSAGE_NOTIFICATION_TYPE,
+    REQUEST_REJECTED_NOTIFICATION_TYPE, REQUEST_TIME_LOCKED_NOTIFICATION_TYPE,
+    SYSTEM_MESSAGE_NOTIFICATION_TYPE,
 };
 use std::fmt::{Display, Formatter};
 
@@ -13,6 +15,9 @@ pub enum NotificationType {
     RequestCreated(RequestCreatedNotification),
     RequestFailed(RequestFailedNotification),
     RequestRejected(RequestRejectedNotification),
+    RequestTimeLocked(RequestTimeLockedNotification),
+    RequestCancelled(RequestCancelledNotification),
+    RequestCommentMention(RequestCommentMentionNotification),
 }
 
 #[storable]
@@ -24,6 +29,24 @@ pub struct RequestNotification {
 pub type RequestCreatedNotification = RequestNotification;
 pub type RequestFailedNotification = RequestNotification;
 pub type RequestRejectedNotification = RequestNotification;
+pub type RequestCancelledNotification = RequestNotification;
+
+/// Notifies the approvers that an approved request is waiting for its time lock to pass.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestTimeLockedNotification {
+    pub request_id: UUID,
+    /// The time after which the request is executed unless it is vetoed.
+    pub unlocks_at: Timestamp,
+}
+
+/// Notifies the users that were mentioned in a comment on a request.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestCommentMentionNotification {
+    pub request_id: UUID,
+    pub comment_id: UUID,
+}
 
 impl Display for NotificationType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -38,6 +61,15 @@ impl Display for NotificationType {
             NotificationType::RequestRejected(_) => {
                 write!(f, "{}", REQUEST_REJECTED_NOTIFICATION_TYPE)
             }
+            NotificationType::RequestTimeLocked(_) => {
+                write!(f, "{}", REQUEST_TIME_LOCKED_NOTIFICATION_TYPE)
+            }
+            NotificationType::RequestCancelled(_) => {
+                write!(f, "{}", REQUEST_CANCELLED_NOTIFICATION_TYPE)
+            }
+            NotificationType::RequestCommentMention(_) => {
+                write!(f, "{}", REQUEST_COMMENT_MENTION_NOTIFICATION_TYPE)
+            }
         }
     }
 }
@@ -75,5 +107,31 @@ mod tests {
             .to_string(),
             "request-rejected"
         );
+
+        assert_eq!(
+            NotificationType::RequestTimeLocked(RequestTimeLockedNotification {
+                request_id: [0; 16],
+                unlocks_at: 0,
+            })
+            .to_string(),
+            "request-time-locked"
+        );
+
+        assert_eq!(
+            NotificationType::RequestCancelled(RequestCancelledNotification {
+                request_id: [0; 16]
+            })
+            .to_string(),
+            "request-cancelled"
+        );
+
+        assert_eq!(
+            NotificationType::RequestCommentMention(RequestCommentMentionNotification {
+                request_id: [0; 16],
+                comment_id: [1; 16],
+            })
+            .to_string(),
+            "request-comment-mention"
+        );
     }
 }
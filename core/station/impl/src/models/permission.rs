@@ -153,6 +153,56 @@ impl Permission {
     }
 }
 
+/// The reason why a user has access to a resource.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccessReason {
+    /// The resource is public.
+    Public,
+    /// The resource is available to all active users.
+    Authenticated,
+    /// The user has access by a default rule that does not depend on permissions.
+    DefaultAccess,
+    /// The user is directly granted access to the resource.
+    UserGrant,
+    /// The user is a member of the groups that are granted access to the resource.
+    GroupGrant(Vec<UserGroupId>),
+}
+
+/// The reason why a user does not have access to a resource.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccessDenialReason {
+    NotAUser,
+    UserNotActive,
+    NoMatchingGrant,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccessCheckOutcome {
+    Allowed(AccessReason),
+    Denied(AccessDenialReason),
+}
+
+impl AccessCheckOutcome {
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, AccessCheckOutcome::Allowed(_))
+    }
+}
+
+/// The access check of one of the resources of the expanded list of the explained resource.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResourceAccessCheck {
+    pub resource: Resource,
+    pub outcome: AccessCheckOutcome,
+}
+
+/// Explains why a user has or does not have access to a resource.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccessExplanation {
+    pub resource: Resource,
+    pub allowed: bool,
+    pub checks: Vec<ResourceAccessCheck>,
+}
+
 #[cfg(any(test, feature = "canbench"))]
 pub mod permission_test_utils {
     use crate::models::resource::{
@@ -230,6 +280,10 @@ pub mod permission_test_utils {
                 Allow::authenticated(),
                 Resource::User(UserResourceAction::Update(ResourceId::Any)),
             ),
+            Permission::new(
+                Allow::authenticated(),
+                Resource::User(UserResourceAction::Remove(ResourceId::Any)),
+            ),
             Permission::new(
                 Allow::authenticated(),
                 Resource::UserGroup(ResourceAction::Create),
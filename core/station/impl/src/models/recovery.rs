@@ -0,0 +1,226 @@
+use crate::errors::RecoveryError;
+use candid::Principal;
+use orbit_essentials::model::{ModelValidator, ModelValidatorResult};
+use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
+use std::collections::HashSet;
+
+/// The guardians that can recover the station when its admins lost access to it.
+///
+/// Guardians are plain principals that don't need to be users of the station, a recovery action
+/// is only executed once `threshold` of them submitted it and the delay has passed.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RecoveryConfig {
+    pub guardians: Vec<Principal>,
+    pub threshold: u16,
+    /// The delay between reaching the threshold and executing the action, during which the admins
+    /// can still cancel the recovery.
+    pub delay_seconds: u64,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ChangeRecoveryConfig {
+    Set(RecoveryConfig),
+    Remove,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RecoveryAdmin {
+    pub name: String,
+    pub identity: Principal,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RecoveryAction {
+    /// Adds the identity as an active member of the admin group.
+    InstallAdminIdentity(RecoveryAdmin),
+    /// Restores the admin group with its default permissions and reactivates its inactive members.
+    ReenableAdminGroup,
+}
+
+/// A recovery that the guardians started, there can only be one at a time.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RecoveryAttempt {
+    pub action: RecoveryAction,
+    /// The guardians that submitted the action.
+    pub approvals: Vec<Principal>,
+    pub started_at: Timestamp,
+    /// The time after which the action can be executed, set once the threshold is reached.
+    pub executable_at: Option<Timestamp>,
+}
+
+impl RecoveryConfig {
+    pub const MAX_GUARDIANS: usize = 20;
+    /// The admins always get at least a day to notice and cancel a recovery.
+    pub const MIN_DELAY_SECONDS: u64 = 24 * 60 * 60;
+
+    pub fn is_guardian(&self, principal: &Principal) -> bool {
+        self.guardians.contains(principal)
+    }
+
+    pub fn delay_ns(&self) -> u64 {
+        self.delay_seconds.saturating_mul(1_000_000_000)
+    }
+}
+
+impl RecoveryAttempt {
+    /// Recoveries that don't reach the threshold within this time are discarded, so that a single
+    /// guardian can't block the other ones by starting a different action.
+    pub const EXPIRATION_NS: u64 = 7 * 24 * 60 * 60 * 1_000_000_000;
+
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        self.executable_at.is_none() && now >= self.started_at.saturating_add(Self::EXPIRATION_NS)
+    }
+
+    pub fn is_executable_at(&self, now: Timestamp) -> bool {
+        matches!(self.executable_at, Some(executable_at) if now >= executable_at)
+    }
+}
+
+fn validate_guardians(config: &RecoveryConfig) -> ModelValidatorResult<RecoveryError> {
+    if config.guardians.is_empty() || config.guardians.len() > RecoveryConfig::MAX_GUARDIANS {
+        return Err(RecoveryError::ValidationError {
+            info: format!(
+                "The number of guardians must be between 1 and {}",
+                RecoveryConfig::MAX_GUARDIANS
+            ),
+        });
+    }
+
+    if config.guardians.contains(&Principal::anonymous()) {
+        return Err(RecoveryError::ValidationError {
+            info: "The anonymous identity cannot be a guardian".to_string(),
+        });
+    }
+
+    if config.guardians.iter().collect::<HashSet<_>>().len() != config.guardians.len() {
+        return Err(RecoveryError::ValidationError {
+            info: "The guardians must be unique".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_threshold(config: &RecoveryConfig) -> ModelValidatorResult<RecoveryError> {
+    if config.threshold == 0 || config.threshold as usize > config.guardians.len() {
+        return Err(RecoveryError::ValidationError {
+            info: format!(
+                "The threshold must be between 1 and the number of guardians ({})",
+                config.guardians.len()
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_delay(config: &RecoveryConfig) -> ModelValidatorResult<RecoveryError> {
+    if config.delay_seconds < RecoveryConfig::MIN_DELAY_SECONDS {
+        return Err(RecoveryError::ValidationError {
+            info: format!(
+                "The recovery delay must be at least {} seconds",
+                RecoveryConfig::MIN_DELAY_SECONDS
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+impl ModelValidator<RecoveryError> for RecoveryConfig {
+    fn validate(&self) -> ModelValidatorResult<RecoveryError> {
+        validate_guardians(self)?;
+        validate_threshold(self)?;
+        validate_delay(self)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::recovery_test_utils::mock_recovery_config;
+    use super::*;
+
+    #[test]
+    fn fail_invalid_guardians() {
+        let mut config = mock_recovery_config();
+        config.guardians = vec![];
+
+        assert!(validate_guardians(&config).is_err());
+
+        config.guardians = vec![Principal::from_slice(&[1; 29]), Principal::anonymous()];
+
+        assert!(validate_guardians(&config).is_err());
+
+        config.guardians = vec![Principal::from_slice(&[1; 29]); 2];
+
+        assert!(validate_guardians(&config).is_err());
+    }
+
+    #[test]
+    fn fail_threshold_out_of_range() {
+        let mut config = mock_recovery_config();
+        config.threshold = 0;
+
+        assert!(validate_threshold(&config).is_err());
+
+        config.threshold = config.guardians.len() as u16 + 1;
+
+        assert!(validate_threshold(&config).is_err());
+
+        config.threshold = config.guardians.len() as u16;
+
+        assert!(validate_threshold(&config).is_ok());
+    }
+
+    #[test]
+    fn fail_delay_too_short() {
+        let mut config = mock_recovery_config();
+        config.delay_seconds = RecoveryConfig::MIN_DELAY_SECONDS - 1;
+
+        assert!(validate_delay(&config).is_err());
+    }
+
+    #[test]
+    fn attempt_expires_only_before_reaching_threshold() {
+        let mut attempt = RecoveryAttempt {
+            action: RecoveryAction::ReenableAdminGroup,
+            approvals: vec![],
+            started_at: 0,
+            executable_at: None,
+        };
+
+        assert!(attempt.is_expired_at(RecoveryAttempt::EXPIRATION_NS));
+        assert!(!attempt.is_executable_at(u64::MAX));
+
+        attempt.executable_at = Some(10);
+
+        assert!(!attempt.is_expired_at(RecoveryAttempt::EXPIRATION_NS));
+        assert!(!attempt.is_executable_at(9));
+        assert!(attempt.is_executable_at(10));
+    }
+}
+
+#[cfg(test)]
+pub mod recovery_test_utils {
+    use super::*;
+
+    pub fn mock_recovery_config() -> RecoveryConfig {
+        RecoveryConfig {
+            guardians: vec![
+                Principal::from_slice(&[1; 29]),
+                Principal::from_slice(&[2; 29]),
+                Principal::from_slice(&[3; 29]),
+            ],
+            threshold: 2,
+            delay_seconds: RecoveryConfig::MIN_DELAY_SECONDS,
+        }
+    }
+}
@@ -1,7 +1,10 @@
-use super::request_policy_rule::{RequestEvaluationResult, RequestPolicyRuleInput};
+use super::request_approval::validate_reason;
+use super::request_policy_rule::{
+    RequestEvaluationResult, RequestPolicyRule, RequestPolicyRuleInput,
+};
 use super::{
-    DisplayUser, EvaluationStatus, RequestApproval, RequestApprovalStatus, RequestOperation,
-    RequestStatus, UserId, UserKey,
+    BundleOperation, DisplayUser, EvaluationStatus, RecurringExecutionPlan, RequestApproval,
+    RequestApprovalChange, RequestApprovalStatus, RequestOperation, RequestStatus, UserId, UserKey,
 };
 use crate::core::evaluation::{
     Evaluate, REQUEST_APPROVE_RIGHTS_REQUEST_POLICY_RULE_EVALUATOR, REQUEST_MATCHER,
@@ -13,12 +16,12 @@ use crate::core::request::{
     RequestApprovalRightsEvaluator, RequestEvaluator, RequestPossibleApproversFinder,
 };
 use crate::core::validation::{
-    EnsureAccount, EnsureAddressBookEntry, EnsureIdExists, EnsureRequestPolicy, EnsureUser,
-    EnsureUserGroup,
+    EnsureAccount, EnsureAddressBookEntry, EnsureAllowance, EnsureAsset, EnsureIdExists,
+    EnsureRequest, EnsureRequestPolicy, EnsureUser, EnsureUserGroup,
 };
-use crate::errors::{EvaluateError, RequestError, ValidationError};
+use crate::errors::{EvaluateError, RequestError, StandingOrderError, ValidationError};
 use crate::models::resource::{ExecutionMethodResourceTarget, ValidationMethodResourceTarget};
-use crate::repositories::USER_REPOSITORY;
+use crate::repositories::{request_policy::REQUEST_POLICY_REPOSITORY, USER_REPOSITORY};
 use candid::{CandidType, Deserialize};
 use orbit_essentials::repository::Repository;
 use orbit_essentials::storable;
@@ -27,6 +30,7 @@ use orbit_essentials::{
     types::{Timestamp, UUID},
 };
 use std::collections::HashSet;
+use uuid::Uuid;
 
 /// The request id, which is a UUID.
 pub type RequestId = UUID;
@@ -35,7 +39,11 @@ pub type RequestId = UUID;
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum RequestExecutionPlan {
     Immediate,
-    Scheduled { execution_time: Timestamp },
+    Scheduled {
+        execution_time: Timestamp,
+    },
+    /// The request is executed repeatedly once approved, see `StandingOrder`.
+    Recurring(RecurringExecutionPlan),
 }
 
 /// Represents a request within the system.
@@ -60,6 +68,12 @@ pub struct Request {
     pub execution_plan: RequestExecutionPlan,
     /// The list of user approvals on the request.
     pub approvals: Vec<RequestApproval>,
+    /// Every decision of the approvers in the order they were made, including the ones that were
+    /// later changed or withdrawn.
+    ///
+    /// Requests created before the history was kept default to an empty history.
+    #[serde(default)]
+    pub approval_history: Vec<RequestApprovalChange>,
     /// The timestamp of the request creation.
     pub created_timestamp: Timestamp,
     /// The last time the record was updated or created.
@@ -124,6 +138,31 @@ fn validate_requested_by(requested_by: &UserId) -> ModelValidatorResult<RequestE
     Ok(())
 }
 
+fn validate_execution_plan(
+    execution_plan: &RequestExecutionPlan,
+    operation: &RequestOperation,
+) -> ModelValidatorResult<RequestError> {
+    if let RequestExecutionPlan::Recurring(plan) = execution_plan {
+        if !matches!(operation, RequestOperation::Transfer(_)) {
+            return Err(RequestError::ValidationError {
+                info: StandingOrderError::UnsupportedOperation {
+                    operation: operation.to_string(),
+                }
+                .to_string(),
+            });
+        }
+
+        plan.validate().map_err(|e| RequestError::ValidationError {
+            info: match e {
+                StandingOrderError::ValidationError { info } => info,
+                _ => e.to_string(),
+            },
+        })?;
+    }
+
+    Ok(())
+}
+
 fn validate_request_operation_foreign_keys(
     operation: &RequestOperation,
 ) -> ModelValidatorResult<ValidationError> {
@@ -132,7 +171,14 @@ fn validate_request_operation_foreign_keys(
         RequestOperation::Transfer(op) => {
             EnsureAccount::id_exists(&op.input.from_account_id)?;
         }
+        RequestOperation::BatchTransfer(op) => {
+            for account_id in op.input.from_account_ids() {
+                EnsureAccount::id_exists(&account_id)?;
+            }
+        }
         RequestOperation::AddAccount(op) => {
+            EnsureAsset::id_exists(&op.input.asset_id)?;
+
             op.input.read_permission.validate()?;
             op.input.configs_permission.validate()?;
             op.input.transfer_permission.validate()?;
@@ -187,6 +233,12 @@ fn validate_request_operation_foreign_keys(
                 EnsureUserGroup::id_list_exists(group_ids)?;
             }
         }
+        RequestOperation::RemoveUser(op) => {
+            EnsureUser::id_exists(&op.input.user_id)?;
+        }
+        RequestOperation::LinkUserIdentity(op) => {
+            EnsureUser::id_exists(&op.input.user_id)?;
+        }
         RequestOperation::EditPermission(op) => {
             op.input.resource.validate()?;
 
@@ -205,6 +257,9 @@ fn validate_request_operation_foreign_keys(
         RequestOperation::RemoveUserGroup(ok) => {
             EnsureUserGroup::id_exists(&ok.input.user_group_id)?;
         }
+        RequestOperation::ApplyRoleTemplate(op) => {
+            EnsureUserGroup::id_exists(&op.input.user_group_id)?;
+        }
         RequestOperation::ChangeCanister(_) => (),
         RequestOperation::ChangeExternalCanister(_) => (),
         RequestOperation::CreateExternalCanister(_) => (),
@@ -234,15 +289,81 @@ fn validate_request_operation_foreign_keys(
         RequestOperation::RemoveRequestPolicy(op) => {
             EnsureRequestPolicy::id_exists(&op.input.policy_id)?;
         }
+        RequestOperation::AddAsset(_) => (),
+        RequestOperation::EditAsset(op) => {
+            EnsureAsset::id_exists(&op.input.asset_id)?;
+        }
+        RequestOperation::RemoveAsset(op) => {
+            EnsureAsset::id_exists(&op.input.asset_id)?;
+        }
+        RequestOperation::ApproveAllowance(op) => {
+            EnsureAccount::id_exists(&op.input.from_account_id)?;
+        }
+        RequestOperation::RevokeAllowance(op) => {
+            EnsureAccount::id_exists(&op.input.from_account_id)?;
+            EnsureAllowance::id_exists(&op.input.allowance_id)?;
+        }
+        RequestOperation::CancelStandingOrder(op) => {
+            EnsureAccount::id_exists(&op.input.from_account_id)?;
+            EnsureRequest::id_exists(&op.input.request_id)?;
+        }
+        RequestOperation::AddApprovalDelegation(op) => {
+            EnsureUser::id_exists(&op.input.delegator_id)?;
+            EnsureUser::id_exists(&op.input.delegate_id)?;
+
+            if let Some(specifier) = &op.input.specifier {
+                specifier.validate()?;
+            }
+        }
+        RequestOperation::Bundle(op) => {
+            for member in &op.operations {
+                validate_request_operation_foreign_keys(member)?;
+            }
+        }
     }
     Ok(())
 }
 
+/// Bundles must be executed in a single step, so operations that complete asynchronously and
+/// nested bundles are not supported.
+fn validate_bundle(operation: &RequestOperation) -> ModelValidatorResult<RequestError> {
+    let RequestOperation::Bundle(bundle) = operation else {
+        return Ok(());
+    };
+
+    if bundle.operations.is_empty() || bundle.operations.len() > BundleOperation::MAX_OPERATIONS {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "The bundle must have between 1 and {} operations",
+                BundleOperation::MAX_OPERATIONS
+            ),
+        });
+    }
+
+    for member in &bundle.operations {
+        if matches!(
+            member,
+            RequestOperation::Bundle(_)
+                | RequestOperation::Transfer(_)
+                | RequestOperation::BatchTransfer(_)
+                | RequestOperation::ChangeCanister(_)
+        ) {
+            return Err(RequestError::ValidationError {
+                info: format!("The operation {} is not supported in bundles", member),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 impl ModelValidator<RequestError> for Request {
     fn validate(&self) -> ModelValidatorResult<RequestError> {
         validate_title(&self.title)?;
         validate_summary(&self.summary)?;
         validate_requested_by(&self.requested_by)?;
+        validate_execution_plan(&self.execution_plan, &self.operation)?;
+        validate_bundle(&self.operation)?;
 
         validate_request_operation_foreign_keys(&self.operation)?;
 
@@ -253,6 +374,9 @@ impl ModelValidator<RequestError> for Request {
 impl Request {
     pub const MAX_TITLE_LEN: u8 = 255;
     pub const MAX_SUMMARY_LEN: u16 = 1000;
+    /// The maximum number of times that an approver can change their decision, which bounds the
+    /// approval history of the request.
+    pub const MAX_APPROVAL_CHANGES: u8 = 10;
 
     /// Creates a new request key from the given key components.
     pub fn key(request_id: RequestId) -> RequestKey {
@@ -304,11 +428,56 @@ impl Request {
         }
     }
 
+    /// Finds the users whose approval rights the user can use on the request, the user first if they
+    /// have their own rights and then the users that delegated their rights to them.
+    pub fn find_approval_rights_owners(&self, user_id: &UUID) -> Vec<UUID> {
+        let validator = RequestApprovalRightsEvaluator {
+            request_id: self.id,
+            approver_id: *user_id,
+            request_matcher: REQUEST_MATCHER.to_owned(),
+            approval_rights_evaluator: REQUEST_APPROVE_RIGHTS_REQUEST_POLICY_RULE_EVALUATOR.clone(),
+        };
+
+        match validator.find_rights_owners() {
+            Ok(owners) => owners,
+            Err(_) => {
+                print(format!(
+                    "Failed to evaluate voting rights for request: {:?}",
+                    self
+                ));
+
+                Vec::new()
+            }
+        }
+    }
+
     pub fn add_approval(
         &mut self,
         user_id: UUID,
         decision: RequestApprovalStatus,
         reason: Option<String>,
+    ) -> ModelValidatorResult<RequestError> {
+        self.record_approval(user_id, None, decision, reason)
+    }
+
+    /// Adds the decision that the delegate cast on behalf of the approver, the decision is counted
+    /// for the approver.
+    pub fn add_delegated_approval(
+        &mut self,
+        approver_id: UUID,
+        delegate_id: UUID,
+        decision: RequestApprovalStatus,
+        reason: Option<String>,
+    ) -> ModelValidatorResult<RequestError> {
+        self.record_approval(approver_id, Some(delegate_id), decision, reason)
+    }
+
+    fn record_approval(
+        &mut self,
+        user_id: UUID,
+        delegate_id: Option<UUID>,
+        decision: RequestApprovalStatus,
+        reason: Option<String>,
     ) -> ModelValidatorResult<RequestError> {
         if self
             .approvals
@@ -322,6 +491,7 @@ impl Request {
         let now = next_time();
         let approval = RequestApproval {
             approver_id: user_id,
+            delegate_id,
             status: decision,
             status_reason: reason,
             decided_dt: now,
@@ -330,20 +500,140 @@ impl Request {
 
         approval.validate()?;
 
+        self.approval_history.push(RequestApprovalChange {
+            approver_id: user_id,
+            status: Some(approval.status.to_owned()),
+            status_reason: approval.status_reason.to_owned(),
+            decided_dt: now,
+        });
         self.approvals.push(approval);
 
         Ok(())
     }
 
+    /// Changes the decision that the user already added to the request, or withdraws it if no new
+    /// decision is given.
+    ///
+    /// The new decision is recorded as cast by the delegate if one is given, decisions can only be
+    /// changed while the request is still being evaluated.
+    pub fn change_approval(
+        &mut self,
+        user_id: UUID,
+        delegate_id: Option<UUID>,
+        decision: Option<RequestApprovalStatus>,
+        reason: Option<String>,
+    ) -> ModelValidatorResult<RequestError> {
+        if self.status != RequestStatus::Created {
+            return Err(RequestError::NotAllowedModification {
+                request_id: Uuid::from_bytes(self.id).hyphenated().to_string(),
+            });
+        }
+
+        // delegates can only change the decisions that they made on behalf of the approver
+        let Some(position) = self.approvals.iter().position(|approval| {
+            approval.approver_id == user_id
+                && (delegate_id.is_none() || approval.delegate_id == delegate_id)
+        }) else {
+            return Err(RequestError::ApprovalNotAllowed);
+        };
+
+        let changes = self
+            .approval_history
+            .iter()
+            .filter(|change| change.approver_id == user_id)
+            .count();
+
+        // the first entry of the approver is their initial decision
+        if changes > Self::MAX_APPROVAL_CHANGES as usize {
+            return Err(RequestError::ApprovalChangeLimitReached {
+                max_changes: Self::MAX_APPROVAL_CHANGES,
+            });
+        }
+
+        let now = next_time();
+        let change = RequestApprovalChange {
+            approver_id: user_id,
+            status: decision.to_owned(),
+            status_reason: reason.to_owned(),
+            decided_dt: now,
+        };
+
+        change.validate()?;
+
+        match decision {
+            Some(decision) => {
+                let approval = RequestApproval {
+                    approver_id: user_id,
+                    delegate_id,
+                    status: decision,
+                    status_reason: reason,
+                    decided_dt: now,
+                    last_modification_timestamp: now,
+                };
+
+                self.approvals[position] = approval;
+            }
+            None => {
+                self.approvals.remove(position);
+            }
+        }
+
+        self.approval_history.push(change);
+        self.last_modification_timestamp = now;
+
+        Ok(())
+    }
+
+    /// Vetoes the request, which replaces the previous decision of the user if any.
+    pub fn add_veto(
+        &mut self,
+        user_id: UUID,
+        reason: Option<String>,
+    ) -> ModelValidatorResult<RequestError> {
+        self.approvals
+            .retain(|approval| approval.approver_id != user_id);
+        self.add_approval(user_id, RequestApprovalStatus::Rejected, reason)?;
+
+        let now = next_time();
+        self.status = RequestStatus::Rejected;
+        self.last_modification_timestamp = now;
+
+        Ok(())
+    }
+
+    /// Cancels the request before it is executed, only pending and scheduled requests can be cancelled.
+    pub fn cancel(&mut self, reason: Option<String>) -> ModelValidatorResult<RequestError> {
+        if !matches!(
+            self.status,
+            RequestStatus::Created | RequestStatus::Scheduled { .. }
+        ) {
+            return Err(RequestError::NotAllowedModification {
+                request_id: Uuid::from_bytes(self.id).hyphenated().to_string(),
+            });
+        }
+
+        validate_reason(&reason)?;
+
+        self.status = RequestStatus::Cancelled { reason };
+        self.last_modification_timestamp = next_time();
+
+        Ok(())
+    }
+
+    /// Evaluates the policies that apply to the request without changing its status.
+    pub fn evaluate(&self) -> Result<RequestEvaluationResult, EvaluateError> {
+        let evaluator = RequestEvaluator {
+            request: self.to_owned(),
+            request_matcher: REQUEST_MATCHER.to_owned(),
+            policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+        };
+
+        evaluator.evaluate()
+    }
+
     pub async fn reevaluate(&mut self) -> Result<Option<RequestEvaluationResult>, EvaluateError> {
         if self.status == RequestStatus::Created {
-            let evaluator = RequestEvaluator {
-                request: self.to_owned(),
-                request_matcher: REQUEST_MATCHER.to_owned(),
-                policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
-            };
-
-            let evaluation_result = evaluator.evaluate()?;
+            let evaluation_result = self.evaluate()?;
 
             if evaluation_result.status == EvaluationStatus::Approved {
                 self.status = RequestStatus::Approved;
@@ -357,6 +647,46 @@ impl Request {
         }
     }
 
+    /// Returns the longest time lock of the policies that apply to the request, if any.
+    pub fn time_lock_ns(&self) -> Option<u64> {
+        self.operation
+            .to_resources()
+            .iter()
+            .flat_map(|resource| REQUEST_POLICY_REPOSITORY.find_by_resource(resource.to_owned()))
+            .filter_map(|policy| policy.rule.time_lock_ns())
+            .max()
+    }
+
+    /// Returns the rules of the stored policies that apply to the request.
+    pub fn find_matching_policy_rules(&self) -> Vec<RequestPolicyRule> {
+        self.operation
+            .to_resources()
+            .iter()
+            .flat_map(|resource| REQUEST_POLICY_REPOSITORY.find_by_resource(resource.to_owned()))
+            .map(|policy| policy.rule)
+            .collect()
+    }
+
+    /// Checks if the requester has approval rights of their own under the given rules, in which
+    /// case they approve the request right away when it is submitted.
+    ///
+    /// Delegated rights are not used to approve on behalf of others when creating the request.
+    pub fn is_approvable_by_requester(
+        &self,
+        rules: &[RequestPolicyRule],
+    ) -> Result<bool, EvaluateError> {
+        let approvers = RequestPossibleApproversFinder::new(
+            REQUEST_MATCHER.to_owned(),
+            REQUEST_POSSIBLE_APPROVERS_REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            self,
+        )
+        .find_direct_approvers_for_rules(rules)?;
+
+        Ok(approvers.contains(&self.requested_by))
+    }
+
+    /// Returns the possible approvers of the request, including the delegates that can approve on
+    /// their behalf.
     pub async fn find_all_possible_approvers(&self) -> Result<HashSet<UUID>, EvaluateError> {
         let evaluator = RequestPossibleApproversFinder {
             request: self,
@@ -372,10 +702,12 @@ impl Request {
 #[cfg(test)]
 mod tests {
     use crate::core::validation::disable_mock_resource_validation;
+    use crate::models::asset_test_utils::add_asset;
     use crate::models::permission::Allow;
     use crate::models::{
-        AddAccountOperationInput, AddUserOperation, AddUserOperationInput, Metadata,
-        TransferOperation, TransferOperationInput,
+        AddAccountOperationInput, AddUserGroupOperation, AddUserGroupOperationInput,
+        AddUserOperation, AddUserOperationInput, Metadata, RecurrenceInterval, TransferOperation,
+        TransferOperationInput,
     };
     use crate::services::AccountService;
 
@@ -392,6 +724,122 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn approval_changes_are_kept_in_the_history() {
+        let mut request = mock_request();
+        request.status = RequestStatus::Created;
+        request.approvals = vec![];
+
+        request
+            .add_approval([1; 16], RequestApprovalStatus::Approved, None)
+            .unwrap();
+        request
+            .change_approval(
+                [1; 16],
+                None,
+                Some(RequestApprovalStatus::Rejected),
+                Some("wrong amount".to_string()),
+            )
+            .unwrap();
+
+        assert_eq!(request.approvals.len(), 1);
+        assert_eq!(request.approvals[0].status, RequestApprovalStatus::Rejected);
+
+        request.change_approval([1; 16], None, None, None).unwrap();
+
+        assert!(request.approvals.is_empty());
+        assert_eq!(
+            request
+                .approval_history
+                .iter()
+                .map(|change| change.status.to_owned())
+                .collect::<Vec<_>>(),
+            vec![
+                Some(RequestApprovalStatus::Approved),
+                Some(RequestApprovalStatus::Rejected),
+                None
+            ]
+        );
+
+        // there is no decision left to change
+        assert_eq!(
+            request.change_approval([1; 16], None, Some(RequestApprovalStatus::Approved), None),
+            Err(RequestError::ApprovalNotAllowed)
+        );
+    }
+
+    #[test]
+    fn delegated_approval_change_keeps_the_delegate() {
+        let mut request = mock_request();
+        request.status = RequestStatus::Created;
+        request.approvals = vec![];
+
+        request
+            .add_delegated_approval([1; 16], [2; 16], RequestApprovalStatus::Approved, None)
+            .unwrap();
+        request
+            .change_approval(
+                [1; 16],
+                Some([2; 16]),
+                Some(RequestApprovalStatus::Rejected),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(request.approvals.len(), 1);
+        assert_eq!(request.approvals[0].approver_id, [1; 16]);
+        assert_eq!(request.approvals[0].delegate_id, Some([2; 16]));
+        assert_eq!(request.approvals[0].status, RequestApprovalStatus::Rejected);
+    }
+
+    #[test]
+    fn fail_change_approval_over_the_limit() {
+        let mut request = mock_request();
+        request.status = RequestStatus::Created;
+        request.approvals = vec![];
+
+        request
+            .add_approval([1; 16], RequestApprovalStatus::Approved, None)
+            .unwrap();
+
+        for i in 0..Request::MAX_APPROVAL_CHANGES {
+            let decision = match i % 2 {
+                0 => RequestApprovalStatus::Rejected,
+                _ => RequestApprovalStatus::Approved,
+            };
+
+            request
+                .change_approval([1; 16], None, Some(decision), None)
+                .unwrap();
+        }
+
+        assert_eq!(
+            request.change_approval([1; 16], None, None, None),
+            Err(RequestError::ApprovalChangeLimitReached {
+                max_changes: Request::MAX_APPROVAL_CHANGES
+            })
+        );
+        assert_eq!(
+            request.approval_history.len(),
+            Request::MAX_APPROVAL_CHANGES as usize + 1
+        );
+    }
+
+    #[test]
+    fn fail_change_approval_of_evaluated_request() {
+        let mut request = mock_request();
+        request.status = RequestStatus::Approved;
+
+        let result = request.change_approval([1; 16], None, None, None);
+
+        assert!(matches!(
+            result,
+            Err(RequestError::NotAllowedModification { .. })
+        ));
+        assert_eq!(request.approvals.len(), 1);
+        assert!(request.approval_history.is_empty());
+    }
+
     #[test]
     fn test_request_title_is_valid() {
         let mut request = mock_request();
@@ -422,6 +870,54 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn fail_bundle_with_unsupported_operations() {
+        let add_user_group = RequestOperation::AddUserGroup(AddUserGroupOperation {
+            user_group_id: None,
+            input: AddUserGroupOperationInput {
+                name: "test".to_string(),
+            },
+        });
+
+        assert!(validate_bundle(&RequestOperation::Bundle(BundleOperation {
+            operations: vec![]
+        }))
+        .is_err());
+        assert!(validate_bundle(&RequestOperation::Bundle(BundleOperation {
+            operations: vec![RequestOperation::Bundle(BundleOperation {
+                operations: vec![add_user_group.clone()]
+            })]
+        }))
+        .is_err());
+        assert!(validate_bundle(&RequestOperation::Bundle(BundleOperation {
+            operations: vec![add_user_group]
+        }))
+        .is_ok());
+    }
+
+    #[test]
+    fn fail_recurring_execution_plan_of_unsupported_operation() {
+        let request = mock_request();
+        let plan = RequestExecutionPlan::Recurring(RecurringExecutionPlan {
+            interval: RecurrenceInterval::Days(1),
+            start_at: 0,
+            end_at: None,
+            max_occurrences: None,
+        });
+
+        assert!(validate_execution_plan(&plan, &request.operation).is_ok());
+        assert!(validate_execution_plan(
+            &plan,
+            &RequestOperation::AddUserGroup(AddUserGroupOperation {
+                user_group_id: None,
+                input: AddUserGroupOperationInput {
+                    name: "test".to_string(),
+                },
+            })
+        )
+        .is_err());
+    }
+
     #[tokio::test]
     async fn test_request_operation_is_valid() {
         disable_mock_resource_validation();
@@ -430,8 +926,7 @@ mod tests {
         let account = account_service
             .create_account(AddAccountOperationInput {
                 name: "a".to_owned(),
-                blockchain: crate::models::Blockchain::InternetComputer,
-                standard: crate::models::BlockchainStandard::Native,
+                asset_id: add_asset("ICP").id,
                 metadata: Metadata::default(),
                 read_permission: Allow::default(),
                 configs_permission: Allow::default(),
@@ -508,6 +1003,7 @@ mod tests {
         validate_request_operation_foreign_keys(&RequestOperation::AddRequestPolicy(
             crate::models::AddRequestPolicyOperation {
                 policy_id: None,
+                warnings: vec![],
                 input: crate::models::AddRequestPolicyOperationInput {
                     specifier: crate::models::request_specifier::RequestSpecifier::EditUser(
                         crate::models::resource::ResourceIds::Ids(vec![[1; 16]]),
@@ -525,6 +1021,7 @@ mod tests {
                     specifier: None,
                     rule: None,
                 },
+                warnings: vec![],
             },
         ))
         .expect_err("Invalid request policy id should fail");
@@ -541,8 +1038,7 @@ mod tests {
                 account_id: None,
                 input: crate::models::AddAccountOperationInput {
                     name: "a".to_owned(),
-                    blockchain: crate::models::Blockchain::InternetComputer,
-                    standard: crate::models::BlockchainStandard::Native,
+                    asset_id: add_asset("ICP").id,
                     metadata: Metadata::default(),
                     read_permission: Allow {
                         auth_scope: crate::models::permission::AuthScope::Restricted,
@@ -593,6 +1089,25 @@ mod tests {
         ))
         .expect_err("Invalid address book entry id should fail");
 
+        validate_request_operation_foreign_keys(&RequestOperation::EditAsset(
+            crate::models::EditAssetOperation {
+                input: crate::models::EditAssetOperationInput {
+                    asset_id: [0; 16],
+                    name: None,
+                    symbol: None,
+                    change_metadata: None,
+                },
+            },
+        ))
+        .expect_err("Invalid asset id should fail");
+
+        validate_request_operation_foreign_keys(&RequestOperation::RemoveAsset(
+            crate::models::RemoveAssetOperation {
+                input: crate::models::RemoveAssetOperationInput { asset_id: [0; 16] },
+            },
+        ))
+        .expect_err("Invalid asset id should fail");
+
         validate_request_operation_foreign_keys(&RequestOperation::EditUser(
             crate::models::EditUserOperation {
                 input: crate::models::EditUserOperationInput {
@@ -653,8 +1168,10 @@ pub mod request_test_utils {
                     from_account_id: [1; 16],
                 },
             }),
+            approval_history: vec![],
             approvals: vec![RequestApproval {
                 approver_id: [1; 16],
+                delegate_id: None,
                 status: RequestApprovalStatus::Approved,
                 status_reason: None,
                 decided_dt: 0,
@@ -9,8 +9,10 @@ use orbit_essentials::{
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct RequestApproval {
-    /// The user that has recorded the approval decision.
+    /// The user whose approval rights the decision is counted for.
     pub approver_id: UserId,
+    /// The user that cast the decision on behalf of the approver, if it was delegated.
+    pub delegate_id: Option<UserId>,
     /// The status is provided by the associated user.
     pub status: RequestApprovalStatus,
     /// Optional reason for the decision.
@@ -25,7 +27,22 @@ impl RequestApproval {
     pub const MAX_REASON_LEN: u8 = 200;
 }
 
-fn validate_reason(reason: &Option<String>) -> ModelValidatorResult<RequestError> {
+/// A decision made by an approver, the request keeps every decision to have an audit trail of the
+/// approvals that were later changed or withdrawn.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestApprovalChange {
+    /// The user that has made the decision.
+    pub approver_id: UserId,
+    /// The new decision of the user, `None` if the user withdrew their decision.
+    pub status: Option<RequestApprovalStatus>,
+    /// Optional reason for the decision.
+    pub status_reason: Option<String>,
+    /// The time at which the decision was made.
+    pub decided_dt: Timestamp,
+}
+
+pub(crate) fn validate_reason(reason: &Option<String>) -> ModelValidatorResult<RequestError> {
     if let Some(reason) = reason {
         if reason.len() > RequestApproval::MAX_REASON_LEN as usize {
             return Err(RequestError::ApprovalReasonTooLong {
@@ -45,6 +62,14 @@ impl ModelValidator<RequestError> for RequestApproval {
     }
 }
 
+impl ModelValidator<RequestError> for RequestApprovalChange {
+    fn validate(&self) -> ModelValidatorResult<RequestError> {
+        validate_reason(&self.status_reason)?;
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,6 +108,7 @@ pub mod request_approval_test_utils {
     pub fn mock_decision() -> RequestApproval {
         RequestApproval {
             approver_id: [0; 16],
+            delegate_id: None,
             status: RequestApprovalStatus::Rejected,
             status_reason: None,
             decided_dt: 0,
@@ -93,6 +119,7 @@ pub mod request_approval_test_utils {
     pub fn mock_approved_with_user(approver_id: UUID) -> RequestApproval {
         RequestApproval {
             approver_id,
+            delegate_id: None,
             status: RequestApprovalStatus::Approved,
             status_reason: None,
             decided_dt: 0,
@@ -103,6 +130,7 @@ pub mod request_approval_test_utils {
     pub fn mock_rejected_with_user(approver_id: UUID) -> RequestApproval {
         RequestApproval {
             approver_id,
+            delegate_id: None,
             status: RequestApprovalStatus::Rejected,
             status_reason: None,
             decided_dt: 0,
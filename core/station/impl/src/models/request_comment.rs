@@ -0,0 +1,126 @@
+use super::{RequestId, UserId};
+use crate::core::validation::{EnsureIdExists, EnsureRequest, EnsureUser};
+use crate::errors::RequestCommentError;
+use orbit_essentials::storable;
+use orbit_essentials::{
+    model::{ModelValidator, ModelValidatorResult},
+    types::{Timestamp, UUID},
+};
+
+/// The request comment id, which is a UUID.
+pub type RequestCommentId = UUID;
+
+/// Represents a comment that a user has added to the discussion of a request.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestComment {
+    /// The comment id, which is a UUID.
+    pub id: RequestCommentId,
+    /// The request that the comment belongs to.
+    pub request_id: RequestId,
+    /// The user that wrote the comment.
+    pub author_id: UserId,
+    pub content: String,
+    /// The users that are mentioned in the comment, they are notified about it.
+    pub mentions: Vec<UserId>,
+    pub created_timestamp: Timestamp,
+    /// The last time the record was updated or created.
+    pub last_modification_timestamp: Timestamp,
+}
+
+impl RequestComment {
+    pub const MAX_CONTENT_LEN: usize = 2000;
+    pub const MAX_MENTIONS: usize = 20;
+}
+
+fn validate_content(content: &str) -> ModelValidatorResult<RequestCommentError> {
+    if content.trim().is_empty() {
+        return Err(RequestCommentError::ValidationError {
+            info: "The comment cannot be empty".to_string(),
+        });
+    }
+
+    if content.len() > RequestComment::MAX_CONTENT_LEN {
+        return Err(RequestCommentError::ValidationError {
+            info: format!(
+                "The comment length exceeds the maximum allowed: {}",
+                RequestComment::MAX_CONTENT_LEN
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_mentions(mentions: &[UserId]) -> ModelValidatorResult<RequestCommentError> {
+    if mentions.len() > RequestComment::MAX_MENTIONS {
+        return Err(RequestCommentError::ValidationError {
+            info: format!(
+                "The comment mentions more users than the maximum allowed: {}",
+                RequestComment::MAX_MENTIONS
+            ),
+        });
+    }
+
+    for user_id in mentions {
+        EnsureUser::id_exists(user_id)?;
+    }
+
+    Ok(())
+}
+
+impl ModelValidator<RequestCommentError> for RequestComment {
+    fn validate(&self) -> ModelValidatorResult<RequestCommentError> {
+        validate_content(&self.content)?;
+        validate_mentions(&self.mentions)?;
+
+        EnsureRequest::id_exists(&self.request_id)?;
+        EnsureUser::id_exists(&self.author_id)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fail_empty_comment() {
+        assert!(validate_content("  ").is_err());
+        assert!(validate_content("looks good").is_ok());
+    }
+
+    #[test]
+    fn fail_comment_too_long() {
+        let content = "a".repeat(RequestComment::MAX_CONTENT_LEN + 1);
+
+        assert!(validate_content(&content).is_err());
+        assert!(validate_content(&content[1..]).is_ok());
+    }
+
+    #[test]
+    fn fail_too_many_mentions() {
+        let mentions = vec![[0; 16]; RequestComment::MAX_MENTIONS + 1];
+
+        assert!(validate_mentions(&mentions).is_err());
+    }
+}
+
+#[cfg(test)]
+pub mod request_comment_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_request_comment() -> RequestComment {
+        RequestComment {
+            id: *Uuid::new_v4().as_bytes(),
+            request_id: *Uuid::new_v4().as_bytes(),
+            author_id: *Uuid::new_v4().as_bytes(),
+            content: "Please double check the destination address.".to_string(),
+            mentions: vec![],
+            created_timestamp: 0,
+            last_modification_timestamp: 0,
+        }
+    }
+}
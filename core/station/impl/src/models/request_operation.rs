@@ -1,10 +1,12 @@
 use super::{
+    legacy_asset_id,
     permission::{Allow, AuthScope},
     request_policy_rule::{RequestPolicyRule, RequestPolicyRuleInput},
     request_specifier::RequestSpecifier,
     resource::Resource,
-    AccountId, AddressBookEntryId, Blockchain, BlockchainStandard, ChangeMetadata, MetadataItem,
-    UserGroupId, UserId, UserStatus,
+    AccountId, AddressBookEntryId, AllowanceId, AllowanceSpender, ApprovalDelegationId, AssetId,
+    Blockchain, BlockchainStandard, ChangeMetadata, ChangeRecoveryConfig, MetadataItem, Request,
+    RequestId, RoleTemplate, TransferId, UserGroupId, UserId, UserStatus,
 };
 use crate::core::validation::EnsureExternalCanister;
 use crate::errors::ValidationError;
@@ -13,12 +15,14 @@ use candid::Principal;
 use orbit_essentials::cdk::api::management_canister::main::{self as mgmt};
 use orbit_essentials::model::{ModelValidator, ModelValidatorResult};
 use orbit_essentials::{storable, types::UUID};
+use std::collections::BTreeSet;
 use std::fmt::Display;
 
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum RequestOperation {
     Transfer(TransferOperation),
+    BatchTransfer(BatchTransferOperation),
     AddAccount(AddAccountOperation),
     EditAccount(EditAccountOperation),
     AddAddressBookEntry(AddAddressBookEntryOperation),
@@ -38,12 +42,24 @@ pub enum RequestOperation {
     EditRequestPolicy(EditRequestPolicyOperation),
     RemoveRequestPolicy(RemoveRequestPolicyOperation),
     ManageSystemInfo(ManageSystemInfoOperation),
+    AddAsset(AddAssetOperation),
+    EditAsset(EditAssetOperation),
+    RemoveAsset(RemoveAssetOperation),
+    ApproveAllowance(ApproveAllowanceOperation),
+    RevokeAllowance(RevokeAllowanceOperation),
+    CancelStandingOrder(CancelStandingOrderOperation),
+    AddApprovalDelegation(AddApprovalDelegationOperation),
+    Bundle(BundleOperation),
+    RemoveUser(RemoveUserOperation),
+    LinkUserIdentity(LinkUserIdentityOperation),
+    ApplyRoleTemplate(ApplyRoleTemplateOperation),
 }
 
 impl Display for RequestOperation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             RequestOperation::Transfer(_) => write!(f, "transfer"),
+            RequestOperation::BatchTransfer(_) => write!(f, "batch_transfer"),
             RequestOperation::AddAccount(_) => write!(f, "add_account"),
             RequestOperation::EditAccount(_) => write!(f, "edit_account"),
             RequestOperation::AddAddressBookEntry(_) => write!(f, "add_address_book_entry"),
@@ -63,6 +79,17 @@ impl Display for RequestOperation {
             RequestOperation::EditRequestPolicy(_) => write!(f, "edit_request_policy"),
             RequestOperation::RemoveRequestPolicy(_) => write!(f, "remove_request_policy"),
             RequestOperation::ManageSystemInfo(_) => write!(f, "manage_system_info"),
+            RequestOperation::AddAsset(_) => write!(f, "add_asset"),
+            RequestOperation::EditAsset(_) => write!(f, "edit_asset"),
+            RequestOperation::RemoveAsset(_) => write!(f, "remove_asset"),
+            RequestOperation::ApproveAllowance(_) => write!(f, "approve_allowance"),
+            RequestOperation::RevokeAllowance(_) => write!(f, "revoke_allowance"),
+            RequestOperation::CancelStandingOrder(_) => write!(f, "cancel_standing_order"),
+            RequestOperation::AddApprovalDelegation(_) => write!(f, "add_approval_delegation"),
+            RequestOperation::Bundle(_) => write!(f, "bundle"),
+            RequestOperation::RemoveUser(_) => write!(f, "remove_user"),
+            RequestOperation::LinkUserIdentity(_) => write!(f, "link_user_identity"),
+            RequestOperation::ApplyRoleTemplate(_) => write!(f, "apply_role_template"),
         }
     }
 }
@@ -85,6 +112,126 @@ pub struct TransferOperationInput {
     pub fee: Option<candid::Nat>,
 }
 
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BatchTransferOperation {
+    /// The transfer ids are only available after the operation is executed and follow the order
+    /// of the input rows.
+    pub transfer_ids: Vec<TransferId>,
+    pub input: BatchTransferOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BatchTransferOperationInput {
+    pub transfers: Vec<TransferOperationInput>,
+}
+
+impl BatchTransferOperationInput {
+    pub const MAX_TRANSFERS: usize = 100;
+
+    /// Returns the distinct source accounts of the batch.
+    pub fn from_account_ids(&self) -> Vec<AccountId> {
+        self.transfers
+            .iter()
+            .map(|transfer| transfer.from_account_id)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ApproveAllowanceOperation {
+    /// The allowance id is only available after the operation is executed.
+    pub allowance_id: Option<AllowanceId>,
+    pub input: ApproveAllowanceOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ApproveAllowanceOperationInput {
+    pub from_account_id: AccountId,
+    pub spender: AllowanceSpender,
+    pub amount: candid::Nat,
+    /// The ledger rejects the approval if the current allowance does not match this amount.
+    pub expected_allowance: Option<candid::Nat>,
+    pub expires_at: Option<u64>,
+    pub fee: Option<candid::Nat>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RevokeAllowanceOperation {
+    pub input: RevokeAllowanceOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RevokeAllowanceOperationInput {
+    pub from_account_id: AccountId,
+    pub allowance_id: AllowanceId,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CancelStandingOrderOperation {
+    pub input: CancelStandingOrderOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CancelStandingOrderOperationInput {
+    /// The request that created the standing order.
+    pub request_id: RequestId,
+    /// The account that the standing order transfers from, resolved when the request is created.
+    pub from_account_id: AccountId,
+}
+
+/// Multiple operations that are approved together and executed in order.
+///
+/// The operations are replaced by their executed versions once the bundle completes.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BundleOperation {
+    pub operations: Vec<RequestOperation>,
+}
+
+impl BundleOperation {
+    pub const MAX_OPERATIONS: usize = 20;
+
+    /// Returns a request for each operation of the bundle, which shares everything but the
+    /// operation with the bundle request so that it can be matched, evaluated and executed on its own.
+    pub fn to_member_requests(&self, request: &Request) -> Vec<Request> {
+        self.operations
+            .iter()
+            .map(|operation| {
+                let mut member = request.to_owned();
+                member.operation = operation.to_owned();
+                member
+            })
+            .collect()
+    }
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddApprovalDelegationOperation {
+    /// The delegation id is only available after the operation is executed.
+    pub delegation_id: Option<ApprovalDelegationId>,
+    pub input: AddApprovalDelegationOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddApprovalDelegationOperationInput {
+    pub delegator_id: UserId,
+    pub delegate_id: UserId,
+    pub specifier: Option<RequestSpecifier>,
+    pub expires_at: u64,
+}
+
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct AddAccountOperation {
@@ -97,8 +244,9 @@ pub struct AddAccountOperation {
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct AddAccountOperationInput {
     pub name: String,
-    pub blockchain: Blockchain,
-    pub standard: BlockchainStandard,
+    /// Requests created before assets were managed at runtime default to the ICP asset.
+    #[serde(default = "legacy_asset_id")]
+    pub asset_id: AssetId,
     pub metadata: Metadata,
     pub read_permission: Allow,
     pub configs_permission: Allow,
@@ -169,6 +317,51 @@ pub struct RemoveAddressBookEntryOperationInput {
     pub address_book_entry_id: AddressBookEntryId,
 }
 
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddAssetOperation {
+    /// The asset id is only available after the operation is executed.
+    pub asset_id: Option<AssetId>,
+    pub input: AddAssetOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddAssetOperationInput {
+    pub blockchain: Blockchain,
+    pub standard: BlockchainStandard,
+    pub symbol: String,
+    pub name: String,
+    pub metadata: Metadata,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EditAssetOperation {
+    pub input: EditAssetOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EditAssetOperationInput {
+    pub asset_id: AssetId,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub change_metadata: Option<ChangeMetadata>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RemoveAssetOperation {
+    pub input: RemoveAssetOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RemoveAssetOperationInput {
+    pub asset_id: AssetId,
+}
+
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct AddUserOperation {
@@ -201,6 +394,34 @@ pub struct EditUserOperationInput {
     pub status: Option<UserStatus>,
 }
 
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RemoveUserOperation {
+    pub input: RemoveUserOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RemoveUserOperationInput {
+    pub user_id: UUID,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LinkUserIdentityOperation {
+    pub input: LinkUserIdentityOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LinkUserIdentityOperationInput {
+    pub user_id: UUID,
+    /// The identity that confirmed the link, it is added to the user.
+    pub identity: Principal,
+    /// The identity that is removed from the user once the new one is added, if any.
+    pub retired_identity: Option<Principal>,
+}
+
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct AddUserGroupOperation {
@@ -239,6 +460,19 @@ pub struct RemoveUserGroupOperationInput {
     pub user_group_id: UUID,
 }
 
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ApplyRoleTemplateOperation {
+    pub input: ApplyRoleTemplateOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ApplyRoleTemplateOperationInput {
+    pub user_group_id: UUID,
+    pub template: RoleTemplate,
+}
+
 #[storable]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ChangeCanisterTarget {
@@ -379,6 +613,10 @@ pub struct AddRequestPolicyOperationInput {
 pub struct AddRequestPolicyOperation {
     pub policy_id: Option<UUID>,
     pub input: AddRequestPolicyOperationInput,
+    /// The problems found in the policy when the request was created, which don't prevent it from
+    /// being saved.
+    #[serde(default)]
+    pub warnings: Vec<String>,
 }
 
 #[storable]
@@ -393,6 +631,10 @@ pub struct EditRequestPolicyOperationInput {
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct EditRequestPolicyOperation {
     pub input: EditRequestPolicyOperationInput,
+    /// The problems found in the policy when the request was created, which don't prevent it from
+    /// being saved.
+    #[serde(default)]
+    pub warnings: Vec<String>,
 }
 
 #[storable]
@@ -411,6 +653,7 @@ pub struct RemoveRequestPolicyOperation {
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ManageSystemInfoOperationInput {
     pub name: Option<String>,
+    pub recovery_config: Option<ChangeRecoveryConfig>,
 }
 
 #[storable]
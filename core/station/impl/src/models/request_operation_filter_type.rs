@@ -6,6 +6,7 @@ use orbit_essentials::storable;
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum RequestOperationFilterType {
     Transfer(Option<AccountId>),
+    BatchTransfer(Option<AccountId>),
     AddAccount,
     EditAccount,
     AddUser,
@@ -25,4 +26,15 @@ pub enum RequestOperationFilterType {
     EditAddressBookEntry,
     RemoveAddressBookEntry,
     ManageSystemInfo,
+    AddAsset,
+    EditAsset,
+    RemoveAsset,
+    ApproveAllowance(Option<AccountId>),
+    RevokeAllowance(Option<AccountId>),
+    CancelStandingOrder(Option<AccountId>),
+    AddApprovalDelegation,
+    Bundle,
+    RemoveUser,
+    LinkUserIdentity,
+    ApplyRoleTemplate,
 }
@@ -27,6 +27,18 @@ pub enum RequestOperationType {
     ChangeExternalCanister = 20,
     CreateExternalCanister = 21,
     CallExternalCanister = 22,
+    AddAsset = 23,
+    EditAsset = 24,
+    RemoveAsset = 25,
+    ApproveAllowance = 26,
+    RevokeAllowance = 27,
+    BatchTransfer = 28,
+    CancelStandingOrder = 29,
+    AddApprovalDelegation = 30,
+    Bundle = 31,
+    RemoveUser = 32,
+    LinkUserIdentity = 33,
+    ApplyRoleTemplate = 34,
 }
 
 impl FromStr for RequestOperationType {
@@ -54,6 +66,18 @@ impl FromStr for RequestOperationType {
             "edit_request_policy" => Ok(RequestOperationType::EditRequestPolicy),
             "remove_request_policy" => Ok(RequestOperationType::RemoveRequestPolicy),
             "manage_system_info" => Ok(RequestOperationType::ManageSystemInfo),
+            "add_asset" => Ok(RequestOperationType::AddAsset),
+            "edit_asset" => Ok(RequestOperationType::EditAsset),
+            "remove_asset" => Ok(RequestOperationType::RemoveAsset),
+            "approve_allowance" => Ok(RequestOperationType::ApproveAllowance),
+            "revoke_allowance" => Ok(RequestOperationType::RevokeAllowance),
+            "batch_transfer" => Ok(RequestOperationType::BatchTransfer),
+            "cancel_standing_order" => Ok(RequestOperationType::CancelStandingOrder),
+            "add_approval_delegation" => Ok(RequestOperationType::AddApprovalDelegation),
+            "bundle" => Ok(RequestOperationType::Bundle),
+            "remove_user" => Ok(RequestOperationType::RemoveUser),
+            "link_user_identity" => Ok(RequestOperationType::LinkUserIdentity),
+            "apply_role_template" => Ok(RequestOperationType::ApplyRoleTemplate),
             _ => Err(()),
         }
     }
@@ -82,6 +106,18 @@ impl Display for RequestOperationType {
             RequestOperationType::EditRequestPolicy => write!(f, "edit_request_policy"),
             RequestOperationType::RemoveRequestPolicy => write!(f, "remove_request_policy"),
             RequestOperationType::ManageSystemInfo => write!(f, "manage_system_info"),
+            RequestOperationType::AddAsset => write!(f, "add_asset"),
+            RequestOperationType::EditAsset => write!(f, "edit_asset"),
+            RequestOperationType::RemoveAsset => write!(f, "remove_asset"),
+            RequestOperationType::ApproveAllowance => write!(f, "approve_allowance"),
+            RequestOperationType::RevokeAllowance => write!(f, "revoke_allowance"),
+            RequestOperationType::BatchTransfer => write!(f, "batch_transfer"),
+            RequestOperationType::CancelStandingOrder => write!(f, "cancel_standing_order"),
+            RequestOperationType::AddApprovalDelegation => write!(f, "add_approval_delegation"),
+            RequestOperationType::Bundle => write!(f, "bundle"),
+            RequestOperationType::RemoveUser => write!(f, "remove_user"),
+            RequestOperationType::LinkUserIdentity => write!(f, "link_user_identity"),
+            RequestOperationType::ApplyRoleTemplate => write!(f, "apply_role_template"),
         }
     }
 }
@@ -204,5 +240,89 @@ mod tests {
             RequestOperationType::from_str("manage_system_info").unwrap(),
             RequestOperationType::ManageSystemInfo
         );
+        assert_eq!(RequestOperationType::AddAsset.to_string(), "add_asset");
+        assert_eq!(
+            RequestOperationType::from_str("add_asset").unwrap(),
+            RequestOperationType::AddAsset
+        );
+        assert_eq!(RequestOperationType::EditAsset.to_string(), "edit_asset");
+        assert_eq!(
+            RequestOperationType::from_str("edit_asset").unwrap(),
+            RequestOperationType::EditAsset
+        );
+        assert_eq!(
+            RequestOperationType::RemoveAsset.to_string(),
+            "remove_asset"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("remove_asset").unwrap(),
+            RequestOperationType::RemoveAsset
+        );
+        assert_eq!(
+            RequestOperationType::ApproveAllowance.to_string(),
+            "approve_allowance"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("approve_allowance").unwrap(),
+            RequestOperationType::ApproveAllowance
+        );
+        assert_eq!(
+            RequestOperationType::RevokeAllowance.to_string(),
+            "revoke_allowance"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("revoke_allowance").unwrap(),
+            RequestOperationType::RevokeAllowance
+        );
+        assert_eq!(
+            RequestOperationType::BatchTransfer.to_string(),
+            "batch_transfer"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("batch_transfer").unwrap(),
+            RequestOperationType::BatchTransfer
+        );
+        assert_eq!(
+            RequestOperationType::CancelStandingOrder.to_string(),
+            "cancel_standing_order"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("cancel_standing_order").unwrap(),
+            RequestOperationType::CancelStandingOrder
+        );
+        assert_eq!(
+            RequestOperationType::AddApprovalDelegation.to_string(),
+            "add_approval_delegation"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("add_approval_delegation").unwrap(),
+            RequestOperationType::AddApprovalDelegation
+        );
+        assert_eq!(RequestOperationType::Bundle.to_string(), "bundle");
+        assert_eq!(
+            RequestOperationType::from_str("bundle").unwrap(),
+            RequestOperationType::Bundle
+        );
+        assert_eq!(RequestOperationType::RemoveUser.to_string(), "remove_user");
+        assert_eq!(
+            RequestOperationType::from_str("remove_user").unwrap(),
+            RequestOperationType::RemoveUser
+        );
+        assert_eq!(
+            RequestOperationType::LinkUserIdentity.to_string(),
+            "link_user_identity"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("link_user_identity").unwrap(),
+            RequestOperationType::LinkUserIdentity
+        );
+        assert_eq!(
+            RequestOperationType::ApplyRoleTemplate.to_string(),
+            "apply_role_template"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("apply_role_template").unwrap(),
+            RequestOperationType::ApplyRoleTemplate
+        );
     }
 }
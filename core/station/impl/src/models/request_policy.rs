@@ -1,4 +1,7 @@
-use super::{request_policy_rule::RequestPolicyRule, request_specifier::RequestSpecifier};
+use super::{
+    request_policy_rule::{RequestEvaluationResult, RequestPolicyRule},
+    request_specifier::RequestSpecifier,
+};
 use crate::errors::{MatchError, RequestPolicyError};
 use candid::{CandidType, Deserialize};
 use orbit_essentials::storable;
@@ -6,6 +9,11 @@ use orbit_essentials::{
     model::{ModelValidator, ModelValidatorResult},
     types::UUID,
 };
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+};
+use uuid::Uuid;
 
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -23,6 +31,70 @@ pub struct RequestPolicy {
     pub rule: RequestPolicyRule,
 }
 
+/// A policy that is only used to simulate the evaluation of a request.
+///
+/// It replaces the stored policy with the same id, or is evaluated in addition to the stored
+/// policies when it has no id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DraftRequestPolicy {
+    pub policy_id: Option<UUID>,
+    pub specifier: RequestSpecifier,
+    pub rule: RequestPolicyRule,
+}
+
+/// The outcome of evaluating an operation against the policies without creating a request.
+#[derive(Clone, Debug)]
+pub struct RequestPolicySimulation {
+    /// The policies that matched the operation, in the order of the evaluated policy results.
+    pub matched_policies: Vec<DraftRequestPolicy>,
+    pub evaluation: RequestEvaluationResult,
+    pub possible_approvers: HashSet<UUID>,
+    /// Whether the request would be approved right away, counting the approval of the requester.
+    pub auto_approved: bool,
+}
+
+/// A problem found in a request policy that doesn't prevent it from being saved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RequestPolicyLintWarning {
+    /// A user specifier that doesn't match any user.
+    EmptyUserSpecifier,
+    /// A user group that no longer exists.
+    UnknownUserGroup { group_id: UUID },
+    /// A user group without active members.
+    EmptyUserGroup { group_id: UUID },
+    /// The rule can't approve requests with the current active users.
+    UnsatisfiableRule,
+    /// The rule approves requests without any approval because none of its approvers are active.
+    ApprovedWithoutApprovers,
+}
+
+impl Display for RequestPolicyLintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequestPolicyLintWarning::EmptyUserSpecifier => {
+                write!(f, "the user specifier doesn't match any user")
+            }
+            RequestPolicyLintWarning::UnknownUserGroup { group_id } => write!(
+                f,
+                "the user group {} does not exist",
+                Uuid::from_bytes(*group_id).hyphenated()
+            ),
+            RequestPolicyLintWarning::EmptyUserGroup { group_id } => write!(
+                f,
+                "the user group {} has no active users",
+                Uuid::from_bytes(*group_id).hyphenated()
+            ),
+            RequestPolicyLintWarning::UnsatisfiableRule => {
+                write!(f, "the rule can't be satisfied by the current active users")
+            }
+            RequestPolicyLintWarning::ApprovedWithoutApprovers => write!(
+                f,
+                "the rule approves requests without approvals since it has no active approvers"
+            ),
+        }
+    }
+}
+
 #[derive(CandidType, Deserialize, Debug, Clone)]
 pub struct RequestPolicyCallerPrivileges {
     pub id: UUID,
@@ -54,6 +126,24 @@ impl ModelValidator<RequestPolicyError> for RequestPolicy {
     }
 }
 
+impl ModelValidator<RequestPolicyError> for DraftRequestPolicy {
+    fn validate(&self) -> ModelValidatorResult<RequestPolicyError> {
+        self.specifier.validate()?;
+        self.rule.validate()?;
+        Ok(())
+    }
+}
+
+impl From<RequestPolicy> for DraftRequestPolicy {
+    fn from(policy: RequestPolicy) -> Self {
+        DraftRequestPolicy {
+            policy_id: Some(policy.id),
+            specifier: policy.specifier,
+            rule: policy.rule,
+        }
+    }
+}
+
 #[cfg(test)]
 pub mod request_policy_test_utils {
     use super::RequestPolicy;
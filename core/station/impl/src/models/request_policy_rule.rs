@@ -1,21 +1,34 @@
 use super::{
+    request_operation_filter_type::RequestOperationFilterType,
     request_specifier::{
         Match, RequestHasMetadata, UserInvolvedInPolicyRuleForRequestResource, UserSpecifier,
     },
-    EvaluateError, EvaluationStatus, MetadataItem, Percentage, Request, RequestApprovalStatus,
-    RequestId, RequestOperation, UserId, UserStatus,
+    AccountId, AssetId, EvaluateError, EvaluationStatus, MetadataItem, Percentage, Request,
+    RequestApprovalStatus, RequestId, RequestOperation, RequestStatusCode, TransferStatus, UserId,
+    UserStatus,
 };
 use crate::{
-    core::{ic_cdk::api::print, utils::calculate_minimum_threshold},
+    core::{
+        ic_cdk::api::{print, time},
+        utils::calculate_minimum_threshold,
+    },
     errors::{MatchError, ValidationError},
-    repositories::{UserWhereClause, ADDRESS_BOOK_REPOSITORY, USER_REPOSITORY},
+    repositories::{
+        UserWhereClause, ADDRESS_BOOK_REPOSITORY, REQUEST_REPOSITORY, TRANSFER_REPOSITORY,
+        USER_REPOSITORY,
+    },
     services::ACCOUNT_SERVICE,
 };
+use num_bigint::BigUint;
 use orbit_essentials::model::{ModelValidator, ModelValidatorResult};
+use orbit_essentials::repository::Repository;
 use orbit_essentials::storable;
 use station_api::EvaluationSummaryReasonDTO;
 use std::{cmp, hash::Hash};
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+};
 
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -23,24 +36,138 @@ pub enum RequestPolicyRule {
     AutoApproved,
     QuorumPercentage(UserSpecifier, Percentage),
     Quorum(UserSpecifier, u16),
+    WeightedQuorum(WeightedQuorum),
     AllowListedByMetadata(MetadataItem),
     AllowListed,
+    AllowListedWithinLimit(TransferLimit),
+    AllowListedWithinRollingLimit(RollingTransferLimit),
+    /// Matches transfers whose total amount, in whole units of the asset, is at most the given amount.
+    ///
+    /// The amounts of batches that transfer several assets are totaled per asset, and all of them
+    /// must be at most the given amount.
+    AmountAtMost(candid::Nat),
+    /// Matches transfers whose total amount, in whole units of the asset, is above the given amount.
+    ///
+    /// The amounts of batches that transfer several assets are totaled per asset, and any of them
+    /// being above the given amount is a match.
+    AmountAbove(candid::Nat),
+    /// Delays the execution of the request once it is approved so that the approvers can still veto
+    /// it.
+    ///
+    /// It does not approve or reject the request by itself and is ignored by the logical operators,
+    /// so it must be combined with the rules that decide the approval.
+    TimeLock(TimeLock),
     // Logical operators
     Or(Vec<RequestPolicyRule>),
     And(Vec<RequestPolicyRule>),
     Not(Box<RequestPolicyRule>),
 }
 
+/// The weight that the approvals of the users that match the specifier have.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ApproverWeight {
+    pub approvers: UserSpecifier,
+    pub weight: u32,
+}
+
+/// A quorum that is reached once the summed weight of the approvals is at least the minimum weight.
+///
+/// Users that match several specifiers count with their highest weight.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WeightedQuorum {
+    pub weights: Vec<ApproverWeight>,
+    pub min_weight: u64,
+}
+
+/// The transfers that are grouped together when checking a transfer limit.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TransferLimitScope {
+    /// The limit applies to the amount sent from each account.
+    PerAccount,
+    /// The limit applies to the amount sent from each account to each address book entry, entries
+    /// can set their own limit with the `transfer_limit` metadata.
+    PerAddressBookEntry,
+}
+
+/// A limit on the amount of the transfers to address book entries.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferLimit {
+    pub scope: TransferLimitScope,
+    /// The maximum amount, in the smallest unit of the asset of the account.
+    pub max_amount: candid::Nat,
+}
+
+/// A limit on the cumulative amount transferred within a rolling window of time.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RollingTransferLimit {
+    pub limit: TransferLimit,
+    /// The length of the window that ends at the time of the evaluation.
+    pub window_ns: u64,
+}
+
+/// The minimum delay between the approval of a request and its execution.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TimeLock {
+    pub delay_ns: u64,
+}
+
+impl RequestPolicyRule {
+    /// Returns the longest time lock delay found in the rule and its sub-rules, if any.
+    pub fn time_lock_ns(&self) -> Option<u64> {
+        match self {
+            RequestPolicyRule::TimeLock(time_lock) => Some(time_lock.delay_ns),
+            RequestPolicyRule::Or(policy_rules) | RequestPolicyRule::And(policy_rules) => {
+                policy_rules
+                    .iter()
+                    .filter_map(|rule| rule.time_lock_ns())
+                    .max()
+            }
+            RequestPolicyRule::Not(rule) => rule.time_lock_ns(),
+            _ => None,
+        }
+    }
+
+    /// Checks if the rule only consists of time locks, which neither approve nor reject a request.
+    pub fn is_neutral(&self) -> bool {
+        match self {
+            RequestPolicyRule::TimeLock(_) => true,
+            RequestPolicyRule::Or(policy_rules) | RequestPolicyRule::And(policy_rules) => {
+                !policy_rules.is_empty() && policy_rules.iter().all(|rule| rule.is_neutral())
+            }
+            RequestPolicyRule::Not(rule) => rule.is_neutral(),
+            _ => false,
+        }
+    }
+}
+
 impl ModelValidator<ValidationError> for RequestPolicyRule {
     fn validate(&self) -> ModelValidatorResult<ValidationError> {
         match self {
             RequestPolicyRule::AutoApproved
             | RequestPolicyRule::AllowListedByMetadata(_)
-            | RequestPolicyRule::AllowListed => Ok(()),
+            | RequestPolicyRule::AllowListed
+            | RequestPolicyRule::AllowListedWithinLimit(_)
+            | RequestPolicyRule::AllowListedWithinRollingLimit(_)
+            | RequestPolicyRule::AmountAtMost(_)
+            | RequestPolicyRule::AmountAbove(_)
+            | RequestPolicyRule::TimeLock(_) => Ok(()),
 
             RequestPolicyRule::QuorumPercentage(user_specifier, _)
             | RequestPolicyRule::Quorum(user_specifier, _) => user_specifier.validate(),
 
+            RequestPolicyRule::WeightedQuorum(quorum) => {
+                for approver_weight in &quorum.weights {
+                    approver_weight.approvers.validate()?;
+                }
+                Ok(())
+            }
+
             RequestPolicyRule::Or(policy_rules) | RequestPolicyRule::And(policy_rules) => {
                 for rule in policy_rules {
                     rule.validate()?;
@@ -66,10 +193,32 @@ pub enum EvaluatedRequestPolicyRule {
         total_possible_approvers: usize,
         approvers: Vec<UserId>,
     },
+    WeightedQuorum {
+        min_weight: u64,
+        total_possible_weight: u64,
+        approved_weight: u64,
+        remaining_weight: u64,
+        approvers: Vec<UserId>,
+    },
     AllowListedByMetadata {
         metadata: MetadataItem,
     },
     AllowListed,
+    AllowListedWithinLimit {
+        limit: TransferLimit,
+    },
+    AllowListedWithinRollingLimit {
+        limit: RollingTransferLimit,
+    },
+    AmountAtMost {
+        amount: candid::Nat,
+    },
+    AmountAbove {
+        amount: candid::Nat,
+    },
+    TimeLock {
+        delay_ns: u64,
+    },
     // Logical operators
     Or(Vec<RequestPolicyRuleResult>),
     And(Vec<RequestPolicyRuleResult>),
@@ -99,7 +248,8 @@ impl RequestPolicyRuleResult {
                 }
             }
             EvaluatedRequestPolicyRule::QuorumPercentage { .. }
-            | EvaluatedRequestPolicyRule::Quorum { .. } => {
+            | EvaluatedRequestPolicyRule::Quorum { .. }
+            | EvaluatedRequestPolicyRule::WeightedQuorum { .. } => {
                 if final_status == self.status {
                     reasons.push(EvaluationSummaryReason::ApprovalQuorum);
                 }
@@ -114,6 +264,19 @@ impl RequestPolicyRuleResult {
                     reasons.push(EvaluationSummaryReason::AllowList);
                 }
             }
+            EvaluatedRequestPolicyRule::AllowListedWithinLimit { .. }
+            | EvaluatedRequestPolicyRule::AllowListedWithinRollingLimit { .. } => {
+                if final_status == self.status {
+                    reasons.push(EvaluationSummaryReason::TransferLimit);
+                }
+            }
+            EvaluatedRequestPolicyRule::AmountAtMost { .. }
+            | EvaluatedRequestPolicyRule::AmountAbove { .. } => {
+                if final_status == self.status {
+                    reasons.push(EvaluationSummaryReason::AmountThreshold);
+                }
+            }
+            EvaluatedRequestPolicyRule::TimeLock { .. } => {}
             EvaluatedRequestPolicyRule::Or(rule_results)
             | EvaluatedRequestPolicyRule::And(rule_results) => {
                 for rule_result in rule_results {
@@ -230,6 +393,198 @@ impl RequestApprovalSummary {
     }
 }
 
+struct WeightedApprovalSummary {
+    total_possible_weight: u64,
+    approvers: Vec<UserId>,
+    approved_weight: u64,
+    rejected_weight: u64,
+}
+
+impl WeightedApprovalSummary {
+    /// Evaluates the weighted approval summary the same way as `RequestApprovalSummary::evaluate`,
+    /// with the approvals counted by the weight of their approvers.
+    fn evaluate(&self, min_weight: u64) -> EvaluationStatus {
+        let min_weight = cmp::min(min_weight, self.total_possible_weight);
+        let uncasted_weight = self
+            .total_possible_weight
+            .saturating_sub(self.approved_weight)
+            .saturating_sub(self.rejected_weight);
+
+        if self.approved_weight >= min_weight {
+            return EvaluationStatus::Approved;
+        }
+
+        if self.approved_weight.saturating_add(uncasted_weight) < min_weight {
+            return EvaluationStatus::Rejected;
+        }
+
+        EvaluationStatus::Pending
+    }
+}
+
+/// Returns the transfers of the request as `(from_account_id, to_address, amount)`.
+fn request_transfers(request: &Request) -> Vec<(AccountId, String, candid::Nat)> {
+    match &request.operation {
+        RequestOperation::Transfer(transfer) => vec![(
+            transfer.input.from_account_id,
+            transfer.input.to.clone(),
+            transfer.input.amount.clone(),
+        )],
+        RequestOperation::BatchTransfer(batch) => batch
+            .input
+            .transfers
+            .iter()
+            .map(|transfer| {
+                (
+                    transfer.from_account_id,
+                    transfer.to.clone(),
+                    transfer.amount.clone(),
+                )
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Returns the amount that the account transferred within the window, optionally only to the given
+/// address. Failed transfers and the transfers of the request itself are not counted.
+///
+/// The requests of the account that are still in flight are counted by their operation, since they
+/// can be executed within the window once approved, and their transfer records are skipped so that
+/// a partially executed request is counted exactly once.
+fn transferred_amount(
+    request: &Request,
+    account_id: AccountId,
+    to_address: Option<&str>,
+    window_ns: u64,
+) -> candid::Nat {
+    let window_start = time().saturating_sub(window_ns);
+    let in_flight_requests = find_in_flight_transfer_requests(account_id);
+    let mut total = candid::Nat::from(0u64);
+
+    for transfer in TRANSFER_REPOSITORY.find_by_account(account_id, Some(window_start), None, None)
+    {
+        if transfer.request_id == request.id
+            || in_flight_requests.contains(&transfer.request_id)
+            || matches!(transfer.status, TransferStatus::Failed { .. })
+            || to_address.is_some_and(|to_address| transfer.to_address != to_address)
+        {
+            continue;
+        }
+
+        total += transfer.amount;
+    }
+
+    for in_flight_id in in_flight_requests {
+        if in_flight_id == request.id {
+            continue;
+        }
+
+        let Some(in_flight) = REQUEST_REPOSITORY.get(&Request::key(in_flight_id)) else {
+            continue;
+        };
+
+        for (from_account_id, to, amount) in request_transfers(&in_flight) {
+            if from_account_id == account_id
+                && to_address.map_or(true, |to_address| to == to_address)
+            {
+                total += amount;
+            }
+        }
+    }
+
+    total
+}
+
+/// Returns the ids of the transfer and batch transfer requests from the account that are not yet
+/// finalized, using the status and operation type indexes.
+fn find_in_flight_transfer_requests(account_id: AccountId) -> HashSet<RequestId> {
+    [
+        RequestStatusCode::Created,
+        RequestStatusCode::Approved,
+        RequestStatusCode::Scheduled,
+        RequestStatusCode::Processing,
+    ]
+    .into_iter()
+    .flat_map(|status| REQUEST_REPOSITORY.find_ids_by_status(status))
+    .filter(|request_id| {
+        REQUEST_REPOSITORY.exists_operation_type(
+            request_id,
+            RequestOperationFilterType::Transfer(Some(account_id)),
+        ) || REQUEST_REPOSITORY.exists_operation_type(
+            request_id,
+            RequestOperationFilterType::BatchTransfer(Some(account_id)),
+        )
+    })
+    .collect()
+}
+
+/// Compares the total amount transferred of each asset of the request with the given amount of
+/// whole units, since the amounts of different assets can not be added up.
+///
+/// The transfer amounts are normalized by the decimals of their accounts, returns `None` if the
+/// request has no transfers or any of their accounts is not found.
+fn compare_request_amount(request: &Request, amount: &candid::Nat) -> Option<Vec<cmp::Ordering>> {
+    let transfers = request_transfers(request);
+    if transfers.is_empty() {
+        return None;
+    }
+
+    let mut amounts_by_asset: BTreeMap<AssetId, Vec<(candid::Nat, u32)>> = BTreeMap::new();
+    for (from_account_id, _, transfer_amount) in transfers {
+        match ACCOUNT_SERVICE.get_account(&from_account_id) {
+            Ok(account) => amounts_by_asset
+                .entry(account.asset_id)
+                .or_default()
+                .push((transfer_amount, account.decimals)),
+            Err(e) => {
+                print(format!(
+                    "Rule rejected due to account not being found: {:?}",
+                    e
+                ));
+
+                return None;
+            }
+        }
+    }
+
+    let orderings = amounts_by_asset
+        .into_values()
+        .map(|amounts| {
+            // scales all amounts to the highest precision so that accounts with different decimals
+            // add up
+            let max_decimals = amounts
+                .iter()
+                .map(|(_, decimals)| *decimals)
+                .max()
+                .unwrap_or_default();
+            let total = amounts.into_iter().fold(
+                BigUint::from(0u32),
+                |total, (transfer_amount, decimals)| {
+                    total + transfer_amount.0 * BigUint::from(10u32).pow(max_decimals - decimals)
+                },
+            );
+
+            total.cmp(&(amount.0.clone() * BigUint::from(10u32).pow(max_decimals)))
+        })
+        .collect();
+
+    Some(orderings)
+}
+
+/// Returns the evaluation statuses of the rules that decide the approval, leaving out the time locks.
+fn deciding_statuses(
+    policy_rules: &[RequestPolicyRule],
+    results: &[RequestPolicyRuleResult],
+) -> Vec<EvaluationStatus> {
+    policy_rules
+        .iter()
+        .zip(results)
+        .filter(|(rule, _)| !rule.is_neutral())
+        .map(|(_, result)| result.status.clone())
+        .collect()
+}
+
 impl RequestPolicyRuleEvaluator {
     fn evaluate_policy_rules(
         &self,
@@ -327,6 +682,152 @@ impl RequestPolicyRuleEvaluator {
                 .collect(),
         })
     }
+
+    /// Returns the highest weight of the specifiers that the user matches, or zero if none.
+    fn find_user_weight(
+        &self,
+        request: &Arc<Request>,
+        user_id: &UserId,
+        weights: &[ApproverWeight],
+    ) -> Result<u64, MatchError> {
+        let mut user_weight = 0;
+
+        for approver_weight in weights {
+            if u64::from(approver_weight.weight) > user_weight
+                && self
+                    .user_matcher
+                    .is_match(UserInvolvedInPolicyRuleForRequestResource {
+                        request_operation_resources: request.operation.to_resources(),
+                        policy_rule_user_specifier: approver_weight.approvers.to_owned(),
+                        user_id: user_id.to_owned(),
+                        request_id: request.id,
+                    })?
+            {
+                user_weight = u64::from(approver_weight.weight);
+            }
+        }
+
+        Ok(user_weight)
+    }
+
+    fn calculate_weighted_approvals(
+        &self,
+        request: &Arc<Request>,
+        weights: &[ApproverWeight],
+    ) -> Result<WeightedApprovalSummary, MatchError> {
+        let mut approvers = vec![];
+        let mut approved_weight = 0u64;
+        let mut rejected_weight = 0u64;
+
+        for approval in &request.approvals {
+            let weight = self.find_user_weight(request, &approval.approver_id, weights)?;
+            if weight == 0 {
+                continue;
+            }
+
+            match approval.status {
+                RequestApprovalStatus::Approved => {
+                    approved_weight = approved_weight.saturating_add(weight)
+                }
+                RequestApprovalStatus::Rejected => {
+                    rejected_weight = rejected_weight.saturating_add(weight)
+                }
+            }
+
+            approvers.push(approval.approver_id);
+        }
+
+        let mut total_possible_weight = 0u64;
+        for user in USER_REPOSITORY.find_where(UserWhereClause {
+            statuses: Some(vec![UserStatus::Active]),
+            search_term: None,
+        }) {
+            total_possible_weight = total_possible_weight
+                .saturating_add(self.find_user_weight(request, &user.id, weights)?);
+        }
+
+        // This is to ensure that the if users become inactive or the rule is misconfigured
+        // the total possible weight is not less than the casted weight.
+        total_possible_weight = cmp::max(
+            approved_weight.saturating_add(rejected_weight),
+            total_possible_weight,
+        );
+
+        Ok(WeightedApprovalSummary {
+            total_possible_weight,
+            approvers,
+            approved_weight,
+            rejected_weight,
+        })
+    }
+
+    /// Approves the request if all its transfers are sent to address book entries and the amounts,
+    /// including what was transferred within the window if any, stay within the limit.
+    fn evaluate_transfer_limit(
+        &self,
+        request: &Request,
+        limit: &TransferLimit,
+        window_ns: Option<u64>,
+    ) -> EvaluationStatus {
+        let transfers = request_transfers(request);
+        if transfers.is_empty() {
+            return EvaluationStatus::Rejected;
+        }
+
+        // the amount of each group of transfers along with the limit that applies to it
+        let mut groups: BTreeMap<(AccountId, Option<String>), (candid::Nat, candid::Nat)> =
+            BTreeMap::new();
+        for (from_account_id, to_address, amount) in transfers {
+            let account = match ACCOUNT_SERVICE.get_account(&from_account_id) {
+                Ok(account) => account,
+                Err(e) => {
+                    print(format!(
+                        "Rule rejected due to account not being found: {:?}",
+                        e
+                    ));
+
+                    return EvaluationStatus::Rejected;
+                }
+            };
+
+            let Some(entry) = ADDRESS_BOOK_REPOSITORY.find_by_address(
+                account.blockchain,
+                account.standard,
+                to_address.clone(),
+            ) else {
+                return EvaluationStatus::Rejected;
+            };
+
+            let (group, max_amount) = match limit.scope {
+                TransferLimitScope::PerAccount => {
+                    ((from_account_id, None), limit.max_amount.clone())
+                }
+                TransferLimitScope::PerAddressBookEntry => (
+                    (from_account_id, Some(to_address)),
+                    entry
+                        .transfer_limit()
+                        .unwrap_or_else(|| limit.max_amount.clone()),
+                ),
+            };
+
+            let (total, _) = groups
+                .entry(group)
+                .or_insert((candid::Nat::from(0u64), max_amount));
+            *total += amount;
+        }
+
+        for ((account_id, to_address), (mut total, max_amount)) in groups {
+            if let Some(window_ns) = window_ns {
+                total += transferred_amount(request, account_id, to_address.as_deref(), window_ns);
+            }
+
+            if total > max_amount {
+                return EvaluationStatus::Rejected;
+            }
+        }
+
+        EvaluationStatus::Approved
+    }
 }
 
 impl
@@ -374,6 +875,24 @@ impl
                     },
                 })
             }
+            RequestPolicyRule::WeightedQuorum(quorum) => {
+                let approval_summary =
+                    self.calculate_weighted_approvals(&request, &quorum.weights)?;
+                let min_weight =
+                    cmp::min(quorum.min_weight, approval_summary.total_possible_weight);
+
+                Ok(RequestPolicyRuleResult {
+                    status: approval_summary.evaluate(quorum.min_weight),
+                    evaluated_rule: EvaluatedRequestPolicyRule::WeightedQuorum {
+                        min_weight: quorum.min_weight,
+                        total_possible_weight: approval_summary.total_possible_weight,
+                        approved_weight: approval_summary.approved_weight,
+                        remaining_weight: min_weight
+                            .saturating_sub(approval_summary.approved_weight),
+                        approvers: approval_summary.approvers,
+                    },
+                })
+            }
             RequestPolicyRule::AllowListedByMetadata(metadata) => {
                 let is_match = self
                     .address_book_metadata_matcher
@@ -391,8 +910,29 @@ impl
                 })
             }
             RequestPolicyRule::AllowListed => {
-                if let RequestOperation::Transfer(transfer) = &request.operation {
-                    let account = ACCOUNT_SERVICE.get_account(&transfer.input.from_account_id);
+                // allowances are evaluated against the spender account, as it is the one that
+                // can move the funds out of the station account
+                let destinations = match &request.operation {
+                    RequestOperation::Transfer(transfer) => {
+                        vec![(transfer.input.from_account_id, transfer.input.to.clone())]
+                    }
+                    // a batch is only allow listed if all of its destinations are
+                    RequestOperation::BatchTransfer(batch) => batch
+                        .input
+                        .transfers
+                        .iter()
+                        .map(|transfer| (transfer.from_account_id, transfer.to.clone()))
+                        .collect(),
+                    RequestOperation::ApproveAllowance(approve) => vec![(
+                        approve.input.from_account_id,
+                        approve.input.spender.to_address(),
+                    )],
+                    _ => vec![],
+                };
+
+                let mut is_allow_listed = !destinations.is_empty();
+                for (from_account_id, to_address) in destinations {
+                    let account = ACCOUNT_SERVICE.get_account(&from_account_id);
                     match account {
                         Err(e) => {
                             print(format!(
@@ -406,33 +946,87 @@ impl
                             });
                         }
                         Ok(account) => {
-                            let is_in_address_book = ADDRESS_BOOK_REPOSITORY.exists(
+                            if !ADDRESS_BOOK_REPOSITORY.exists(
                                 account.blockchain,
                                 account.standard,
-                                transfer.input.to.clone(),
-                            );
-
-                            if is_in_address_book {
-                                return Ok(RequestPolicyRuleResult {
-                                    status: EvaluationStatus::Approved,
-                                    evaluated_rule: EvaluatedRequestPolicyRule::AllowListed,
-                                });
+                                to_address,
+                            ) {
+                                is_allow_listed = false;
+                                break;
                             }
                         }
                     }
                 }
 
                 Ok(RequestPolicyRuleResult {
-                    status: EvaluationStatus::Rejected,
+                    status: if is_allow_listed {
+                        EvaluationStatus::Approved
+                    } else {
+                        EvaluationStatus::Rejected
+                    },
                     evaluated_rule: EvaluatedRequestPolicyRule::AllowListed,
                 })
             }
+            RequestPolicyRule::AllowListedWithinLimit(limit) => Ok(RequestPolicyRuleResult {
+                status: self.evaluate_transfer_limit(&request, limit, None),
+                evaluated_rule: EvaluatedRequestPolicyRule::AllowListedWithinLimit {
+                    limit: limit.clone(),
+                },
+            }),
+            RequestPolicyRule::AllowListedWithinRollingLimit(rolling_limit) => {
+                Ok(RequestPolicyRuleResult {
+                    status: self.evaluate_transfer_limit(
+                        &request,
+                        &rolling_limit.limit,
+                        Some(rolling_limit.window_ns),
+                    ),
+                    evaluated_rule: EvaluatedRequestPolicyRule::AllowListedWithinRollingLimit {
+                        limit: rolling_limit.clone(),
+                    },
+                })
+            }
+            RequestPolicyRule::AmountAtMost(amount) => Ok(RequestPolicyRuleResult {
+                status: match compare_request_amount(&request, amount) {
+                    Some(orderings) if orderings.iter().all(|ordering| ordering.is_le()) => {
+                        EvaluationStatus::Approved
+                    }
+                    _ => EvaluationStatus::Rejected,
+                },
+                evaluated_rule: EvaluatedRequestPolicyRule::AmountAtMost {
+                    amount: amount.clone(),
+                },
+            }),
+            RequestPolicyRule::AmountAbove(amount) => Ok(RequestPolicyRuleResult {
+                status: match compare_request_amount(&request, amount) {
+                    Some(orderings) if orderings.iter().any(|ordering| ordering.is_gt()) => {
+                        EvaluationStatus::Approved
+                    }
+                    _ => EvaluationStatus::Rejected,
+                },
+                evaluated_rule: EvaluatedRequestPolicyRule::AmountAbove {
+                    amount: amount.clone(),
+                },
+            }),
+            RequestPolicyRule::TimeLock(time_lock) => Ok(RequestPolicyRuleResult {
+                status: EvaluationStatus::Pending,
+                evaluated_rule: EvaluatedRequestPolicyRule::TimeLock {
+                    delay_ns: time_lock.delay_ns,
+                },
+            }),
             RequestPolicyRule::And(policy_rules) => {
                 let evaluation_statuses = self.evaluate_policy_rules(&request, policy_rules)?;
+                let deciding_statuses = deciding_statuses(policy_rules, &evaluation_statuses);
+
+                if critera.is_neutral() {
+                    return Ok(RequestPolicyRuleResult {
+                        status: EvaluationStatus::Pending,
+                        evaluated_rule: EvaluatedRequestPolicyRule::And(evaluation_statuses),
+                    });
+                }
 
-                if evaluation_statuses
+                if deciding_statuses
                     .iter()
-                    .any(|result| result.status == EvaluationStatus::Rejected)
+                    .any(|status| *status == EvaluationStatus::Rejected)
                 {
                     return Ok(RequestPolicyRuleResult {
                         status: EvaluationStatus::Rejected,
@@ -440,9 +1034,9 @@ impl
                     });
                 }
 
-                if evaluation_statuses
+                if deciding_statuses
                     .iter()
-                    .all(|result| result.status == EvaluationStatus::Approved)
+                    .all(|status| *status == EvaluationStatus::Approved)
                 {
                     return Ok(RequestPolicyRuleResult {
                         status: EvaluationStatus::Approved,
@@ -457,10 +1051,18 @@ impl
             }
             RequestPolicyRule::Or(policy_rules) => {
                 let evaluation_statuses = self.evaluate_policy_rules(&request, policy_rules)?;
+                let deciding_statuses = deciding_statuses(policy_rules, &evaluation_statuses);
+
+                if critera.is_neutral() {
+                    return Ok(RequestPolicyRuleResult {
+                        status: EvaluationStatus::Pending,
+                        evaluated_rule: EvaluatedRequestPolicyRule::Or(evaluation_statuses),
+                    });
+                }
 
-                if evaluation_statuses
+                if deciding_statuses
                     .iter()
-                    .any(|result| result.status == EvaluationStatus::Approved)
+                    .any(|status| *status == EvaluationStatus::Approved)
                 {
                     return Ok(RequestPolicyRuleResult {
                         status: EvaluationStatus::Approved,
@@ -468,9 +1070,9 @@ impl
                     });
                 }
 
-                if evaluation_statuses
+                if deciding_statuses
                     .iter()
-                    .all(|result| result.status == EvaluationStatus::Rejected)
+                    .all(|status| *status == EvaluationStatus::Rejected)
                 {
                     return Ok(RequestPolicyRuleResult {
                         status: EvaluationStatus::Rejected,
@@ -490,6 +1092,7 @@ impl
                 ))?;
                 Ok(RequestPolicyRuleResult {
                     status: match evaluation_result.status {
+                        _ if policy_rule.is_neutral() => EvaluationStatus::Pending,
                         EvaluationStatus::Pending => EvaluationStatus::Pending,
                         EvaluationStatus::Approved => EvaluationStatus::Rejected,
                         EvaluationStatus::Rejected => EvaluationStatus::Approved,
@@ -543,7 +1146,492 @@ pub mod request_policy_rule_test_utils {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::core::evaluation::REQUEST_POLICY_RULE_EVALUATOR;
     use crate::core::validation::disable_mock_resource_validation;
+    use crate::models::{
+        account_test_utils::add_account,
+        address_book_entry_test_utils::mock_address_book_entry,
+        request_approval_test_utils::{mock_approved_with_user, mock_rejected_with_user},
+        request_test_utils::mock_request,
+        transfer_test_utils::mock_transfer,
+        user_test_utils::add_user,
+        AddUserGroupOperation, AddUserGroupOperationInput, AddressBookEntry,
+        BatchTransferOperation, BatchTransferOperationInput, Metadata, RequestStatus,
+        TransferOperation, TransferOperationInput,
+    };
+    use crate::repositories::ACCOUNT_REPOSITORY;
+    use orbit_essentials::repository::Repository;
+
+    fn mock_transfer_request(from_account_id: AccountId, to: String, amount: u64) -> Arc<Request> {
+        let mut request = mock_request();
+        request.operation = RequestOperation::Transfer(TransferOperation {
+            transfer_id: None,
+            input: TransferOperationInput {
+                from_account_id,
+                to,
+                amount: candid::Nat::from(amount),
+                fee: None,
+                metadata: Metadata::default(),
+                network: "mainnet".to_string(),
+            },
+        });
+
+        Arc::new(request)
+    }
+
+    fn evaluate_rule(request: &Arc<Request>, rule: RequestPolicyRule) -> EvaluationStatus {
+        REQUEST_POLICY_RULE_EVALUATOR
+            .evaluate((request.clone(), Arc::new(rule)))
+            .unwrap()
+            .status
+    }
+
+    #[test]
+    fn allow_listed_within_limit_checks_destination_and_amount() {
+        let account = add_account(&[1; 16]);
+        let entry = mock_address_book_entry();
+        ADDRESS_BOOK_REPOSITORY.insert(entry.to_key(), entry.clone());
+
+        let rule = RequestPolicyRule::AllowListedWithinLimit(TransferLimit {
+            scope: TransferLimitScope::PerAccount,
+            max_amount: candid::Nat::from(100u64),
+        });
+
+        let request = mock_transfer_request(account.id, entry.address.clone(), 100);
+        assert_eq!(
+            evaluate_rule(&request, rule.clone()),
+            EvaluationStatus::Approved
+        );
+
+        let request = mock_transfer_request(account.id, entry.address.clone(), 101);
+        assert_eq!(
+            evaluate_rule(&request, rule.clone()),
+            EvaluationStatus::Rejected
+        );
+
+        let request = mock_transfer_request(account.id, "not-in-address-book".to_string(), 1);
+        assert_eq!(evaluate_rule(&request, rule), EvaluationStatus::Rejected);
+    }
+
+    #[test]
+    fn address_book_entry_overrides_the_transfer_limit() {
+        let account = add_account(&[1; 16]);
+        let mut entry = mock_address_book_entry();
+        entry.metadata = Metadata::new(
+            [(
+                AddressBookEntry::TRANSFER_LIMIT_METADATA_KEY.to_string(),
+                "10".to_string(),
+            )]
+            .into(),
+        );
+        ADDRESS_BOOK_REPOSITORY.insert(entry.to_key(), entry.clone());
+
+        let request = mock_transfer_request(account.id, entry.address.clone(), 50);
+
+        assert_eq!(
+            evaluate_rule(
+                &request,
+                RequestPolicyRule::AllowListedWithinLimit(TransferLimit {
+                    scope: TransferLimitScope::PerAddressBookEntry,
+                    max_amount: candid::Nat::from(100u64),
+                })
+            ),
+            EvaluationStatus::Rejected
+        );
+        assert_eq!(
+            evaluate_rule(
+                &request,
+                RequestPolicyRule::AllowListedWithinLimit(TransferLimit {
+                    scope: TransferLimitScope::PerAccount,
+                    max_amount: candid::Nat::from(100u64),
+                })
+            ),
+            EvaluationStatus::Approved
+        );
+    }
+
+    #[test]
+    fn rolling_limit_includes_recent_transfers() {
+        let account = add_account(&[1; 16]);
+        let entry = mock_address_book_entry();
+        ADDRESS_BOOK_REPOSITORY.insert(entry.to_key(), entry.clone());
+
+        let rule = RequestPolicyRule::AllowListedWithinRollingLimit(RollingTransferLimit {
+            limit: TransferLimit {
+                scope: TransferLimitScope::PerAccount,
+                max_amount: candid::Nat::from(100u64),
+            },
+            window_ns: 24 * 60 * 60 * 1_000_000_000,
+        });
+        let request = mock_transfer_request(account.id, entry.address.clone(), 60);
+
+        assert_eq!(
+            evaluate_rule(&request, rule.clone()),
+            EvaluationStatus::Approved
+        );
+
+        let mut transfer = mock_transfer();
+        transfer.from_account = account.id;
+        transfer.amount = candid::Nat::from(50u64);
+        TRANSFER_REPOSITORY.insert(transfer.to_key(), transfer.clone());
+
+        assert_eq!(
+            evaluate_rule(&request, rule.clone()),
+            EvaluationStatus::Rejected
+        );
+
+        // failed transfers do not count towards the limit
+        transfer.status = TransferStatus::Failed {
+            reason: "error".to_string(),
+        };
+        TRANSFER_REPOSITORY.insert(transfer.to_key(), transfer);
+
+        assert_eq!(evaluate_rule(&request, rule), EvaluationStatus::Approved);
+    }
+
+    #[test]
+    fn rolling_limit_includes_in_flight_requests() {
+        let account = add_account(&[1; 16]);
+        let entry = mock_address_book_entry();
+        ADDRESS_BOOK_REPOSITORY.insert(entry.to_key(), entry.clone());
+
+        let rule = RequestPolicyRule::AllowListedWithinRollingLimit(RollingTransferLimit {
+            limit: TransferLimit {
+                scope: TransferLimitScope::PerAccount,
+                max_amount: candid::Nat::from(100u64),
+            },
+            window_ns: 24 * 60 * 60 * 1_000_000_000,
+        });
+
+        let mut approved = 0;
+        for _ in 0..3 {
+            let request = mock_transfer_request(account.id, entry.address.clone(), 60);
+            REQUEST_REPOSITORY.insert(request.to_key(), request.as_ref().clone());
+
+            if evaluate_rule(&request, rule.clone()) == EvaluationStatus::Approved {
+                approved += 1;
+            }
+        }
+
+        assert_eq!(approved, 1);
+
+        // the transfers of finished requests only count if they are within the window
+        for mut request in REQUEST_REPOSITORY.list() {
+            request.status = RequestStatus::Completed { completed_at: 0 };
+            REQUEST_REPOSITORY.insert(request.to_key(), request);
+        }
+
+        let request = mock_transfer_request(account.id, entry.address.clone(), 60);
+
+        assert_eq!(evaluate_rule(&request, rule), EvaluationStatus::Approved);
+    }
+
+    #[test]
+    fn rolling_limit_counts_processing_requests_once() {
+        let account = add_account(&[1; 16]);
+        let entry = mock_address_book_entry();
+        ADDRESS_BOOK_REPOSITORY.insert(entry.to_key(), entry.clone());
+
+        let rule = RequestPolicyRule::AllowListedWithinRollingLimit(RollingTransferLimit {
+            limit: TransferLimit {
+                scope: TransferLimitScope::PerAccount,
+                max_amount: candid::Nat::from(100u64),
+            },
+            window_ns: 24 * 60 * 60 * 1_000_000_000,
+        });
+
+        // a batch that is processing and already executed only its first row
+        let mut batch = mock_request();
+        batch.status = RequestStatus::Processing { started_at: 0 };
+        batch.operation = RequestOperation::BatchTransfer(BatchTransferOperation {
+            transfer_ids: vec![],
+            input: BatchTransferOperationInput {
+                transfers: (0..2)
+                    .map(|_| TransferOperationInput {
+                        from_account_id: account.id,
+                        to: entry.address.clone(),
+                        amount: candid::Nat::from(40u64),
+                        fee: None,
+                        metadata: Metadata::default(),
+                        network: "mainnet".to_string(),
+                    })
+                    .collect(),
+            },
+        });
+        REQUEST_REPOSITORY.insert(batch.to_key(), batch.clone());
+
+        let mut transfer = mock_transfer();
+        transfer.request_id = batch.id;
+        transfer.from_account = account.id;
+        transfer.to_address = entry.address.clone();
+        transfer.amount = candid::Nat::from(40u64);
+        transfer.status = TransferStatus::Processing { started_at: 0 };
+        TRANSFER_REPOSITORY.insert(transfer.to_key(), transfer);
+
+        let request = mock_transfer_request(account.id, entry.address.clone(), 20);
+        assert_eq!(
+            evaluate_rule(&request, rule.clone()),
+            EvaluationStatus::Approved
+        );
+
+        let request = mock_transfer_request(account.id, entry.address.clone(), 21);
+        assert_eq!(evaluate_rule(&request, rule), EvaluationStatus::Rejected);
+    }
+
+    #[test]
+    fn amount_rules_normalize_by_account_decimals() {
+        let mut account = add_account(&[1; 16]);
+        account.decimals = 8;
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let at_most = RequestPolicyRule::AmountAtMost(candid::Nat::from(10u64));
+        let above = RequestPolicyRule::AmountAbove(candid::Nat::from(10u64));
+
+        let request = mock_transfer_request(account.id, "address".to_string(), 1_000_000_000);
+        assert_eq!(
+            evaluate_rule(&request, at_most.clone()),
+            EvaluationStatus::Approved
+        );
+        assert_eq!(
+            evaluate_rule(&request, above.clone()),
+            EvaluationStatus::Rejected
+        );
+
+        let request = mock_transfer_request(account.id, "address".to_string(), 1_000_000_001);
+        assert_eq!(
+            evaluate_rule(&request, at_most.clone()),
+            EvaluationStatus::Rejected
+        );
+        assert_eq!(
+            evaluate_rule(&request, above.clone()),
+            EvaluationStatus::Approved
+        );
+
+        // the tiers can be combined with the other rules
+        let request = mock_transfer_request(account.id, "address".to_string(), 1);
+        assert_eq!(
+            evaluate_rule(
+                &request,
+                RequestPolicyRule::Or(vec![
+                    RequestPolicyRule::AmountAtMost(candid::Nat::from(1u64)),
+                    RequestPolicyRule::Quorum(UserSpecifier::Any, 1),
+                ])
+            ),
+            EvaluationStatus::Approved
+        );
+        assert_eq!(
+            evaluate_rule(&request, RequestPolicyRule::Not(Box::new(above))),
+            EvaluationStatus::Approved
+        );
+    }
+
+    #[test]
+    fn amount_rules_total_batches_per_asset() {
+        let first_account = add_account(&[1; 16]);
+        let mut second_account = add_account(&[2; 16]);
+        second_account.asset_id = [9; 16];
+        ACCOUNT_REPOSITORY.insert(second_account.to_key(), second_account.clone());
+
+        let mut request = mock_request();
+        request.operation = RequestOperation::BatchTransfer(BatchTransferOperation {
+            transfer_ids: vec![],
+            input: BatchTransferOperationInput {
+                transfers: [first_account.id, first_account.id, second_account.id]
+                    .into_iter()
+                    .map(|from_account_id| TransferOperationInput {
+                        from_account_id,
+                        to: "address".to_string(),
+                        amount: candid::Nat::from(6u64),
+                        fee: None,
+                        metadata: Metadata::default(),
+                        network: "mainnet".to_string(),
+                    })
+                    .collect(),
+            },
+        });
+        let request = Arc::new(request);
+
+        // the amounts of the two assets are not added up
+        assert_eq!(
+            evaluate_rule(
+                &request,
+                RequestPolicyRule::AmountAtMost(candid::Nat::from(12u64))
+            ),
+            EvaluationStatus::Approved
+        );
+        assert_eq!(
+            evaluate_rule(
+                &request,
+                RequestPolicyRule::AmountAbove(candid::Nat::from(12u64))
+            ),
+            EvaluationStatus::Rejected
+        );
+
+        // any asset above the amount is enough to match
+        assert_eq!(
+            evaluate_rule(
+                &request,
+                RequestPolicyRule::AmountAtMost(candid::Nat::from(11u64))
+            ),
+            EvaluationStatus::Rejected
+        );
+        assert_eq!(
+            evaluate_rule(
+                &request,
+                RequestPolicyRule::AmountAbove(candid::Nat::from(11u64))
+            ),
+            EvaluationStatus::Approved
+        );
+    }
+
+    #[test]
+    fn amount_rules_reject_requests_without_transfers() {
+        let mut request = mock_request();
+        request.operation = RequestOperation::AddUserGroup(AddUserGroupOperation {
+            user_group_id: None,
+            input: AddUserGroupOperationInput {
+                name: "test".to_string(),
+            },
+        });
+        let request = Arc::new(request);
+
+        assert_eq!(
+            evaluate_rule(
+                &request,
+                RequestPolicyRule::AmountAtMost(candid::Nat::from(10u64))
+            ),
+            EvaluationStatus::Rejected
+        );
+        assert_eq!(
+            evaluate_rule(
+                &request,
+                RequestPolicyRule::AmountAbove(candid::Nat::from(10u64))
+            ),
+            EvaluationStatus::Rejected
+        );
+    }
+
+    #[test]
+    fn weighted_quorum_sums_the_weight_of_approvals() {
+        let board_member = add_user(&[1; 16]);
+        let operator = add_user(&[2; 16]);
+        add_user(&[3; 16]);
+
+        let rule = RequestPolicyRule::WeightedQuorum(WeightedQuorum {
+            weights: vec![
+                ApproverWeight {
+                    approvers: UserSpecifier::Any,
+                    weight: 1,
+                },
+                ApproverWeight {
+                    approvers: UserSpecifier::Id(vec![board_member.id]),
+                    weight: 3,
+                },
+            ],
+            min_weight: 4,
+        });
+
+        let mut request = mock_request();
+        request.approvals = vec![mock_approved_with_user(board_member.id)];
+        let result = REQUEST_POLICY_RULE_EVALUATOR
+            .evaluate((Arc::new(request.clone()), Arc::new(rule.clone())))
+            .unwrap();
+
+        assert_eq!(result.status, EvaluationStatus::Pending);
+        assert_eq!(
+            result.evaluated_rule,
+            EvaluatedRequestPolicyRule::WeightedQuorum {
+                min_weight: 4,
+                total_possible_weight: 5,
+                approved_weight: 3,
+                remaining_weight: 1,
+                approvers: vec![board_member.id],
+            }
+        );
+
+        request.approvals.push(mock_approved_with_user(operator.id));
+
+        assert_eq!(
+            evaluate_rule(&Arc::new(request.clone()), rule.clone()),
+            EvaluationStatus::Approved
+        );
+
+        // a rejection of the board member can not be outvoted by the operators
+        request.approvals = vec![
+            mock_rejected_with_user(board_member.id),
+            mock_approved_with_user(operator.id),
+        ];
+
+        assert_eq!(
+            evaluate_rule(&Arc::new(request), rule),
+            EvaluationStatus::Rejected
+        );
+    }
+
+    #[test]
+    fn time_lock_takes_the_longest_delay() {
+        let rule = RequestPolicyRule::And(vec![
+            RequestPolicyRule::Quorum(UserSpecifier::Any, 1),
+            RequestPolicyRule::TimeLock(TimeLock { delay_ns: 10 }),
+            RequestPolicyRule::Or(vec![
+                RequestPolicyRule::AutoApproved,
+                RequestPolicyRule::TimeLock(TimeLock { delay_ns: 20 }),
+            ]),
+        ]);
+
+        assert_eq!(rule.time_lock_ns(), Some(20));
+        assert_eq!(RequestPolicyRule::AutoApproved.time_lock_ns(), None);
+    }
+
+    #[test]
+    fn time_lock_does_not_decide_the_approval() {
+        add_user(&[1; 16]);
+        let mut request = mock_request();
+        request.approvals = vec![];
+        let request = Arc::new(request);
+        let time_lock = || RequestPolicyRule::TimeLock(TimeLock { delay_ns: 10 });
+        let quorum = || RequestPolicyRule::Quorum(UserSpecifier::Any, 1);
+
+        assert_eq!(
+            evaluate_rule(&request, time_lock()),
+            EvaluationStatus::Pending
+        );
+        assert_eq!(
+            evaluate_rule(&request, RequestPolicyRule::Or(vec![quorum(), time_lock()])),
+            EvaluationStatus::Pending
+        );
+        assert_eq!(
+            evaluate_rule(
+                &request,
+                RequestPolicyRule::Not(Box::new(RequestPolicyRule::And(vec![
+                    RequestPolicyRule::AutoApproved,
+                    time_lock()
+                ])))
+            ),
+            EvaluationStatus::Rejected
+        );
+        assert_eq!(
+            evaluate_rule(&request, RequestPolicyRule::Not(Box::new(time_lock()))),
+            EvaluationStatus::Pending
+        );
+        assert_eq!(
+            evaluate_rule(
+                &request,
+                RequestPolicyRule::Or(vec![
+                    RequestPolicyRule::Not(Box::new(time_lock())),
+                    RequestPolicyRule::AutoApproved
+                ])
+            ),
+            EvaluationStatus::Approved
+        );
+        assert_eq!(
+            evaluate_rule(
+                &request,
+                RequestPolicyRule::And(vec![RequestPolicyRule::AutoApproved, time_lock()])
+            ),
+            EvaluationStatus::Approved
+        );
+    }
 
     #[test]
     fn fail_critera_with_non_existent_user_specifier() {
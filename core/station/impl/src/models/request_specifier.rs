@@ -1,7 +1,7 @@
 use super::resource::{Resource, ResourceIds};
 use super::{MetadataItem, Request, RequestId, RequestOperation, RequestOperationType};
 use crate::core::validation::{
-    EnsureAccount, EnsureAddressBookEntry, EnsureIdExists, EnsureRequestPolicy,
+    EnsureAccount, EnsureAddressBookEntry, EnsureAsset, EnsureIdExists, EnsureRequestPolicy,
     EnsureResourceIdExists, EnsureUser, EnsureUserGroup,
 };
 use crate::errors::ValidationError;
@@ -68,6 +68,7 @@ pub enum RequestSpecifier {
     EditAddressBookEntry(ResourceIds),
     RemoveAddressBookEntry(ResourceIds),
     Transfer(ResourceIds),
+    BatchTransfer(ResourceIds),
     ChangeCanister,
     ChangeExternalCanister(ChangeExternalCanisterResourceTarget),
     CreateExternalCanister(CreateExternalCanisterResourceTarget),
@@ -80,6 +81,13 @@ pub enum RequestSpecifier {
     EditUserGroup(ResourceIds),
     RemoveUserGroup(ResourceIds),
     ManageSystemInfo,
+    AddAsset,
+    EditAsset(ResourceIds),
+    RemoveAsset(ResourceIds),
+    AddApprovalDelegation,
+    RemoveUser(ResourceIds),
+    LinkUserIdentity(ResourceIds),
+    ApplyRoleTemplate(ResourceIds),
 }
 
 impl ModelValidator<ValidationError> for RequestSpecifier {
@@ -93,17 +101,22 @@ impl ModelValidator<ValidationError> for RequestSpecifier {
             | RequestSpecifier::CreateExternalCanister(_)
             | RequestSpecifier::AddRequestPolicy
             | RequestSpecifier::ManageSystemInfo
-            | RequestSpecifier::AddUserGroup => (),
+            | RequestSpecifier::AddUserGroup
+            | RequestSpecifier::AddAsset
+            | RequestSpecifier::AddApprovalDelegation => (),
 
             RequestSpecifier::CallExternalCanister(target) => {
                 target.validate()?;
             }
 
             RequestSpecifier::Transfer(resource_ids)
+            | RequestSpecifier::BatchTransfer(resource_ids)
             | RequestSpecifier::EditAccount(resource_ids) => {
                 EnsureAccount::resource_ids_exist(resource_ids)?
             }
-            RequestSpecifier::EditUser(resource_ids) => {
+            RequestSpecifier::EditUser(resource_ids)
+            | RequestSpecifier::RemoveUser(resource_ids)
+            | RequestSpecifier::LinkUserIdentity(resource_ids) => {
                 EnsureUser::resource_ids_exist(resource_ids)?
             }
             RequestSpecifier::RemoveAddressBookEntry(resource_ids)
@@ -120,9 +133,14 @@ impl ModelValidator<ValidationError> for RequestSpecifier {
                 EnsureRequestPolicy::resource_ids_exist(resource_ids)?
             }
             RequestSpecifier::EditUserGroup(resource_ids)
-            | RequestSpecifier::RemoveUserGroup(resource_ids) => {
+            | RequestSpecifier::RemoveUserGroup(resource_ids)
+            | RequestSpecifier::ApplyRoleTemplate(resource_ids) => {
                 EnsureUserGroup::resource_ids_exist(resource_ids)?
             }
+            RequestSpecifier::EditAsset(resource_ids)
+            | RequestSpecifier::RemoveAsset(resource_ids) => {
+                EnsureAsset::resource_ids_exist(resource_ids)?
+            }
         }
         Ok(())
     }
@@ -135,12 +153,15 @@ impl From<&RequestSpecifier> for RequestOperationType {
             RequestSpecifier::AddUser => RequestOperationType::AddUser,
             RequestSpecifier::EditAccount(_) => RequestOperationType::EditAccount,
             RequestSpecifier::EditUser(_) => RequestOperationType::EditUser,
+            RequestSpecifier::RemoveUser(_) => RequestOperationType::RemoveUser,
+            RequestSpecifier::LinkUserIdentity(_) => RequestOperationType::LinkUserIdentity,
             RequestSpecifier::AddAddressBookEntry => RequestOperationType::AddAddressBookEntry,
             RequestSpecifier::EditAddressBookEntry(_) => RequestOperationType::EditAddressBookEntry,
             RequestSpecifier::RemoveAddressBookEntry(_) => {
                 RequestOperationType::RemoveAddressBookEntry
             }
             RequestSpecifier::Transfer(_) => RequestOperationType::Transfer,
+            RequestSpecifier::BatchTransfer(_) => RequestOperationType::BatchTransfer,
             RequestSpecifier::EditPermission(_) => RequestOperationType::EditPermission,
             RequestSpecifier::ChangeCanister => RequestOperationType::ChangeCanister,
             RequestSpecifier::ChangeExternalCanister(_) => {
@@ -156,7 +177,12 @@ impl From<&RequestSpecifier> for RequestOperationType {
             RequestSpecifier::AddUserGroup => RequestOperationType::AddUserGroup,
             RequestSpecifier::EditUserGroup(_) => RequestOperationType::EditUserGroup,
             RequestSpecifier::RemoveUserGroup(_) => RequestOperationType::RemoveUserGroup,
+            RequestSpecifier::ApplyRoleTemplate(_) => RequestOperationType::ApplyRoleTemplate,
             RequestSpecifier::ManageSystemInfo => RequestOperationType::ManageSystemInfo,
+            RequestSpecifier::AddAsset => RequestOperationType::AddAsset,
+            RequestSpecifier::EditAsset(_) => RequestOperationType::EditAsset,
+            RequestSpecifier::RemoveAsset(_) => RequestOperationType::RemoveAsset,
+            RequestSpecifier::AddApprovalDelegation => RequestOperationType::AddApprovalDelegation,
         }
     }
 }
@@ -250,6 +276,31 @@ impl Match<(Request, RequestSpecifier)> for RequestMatcher {
                     user_id: params.input.user_id,
                     request_id: p.id,
                 })?,
+            (RequestOperation::RemoveUser(params), RequestSpecifier::RemoveUser(user)) => self
+                .user_matcher
+                .is_match(UserInvolvedInPolicyRuleForRequestResource {
+                    request_operation_resources: p.operation.to_resources(),
+                    policy_rule_user_specifier: match user {
+                        ResourceIds::Any => UserSpecifier::Any,
+                        ResourceIds::Ids(ids) => UserSpecifier::Id(ids),
+                    },
+                    user_id: params.input.user_id,
+                    request_id: p.id,
+                })?,
+            (
+                RequestOperation::LinkUserIdentity(params),
+                RequestSpecifier::LinkUserIdentity(user),
+            ) => self
+                .user_matcher
+                .is_match(UserInvolvedInPolicyRuleForRequestResource {
+                    request_operation_resources: p.operation.to_resources(),
+                    policy_rule_user_specifier: match user {
+                        ResourceIds::Any => UserSpecifier::Any,
+                        ResourceIds::Ids(ids) => UserSpecifier::Id(ids),
+                    },
+                    user_id: params.input.user_id,
+                    request_id: p.id,
+                })?,
             (RequestOperation::AddAddressBookEntry(_), RequestSpecifier::AddAddressBookEntry) => {
                 true
             }
@@ -272,6 +323,38 @@ impl Match<(Request, RequestSpecifier)> for RequestMatcher {
             (RequestOperation::Transfer(params), RequestSpecifier::Transfer(account)) => self
                 .account_matcher
                 .is_match((p.clone(), params.input.from_account_id, account))?,
+            // allowances grant spending rights on the account, hence they use the transfer policies
+            (RequestOperation::ApproveAllowance(params), RequestSpecifier::Transfer(account)) => {
+                self.account_matcher
+                    .is_match((p.clone(), params.input.from_account_id, account))?
+            }
+            (RequestOperation::RevokeAllowance(params), RequestSpecifier::Transfer(account)) => {
+                self.account_matcher
+                    .is_match((p.clone(), params.input.from_account_id, account))?
+            }
+            // cancelling a standing order stops future transfers of the account
+            (
+                RequestOperation::CancelStandingOrder(params),
+                RequestSpecifier::Transfer(account),
+            ) => {
+                self.account_matcher
+                    .is_match((p.clone(), params.input.from_account_id, account))?
+            }
+            // a batch is only covered by the specifier if all of its source accounts are
+            (RequestOperation::BatchTransfer(params), RequestSpecifier::BatchTransfer(account)) => {
+                let mut is_match = true;
+                for account_id in params.input.from_account_ids() {
+                    if !self
+                        .account_matcher
+                        .is_match((p.clone(), account_id, account.clone()))?
+                    {
+                        is_match = false;
+                        break;
+                    }
+                }
+
+                is_match
+            }
             (RequestOperation::ChangeCanister(_), RequestSpecifier::ChangeCanister) => true,
             (
                 RequestOperation::ChangeExternalCanister(ChangeExternalCanisterOperation {
@@ -337,11 +420,44 @@ impl Match<(Request, RequestSpecifier)> for RequestMatcher {
             ) => self
                 .common_id_matcher
                 .is_match((p, operation.input.user_group_id, specifier))?,
+            (
+                RequestOperation::ApplyRoleTemplate(operation),
+                RequestSpecifier::ApplyRoleTemplate(specifier),
+            ) => self
+                .common_id_matcher
+                .is_match((p, operation.input.user_group_id, specifier))?,
+            (RequestOperation::AddAsset(_), RequestSpecifier::AddAsset) => true,
+            (RequestOperation::EditAsset(operation), RequestSpecifier::EditAsset(specifier)) => {
+                self.common_id_matcher
+                    .is_match((p, operation.input.asset_id, specifier))?
+            }
+            (
+                RequestOperation::RemoveAsset(operation),
+                RequestSpecifier::RemoveAsset(specifier),
+            ) => self
+                .common_id_matcher
+                .is_match((p, operation.input.asset_id, specifier))?,
+            (
+                RequestOperation::AddApprovalDelegation(_),
+                RequestSpecifier::AddApprovalDelegation,
+            ) => true,
+            // bundles match the specifiers of any of their operations
+            (RequestOperation::Bundle(operation), _) => {
+                for member in operation.to_member_requests(&p) {
+                    if self.is_match((member, s.to_owned()))? {
+                        return Ok(true);
+                    }
+                }
+
+                false
+            }
             // this is here to make sure that new operations are not added without updating this
             (RequestOperation::AddAccount(_), _)
             | (RequestOperation::AddUser(_), _)
             | (RequestOperation::EditAccount(_), _)
             | (RequestOperation::EditUser(_), _)
+            | (RequestOperation::RemoveUser(_), _)
+            | (RequestOperation::LinkUserIdentity(_), _)
             | (RequestOperation::AddAddressBookEntry(_), _)
             | (RequestOperation::EditAddressBookEntry(_), _)
             | (RequestOperation::RemoveAddressBookEntry(_), _)
@@ -354,10 +470,19 @@ impl Match<(Request, RequestSpecifier)> for RequestMatcher {
             | (RequestOperation::EditPermission(_), _)
             | (RequestOperation::EditUserGroup(_), _)
             | (RequestOperation::RemoveUserGroup(_), _)
+            | (RequestOperation::ApplyRoleTemplate(_), _)
             | (RequestOperation::RemoveRequestPolicy(_), _)
             | (RequestOperation::AddUserGroup(_), _)
             | (RequestOperation::ManageSystemInfo(_), _)
-            | (RequestOperation::Transfer(_), _) => false,
+            | (RequestOperation::AddAsset(_), _)
+            | (RequestOperation::EditAsset(_), _)
+            | (RequestOperation::RemoveAsset(_), _)
+            | (RequestOperation::ApproveAllowance(_), _)
+            | (RequestOperation::RevokeAllowance(_), _)
+            | (RequestOperation::CancelStandingOrder(_), _)
+            | (RequestOperation::AddApprovalDelegation(_), _)
+            | (RequestOperation::Transfer(_), _)
+            | (RequestOperation::BatchTransfer(_), _) => false,
         })
     }
 }
@@ -387,6 +512,34 @@ impl Match<RequestHasMetadata> for AddressBookMetadataMatcher {
                     false
                 }
             }
+            // every destination of the batch must be tagged with the metadata
+            RequestOperation::BatchTransfer(batch) => {
+                batch.input.transfers.into_iter().all(|transfer| {
+                    match ACCOUNT_SERVICE.get_account(&transfer.from_account_id) {
+                        Ok(account) => ADDRESS_BOOK_REPOSITORY
+                            .find_by_address(account.blockchain, account.standard, transfer.to)
+                            .is_some_and(|address_book_entry| {
+                                address_book_entry.metadata.contains(&metadata)
+                            }),
+                        Err(_) => false,
+                    }
+                })
+            }
+            RequestOperation::ApproveAllowance(approve) => {
+                if let Ok(account) = ACCOUNT_SERVICE.get_account(&approve.input.from_account_id) {
+                    if let Some(address_book_entry) = ADDRESS_BOOK_REPOSITORY.find_by_address(
+                        account.blockchain,
+                        account.standard,
+                        approve.input.spender.to_address(),
+                    ) {
+                        address_book_entry.metadata.contains(&metadata)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            }
             _ => false,
         })
     }
@@ -413,9 +566,10 @@ mod tests {
             },
             system::SystemInfo,
             AddAccountOperation, AddAccountOperationInput, AddUserOperation, AddUserOperationInput,
-            Blockchain, CanisterMethod, EditAccountOperation, EditAccountOperationInput,
-            EditUserOperation, EditUserOperationInput, Metadata, RequestKey, RequestOperation,
-            TransferOperation, TransferOperationInput, UserStatus,
+            BatchTransferOperation, BatchTransferOperationInput, CanisterMethod,
+            EditAccountOperation, EditAccountOperationInput, EditUserOperation,
+            EditUserOperationInput, Metadata, RequestKey, RequestOperation, TransferOperation,
+            TransferOperationInput, UserStatus, ICP_ASSET_ID,
         },
         repositories::REQUEST_REPOSITORY,
     };
@@ -440,8 +594,7 @@ mod tests {
                     account_id: None,
                     input: AddAccountOperationInput {
                         name: "account-1".into(),
-                        blockchain: Blockchain::InternetComputer,
-                        standard: crate::models::BlockchainStandard::Native,
+                        asset_id: *ICP_ASSET_ID,
                         metadata: Metadata::default(),
                         transfer_request_policy: Some(RequestPolicyRule::AutoApproved),
                         configs_request_policy: Some(RequestPolicyRule::AutoApproved),
@@ -520,6 +673,54 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_request_matcher_batch_transfer_requires_all_accounts() {
+        let m = RequestMatcher {
+            account_matcher: Arc::new(AccountMatcher),
+            user_matcher: Arc::new(UserMatcher),
+            common_id_matcher: Arc::new(AccountMatcher),
+        };
+        let transfer = |from_account_id| TransferOperationInput {
+            from_account_id,
+            to: "address-1".into(),
+            amount: Nat::from(1_u64),
+            metadata: Metadata::default(),
+            network: "network-1".into(),
+            fee: None,
+        };
+
+        let mut request = mock_request();
+        request.operation = RequestOperation::BatchTransfer(BatchTransferOperation {
+            transfer_ids: vec![],
+            input: BatchTransferOperationInput {
+                transfers: vec![transfer([0; 16]), transfer([1; 16])],
+            },
+        });
+
+        assert!(m
+            .is_match((
+                request.clone(),
+                RequestSpecifier::BatchTransfer(ResourceIds::Any)
+            ))
+            .unwrap());
+        assert!(m
+            .is_match((
+                request.clone(),
+                RequestSpecifier::BatchTransfer(ResourceIds::Ids(vec![[0; 16], [1; 16]]))
+            ))
+            .unwrap());
+        assert!(!m
+            .is_match((
+                request.clone(),
+                RequestSpecifier::BatchTransfer(ResourceIds::Ids(vec![[0; 16]]))
+            ))
+            .unwrap());
+        // the batch has its own specifier and is not covered by the transfer policies
+        assert!(!m
+            .is_match((request, RequestSpecifier::Transfer(ResourceIds::Any)))
+            .unwrap());
+    }
+
     #[tokio::test]
     async fn test_user_matcher() {
         let m = UserMatcher;
@@ -716,6 +917,12 @@ mod tests {
         RequestSpecifier::AddUserGroup
             .validate()
             .expect("AddUserGroup should be valid");
+        RequestSpecifier::AddAsset
+            .validate()
+            .expect("AddAsset should be valid");
+        RequestSpecifier::AddApprovalDelegation
+            .validate()
+            .expect("AddApprovalDelegation should be valid");
     }
 
     #[test]
@@ -731,6 +938,12 @@ mod tests {
         RequestSpecifier::EditUser(ResourceIds::Ids(vec![[0; 16]]))
             .validate()
             .expect_err("Non existent user ID should be invalid");
+        RequestSpecifier::RemoveUser(ResourceIds::Ids(vec![[0; 16]]))
+            .validate()
+            .expect_err("Non existent user ID should be invalid");
+        RequestSpecifier::LinkUserIdentity(ResourceIds::Ids(vec![[0; 16]]))
+            .validate()
+            .expect_err("Non existent user ID should be invalid");
         RequestSpecifier::EditAddressBookEntry(ResourceIds::Ids(vec![[0; 16]]))
             .validate()
             .expect_err("Non existent address book entry ID should be invalid");
@@ -749,5 +962,14 @@ mod tests {
         RequestSpecifier::RemoveUserGroup(ResourceIds::Ids(vec![[0; 16]]))
             .validate()
             .expect_err("Non existent user group ID should be invalid");
+        RequestSpecifier::ApplyRoleTemplate(ResourceIds::Ids(vec![[0; 16]]))
+            .validate()
+            .expect_err("Non existent user group ID should be invalid");
+        RequestSpecifier::EditAsset(ResourceIds::Ids(vec![[0; 16]]))
+            .validate()
+            .expect_err("Non existent asset ID should be invalid");
+        RequestSpecifier::RemoveAsset(ResourceIds::Ids(vec![[0; 16]]))
+            .validate()
+            .expect_err("Non existent asset ID should be invalid");
     }
 }
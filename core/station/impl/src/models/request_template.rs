@@ -0,0 +1,174 @@
+use super::{AccountId, Metadata, Request};
+use crate::core::validation::{EnsureAccount, EnsureIdExists};
+use crate::errors::RequestTemplateError;
+use orbit_essentials::storable;
+use orbit_essentials::{
+    model::{ModelValidator, ModelValidatorResult},
+    types::{Timestamp, UUID},
+};
+
+/// The request template id, which is a UUID.
+pub type RequestTemplateId = UUID;
+
+/// Represents a stored shape of a request that users can instantiate into a new request.
+///
+/// The fields of the operation that are left open are placeholders that must be provided when
+/// the template is instantiated, the fixed ones keep the created requests consistent.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestTemplate {
+    /// The request template id, which is a UUID.
+    pub id: RequestTemplateId,
+    pub name: String,
+    pub description: Option<String>,
+    /// The title of the created requests, the template name is used if not set.
+    pub title: Option<String>,
+    /// The default summary of the created requests, it can be replaced when instantiating.
+    pub summary: Option<String>,
+    pub operation: RequestTemplateOperation,
+    pub created_timestamp: Timestamp,
+    /// The last time the record was updated or created.
+    pub last_modification_timestamp: Timestamp,
+}
+
+/// The operation shapes that can be stored in a template.
+///
+/// Templates are scoped to transfers, other operations such as canister top-ups are requested
+/// directly until a shape is added for them here and in `to_create_request_input`.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RequestTemplateOperation {
+    Transfer(TransferRequestTemplate),
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferRequestTemplate {
+    pub from_account_id: AccountId,
+    /// The destination address, a placeholder that is provided when instantiating if not set.
+    pub to: Option<String>,
+    /// The amount to transfer, a placeholder that is provided when instantiating if not set.
+    pub amount: Option<candid::Nat>,
+    pub metadata: Metadata,
+}
+
+impl RequestTemplate {
+    pub const NAME_RANGE: (u16, u16) = (1, 100);
+    pub const MAX_DESCRIPTION_LEN: usize = 1000;
+}
+
+fn validate_name(name: &str) -> ModelValidatorResult<RequestTemplateError> {
+    let (min, max) = RequestTemplate::NAME_RANGE;
+    let len = name.trim().len();
+    if len < min as usize || len > max as usize {
+        return Err(RequestTemplateError::ValidationError {
+            info: format!(
+                "The template name must be between {} and {} characters",
+                min, max
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_texts(template: &RequestTemplate) -> ModelValidatorResult<RequestTemplateError> {
+    if let Some(description) = &template.description {
+        if description.len() > RequestTemplate::MAX_DESCRIPTION_LEN {
+            return Err(RequestTemplateError::ValidationError {
+                info: format!(
+                    "The template description exceeds the maximum allowed: {}",
+                    RequestTemplate::MAX_DESCRIPTION_LEN
+                ),
+            });
+        }
+    }
+
+    if let Some(title) = &template.title {
+        if title.len() > Request::MAX_TITLE_LEN as usize {
+            return Err(RequestTemplateError::ValidationError {
+                info: format!(
+                    "The template title exceeds the maximum allowed: {}",
+                    Request::MAX_TITLE_LEN
+                ),
+            });
+        }
+    }
+
+    if let Some(summary) = &template.summary {
+        if summary.len() > Request::MAX_SUMMARY_LEN as usize {
+            return Err(RequestTemplateError::ValidationError {
+                info: format!(
+                    "The template summary exceeds the maximum allowed: {}",
+                    Request::MAX_SUMMARY_LEN
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+impl ModelValidator<RequestTemplateError> for RequestTemplate {
+    fn validate(&self) -> ModelValidatorResult<RequestTemplateError> {
+        validate_name(&self.name)?;
+        validate_texts(self)?;
+
+        match &self.operation {
+            RequestTemplateOperation::Transfer(transfer) => {
+                EnsureAccount::id_exists(&transfer.from_account_id)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::request_template_test_utils::mock_request_template;
+    use super::*;
+
+    #[test]
+    fn fail_invalid_template_name() {
+        assert!(validate_name("").is_err());
+        assert!(validate_name(&"a".repeat(101)).is_err());
+        assert!(validate_name("Vendor payment").is_ok());
+    }
+
+    #[test]
+    fn fail_template_title_too_long() {
+        let mut template = mock_request_template();
+        template.title = Some("a".repeat(Request::MAX_TITLE_LEN as usize + 1));
+
+        assert!(validate_texts(&template).is_err());
+
+        template.title = Some("Pay the vendor".to_string());
+
+        assert!(validate_texts(&template).is_ok());
+    }
+}
+
+#[cfg(test)]
+pub mod request_template_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_request_template() -> RequestTemplate {
+        RequestTemplate {
+            id: *Uuid::new_v4().as_bytes(),
+            name: "Vendor payment".to_string(),
+            description: None,
+            title: None,
+            summary: None,
+            operation: RequestTemplateOperation::Transfer(TransferRequestTemplate {
+                from_account_id: *Uuid::new_v4().as_bytes(),
+                to: None,
+                amount: None,
+                metadata: Metadata::default(),
+            }),
+            created_timestamp: 0,
+            last_modification_timestamp: 0,
+        }
+    }
+}
@@ -9,8 +9,8 @@ use uuid::Uuid;
 
 use crate::{
     core::validation::{
-        EnsureAccount, EnsureAddressBookEntry, EnsureRequest, EnsureRequestPolicy,
-        EnsureResourceIdExists, EnsureUser, EnsureUserGroup,
+        EnsureAccount, EnsureAddressBookEntry, EnsureAsset, EnsureRequest, EnsureRequestPolicy,
+        EnsureRequestTemplate, EnsureResourceIdExists, EnsureUser, EnsureUserGroup,
     },
     errors::ValidationError,
     models::CanisterMethod,
@@ -22,10 +22,12 @@ pub enum Resource {
     Permission(PermissionResourceAction),
     Account(AccountResourceAction),
     AddressBook(ResourceAction),
+    Asset(ResourceAction),
     ChangeCanister(ChangeCanisterResourceAction),
     ExternalCanister(ExternalCanisterResourceAction),
     Request(RequestResourceAction),
     RequestPolicy(ResourceAction),
+    RequestTemplate(ResourceAction),
     System(SystemResourceAction),
     User(UserResourceAction),
     UserGroup(ResourceAction),
@@ -54,6 +56,14 @@ impl ModelValidator<ValidationError> for Resource {
                     EnsureAddressBookEntry::resource_id_exists(resource_id)?
                 }
             },
+            Resource::Asset(action) => match action {
+                ResourceAction::List | ResourceAction::Create => (),
+                ResourceAction::Read(resource_id)
+                | ResourceAction::Update(resource_id)
+                | ResourceAction::Delete(resource_id) => {
+                    EnsureAsset::resource_id_exists(resource_id)?
+                }
+            },
             Resource::ChangeCanister(action) => match action {
                 ChangeCanisterResourceAction::Create => (),
             },
@@ -65,7 +75,8 @@ impl ModelValidator<ValidationError> for Resource {
             },
             Resource::Request(action) => match action {
                 RequestResourceAction::List => (),
-                RequestResourceAction::Read(resource_id) => {
+                RequestResourceAction::Read(resource_id)
+                | RequestResourceAction::Cancel(resource_id) => {
                     EnsureRequest::resource_id_exists(resource_id)?
                 }
             },
@@ -77,6 +88,14 @@ impl ModelValidator<ValidationError> for Resource {
                     EnsureRequestPolicy::resource_id_exists(resource_id)?
                 }
             },
+            Resource::RequestTemplate(action) => match action {
+                ResourceAction::List | ResourceAction::Create => (),
+                ResourceAction::Read(resource_id)
+                | ResourceAction::Update(resource_id)
+                | ResourceAction::Delete(resource_id) => {
+                    EnsureRequestTemplate::resource_id_exists(resource_id)?
+                }
+            },
             Resource::System(action) => match action {
                 SystemResourceAction::SystemInfo
                 | SystemResourceAction::Capabilities
@@ -84,7 +103,10 @@ impl ModelValidator<ValidationError> for Resource {
             },
             Resource::User(action) => match action {
                 UserResourceAction::List | UserResourceAction::Create => (),
-                UserResourceAction::Read(resource_id) | UserResourceAction::Update(resource_id) => {
+                UserResourceAction::Read(resource_id)
+                | UserResourceAction::Update(resource_id)
+                | UserResourceAction::Remove(resource_id)
+                | UserResourceAction::Delegate(resource_id) => {
                     EnsureUser::resource_id_exists(resource_id)?
                 }
             },
@@ -125,6 +147,10 @@ pub enum UserResourceAction {
     Create,
     Read(ResourceId),
     Update(ResourceId),
+    /// Delegating the approval rights of the user to another user.
+    Delegate(ResourceId),
+    /// Removing the user, which archives it and strips it from permissions and policies.
+    Remove(ResourceId),
 }
 
 #[storable]
@@ -237,6 +263,7 @@ impl ModelValidator<ValidationError> for CallExternalCanisterResourceTarget {
 pub enum RequestResourceAction {
     List,
     Read(ResourceId),
+    Cancel(ResourceId),
 }
 
 #[storable]
@@ -362,6 +389,37 @@ impl Resource {
                     ))]
                 }
             },
+            Resource::Asset(action) => match action {
+                ResourceAction::Create => vec![Resource::Asset(ResourceAction::Create)],
+                ResourceAction::Delete(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::Asset(ResourceAction::Delete(ResourceId::Id(*id))),
+                        Resource::Asset(ResourceAction::Delete(ResourceId::Any)),
+                    ]
+                }
+                ResourceAction::List => vec![Resource::Asset(ResourceAction::List)],
+                ResourceAction::Read(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::Asset(ResourceAction::Read(ResourceId::Id(*id))),
+                        Resource::Asset(ResourceAction::Read(ResourceId::Any)),
+                    ]
+                }
+                ResourceAction::Update(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::Asset(ResourceAction::Update(ResourceId::Id(*id))),
+                        Resource::Asset(ResourceAction::Update(ResourceId::Any)),
+                    ]
+                }
+                ResourceAction::Read(ResourceId::Any) => {
+                    vec![Resource::Asset(ResourceAction::Read(ResourceId::Any))]
+                }
+                ResourceAction::Update(ResourceId::Any) => {
+                    vec![Resource::Asset(ResourceAction::Update(ResourceId::Any))]
+                }
+                ResourceAction::Delete(ResourceId::Any) => {
+                    vec![Resource::Asset(ResourceAction::Delete(ResourceId::Any))]
+                }
+            },
             Resource::ChangeCanister(action) => match action {
                 ChangeCanisterResourceAction::Create => {
                     vec![Resource::ChangeCanister(
@@ -466,6 +524,17 @@ impl Resource {
                         ResourceId::Any,
                     ))]
                 }
+                RequestResourceAction::Cancel(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::Request(RequestResourceAction::Cancel(ResourceId::Id(*id))),
+                        Resource::Request(RequestResourceAction::Cancel(ResourceId::Any)),
+                    ]
+                }
+                RequestResourceAction::Cancel(ResourceId::Any) => {
+                    vec![Resource::Request(RequestResourceAction::Cancel(
+                        ResourceId::Any,
+                    ))]
+                }
             },
             Resource::RequestPolicy(action) => match action {
                 ResourceAction::Create => vec![Resource::RequestPolicy(ResourceAction::Create)],
@@ -504,6 +573,43 @@ impl Resource {
                     ))]
                 }
             },
+            Resource::RequestTemplate(action) => match action {
+                ResourceAction::Create => vec![Resource::RequestTemplate(ResourceAction::Create)],
+                ResourceAction::Delete(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::RequestTemplate(ResourceAction::Delete(ResourceId::Id(*id))),
+                        Resource::RequestTemplate(ResourceAction::Delete(ResourceId::Any)),
+                    ]
+                }
+                ResourceAction::List => vec![Resource::RequestTemplate(ResourceAction::List)],
+                ResourceAction::Read(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::RequestTemplate(ResourceAction::Read(ResourceId::Id(*id))),
+                        Resource::RequestTemplate(ResourceAction::Read(ResourceId::Any)),
+                    ]
+                }
+                ResourceAction::Update(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::RequestTemplate(ResourceAction::Update(ResourceId::Id(*id))),
+                        Resource::RequestTemplate(ResourceAction::Update(ResourceId::Any)),
+                    ]
+                }
+                ResourceAction::Read(ResourceId::Any) => {
+                    vec![Resource::RequestTemplate(ResourceAction::Read(
+                        ResourceId::Any,
+                    ))]
+                }
+                ResourceAction::Update(ResourceId::Any) => {
+                    vec![Resource::RequestTemplate(ResourceAction::Update(
+                        ResourceId::Any,
+                    ))]
+                }
+                ResourceAction::Delete(ResourceId::Any) => {
+                    vec![Resource::RequestTemplate(ResourceAction::Delete(
+                        ResourceId::Any,
+                    ))]
+                }
+            },
             Resource::System(action) => match action {
                 SystemResourceAction::SystemInfo => {
                     vec![Resource::System(SystemResourceAction::SystemInfo)]
@@ -536,6 +642,26 @@ impl Resource {
                 UserResourceAction::Update(ResourceId::Any) => {
                     vec![Resource::User(UserResourceAction::Update(ResourceId::Any))]
                 }
+                UserResourceAction::Remove(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::User(UserResourceAction::Remove(ResourceId::Id(*id))),
+                        Resource::User(UserResourceAction::Remove(ResourceId::Any)),
+                    ]
+                }
+                UserResourceAction::Remove(ResourceId::Any) => {
+                    vec![Resource::User(UserResourceAction::Remove(ResourceId::Any))]
+                }
+                UserResourceAction::Delegate(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::User(UserResourceAction::Delegate(ResourceId::Id(*id))),
+                        Resource::User(UserResourceAction::Delegate(ResourceId::Any)),
+                    ]
+                }
+                UserResourceAction::Delegate(ResourceId::Any) => {
+                    vec![Resource::User(UserResourceAction::Delegate(
+                        ResourceId::Any,
+                    ))]
+                }
             },
             Resource::UserGroup(action) => match action {
                 ResourceAction::Create => vec![Resource::UserGroup(ResourceAction::Create)],
@@ -578,12 +704,14 @@ impl Display for Resource {
             Resource::Permission(action) => write!(f, "Permission({})", action),
             Resource::Account(action) => write!(f, "Account({})", action),
             Resource::AddressBook(action) => write!(f, "AddressBook({})", action),
+            Resource::Asset(action) => write!(f, "Asset({})", action),
             Resource::ChangeCanister(action) => write!(f, "ChangeCanister({})", action),
             Resource::ExternalCanister(action) => {
                 write!(f, "ExternalCanister({})", action)
             }
             Resource::Request(action) => write!(f, "Request({})", action),
             Resource::RequestPolicy(action) => write!(f, "RequestPolicy({})", action),
+            Resource::RequestTemplate(action) => write!(f, "RequestTemplate({})", action),
             Resource::System(action) => write!(f, "System({})", action),
             Resource::User(action) => write!(f, "User({})", action),
             Resource::UserGroup(action) => write!(f, "UserGroup({})", action),
@@ -728,6 +856,7 @@ impl Display for RequestResourceAction {
         match self {
             RequestResourceAction::List => write!(f, "List"),
             RequestResourceAction::Read(id) => write!(f, "Read({})", id),
+            RequestResourceAction::Cancel(id) => write!(f, "Cancel({})", id),
         }
     }
 }
@@ -749,6 +878,8 @@ impl Display for UserResourceAction {
             UserResourceAction::Create => write!(f, "Create"),
             UserResourceAction::Read(id) => write!(f, "Read({})", id),
             UserResourceAction::Update(id) => write!(f, "Update({})", id),
+            UserResourceAction::Remove(id) => write!(f, "Remove({})", id),
+            UserResourceAction::Delegate(id) => write!(f, "Delegate({})", id),
         }
     }
 }
@@ -795,6 +926,11 @@ mod test {
             Resource::AddressBook(ResourceAction::Read(ResourceId::Any)),
             Resource::AddressBook(ResourceAction::Update(ResourceId::Any)),
             Resource::AddressBook(ResourceAction::Delete(ResourceId::Any)),
+            Resource::Asset(ResourceAction::List),
+            Resource::Asset(ResourceAction::Create),
+            Resource::Asset(ResourceAction::Read(ResourceId::Any)),
+            Resource::Asset(ResourceAction::Update(ResourceId::Any)),
+            Resource::Asset(ResourceAction::Delete(ResourceId::Any)),
             Resource::ChangeCanister(ChangeCanisterResourceAction::Create),
             Resource::ExternalCanister(ExternalCanisterResourceAction::Create(
                 CreateExternalCanisterResourceTarget::Any,
@@ -813,17 +949,25 @@ mod test {
             )),
             Resource::Request(RequestResourceAction::List),
             Resource::Request(RequestResourceAction::Read(ResourceId::Any)),
+            Resource::Request(RequestResourceAction::Cancel(ResourceId::Any)),
             Resource::RequestPolicy(ResourceAction::List),
             Resource::RequestPolicy(ResourceAction::Create),
             Resource::RequestPolicy(ResourceAction::Read(ResourceId::Any)),
             Resource::RequestPolicy(ResourceAction::Update(ResourceId::Any)),
             Resource::RequestPolicy(ResourceAction::Delete(ResourceId::Any)),
+            Resource::RequestTemplate(ResourceAction::List),
+            Resource::RequestTemplate(ResourceAction::Create),
+            Resource::RequestTemplate(ResourceAction::Read(ResourceId::Any)),
+            Resource::RequestTemplate(ResourceAction::Update(ResourceId::Any)),
+            Resource::RequestTemplate(ResourceAction::Delete(ResourceId::Any)),
             Resource::System(SystemResourceAction::SystemInfo),
             Resource::System(SystemResourceAction::Capabilities),
             Resource::User(UserResourceAction::List),
             Resource::User(UserResourceAction::Create),
             Resource::User(UserResourceAction::Read(ResourceId::Any)),
             Resource::User(UserResourceAction::Update(ResourceId::Any)),
+            Resource::User(UserResourceAction::Remove(ResourceId::Any)),
+            Resource::User(UserResourceAction::Delegate(ResourceId::Any)),
             Resource::UserGroup(ResourceAction::List),
             Resource::UserGroup(ResourceAction::Create),
             Resource::UserGroup(ResourceAction::Read(ResourceId::Any)),
@@ -849,12 +993,21 @@ mod test {
             Resource::AddressBook(ResourceAction::Read(ResourceId::Id([0; 16]))),
             Resource::AddressBook(ResourceAction::Update(ResourceId::Id([0; 16]))),
             Resource::AddressBook(ResourceAction::Delete(ResourceId::Id([0; 16]))),
+            Resource::Asset(ResourceAction::Read(ResourceId::Id([0; 16]))),
+            Resource::Asset(ResourceAction::Update(ResourceId::Id([0; 16]))),
+            Resource::Asset(ResourceAction::Delete(ResourceId::Id([0; 16]))),
             Resource::Request(RequestResourceAction::Read(ResourceId::Id([0; 16]))),
+            Resource::Request(RequestResourceAction::Cancel(ResourceId::Id([0; 16]))),
             Resource::RequestPolicy(ResourceAction::Read(ResourceId::Id([0; 16]))),
             Resource::RequestPolicy(ResourceAction::Update(ResourceId::Id([0; 16]))),
             Resource::RequestPolicy(ResourceAction::Delete(ResourceId::Id([0; 16]))),
+            Resource::RequestTemplate(ResourceAction::Read(ResourceId::Id([0; 16]))),
+            Resource::RequestTemplate(ResourceAction::Update(ResourceId::Id([0; 16]))),
+            Resource::RequestTemplate(ResourceAction::Delete(ResourceId::Id([0; 16]))),
             Resource::User(UserResourceAction::Read(ResourceId::Id([0; 16]))),
             Resource::User(UserResourceAction::Update(ResourceId::Id([0; 16]))),
+            Resource::User(UserResourceAction::Remove(ResourceId::Id([0; 16]))),
+            Resource::User(UserResourceAction::Delegate(ResourceId::Id([0; 16]))),
             Resource::UserGroup(ResourceAction::Read(ResourceId::Id([0; 16]))),
             Resource::UserGroup(ResourceAction::Update(ResourceId::Id([0; 16]))),
             Resource::UserGroup(ResourceAction::Delete(ResourceId::Id([0; 16]))),
@@ -0,0 +1,165 @@
+use super::{
+    request_policy_rule::RequestPolicyRule,
+    request_specifier::{RequestSpecifier, UserSpecifier},
+    resource::{
+        AccountResourceAction, PermissionResourceAction, RequestResourceAction, Resource,
+        ResourceAction, ResourceId, ResourceIds, SystemResourceAction, UserResourceAction,
+    },
+    DraftRequestPolicy, RequestPolicyLintWarning, UserGroupId, ADMIN_GROUP_ID,
+};
+use crate::core::init::{DEFAULT_PERMISSIONS, DEFAULT_REQUEST_POLICIES};
+use orbit_essentials::storable;
+
+/// A predefined role that expands into the permissions and request policies of a user group.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RoleTemplate {
+    Viewer,
+    Operator,
+    Treasurer,
+    Auditor,
+    Admin,
+}
+
+/// The changes that applying a role template makes for a user group.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoleTemplateDiff {
+    pub added_permissions: Vec<Resource>,
+    pub unchanged_permissions: Vec<Resource>,
+    pub added_request_policies: Vec<RequestSpecifier>,
+    pub unchanged_request_policies: Vec<RequestSpecifier>,
+    /// The requests whose policies list their approvers by id, the group can't be added to them.
+    pub skipped_request_policies: Vec<RequestSpecifier>,
+    /// The policies that make the group an approver, the ones with an id replace the stored policy.
+    pub request_policy_changes: Vec<DraftRequestPolicy>,
+    /// The lint warnings of the changed policies.
+    pub warnings: Vec<RequestPolicyLintWarning>,
+}
+
+impl RoleTemplate {
+    /// The resources that the template grants access to.
+    pub fn permissions(&self) -> Vec<Resource> {
+        let mut permissions = match self {
+            RoleTemplate::Admin => {
+                return DEFAULT_PERMISSIONS
+                    .iter()
+                    .filter(|(allow, _)| allow.user_groups.contains(ADMIN_GROUP_ID))
+                    .map(|(_, resource)| resource.clone())
+                    .collect();
+            }
+            _ => viewer_permissions(),
+        };
+
+        match self {
+            RoleTemplate::Operator => permissions.extend([
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+                Resource::AddressBook(ResourceAction::Create),
+                Resource::AddressBook(ResourceAction::Update(ResourceId::Any)),
+                Resource::RequestTemplate(ResourceAction::List),
+                Resource::RequestTemplate(ResourceAction::Read(ResourceId::Any)),
+                Resource::RequestTemplate(ResourceAction::Create),
+            ]),
+            RoleTemplate::Treasurer => permissions.extend([
+                Resource::Account(AccountResourceAction::Create),
+                Resource::Account(AccountResourceAction::Update(ResourceId::Any)),
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+            ]),
+            RoleTemplate::Auditor => permissions.extend([
+                Resource::Permission(PermissionResourceAction::Read),
+                Resource::RequestPolicy(ResourceAction::List),
+                Resource::RequestPolicy(ResourceAction::Read(ResourceId::Any)),
+                Resource::RequestTemplate(ResourceAction::List),
+                Resource::RequestTemplate(ResourceAction::Read(ResourceId::Any)),
+            ]),
+            RoleTemplate::Viewer | RoleTemplate::Admin => (),
+        }
+
+        permissions
+    }
+
+    /// The requests that the members of the group approve once the template is applied.
+    pub fn request_specifiers(&self) -> Vec<RequestSpecifier> {
+        match self {
+            RoleTemplate::Treasurer => vec![
+                RequestSpecifier::AddAccount,
+                RequestSpecifier::EditAccount(ResourceIds::Any),
+                RequestSpecifier::Transfer(ResourceIds::Any),
+                RequestSpecifier::BatchTransfer(ResourceIds::Any),
+            ],
+            RoleTemplate::Admin => DEFAULT_REQUEST_POLICIES
+                .iter()
+                .filter(|(_, rule)| {
+                    matches!(
+                        rule,
+                        RequestPolicyRule::Quorum(UserSpecifier::Group(groups), _)
+                            if groups.contains(ADMIN_GROUP_ID)
+                    )
+                })
+                .map(|(specifier, _)| specifier.clone())
+                .collect(),
+            RoleTemplate::Viewer | RoleTemplate::Operator | RoleTemplate::Auditor => Vec::new(),
+        }
+    }
+
+    /// The rule of the request policies that the template adds for the requests without a policy,
+    /// any member of the group can approve.
+    pub fn request_policy_rule(user_group_id: &UserGroupId) -> RequestPolicyRule {
+        RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*user_group_id]), 1)
+    }
+}
+
+fn viewer_permissions() -> Vec<Resource> {
+    vec![
+        Resource::Account(AccountResourceAction::List),
+        Resource::Account(AccountResourceAction::Read(ResourceId::Any)),
+        Resource::AddressBook(ResourceAction::List),
+        Resource::AddressBook(ResourceAction::Read(ResourceId::Any)),
+        Resource::Asset(ResourceAction::List),
+        Resource::Asset(ResourceAction::Read(ResourceId::Any)),
+        Resource::Request(RequestResourceAction::List),
+        Resource::Request(RequestResourceAction::Read(ResourceId::Any)),
+        Resource::User(UserResourceAction::List),
+        Resource::User(UserResourceAction::Read(ResourceId::Any)),
+        Resource::UserGroup(ResourceAction::List),
+        Resource::UserGroup(ResourceAction::Read(ResourceId::Any)),
+        Resource::System(SystemResourceAction::SystemInfo),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn templates_extend_viewer_permissions() {
+        let viewer = RoleTemplate::Viewer.permissions();
+
+        for template in [
+            RoleTemplate::Operator,
+            RoleTemplate::Treasurer,
+            RoleTemplate::Auditor,
+        ] {
+            let permissions = template.permissions();
+
+            assert!(viewer.iter().all(|resource| permissions.contains(resource)));
+            assert_eq!(
+                permissions.iter().collect::<HashSet<_>>().len(),
+                permissions.len()
+            );
+        }
+    }
+
+    #[test]
+    fn admin_template_matches_default_admin_group() {
+        assert!(RoleTemplate::Admin
+            .permissions()
+            .contains(&Resource::Permission(PermissionResourceAction::Update)));
+        assert!(RoleTemplate::Admin
+            .request_specifiers()
+            .contains(&RequestSpecifier::ChangeCanister));
+        assert!(!RoleTemplate::Admin
+            .request_specifiers()
+            .contains(&RequestSpecifier::LinkUserIdentity(ResourceIds::Any)));
+    }
+}
@@ -0,0 +1,388 @@
+use super::{AccountId, RequestId, TransferId};
+use crate::errors::StandingOrderError;
+use orbit_essentials::storable;
+use orbit_essentials::{
+    model::{ModelValidator, ModelValidatorResult},
+    types::Timestamp,
+    utils::add_months_to_timestamp,
+};
+
+const NANOS_PER_MINUTE: u64 = 60 * 1_000_000_000;
+
+/// The interval between two executions of a recurring request.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RecurrenceInterval {
+    Minutes(u32),
+    Hours(u32),
+    Days(u32),
+    Weeks(u32),
+    /// Calendar months, executions that fall on a day that does not exist in the month are
+    /// moved to the last day of that month.
+    Months(u32),
+}
+
+impl RecurrenceInterval {
+    /// Returns the fixed duration of the interval in nanoseconds, calendar months have none.
+    fn fixed_duration_ns(&self) -> Option<u64> {
+        let minutes = match self {
+            RecurrenceInterval::Minutes(every) => *every as u64,
+            RecurrenceInterval::Hours(every) => *every as u64 * 60,
+            RecurrenceInterval::Days(every) => *every as u64 * 60 * 24,
+            RecurrenceInterval::Weeks(every) => *every as u64 * 60 * 24 * 7,
+            RecurrenceInterval::Months(_) => return None,
+        };
+
+        Some(minutes.saturating_mul(NANOS_PER_MINUTE))
+    }
+
+    fn every(&self) -> u32 {
+        match self {
+            RecurrenceInterval::Minutes(every)
+            | RecurrenceInterval::Hours(every)
+            | RecurrenceInterval::Days(every)
+            | RecurrenceInterval::Weeks(every)
+            | RecurrenceInterval::Months(every) => *every,
+        }
+    }
+}
+
+/// The plan of a request that is executed repeatedly once approved, e.g. a standing order.
+///
+/// Executions happen at `start_at + n * interval` until the end date or the maximum number of
+/// occurrences is reached, the plan runs forever if neither is set.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RecurringExecutionPlan {
+    pub interval: RecurrenceInterval,
+    /// The time of the first execution.
+    pub start_at: Timestamp,
+    /// No executions happen after this time, if set.
+    pub end_at: Option<Timestamp>,
+    /// The maximum number of executions, if set.
+    pub max_occurrences: Option<u32>,
+}
+
+impl RecurringExecutionPlan {
+    /// The longest a calendar month can be, used to estimate the occurrence of a given time.
+    const MAX_MONTH_DURATION_NS: u64 = 31 * 24 * 60 * NANOS_PER_MINUTE;
+    /// The longest interval between two executions, which is ten years.
+    pub const MAX_INTERVAL_NS: u64 = 10 * 366 * 24 * 60 * NANOS_PER_MINUTE;
+    /// The longest interval between two executions of a monthly plan, which is ten years.
+    pub const MAX_INTERVAL_MONTHS: u32 = 10 * 12;
+    /// The maximum number of executions a plan can be limited to.
+    pub const MAX_OCCURRENCES: u32 = 10_000;
+
+    /// Returns the execution time of the given occurrence (zero based), if it is part of the plan.
+    pub fn occurrence_at(&self, occurrence: u32) -> Option<Timestamp> {
+        if self
+            .max_occurrences
+            .is_some_and(|max_occurrences| occurrence >= max_occurrences)
+        {
+            return None;
+        }
+
+        let execution_time = match self.interval.fixed_duration_ns() {
+            Some(duration_ns) => self
+                .start_at
+                .checked_add(duration_ns.checked_mul(occurrence as u64)?)?,
+            None => add_months_to_timestamp(
+                &self.start_at,
+                self.interval.every().checked_mul(occurrence)?,
+            )?,
+        };
+
+        if self.end_at.is_some_and(|end_at| execution_time > end_at) {
+            return None;
+        }
+
+        Some(execution_time)
+    }
+
+    /// Returns the first occurrence from the given one that is not scheduled before `not_before`,
+    /// together with its execution time.
+    ///
+    /// Occurrences that were missed, e.g. because the plan was approved after its start, are
+    /// skipped.
+    pub fn next_occurrence(&self, from: u32, not_before: Timestamp) -> Option<(u32, Timestamp)> {
+        let elapsed_ns = not_before.saturating_sub(self.start_at);
+        let estimated_occurrence = match self.interval.fixed_duration_ns() {
+            Some(duration_ns) => elapsed_ns.div_ceil(duration_ns),
+            // calendar months are at most 31 days long, so this never overshoots
+            None => {
+                elapsed_ns
+                    / (Self::MAX_MONTH_DURATION_NS.saturating_mul(self.interval.every() as u64))
+            }
+        };
+
+        let mut occurrence = from.max(u32::try_from(estimated_occurrence).ok()?);
+        loop {
+            let execution_time = self.occurrence_at(occurrence)?;
+            if execution_time >= not_before {
+                return Some((occurrence, execution_time));
+            }
+
+            occurrence = occurrence.checked_add(1)?;
+        }
+    }
+}
+
+impl ModelValidator<StandingOrderError> for RecurringExecutionPlan {
+    fn validate(&self) -> ModelValidatorResult<StandingOrderError> {
+        if self.interval.every() == 0 {
+            return Err(StandingOrderError::ValidationError {
+                info: "The recurrence interval must be greater than zero".to_string(),
+            });
+        }
+
+        let interval_too_long = match self.interval.fixed_duration_ns() {
+            Some(interval_ns) => interval_ns > RecurringExecutionPlan::MAX_INTERVAL_NS,
+            None => self.interval.every() > RecurringExecutionPlan::MAX_INTERVAL_MONTHS,
+        };
+        if interval_too_long {
+            return Err(StandingOrderError::ValidationError {
+                info: "The recurrence interval must not be longer than ten years".to_string(),
+            });
+        }
+
+        if self.max_occurrences.is_some_and(|max_occurrences| {
+            max_occurrences == 0 || max_occurrences > RecurringExecutionPlan::MAX_OCCURRENCES
+        }) {
+            return Err(StandingOrderError::ValidationError {
+                info: format!(
+                    "The maximum number of occurrences must be between 1 and {}",
+                    RecurringExecutionPlan::MAX_OCCURRENCES
+                ),
+            });
+        }
+
+        if self.end_at.is_some_and(|end_at| end_at < self.start_at) {
+            return Err(StandingOrderError::ValidationError {
+                info: "The end of the recurrence must not be before its start".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum StandingOrderStatus {
+    Active,
+    /// All the occurrences of the plan were executed.
+    Finished {
+        finished_at: Timestamp,
+    },
+    Cancelled {
+        cancelled_at: Timestamp,
+        /// The request that cancelled the standing order.
+        cancelled_by_request_id: RequestId,
+    },
+}
+
+/// An occurrence of a standing order that was skipped because its transfer could not be created.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StandingOrderFailure {
+    pub occurrence: u32,
+    pub reason: String,
+    pub failed_at: Timestamp,
+}
+
+/// A recurring transfer that was approved through a request with a recurring execution plan.
+///
+/// Each occurrence creates a new transfer that references the approved request, the request itself
+/// stays in processing until the standing order is finished or cancelled.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StandingOrder {
+    /// The approved request, which is also the id of the standing order.
+    pub request_id: RequestId,
+    /// The account that the transfers are made from.
+    pub account_id: AccountId,
+    pub plan: RecurringExecutionPlan,
+    /// The occurrence that is executed next, if any.
+    pub next_occurrence: Option<u32>,
+    /// The execution time of the next occurrence, if any.
+    pub next_execution_at: Option<Timestamp>,
+    /// The transfers created by the standing order, in order of execution.
+    pub transfer_ids: Vec<TransferId>,
+    /// The last occurrence whose transfer could not be created, if any.
+    pub last_failure: Option<StandingOrderFailure>,
+    pub status: StandingOrderStatus,
+    pub created_timestamp: Timestamp,
+    /// The last time the record was updated or created.
+    pub last_modification_timestamp: Timestamp,
+}
+
+impl StandingOrder {
+    pub fn is_active(&self) -> bool {
+        matches!(self.status, StandingOrderStatus::Active)
+    }
+}
+
+/// A future execution of a standing order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpcomingExecution {
+    pub request_id: RequestId,
+    pub occurrence: u32,
+    pub execution_time: Timestamp,
+    pub from_account_id: AccountId,
+    pub to: String,
+    pub amount: candid::Nat,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use orbit_essentials::utils::{rfc3339_to_timestamp, timestamp_to_rfc3339};
+
+    fn plan(interval: RecurrenceInterval, start_at: &str) -> RecurringExecutionPlan {
+        RecurringExecutionPlan {
+            interval,
+            start_at: rfc3339_to_timestamp(start_at),
+            end_at: None,
+            max_occurrences: None,
+        }
+    }
+
+    #[test]
+    fn computes_fixed_interval_occurrences() {
+        let plan = plan(RecurrenceInterval::Days(2), "2024-01-01T00:00:00Z");
+
+        assert_eq!(
+            timestamp_to_rfc3339(&plan.occurrence_at(0).unwrap()),
+            "2024-01-01T00:00:00Z"
+        );
+        assert_eq!(
+            timestamp_to_rfc3339(&plan.occurrence_at(3).unwrap()),
+            "2024-01-07T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn computes_monthly_occurrences_from_the_start() {
+        let plan = plan(RecurrenceInterval::Months(1), "2024-01-31T09:00:00Z");
+
+        assert_eq!(
+            timestamp_to_rfc3339(&plan.occurrence_at(1).unwrap()),
+            "2024-02-29T09:00:00Z"
+        );
+        // the day does not drift after a shorter month
+        assert_eq!(
+            timestamp_to_rfc3339(&plan.occurrence_at(2).unwrap()),
+            "2024-03-31T09:00:00Z"
+        );
+    }
+
+    #[test]
+    fn stops_at_end_or_max_occurrences() {
+        let mut plan = plan(RecurrenceInterval::Weeks(1), "2024-01-01T00:00:00Z");
+        plan.max_occurrences = Some(2);
+
+        assert!(plan.occurrence_at(1).is_some());
+        assert!(plan.occurrence_at(2).is_none());
+
+        plan.max_occurrences = None;
+        plan.end_at = Some(rfc3339_to_timestamp("2024-01-15T00:00:00Z"));
+
+        assert!(plan.occurrence_at(2).is_some());
+        assert!(plan.occurrence_at(3).is_none());
+    }
+
+    #[test]
+    fn next_occurrence_skips_missed_executions() {
+        let plan = plan(RecurrenceInterval::Months(1), "2024-01-15T00:00:00Z");
+
+        let (occurrence, execution_time) = plan
+            .next_occurrence(0, rfc3339_to_timestamp("2024-05-20T00:00:00Z"))
+            .unwrap();
+
+        assert_eq!(occurrence, 5);
+        assert_eq!(
+            timestamp_to_rfc3339(&execution_time),
+            "2024-06-15T00:00:00Z"
+        );
+
+        let (occurrence, _) = plan
+            .next_occurrence(7, rfc3339_to_timestamp("2024-05-20T00:00:00Z"))
+            .unwrap();
+
+        assert_eq!(occurrence, 7);
+    }
+
+    #[test]
+    fn fail_invalid_plan() {
+        let mut plan = plan(RecurrenceInterval::Hours(0), "2024-01-01T00:00:00Z");
+
+        assert!(plan.validate().is_err());
+
+        plan.interval = RecurrenceInterval::Hours(1);
+        plan.max_occurrences = Some(0);
+
+        assert!(plan.validate().is_err());
+
+        plan.max_occurrences = None;
+        plan.end_at = Some(plan.start_at - 1);
+
+        assert!(plan.validate().is_err());
+
+        plan.end_at = Some(plan.start_at);
+
+        assert!(plan.validate().is_ok());
+    }
+
+    #[test]
+    fn fail_plan_over_limits() {
+        let mut plan = plan(RecurrenceInterval::Months(121), "2024-01-01T00:00:00Z");
+
+        assert!(plan.validate().is_err());
+
+        plan.interval = RecurrenceInterval::Minutes(u32::MAX);
+
+        assert!(plan.validate().is_err());
+
+        plan.interval = RecurrenceInterval::Months(120);
+
+        assert!(plan.validate().is_ok());
+
+        plan.max_occurrences = Some(RecurringExecutionPlan::MAX_OCCURRENCES + 1);
+
+        assert!(plan.validate().is_err());
+    }
+
+    #[test]
+    fn occurrences_past_the_supported_dates_end_the_plan() {
+        let plan = plan(RecurrenceInterval::Months(120), "2024-01-01T00:00:00Z");
+
+        assert!(plan.occurrence_at(u32::MAX).is_none());
+        assert!(plan.next_occurrence(0, u64::MAX).is_none());
+    }
+}
+
+#[cfg(test)]
+pub mod standing_order_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_standing_order() -> StandingOrder {
+        StandingOrder {
+            request_id: *Uuid::new_v4().as_bytes(),
+            account_id: *Uuid::new_v4().as_bytes(),
+            plan: RecurringExecutionPlan {
+                interval: RecurrenceInterval::Days(1),
+                start_at: 0,
+                end_at: None,
+                max_occurrences: Some(3),
+            },
+            next_occurrence: Some(0),
+            next_execution_at: Some(0),
+            transfer_ids: vec![],
+            last_failure: None,
+            status: StandingOrderStatus::Active,
+            created_timestamp: 0,
+            last_modification_timestamp: 0,
+        }
+    }
+}
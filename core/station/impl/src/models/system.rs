@@ -1,3 +1,4 @@
+use super::{RecoveryAttempt, RecoveryConfig};
 use crate::core::{
     ic_cdk::api::{time, trap},
     SYSTEM_RESERVED_MEMORY_BYTES,
@@ -11,7 +12,7 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SystemState {
     Uninitialized, // This state is only used between wasm module instantiation and init().
-    Initialized(SystemInfo),
+    Initialized(Box<SystemInfo>),
 }
 
 #[storable(size = SYSTEM_RESERVED_MEMORY_BYTES)]
@@ -27,6 +28,10 @@ pub struct SystemInfo {
     upgrader_canister_id: Option<Principal>,
     /// The upgrader canister wasm module.
     upgrader_wasm_module: Option<Vec<u8>>,
+    /// The guardians that can recover the station, if configured.
+    recovery_config: Option<RecoveryConfig>,
+    /// The recovery that the guardians started, if any.
+    recovery_attempt: Option<RecoveryAttempt>,
 }
 
 impl Default for SystemInfo {
@@ -37,6 +42,8 @@ impl Default for SystemInfo {
             change_canister_request: None,
             upgrader_canister_id: None,
             upgrader_wasm_module: None,
+            recovery_config: None,
+            recovery_attempt: None,
         }
     }
 }
@@ -108,6 +115,24 @@ impl SystemInfo {
     pub fn clear_change_canister_request(&mut self) {
         self.change_canister_request = None;
     }
+
+    pub fn get_recovery_config(&self) -> Option<&RecoveryConfig> {
+        self.recovery_config.as_ref()
+    }
+
+    /// Changing the guardians discards the pending recovery, since it was approved by the previous ones.
+    pub fn set_recovery_config(&mut self, recovery_config: Option<RecoveryConfig>) {
+        self.recovery_config = recovery_config;
+        self.recovery_attempt = None;
+    }
+
+    pub fn get_recovery_attempt(&self) -> Option<&RecoveryAttempt> {
+        self.recovery_attempt.as_ref()
+    }
+
+    pub fn set_recovery_attempt(&mut self, recovery_attempt: Option<RecoveryAttempt>) {
+        self.recovery_attempt = recovery_attempt;
+    }
 }
 
 impl SystemState {
@@ -136,7 +161,7 @@ impl Storable for SystemState {
         if bytes.len() == 0 {
             return SystemState::Uninitialized;
         }
-        SystemState::Initialized(SystemInfo::from_bytes(bytes))
+        SystemState::Initialized(Box::new(SystemInfo::from_bytes(bytes)))
     }
 
     const BOUND: Bound = Bound::Bounded {
@@ -2,14 +2,14 @@ use super::{AccountId, UserId};
 use crate::core::ic_cdk::next_time;
 use crate::core::validation::{EnsureAccount, EnsureIdExists, EnsureRequest, EnsureUser};
 use crate::errors::{RecordValidationError, TransferError};
-use crate::models::Metadata;
+use crate::models::{request_policy_rule::RequestEvaluationResult, Metadata};
 use orbit_essentials::storable;
 use orbit_essentials::{
     model::{ModelValidator, ModelValidatorResult},
     types::{Timestamp, UUID},
 };
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::{Display, Formatter},
     hash::Hash,
 };
@@ -132,6 +132,16 @@ impl Transfer {
     }
 }
 
+/// The projected outcome of a transfer request that was validated and evaluated without being
+/// created.
+#[derive(Clone, Debug)]
+pub struct TransferEstimate {
+    pub fee: candid::Nat,
+    pub balance: candid::Nat,
+    pub evaluation_result: RequestEvaluationResult,
+    pub possible_approvers: HashSet<UserId>,
+}
+
 fn validate_to_address(to_address: &str) -> ModelValidatorResult<TransferError> {
     if (to_address.len() < Transfer::ADDRESS_RANGE.0 as usize)
         || (to_address.len() > Transfer::ADDRESS_RANGE.1 as usize)
@@ -43,6 +43,7 @@ pub struct UserKey {
 pub struct UserCallerPrivileges {
     pub id: UUID,
     pub can_edit: bool,
+    pub can_remove: bool,
 }
 
 #[derive(CandidType, Deserialize, Debug, Clone)]
@@ -68,6 +69,12 @@ impl User {
     pub fn is_active(&self) -> bool {
         self.status == UserStatus::Active
     }
+
+    /// Archived users are tombstones of removed users, kept so that past requests can still
+    /// display who created or approved them.
+    pub fn is_archived(&self) -> bool {
+        self.status == UserStatus::Archived
+    }
 }
 
 fn validate_identities(identities: &[Principal]) -> ModelValidatorResult<UserError> {
@@ -120,7 +127,11 @@ fn validate_name(name: &str) -> ModelValidatorResult<UserError> {
 
 impl ModelValidator<UserError> for User {
     fn validate(&self) -> ModelValidatorResult<UserError> {
-        validate_identities(&self.identities)?;
+        // removed users no longer have identities to sign in with
+        if !self.is_archived() {
+            validate_identities(&self.identities)?;
+        }
+
         validate_groups(&self.groups)?;
         validate_name(&self.name)?;
 
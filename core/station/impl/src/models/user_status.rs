@@ -11,6 +11,10 @@ use std::{
 pub enum UserStatus {
     Active = 0,
     Inactive = 1,
+    /// The user is temporarily blocked from accessing the station and approving requests.
+    Suspended = 2,
+    /// The user was removed, the record is only kept as a tombstone for historical requests.
+    Archived = 3,
 }
 
 impl From<UserStatus> for u8 {
@@ -26,6 +30,8 @@ impl TryFrom<u8> for UserStatus {
         match value {
             0 => Ok(UserStatus::Active),
             1 => Ok(UserStatus::Inactive),
+            2 => Ok(UserStatus::Suspended),
+            3 => Ok(UserStatus::Archived),
             _ => Err(()),
         }
     }
@@ -38,6 +44,8 @@ impl FromStr for UserStatus {
         match variant {
             "active" => Ok(UserStatus::Active),
             "inactive" => Ok(UserStatus::Inactive),
+            "suspended" => Ok(UserStatus::Suspended),
+            "archived" => Ok(UserStatus::Archived),
             _ => Err(()),
         }
     }
@@ -48,6 +56,8 @@ impl Display for UserStatus {
         match self {
             UserStatus::Active => write!(f, "active"),
             UserStatus::Inactive => write!(f, "inactive"),
+            UserStatus::Suspended => write!(f, "suspended"),
+            UserStatus::Archived => write!(f, "archived"),
         }
     }
 }
@@ -60,6 +70,8 @@ mod tests {
     fn test_string_conversion() {
         assert_eq!(UserStatus::Active.to_string(), "active");
         assert_eq!(UserStatus::Inactive.to_string(), "inactive");
+        assert_eq!(UserStatus::Suspended.to_string(), "suspended");
+        assert_eq!(UserStatus::Archived.to_string(), "archived");
     }
 
     #[test]
@@ -69,17 +81,30 @@ mod tests {
             UserStatus::from_str("inactive").unwrap(),
             UserStatus::Inactive
         );
+        assert_eq!(
+            UserStatus::from_str("suspended").unwrap(),
+            UserStatus::Suspended
+        );
+        assert_eq!(
+            UserStatus::from_str("archived").unwrap(),
+            UserStatus::Archived
+        );
     }
 
     #[test]
     fn test_from_number() {
         assert_eq!(UserStatus::try_from(0).unwrap(), UserStatus::Active);
         assert_eq!(UserStatus::try_from(1).unwrap(), UserStatus::Inactive);
+        assert_eq!(UserStatus::try_from(2).unwrap(), UserStatus::Suspended);
+        assert_eq!(UserStatus::try_from(3).unwrap(), UserStatus::Archived);
+        assert!(UserStatus::try_from(4).is_err());
     }
 
     #[test]
     fn test_to_number() {
         assert_eq!(UserStatus::Active as u8, 0u8);
         assert_eq!(UserStatus::Inactive as u8, 1u8);
+        assert_eq!(UserStatus::Suspended as u8, 2u8);
+        assert_eq!(UserStatus::Archived as u8, 3u8);
     }
 }
@@ -0,0 +1,117 @@
+use crate::{
+    core::{with_memory_manager, Memory, ALLOWANCE_MEMORY_ID},
+    models::{AccountId, Allowance, AllowanceId, AllowanceSpender},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::Repository;
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  /// The memory reference to the Allowance repository.
+  static DB: RefCell<StableBTreeMap<AllowanceId, Allowance, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(ALLOWANCE_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref ALLOWANCE_REPOSITORY: Arc<AllowanceRepository> =
+        Arc::new(AllowanceRepository::default());
+}
+
+/// A repository that enables managing the allowances granted by station accounts in stable memory.
+#[derive(Default, Debug)]
+pub struct AllowanceRepository {}
+
+impl Repository<AllowanceId, Allowance> for AllowanceRepository {
+    fn list(&self) -> Vec<Allowance> {
+        DB.with(|m| m.borrow().iter().map(|(_, v)| v).collect())
+    }
+
+    fn get(&self, key: &AllowanceId) -> Option<Allowance> {
+        DB.with(|m| m.borrow().get(key))
+    }
+
+    fn insert(&self, key: AllowanceId, value: Allowance) -> Option<Allowance> {
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+
+    fn remove(&self, key: &AllowanceId) -> Option<Allowance> {
+        DB.with(|m| m.borrow_mut().remove(key))
+    }
+
+    fn len(&self) -> usize {
+        DB.with(|m| m.borrow().len()) as usize
+    }
+}
+
+impl AllowanceRepository {
+    /// Returns the allowances granted by the given account, sorted by creation time.
+    pub fn find_by_account(&self, account_id: &AccountId) -> Vec<Allowance> {
+        let mut allowances: Vec<Allowance> = DB.with(|m| {
+            m.borrow()
+                .iter()
+                .map(|(_, v)| v)
+                .filter(|allowance| allowance.account_id == *account_id)
+                .collect()
+        });
+
+        allowances.sort_by_key(|allowance| allowance.created_timestamp);
+
+        allowances
+    }
+
+    /// Returns the active allowance granted by the given account to the spender, if any.
+    pub fn find_active_by_spender(
+        &self,
+        account_id: &AccountId,
+        spender: &AllowanceSpender,
+    ) -> Option<Allowance> {
+        self.find_by_account(account_id)
+            .into_iter()
+            .find(|allowance| allowance.is_active() && allowance.spender == *spender)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{allowance_test_utils, AllowanceStatus};
+
+    #[test]
+    fn test_crud() {
+        let repository = AllowanceRepository::default();
+        let allowance = allowance_test_utils::mock_allowance();
+
+        assert!(repository.get(&allowance.id).is_none());
+
+        repository.insert(allowance.id.to_owned(), allowance.clone());
+
+        assert!(repository.get(&allowance.id).is_some());
+        assert!(repository.remove(&allowance.id).is_some());
+        assert!(repository.get(&allowance.id).is_none());
+    }
+
+    #[test]
+    fn test_find_active_by_spender() {
+        let repository = AllowanceRepository::default();
+        let mut revoked = allowance_test_utils::mock_allowance();
+        revoked.status = AllowanceStatus::Revoked { revoked_at: 1 };
+        let mut active = allowance_test_utils::mock_allowance();
+        active.account_id = revoked.account_id;
+
+        repository.insert(revoked.id, revoked.clone());
+        repository.insert(active.id, active.clone());
+
+        assert_eq!(repository.find_by_account(&active.account_id).len(), 2);
+        assert_eq!(
+            repository
+                .find_active_by_spender(&active.account_id, &active.spender)
+                .unwrap()
+                .id,
+            active.id
+        );
+    }
+}
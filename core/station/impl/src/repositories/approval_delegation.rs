@@ -0,0 +1,133 @@
+use crate::{
+    core::{with_memory_manager, Memory, APPROVAL_DELEGATION_MEMORY_ID},
+    models::{ApprovalDelegation, ApprovalDelegationId, UserId},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::{repository::Repository, types::Timestamp};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  /// The memory reference to the ApprovalDelegation repository.
+  static DB: RefCell<StableBTreeMap<ApprovalDelegationId, ApprovalDelegation, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(APPROVAL_DELEGATION_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref APPROVAL_DELEGATION_REPOSITORY: Arc<ApprovalDelegationRepository> =
+        Arc::new(ApprovalDelegationRepository::default());
+}
+
+/// A repository that enables managing the approval delegations between users in stable memory.
+#[derive(Default, Debug)]
+pub struct ApprovalDelegationRepository {}
+
+impl Repository<ApprovalDelegationId, ApprovalDelegation> for ApprovalDelegationRepository {
+    fn list(&self) -> Vec<ApprovalDelegation> {
+        DB.with(|m| m.borrow().iter().map(|(_, v)| v).collect())
+    }
+
+    fn get(&self, key: &ApprovalDelegationId) -> Option<ApprovalDelegation> {
+        DB.with(|m| m.borrow().get(key))
+    }
+
+    fn insert(
+        &self,
+        key: ApprovalDelegationId,
+        value: ApprovalDelegation,
+    ) -> Option<ApprovalDelegation> {
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+
+    fn remove(&self, key: &ApprovalDelegationId) -> Option<ApprovalDelegation> {
+        DB.with(|m| m.borrow_mut().remove(key))
+    }
+
+    fn len(&self) -> usize {
+        DB.with(|m| m.borrow().len()) as usize
+    }
+}
+
+impl ApprovalDelegationRepository {
+    /// Returns the delegations that the user has received and that are active at the given time.
+    pub fn find_active_by_delegate(
+        &self,
+        delegate_id: &UserId,
+        now: Timestamp,
+    ) -> Vec<ApprovalDelegation> {
+        DB.with(|m| {
+            m.borrow()
+                .iter()
+                .map(|(_, v)| v)
+                .filter(|delegation| {
+                    delegation.delegate_id == *delegate_id && delegation.is_active_at(now)
+                })
+                .collect()
+        })
+    }
+
+    /// Returns the delegations that the user has given and that are active at the given time.
+    pub fn find_active_by_delegator(
+        &self,
+        delegator_id: &UserId,
+        now: Timestamp,
+    ) -> Vec<ApprovalDelegation> {
+        DB.with(|m| {
+            m.borrow()
+                .iter()
+                .map(|(_, v)| v)
+                .filter(|delegation| {
+                    delegation.delegator_id == *delegator_id && delegation.is_active_at(now)
+                })
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::approval_delegation_test_utils::mock_approval_delegation;
+
+    #[test]
+    fn test_crud() {
+        let repository = ApprovalDelegationRepository::default();
+        let delegation = mock_approval_delegation();
+
+        assert!(repository.get(&delegation.id).is_none());
+
+        repository.insert(delegation.id.to_owned(), delegation.clone());
+
+        assert!(repository.get(&delegation.id).is_some());
+        assert!(repository.remove(&delegation.id).is_some());
+        assert!(repository.get(&delegation.id).is_none());
+    }
+
+    #[test]
+    fn test_find_active_delegations() {
+        let repository = ApprovalDelegationRepository::default();
+        let mut expired = mock_approval_delegation();
+        expired.expires_at = 10;
+        let mut active = mock_approval_delegation();
+        active.delegator_id = expired.delegator_id;
+        active.delegate_id = expired.delegate_id;
+
+        repository.insert(expired.id, expired.clone());
+        repository.insert(active.id, active.clone());
+
+        let by_delegate = repository.find_active_by_delegate(&active.delegate_id, 10);
+        let by_delegator = repository.find_active_by_delegator(&active.delegator_id, 10);
+
+        assert_eq!(by_delegate, vec![active.clone()]);
+        assert_eq!(by_delegator, vec![active]);
+        assert_eq!(
+            repository
+                .find_active_by_delegate(&expired.delegate_id, 9)
+                .len(),
+            2
+        );
+    }
+}
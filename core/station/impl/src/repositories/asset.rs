@@ -0,0 +1,102 @@
+use crate::{
+    core::{with_memory_manager, Memory, ASSET_MEMORY_ID},
+    models::{Asset, AssetId, Blockchain, BlockchainStandard},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::Repository;
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  /// The memory reference to the Asset repository.
+  static DB: RefCell<StableBTreeMap<AssetId, Asset, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(ASSET_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref ASSET_REPOSITORY: Arc<AssetRepository> = Arc::new(AssetRepository::default());
+}
+
+/// A repository that enables managing assets in stable memory.
+#[derive(Default, Debug)]
+pub struct AssetRepository {}
+
+impl Repository<AssetId, Asset> for AssetRepository {
+    fn list(&self) -> Vec<Asset> {
+        DB.with(|m| m.borrow().iter().map(|(_, v)| v).collect())
+    }
+
+    fn get(&self, key: &AssetId) -> Option<Asset> {
+        DB.with(|m| m.borrow().get(key))
+    }
+
+    fn insert(&self, key: AssetId, value: Asset) -> Option<Asset> {
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+
+    fn remove(&self, key: &AssetId) -> Option<Asset> {
+        DB.with(|m| m.borrow_mut().remove(key))
+    }
+
+    fn len(&self) -> usize {
+        DB.with(|m| m.borrow().len()) as usize
+    }
+}
+
+impl AssetRepository {
+    /// Finds the asset with the given symbol for the blockchain and standard.
+    ///
+    /// The registry is expected to hold a small number of assets, hence no index is used.
+    pub fn find_by_symbol(
+        &self,
+        blockchain: &Blockchain,
+        standard: &BlockchainStandard,
+        symbol: &str,
+    ) -> Option<Asset> {
+        DB.with(|m| {
+            m.borrow().iter().map(|(_, v)| v).find(|asset| {
+                asset.blockchain == *blockchain
+                    && asset.standard == *standard
+                    && asset.symbol.eq_ignore_ascii_case(symbol)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::asset_test_utils;
+
+    #[test]
+    fn test_crud() {
+        let repository = AssetRepository::default();
+        let asset = asset_test_utils::mock_asset();
+
+        assert!(repository.get(&asset.id).is_none());
+
+        repository.insert(asset.id.to_owned(), asset.clone());
+
+        assert!(repository.get(&asset.id).is_some());
+        assert!(repository.remove(&asset.id).is_some());
+        assert!(repository.get(&asset.id).is_none());
+    }
+
+    #[test]
+    fn test_find_by_symbol() {
+        let repository = AssetRepository::default();
+        let asset = asset_test_utils::mock_asset();
+
+        repository.insert(asset.id, asset.clone());
+
+        assert!(repository
+            .find_by_symbol(&asset.blockchain, &asset.standard, "icp")
+            .is_some());
+        assert!(repository
+            .find_by_symbol(&asset.blockchain, &BlockchainStandard::ICRC1, "ICP")
+            .is_none());
+    }
+}
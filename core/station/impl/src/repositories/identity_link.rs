@@ -0,0 +1,111 @@
+use crate::{
+    core::{with_memory_manager, Memory, IDENTITY_LINK_MEMORY_ID},
+    models::{IdentityLink, IdentityLinkId},
+};
+use candid::Principal;
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::{repository::Repository, types::Timestamp};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  /// The memory reference to the IdentityLink repository.
+  static DB: RefCell<StableBTreeMap<IdentityLinkId, IdentityLink, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(IDENTITY_LINK_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref IDENTITY_LINK_REPOSITORY: Arc<IdentityLinkRepository> =
+        Arc::new(IdentityLinkRepository::default());
+}
+
+/// A repository that enables managing the pending identity links in stable memory.
+#[derive(Default, Debug)]
+pub struct IdentityLinkRepository {}
+
+impl Repository<IdentityLinkId, IdentityLink> for IdentityLinkRepository {
+    fn list(&self) -> Vec<IdentityLink> {
+        DB.with(|m| m.borrow().iter().map(|(_, v)| v).collect())
+    }
+
+    fn get(&self, key: &IdentityLinkId) -> Option<IdentityLink> {
+        DB.with(|m| m.borrow().get(key))
+    }
+
+    fn insert(&self, key: IdentityLinkId, value: IdentityLink) -> Option<IdentityLink> {
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+
+    fn remove(&self, key: &IdentityLinkId) -> Option<IdentityLink> {
+        DB.with(|m| m.borrow_mut().remove(key))
+    }
+
+    fn len(&self) -> usize {
+        DB.with(|m| m.borrow().len()) as usize
+    }
+}
+
+impl IdentityLinkRepository {
+    /// Returns the links waiting for the given identity to confirm them.
+    pub fn find_by_new_identity(&self, new_identity: &Principal) -> Vec<IdentityLink> {
+        DB.with(|m| {
+            m.borrow()
+                .iter()
+                .map(|(_, v)| v)
+                .filter(|link| link.new_identity == *new_identity)
+                .collect()
+        })
+    }
+
+    /// Returns the links that can no longer be confirmed at the given time.
+    pub fn find_expired(&self, now: Timestamp) -> Vec<IdentityLink> {
+        DB.with(|m| {
+            m.borrow()
+                .iter()
+                .map(|(_, v)| v)
+                .filter(|link| link.is_expired_at(now))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::identity_link_test_utils::mock_identity_link;
+
+    #[test]
+    fn test_crud() {
+        let repository = IdentityLinkRepository::default();
+        let link = mock_identity_link();
+
+        assert!(repository.get(&link.id).is_none());
+
+        repository.insert(link.id.to_owned(), link.clone());
+
+        assert!(repository.get(&link.id).is_some());
+        assert!(repository.remove(&link.id).is_some());
+        assert!(repository.get(&link.id).is_none());
+    }
+
+    #[test]
+    fn test_find_by_new_identity_and_expired() {
+        let repository = IdentityLinkRepository::default();
+        let mut expired = mock_identity_link();
+        expired.expires_at = 10;
+        let mut pending = mock_identity_link();
+        pending.new_identity = Principal::from_slice(&[3; 29]);
+
+        repository.insert(expired.id, expired.clone());
+        repository.insert(pending.id, pending.clone());
+
+        assert_eq!(
+            repository.find_by_new_identity(&Principal::from_slice(&[3; 29])),
+            vec![pending]
+        );
+        assert_eq!(repository.find_expired(10), vec![expired]);
+    }
+}
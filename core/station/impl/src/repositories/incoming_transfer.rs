@@ -0,0 +1,142 @@
+use super::indexes::incoming_transfer_account_index::IncomingTransferAccountIndexRepository;
+use crate::{
+    core::{with_memory_manager, Memory, INCOMING_TRANSFER_MEMORY_ID},
+    models::{
+        indexes::incoming_transfer_account_index::IncomingTransferAccountIndexCriteria, AccountId,
+        IncomingTransfer, IncomingTransferKey,
+    },
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    repository::{IndexRepository, RefreshIndexMode, Repository},
+    types::Timestamp,
+};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+    /// The memory reference to the IncomingTransfer repository.
+    static DB: RefCell<StableBTreeMap<IncomingTransferKey, IncomingTransfer, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+        RefCell::new(
+            StableBTreeMap::init(memory_manager.get(INCOMING_TRANSFER_MEMORY_ID))
+        )
+    });
+}
+
+lazy_static! {
+    pub static ref INCOMING_TRANSFER_REPOSITORY: Arc<IncomingTransferRepository> =
+        Arc::new(IncomingTransferRepository::default());
+}
+
+/// A repository that enables managing the deposits into the station accounts in stable memory.
+#[derive(Default, Debug)]
+pub struct IncomingTransferRepository {
+    account_index: IncomingTransferAccountIndexRepository,
+}
+
+impl Repository<IncomingTransferKey, IncomingTransfer> for IncomingTransferRepository {
+    fn list(&self) -> Vec<IncomingTransfer> {
+        DB.with(|m| m.borrow().iter().map(|(_, v)| v).collect())
+    }
+
+    fn get(&self, key: &IncomingTransferKey) -> Option<IncomingTransfer> {
+        DB.with(|m| m.borrow().get(key))
+    }
+
+    fn insert(
+        &self,
+        key: IncomingTransferKey,
+        value: IncomingTransfer,
+    ) -> Option<IncomingTransfer> {
+        DB.with(|m| {
+            let prev = m.borrow_mut().insert(key, value.clone());
+
+            self.account_index
+                .refresh_index_on_modification(RefreshIndexMode::Value {
+                    previous: prev.clone().map(|prev| prev.to_index_by_account()),
+                    current: Some(value.to_index_by_account()),
+                });
+
+            prev
+        })
+    }
+
+    fn remove(&self, key: &IncomingTransferKey) -> Option<IncomingTransfer> {
+        DB.with(|m| {
+            let prev = m.borrow_mut().remove(key);
+
+            self.account_index
+                .refresh_index_on_modification(RefreshIndexMode::CleanupValue {
+                    current: prev.clone().map(|prev| prev.to_index_by_account()),
+                });
+
+            prev
+        })
+    }
+
+    fn len(&self) -> usize {
+        DB.with(|m| m.borrow().len()) as usize
+    }
+}
+
+impl IncomingTransferRepository {
+    /// Returns the incoming transfers of the account that were recorded by the ledger in the given time range.
+    pub fn find_by_account(
+        &self,
+        account_id: AccountId,
+        from_dt: Option<Timestamp>,
+        to_dt: Option<Timestamp>,
+    ) -> Vec<IncomingTransfer> {
+        self.account_index
+            .find_by_criteria(IncomingTransferAccountIndexCriteria {
+                account_id,
+                from_dt,
+                to_dt,
+            })
+            .into_iter()
+            .filter_map(|tx_id| self.get(&IncomingTransfer::key(account_id, tx_id)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::incoming_transfer_test_utils::mock_incoming_transfer;
+
+    #[test]
+    fn perform_crud() {
+        let repository = IncomingTransferRepository::default();
+        let incoming_transfer = mock_incoming_transfer();
+
+        assert!(repository.get(&incoming_transfer.to_key()).is_none());
+
+        repository.insert(incoming_transfer.to_key(), incoming_transfer.clone());
+
+        assert!(repository.get(&incoming_transfer.to_key()).is_some());
+        assert!(repository.remove(&incoming_transfer.to_key()).is_some());
+        assert!(repository.get(&incoming_transfer.to_key()).is_none());
+    }
+
+    #[test]
+    fn find_by_account_and_time() {
+        let repository = IncomingTransferRepository::default();
+        let mut first = mock_incoming_transfer();
+        first.timestamp = 10;
+        let mut second = mock_incoming_transfer();
+        second.tx_id = 2;
+        second.timestamp = 20;
+        let mut other_account = mock_incoming_transfer();
+        other_account.account_id = [2; 16];
+
+        for incoming_transfer in [&first, &second, &other_account] {
+            repository.insert(incoming_transfer.to_key(), incoming_transfer.clone());
+        }
+
+        assert_eq!(repository.find_by_account([1; 16], None, None).len(), 2);
+        assert_eq!(
+            repository.find_by_account([1; 16], Some(15), None),
+            vec![second]
+        );
+    }
+}
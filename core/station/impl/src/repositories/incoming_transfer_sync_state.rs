@@ -0,0 +1,71 @@
+use crate::{
+    core::{with_memory_manager, Memory, INCOMING_TRANSFER_SYNC_MEMORY_ID},
+    models::{AccountId, IncomingTransferSyncState},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::Repository;
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  /// The memory reference to the IncomingTransferSyncState repository.
+  static DB: RefCell<StableBTreeMap<AccountId, IncomingTransferSyncState, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(INCOMING_TRANSFER_SYNC_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref INCOMING_TRANSFER_SYNC_STATE_REPOSITORY: Arc<IncomingTransferSyncStateRepository> =
+        Arc::new(IncomingTransferSyncStateRepository::default());
+}
+
+/// A repository that keeps track of how far the ledger history of each account was indexed.
+#[derive(Default, Debug)]
+pub struct IncomingTransferSyncStateRepository {}
+
+impl Repository<AccountId, IncomingTransferSyncState> for IncomingTransferSyncStateRepository {
+    fn list(&self) -> Vec<IncomingTransferSyncState> {
+        DB.with(|m| m.borrow().iter().map(|(_, v)| v).collect())
+    }
+
+    fn get(&self, key: &AccountId) -> Option<IncomingTransferSyncState> {
+        DB.with(|m| m.borrow().get(key))
+    }
+
+    fn insert(
+        &self,
+        key: AccountId,
+        value: IncomingTransferSyncState,
+    ) -> Option<IncomingTransferSyncState> {
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+
+    fn remove(&self, key: &AccountId) -> Option<IncomingTransferSyncState> {
+        DB.with(|m| m.borrow_mut().remove(key))
+    }
+
+    fn len(&self) -> usize {
+        DB.with(|m| m.borrow().len()) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crud() {
+        let repository = IncomingTransferSyncStateRepository::default();
+        let state = IncomingTransferSyncState::new([1; 16]);
+
+        assert!(repository.get(&state.account_id).is_none());
+
+        repository.insert(state.account_id, state.clone());
+
+        assert_eq!(repository.get(&state.account_id), Some(state.clone()));
+        assert!(repository.remove(&state.account_id).is_some());
+        assert!(repository.get(&state.account_id).is_none());
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    core::{with_memory_manager, Memory, INCOMING_TRANSFER_ACCOUNT_INDEX_MEMORY_ID},
+    models::{
+        indexes::incoming_transfer_account_index::{
+            IncomingTransferAccountIndex, IncomingTransferAccountIndexCriteria,
+        },
+        LedgerTransactionId,
+    },
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use orbit_essentials::repository::IndexRepository;
+use std::{cell::RefCell, collections::HashSet};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<IncomingTransferAccountIndex, (), VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(INCOMING_TRANSFER_ACCOUNT_INDEX_MEMORY_ID))
+    )
+  })
+}
+
+#[derive(Default, Debug)]
+pub struct IncomingTransferAccountIndexRepository {}
+
+impl IndexRepository<IncomingTransferAccountIndex, LedgerTransactionId>
+    for IncomingTransferAccountIndexRepository
+{
+    type FindByCriteria = IncomingTransferAccountIndexCriteria;
+
+    fn exists(&self, index: &IncomingTransferAccountIndex) -> bool {
+        DB.with(|m| m.borrow().get(index).is_some())
+    }
+
+    fn insert(&self, index: IncomingTransferAccountIndex) {
+        DB.with(|m| m.borrow_mut().insert(index, ()));
+    }
+
+    fn remove(&self, index: &IncomingTransferAccountIndex) -> bool {
+        DB.with(|m| m.borrow_mut().remove(index).is_some())
+    }
+
+    fn find_by_criteria(&self, criteria: Self::FindByCriteria) -> HashSet<LedgerTransactionId> {
+        DB.with(|db| {
+            let start_key = IncomingTransferAccountIndex {
+                account_id: criteria.account_id,
+                timestamp: criteria.from_dt.unwrap_or(u64::MIN),
+                tx_id: u64::MIN,
+            };
+            let end_key = IncomingTransferAccountIndex {
+                account_id: criteria.account_id,
+                timestamp: criteria.to_dt.unwrap_or(u64::MAX),
+                tx_id: u64::MAX,
+            };
+
+            if start_key > end_key {
+                return HashSet::new();
+            }
+
+            db.borrow()
+                .range(start_key..=end_key)
+                .map(|(index, _)| index.tx_id)
+                .collect::<HashSet<LedgerTransactionId>>()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repository_crud() {
+        let repository = IncomingTransferAccountIndexRepository::default();
+        let index = IncomingTransferAccountIndex {
+            account_id: [1; 16],
+            timestamp: 10,
+            tx_id: 1,
+        };
+
+        assert!(!repository.exists(&index));
+
+        repository.insert(index.clone());
+
+        assert!(repository.exists(&index));
+        assert!(repository.remove(&index));
+        assert!(!repository.exists(&index));
+    }
+
+    #[test]
+    fn test_find_by_criteria() {
+        let repository = IncomingTransferAccountIndexRepository::default();
+
+        for (account_id, timestamp, tx_id) in [([1; 16], 10, 1), ([1; 16], 20, 2), ([2; 16], 10, 3)]
+        {
+            repository.insert(IncomingTransferAccountIndex {
+                account_id,
+                timestamp,
+                tx_id,
+            });
+        }
+
+        let result = repository.find_by_criteria(IncomingTransferAccountIndexCriteria {
+            account_id: [1; 16],
+            from_dt: None,
+            to_dt: Some(15),
+        });
+
+        assert_eq!(result, HashSet::from([1]));
+    }
+}
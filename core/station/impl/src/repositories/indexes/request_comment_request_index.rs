@@ -0,0 +1,107 @@
+use crate::{
+    core::{with_memory_manager, Memory, REQUEST_COMMENT_REQUEST_INDEX_MEMORY_ID},
+    models::{
+        indexes::request_comment_request_index::{
+            RequestCommentRequestIndex, RequestCommentRequestIndexCriteria,
+        },
+        RequestCommentId,
+    },
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use orbit_essentials::repository::IndexRepository;
+use std::{cell::RefCell, collections::HashSet};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<RequestCommentRequestIndex, (), VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(REQUEST_COMMENT_REQUEST_INDEX_MEMORY_ID))
+    )
+  })
+}
+
+/// A repository that enables finding request comments based on the request in stable memory.
+#[derive(Default, Debug)]
+pub struct RequestCommentRequestIndexRepository {}
+
+impl IndexRepository<RequestCommentRequestIndex, RequestCommentId>
+    for RequestCommentRequestIndexRepository
+{
+    type FindByCriteria = RequestCommentRequestIndexCriteria;
+
+    fn exists(&self, key: &RequestCommentRequestIndex) -> bool {
+        DB.with(|m| m.borrow().get(key).is_some())
+    }
+
+    fn insert(&self, key: RequestCommentRequestIndex) {
+        DB.with(|m| m.borrow_mut().insert(key, ()));
+    }
+
+    fn remove(&self, key: &RequestCommentRequestIndex) -> bool {
+        DB.with(|m| m.borrow_mut().remove(key).is_some())
+    }
+
+    fn find_by_criteria(&self, criteria: Self::FindByCriteria) -> HashSet<RequestCommentId> {
+        DB.with(|db| {
+            let start_key = RequestCommentRequestIndex {
+                request_id: criteria.request_id.to_owned(),
+                created_at: u64::MIN,
+                comment_id: [u8::MIN; 16],
+            };
+            let end_key = RequestCommentRequestIndex {
+                request_id: criteria.request_id.to_owned(),
+                created_at: u64::MAX,
+                comment_id: [u8::MAX; 16],
+            };
+
+            db.borrow()
+                .range(start_key..=end_key)
+                .map(|(index, _)| index.comment_id)
+                .collect::<HashSet<RequestCommentId>>()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repository_crud() {
+        let repository = RequestCommentRequestIndexRepository::default();
+        let index = RequestCommentRequestIndex {
+            request_id: [1; 16],
+            created_at: 10,
+            comment_id: [0; 16],
+        };
+
+        assert!(!repository.exists(&index));
+
+        repository.insert(index.clone());
+
+        assert!(repository.exists(&index));
+        assert!(repository.remove(&index));
+        assert!(!repository.exists(&index));
+    }
+
+    #[test]
+    fn test_find_by_criteria() {
+        let repository = RequestCommentRequestIndexRepository::default();
+        repository.insert(RequestCommentRequestIndex {
+            request_id: [1; 16],
+            created_at: 10,
+            comment_id: [0; 16],
+        });
+        repository.insert(RequestCommentRequestIndex {
+            request_id: [2; 16],
+            created_at: 10,
+            comment_id: [3; 16],
+        });
+
+        let result = repository.find_by_criteria(RequestCommentRequestIndexCriteria {
+            request_id: [1; 16],
+        });
+
+        assert_eq!(result.len(), 1);
+        assert!(result.contains(&[0; 16]));
+    }
+}
@@ -2,10 +2,21 @@
 
 pub mod address_book;
 pub use address_book::*;
+pub mod allowance;
+pub use allowance::*;
+
+pub mod approval_delegation;
+pub use approval_delegation::*;
+
+pub mod asset;
+pub use asset::*;
 
 pub mod user;
 pub use user::*;
 
+pub mod identity_link;
+pub use identity_link::*;
+
 pub mod user_group;
 pub use user_group::*;
 
@@ -15,18 +26,33 @@ pub use account::*;
 pub mod transfer;
 pub use transfer::*;
 
+pub mod incoming_transfer;
+pub use incoming_transfer::*;
+
+pub mod incoming_transfer_sync_state;
+pub use incoming_transfer_sync_state::*;
+
 pub mod notification;
 pub use notification::*;
 
 pub mod request;
 pub use request::*;
 
+pub mod request_comment;
+pub use request_comment::*;
+
+pub mod request_template;
+pub use request_template::*;
+
 pub mod request_policy;
 pub use request_policy::*;
 
 pub mod request_evaluation_result;
 pub use request_evaluation_result::*;
 
+pub mod standing_order;
+pub use standing_order::*;
+
 pub mod permission;
 
 pub mod indexes;
@@ -363,6 +363,25 @@ impl RequestRepository {
             .collect::<Vec<Request>>()
     }
 
+    /// Returns the ids of the requests that are currently of the provided status.
+    pub fn find_ids_by_status(&self, status: RequestStatusCode) -> HashSet<RequestId> {
+        self.status_index
+            .find_by_criteria(RequestStatusIndexCriteria { status })
+    }
+
+    /// Checks if the request is indexed under the provided operation type.
+    pub fn exists_operation_type(
+        &self,
+        request_id: &RequestId,
+        operation_type: RequestOperationFilterType,
+    ) -> bool {
+        self.operation_type_index
+            .exists(&RequestOperationTypeIndex {
+                operation_type,
+                request_id: *request_id,
+            })
+    }
+
     /// Checks if the request is of the provided status.
     pub fn exists_status(&self, request_id: &RequestId, status: RequestStatusCode) -> bool {
         self.status_index.exists(&RequestStatusIndex {
@@ -0,0 +1,139 @@
+use super::indexes::request_comment_request_index::RequestCommentRequestIndexRepository;
+use crate::{
+    core::{with_memory_manager, Memory, REQUEST_COMMENT_MEMORY_ID},
+    models::{
+        indexes::request_comment_request_index::RequestCommentRequestIndexCriteria, RequestComment,
+        RequestCommentId, RequestId,
+    },
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{IndexRepository, Repository};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  /// The memory reference to the request comment repository.
+  static DB: RefCell<StableBTreeMap<RequestCommentId, RequestComment, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(REQUEST_COMMENT_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref REQUEST_COMMENT_REPOSITORY: Arc<RequestCommentRepository> =
+        Arc::new(RequestCommentRepository::default());
+}
+
+/// A repository that enables managing the comments of requests in stable memory.
+#[derive(Default, Debug)]
+pub struct RequestCommentRepository {
+    request_index: RequestCommentRequestIndexRepository,
+}
+
+impl Repository<RequestCommentId, RequestComment> for RequestCommentRepository {
+    fn list(&self) -> Vec<RequestComment> {
+        DB.with(|m| m.borrow().iter().map(|(_, v)| v).collect())
+    }
+
+    fn get(&self, key: &RequestCommentId) -> Option<RequestComment> {
+        DB.with(|m| m.borrow().get(key))
+    }
+
+    fn insert(&self, key: RequestCommentId, value: RequestComment) -> Option<RequestComment> {
+        DB.with(|m| match m.borrow_mut().insert(key, value.clone()) {
+            Some(prev) => {
+                let prev_request_index = prev.to_index_by_request();
+                if prev_request_index != value.to_index_by_request() {
+                    self.request_index.remove(&prev_request_index);
+                    self.request_index.insert(value.to_index_by_request());
+                }
+
+                Some(prev)
+            }
+            None => {
+                self.request_index.insert(value.to_index_by_request());
+
+                None
+            }
+        })
+    }
+
+    fn remove(&self, key: &RequestCommentId) -> Option<RequestComment> {
+        DB.with(|m| match m.borrow_mut().remove(key) {
+            Some(prev) => {
+                self.request_index.remove(&prev.to_index_by_request());
+
+                Some(prev)
+            }
+            None => None,
+        })
+    }
+
+    fn len(&self) -> usize {
+        DB.with(|m| m.borrow().len()) as usize
+    }
+}
+
+impl RequestCommentRepository {
+    /// Returns the comments of the request, sorted from the oldest to the newest.
+    pub fn find_by_request(&self, request_id: &RequestId) -> Vec<RequestComment> {
+        let mut comments: Vec<RequestComment> = self
+            .request_index
+            .find_by_criteria(RequestCommentRequestIndexCriteria {
+                request_id: request_id.to_owned(),
+            })
+            .iter()
+            .filter_map(|id| self.get(id))
+            .collect();
+
+        comments.sort_by(|a, b| {
+            a.created_timestamp
+                .cmp(&b.created_timestamp)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        comments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::request_comment_test_utils::mock_request_comment;
+
+    #[test]
+    fn test_crud() {
+        let repository = RequestCommentRepository::default();
+        let comment = mock_request_comment();
+
+        assert!(repository.get(&comment.id).is_none());
+
+        repository.insert(comment.id.to_owned(), comment.clone());
+
+        assert!(repository.get(&comment.id).is_some());
+        assert!(repository.remove(&comment.id).is_some());
+        assert!(repository.get(&comment.id).is_none());
+        assert!(repository.find_by_request(&comment.request_id).is_empty());
+    }
+
+    #[test]
+    fn test_find_by_request_sorted_by_creation() {
+        let repository = RequestCommentRepository::default();
+        let mut first = mock_request_comment();
+        first.created_timestamp = 10;
+        let mut second = mock_request_comment();
+        second.request_id = first.request_id;
+        second.created_timestamp = 20;
+        let other = mock_request_comment();
+
+        repository.insert(second.id.to_owned(), second.clone());
+        repository.insert(first.id.to_owned(), first.clone());
+        repository.insert(other.id.to_owned(), other.clone());
+
+        assert_eq!(
+            repository.find_by_request(&first.request_id),
+            vec![first, second]
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    core::{with_memory_manager, Memory, REQUEST_TEMPLATE_MEMORY_ID},
+    models::{RequestTemplate, RequestTemplateId},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::Repository;
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  /// The memory reference to the request template repository.
+  static DB: RefCell<StableBTreeMap<RequestTemplateId, RequestTemplate, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(REQUEST_TEMPLATE_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref REQUEST_TEMPLATE_REPOSITORY: Arc<RequestTemplateRepository> =
+        Arc::new(RequestTemplateRepository::default());
+}
+
+/// A repository that enables managing the request templates in stable memory.
+#[derive(Default, Debug)]
+pub struct RequestTemplateRepository {}
+
+impl Repository<RequestTemplateId, RequestTemplate> for RequestTemplateRepository {
+    fn list(&self) -> Vec<RequestTemplate> {
+        DB.with(|m| m.borrow().iter().map(|(_, v)| v).collect())
+    }
+
+    fn get(&self, key: &RequestTemplateId) -> Option<RequestTemplate> {
+        DB.with(|m| m.borrow().get(key))
+    }
+
+    fn insert(&self, key: RequestTemplateId, value: RequestTemplate) -> Option<RequestTemplate> {
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+
+    fn remove(&self, key: &RequestTemplateId) -> Option<RequestTemplate> {
+        DB.with(|m| m.borrow_mut().remove(key))
+    }
+
+    fn len(&self) -> usize {
+        DB.with(|m| m.borrow().len()) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::request_template_test_utils::mock_request_template;
+
+    #[test]
+    fn test_crud() {
+        let repository = RequestTemplateRepository::default();
+        let template = mock_request_template();
+
+        assert!(repository.get(&template.id).is_none());
+
+        repository.insert(template.id.to_owned(), template.clone());
+
+        assert_eq!(repository.get(&template.id), Some(template.clone()));
+        assert!(repository.remove(&template.id).is_some());
+        assert!(repository.get(&template.id).is_none());
+    }
+}
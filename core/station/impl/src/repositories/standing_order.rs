@@ -0,0 +1,120 @@
+use crate::{
+    core::{with_memory_manager, Memory, STANDING_ORDER_MEMORY_ID},
+    models::{RequestId, StandingOrder},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::Repository;
+use orbit_essentials::types::Timestamp;
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  /// The memory reference to the StandingOrder repository.
+  static DB: RefCell<StableBTreeMap<RequestId, StandingOrder, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(STANDING_ORDER_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref STANDING_ORDER_REPOSITORY: Arc<StandingOrderRepository> =
+        Arc::new(StandingOrderRepository::default());
+}
+
+/// A repository that enables managing the standing orders of recurring requests in stable memory.
+#[derive(Default, Debug)]
+pub struct StandingOrderRepository {}
+
+impl Repository<RequestId, StandingOrder> for StandingOrderRepository {
+    fn list(&self) -> Vec<StandingOrder> {
+        DB.with(|m| m.borrow().iter().map(|(_, v)| v).collect())
+    }
+
+    fn get(&self, key: &RequestId) -> Option<StandingOrder> {
+        DB.with(|m| m.borrow().get(key))
+    }
+
+    fn insert(&self, key: RequestId, value: StandingOrder) -> Option<StandingOrder> {
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+
+    fn remove(&self, key: &RequestId) -> Option<StandingOrder> {
+        DB.with(|m| m.borrow_mut().remove(key))
+    }
+
+    fn len(&self) -> usize {
+        DB.with(|m| m.borrow().len()) as usize
+    }
+}
+
+impl StandingOrderRepository {
+    /// Returns the active standing orders, sorted by their next execution time.
+    pub fn find_active(&self) -> Vec<StandingOrder> {
+        let mut standing_orders: Vec<StandingOrder> = DB.with(|m| {
+            m.borrow()
+                .iter()
+                .map(|(_, v)| v)
+                .filter(|standing_order| standing_order.is_active())
+                .collect()
+        });
+
+        standing_orders.sort_by_key(|standing_order| standing_order.next_execution_at);
+
+        standing_orders
+    }
+
+    /// Returns the active standing orders that have an execution due until the given time.
+    pub fn find_due(&self, until: Timestamp) -> Vec<StandingOrder> {
+        self.find_active()
+            .into_iter()
+            .filter(|standing_order| {
+                standing_order
+                    .next_execution_at
+                    .is_some_and(|next_execution_at| next_execution_at <= until)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{standing_order_test_utils, StandingOrderStatus};
+
+    #[test]
+    fn test_crud() {
+        let repository = StandingOrderRepository::default();
+        let standing_order = standing_order_test_utils::mock_standing_order();
+
+        assert!(repository.get(&standing_order.request_id).is_none());
+
+        repository.insert(standing_order.request_id, standing_order.clone());
+
+        assert!(repository.get(&standing_order.request_id).is_some());
+        assert!(repository.remove(&standing_order.request_id).is_some());
+        assert!(repository.get(&standing_order.request_id).is_none());
+    }
+
+    #[test]
+    fn test_find_due() {
+        let repository = StandingOrderRepository::default();
+        let mut due = standing_order_test_utils::mock_standing_order();
+        due.next_execution_at = Some(10);
+        let mut upcoming = standing_order_test_utils::mock_standing_order();
+        upcoming.next_execution_at = Some(20);
+        let mut cancelled = standing_order_test_utils::mock_standing_order();
+        cancelled.next_execution_at = Some(5);
+        cancelled.status = StandingOrderStatus::Cancelled {
+            cancelled_at: 1,
+            cancelled_by_request_id: [0; 16],
+        };
+
+        repository.insert(due.request_id, due.clone());
+        repository.insert(upcoming.request_id, upcoming.clone());
+        repository.insert(cancelled.request_id, cancelled.clone());
+
+        assert_eq!(repository.find_active().len(), 2);
+        assert_eq!(repository.find_due(15), vec![due]);
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
         utils::{paginated_items, retain_accessible_resources, PaginatedData, PaginatedItemsArgs},
         CallContext, ACCOUNT_BALANCE_FRESHNESS_IN_MS,
     },
-    errors::AccountError,
+    errors::{AccountError, AssetError},
     factories::blockchains::BlockchainApiFactory,
     mappers::{account::AccountMapper, HelperMapper},
     models::{
@@ -16,7 +16,7 @@ use crate::{
         Account, AccountBalance, AccountCallerPrivileges, AccountId, AddAccountOperationInput,
         AddRequestPolicyOperationInput, EditAccountOperationInput, EditPermissionOperationInput,
     },
-    repositories::{AccountRepository, AccountWhereClause, ACCOUNT_REPOSITORY},
+    repositories::{AccountRepository, AccountWhereClause, ACCOUNT_REPOSITORY, ASSET_REPOSITORY},
     services::{
         permission::{PermissionService, PERMISSION_SERVICE},
         RequestPolicyService, REQUEST_POLICY_SERVICE,
@@ -129,12 +129,17 @@ impl AccountService {
             Err(AccountError::AccountNameAlreadyExists)?
         }
 
+        let asset = ASSET_REPOSITORY
+            .get(&input.asset_id)
+            .ok_or(AssetError::NotFound {
+                id: Uuid::from_bytes(input.asset_id).hyphenated().to_string(),
+            })?;
+
         let uuid = generate_uuid_v4().await;
         let key = Account::key(*uuid.as_bytes());
-        let blockchain_api =
-            BlockchainApiFactory::build(&input.blockchain.clone(), &input.standard.clone())?;
+        let blockchain_api = BlockchainApiFactory::build(&asset.blockchain, &asset.standard)?;
         let mut new_account =
-            AccountMapper::from_create_input(input.to_owned(), *uuid.as_bytes(), None)?;
+            AccountMapper::from_create_input(input.to_owned(), asset, *uuid.as_bytes(), None);
 
         // The account address is generated after the account is created from the user input and
         // all the validations are successfully completed.
@@ -158,6 +163,18 @@ impl AccountService {
         // depending on the blockchain standard used by the account the decimals used by each asset can vary.
         new_account.decimals = blockchain_api.decimals(&new_account).await?;
 
+        // The symbol advertised by the ledger must match the asset so that both refer to the same token.
+        let symbol = blockchain_api.symbol(&new_account).await?;
+        if !symbol.eq_ignore_ascii_case(&new_account.symbol) {
+            Err(AccountError::ValidationError {
+                info: format!(
+                    "The ledger symbol `{}` does not match the asset symbol `{}`",
+                    symbol, new_account.symbol
+                ),
+            })?
+        }
+        new_account.symbol = symbol;
+
         // Validate here before database operations.
         new_account.validate()?;
 
@@ -408,10 +425,10 @@ mod tests {
     use crate::{
         core::{test_utils, validation::disable_mock_resource_validation, CallContext},
         models::{
-            account_test_utils::mock_account, permission::Allow,
+            account_test_utils::mock_account, asset_test_utils::add_asset, permission::Allow,
             request_policy_rule::RequestPolicyRule, request_specifier::UserSpecifier,
-            user_test_utils::mock_user, AddAccountOperation, AddAccountOperationInput, Blockchain,
-            BlockchainStandard, Metadata, User,
+            user_test_utils::mock_user, AddAccountOperation, AddAccountOperationInput, Asset,
+            Metadata, User,
         },
         repositories::UserRepository,
     };
@@ -420,6 +437,7 @@ mod tests {
         repository: AccountRepository,
         service: AccountService,
         caller_user: User,
+        asset: Asset,
     }
 
     fn setup() -> TestContext {
@@ -435,6 +453,7 @@ mod tests {
             repository: AccountRepository::default(),
             service: AccountService::default(),
             caller_user: user,
+            asset: add_asset("ICP"),
         }
     }
 
@@ -457,8 +476,7 @@ mod tests {
             account_id: None,
             input: AddAccountOperationInput {
                 name: "foo".to_string(),
-                blockchain: Blockchain::InternetComputer,
-                standard: BlockchainStandard::Native,
+                asset_id: ctx.asset.id,
                 metadata: Metadata::default(),
                 read_permission: Allow::users(vec![ctx.caller_user.id]),
                 configs_permission: Allow::users(vec![ctx.caller_user.id]),
@@ -488,8 +506,7 @@ mod tests {
             account_id: None,
             input: AddAccountOperationInput {
                 name: account.name,
-                blockchain: Blockchain::InternetComputer,
-                standard: BlockchainStandard::Native,
+                asset_id: ctx.asset.id,
                 metadata: Metadata::default(),
                 read_permission: Allow::users(vec![ctx.caller_user.id]),
                 configs_permission: Allow::users(vec![ctx.caller_user.id]),
@@ -512,8 +529,7 @@ mod tests {
 
         let base_input = AddAccountOperationInput {
             name: "foo".to_string(),
-            blockchain: Blockchain::InternetComputer,
-            standard: BlockchainStandard::Native,
+            asset_id: ctx.asset.id,
             metadata: Metadata::default(),
             read_permission: Allow::users(vec![ctx.caller_user.id]),
             configs_permission: Allow::users(vec![ctx.caller_user.id]),
@@ -624,14 +640,13 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn fail_create_account_invalid_blockchain_standard() {
+    async fn fail_create_account_unknown_asset() {
         let ctx = setup();
         let operation = AddAccountOperation {
             account_id: None,
             input: AddAccountOperationInput {
                 name: "foo".to_string(),
-                blockchain: Blockchain::InternetComputer,
-                standard: BlockchainStandard::ERC20,
+                asset_id: [1; 16],
                 metadata: Metadata::default(),
                 read_permission: Allow::users(vec![ctx.caller_user.id]),
                 configs_permission: Allow::users(vec![ctx.caller_user.id]),
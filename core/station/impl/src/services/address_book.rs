@@ -107,6 +107,7 @@ impl AddressBookService {
 
         let new_entry = AddressBookMapper::from_create_input(input.to_owned(), *uuid.as_bytes())?;
         new_entry.validate()?;
+        new_entry.validate_blockchain_address()?;
 
         if let Some(v) = self.address_book_repository.find_by_address(
             new_entry.blockchain.clone(),
@@ -198,7 +199,7 @@ mod tests {
             address_book_entry_id: None,
             input: AddAddressBookEntryOperationInput {
                 address_owner: "foo".to_string(),
-                address: "0x1234".to_string(),
+                address: address_book_entry.address.clone(),
                 blockchain: Blockchain::InternetComputer,
                 standard: BlockchainStandard::Native,
                 metadata: address_book_entry.metadata.clone().into(),
@@ -349,4 +350,24 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn fail_create_entry_invalid_address() {
+        let ctx = setup();
+
+        let operation = AddAddressBookEntryOperation {
+            address_book_entry_id: None,
+            input: AddAddressBookEntryOperationInput {
+                address_owner: "foo".to_string(),
+                address: "0x1234".to_string(),
+                blockchain: Blockchain::InternetComputer,
+                standard: BlockchainStandard::Native,
+                metadata: vec![],
+            },
+        };
+
+        let result = ctx.service.create_entry(operation.input).await;
+
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,314 @@
+use crate::{
+    core::{
+        generate_uuid_v4,
+        ic_cdk::next_time,
+        utils::{paginated_items, PaginatedData, PaginatedItemsArgs},
+    },
+    errors::AllowanceError,
+    factories::blockchains::InternetComputerIcrc1,
+    mappers::HelperMapper,
+    models::{
+        Account, AccountId, Allowance, AllowanceId, AllowanceStatus,
+        ApproveAllowanceOperationInput, BlockchainStandard, RevokeAllowanceOperationInput,
+    },
+    repositories::{AllowanceRepository, ALLOWANCE_REPOSITORY},
+    services::{AccountService, ACCOUNT_SERVICE},
+};
+use candid::Nat;
+use lazy_static::lazy_static;
+use orbit_essentials::types::UUID;
+use orbit_essentials::{api::ServiceResult, model::ModelValidator, repository::Repository};
+use station_api::{AllowanceStatusDTO, ListAccountAllowancesInput};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref ALLOWANCE_SERVICE: Arc<AllowanceService> = Arc::new(AllowanceService::new(
+        Arc::clone(&ALLOWANCE_REPOSITORY),
+        Arc::clone(&ACCOUNT_SERVICE),
+    ));
+}
+
+#[derive(Default, Debug)]
+pub struct AllowanceService {
+    allowance_repository: Arc<AllowanceRepository>,
+    account_service: Arc<AccountService>,
+}
+
+impl AllowanceService {
+    pub const DEFAULT_ALLOWANCES_LIMIT: u16 = 100;
+    pub const MAX_LIST_ALLOWANCES_LIMIT: u16 = 1000;
+
+    pub fn new(
+        allowance_repository: Arc<AllowanceRepository>,
+        account_service: Arc<AccountService>,
+    ) -> Self {
+        Self {
+            allowance_repository,
+            account_service,
+        }
+    }
+
+    /// Returns the allowance with the given id.
+    pub fn get(&self, allowance_id: &AllowanceId) -> ServiceResult<Allowance> {
+        let allowance =
+            self.allowance_repository
+                .get(allowance_id)
+                .ok_or(AllowanceError::NotFound {
+                    id: Uuid::from_bytes(*allowance_id).hyphenated().to_string(),
+                })?;
+
+        Ok(allowance)
+    }
+
+    /// Returns the allowances granted by the given account, optionally filtered by status.
+    pub fn list_account_allowances(
+        &self,
+        input: ListAccountAllowancesInput,
+    ) -> ServiceResult<PaginatedData<Allowance>> {
+        let account_id = HelperMapper::to_uuid(input.account_id)?;
+        let account = self.account_service.get_account(account_id.as_bytes())?;
+
+        let mut allowances = self.allowance_repository.find_by_account(&account.id);
+        if let Some(status) = input.status {
+            allowances.retain(|allowance| match status {
+                AllowanceStatusDTO::Active => allowance.is_active(),
+                AllowanceStatusDTO::Revoked => !allowance.is_active(),
+            });
+        }
+
+        let result = paginated_items(PaginatedItemsArgs {
+            offset: input.paginate.to_owned().and_then(|p| p.offset),
+            limit: input.paginate.and_then(|p| p.limit),
+            default_limit: Some(Self::DEFAULT_ALLOWANCES_LIMIT),
+            max_limit: Some(Self::MAX_LIST_ALLOWANCES_LIMIT),
+            items: &allowances,
+        })?;
+
+        Ok(result)
+    }
+
+    /// Approves the allowance on the ledger of the account and records it.
+    ///
+    /// The ledger keeps a single allowance per spender, hence approving an allowance for a spender
+    /// that already has an active one updates the existing record.
+    pub async fn approve(
+        &self,
+        request_id: UUID,
+        input: ApproveAllowanceOperationInput,
+    ) -> ServiceResult<Allowance> {
+        let account = self.get_allowance_capable_account(&input.from_account_id)?;
+        let now = next_time();
+        let mut allowance = match self
+            .allowance_repository
+            .find_active_by_spender(&account.id, &input.spender)
+        {
+            Some(allowance) => allowance,
+            None => Allowance {
+                id: *generate_uuid_v4().await.as_bytes(),
+                request_id,
+                account_id: account.id,
+                spender: input.spender.clone(),
+                amount: input.amount.clone(),
+                expires_at: input.expires_at,
+                status: AllowanceStatus::Active,
+                created_timestamp: now,
+                last_modification_timestamp: now,
+            },
+        };
+
+        allowance.request_id = request_id;
+        allowance.amount = input.amount.clone();
+        allowance.expires_at = input.expires_at;
+        allowance.last_modification_timestamp = now;
+
+        allowance.validate()?;
+
+        InternetComputerIcrc1::create()
+            .submit_approve(
+                &account,
+                &input.spender,
+                input.amount,
+                input.expected_allowance,
+                input.expires_at,
+                input.fee,
+            )
+            .await
+            .map_err(|e| AllowanceError::ApproveFailed {
+                info: e.to_string(),
+            })?;
+
+        self.allowance_repository
+            .insert(allowance.id, allowance.clone());
+
+        Ok(allowance)
+    }
+
+    /// Revokes the allowance by setting it to zero on the ledger of the account.
+    pub async fn revoke(&self, input: RevokeAllowanceOperationInput) -> ServiceResult<Allowance> {
+        let mut allowance = self.get(&input.allowance_id)?;
+
+        if allowance.account_id != input.from_account_id {
+            Err(AllowanceError::AccountMismatch {
+                allowance_id: Uuid::from_bytes(allowance.id).hyphenated().to_string(),
+                account_id: Uuid::from_bytes(input.from_account_id)
+                    .hyphenated()
+                    .to_string(),
+            })?;
+        }
+
+        if !allowance.is_active() {
+            Err(AllowanceError::AlreadyRevoked {
+                id: Uuid::from_bytes(allowance.id).hyphenated().to_string(),
+            })?;
+        }
+
+        let account = self.get_allowance_capable_account(&allowance.account_id)?;
+
+        InternetComputerIcrc1::create()
+            .submit_approve(
+                &account,
+                &allowance.spender,
+                Nat::from(0u64),
+                None,
+                None,
+                None,
+            )
+            .await
+            .map_err(|e| AllowanceError::ApproveFailed {
+                info: e.to_string(),
+            })?;
+
+        let now = next_time();
+        allowance.status = AllowanceStatus::Revoked { revoked_at: now };
+        allowance.last_modification_timestamp = now;
+
+        self.allowance_repository
+            .insert(allowance.id, allowance.clone());
+
+        Ok(allowance)
+    }
+
+    /// Only accounts that hold tokens of an ICRC ledger can grant allowances.
+    fn get_allowance_capable_account(&self, account_id: &AccountId) -> ServiceResult<Account> {
+        let account = self.account_service.get_account(account_id)?;
+
+        if account.standard != BlockchainStandard::ICRC1 {
+            Err(AllowanceError::UnsupportedAccountStandard {
+                standard: account.standard.to_string(),
+            })?;
+        }
+
+        Ok(account)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{account_test_utils::mock_account, allowance_test_utils::mock_allowance},
+        repositories::ACCOUNT_REPOSITORY,
+    };
+
+    #[tokio::test]
+    async fn list_account_allowances_by_status() {
+        test_utils::init_canister_system();
+
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let mut active = mock_allowance();
+        active.account_id = account.id;
+        let mut revoked = mock_allowance();
+        revoked.account_id = account.id;
+        revoked.status = AllowanceStatus::Revoked { revoked_at: 1 };
+
+        ALLOWANCE_REPOSITORY.insert(active.id, active.clone());
+        ALLOWANCE_REPOSITORY.insert(revoked.id, revoked.clone());
+        ALLOWANCE_REPOSITORY.insert(mock_allowance().id, mock_allowance());
+
+        let all = ALLOWANCE_SERVICE
+            .list_account_allowances(ListAccountAllowancesInput {
+                account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+                status: None,
+                paginate: None,
+            })
+            .unwrap();
+
+        assert_eq!(all.total, 2);
+
+        let active_only = ALLOWANCE_SERVICE
+            .list_account_allowances(ListAccountAllowancesInput {
+                account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+                status: Some(AllowanceStatusDTO::Active),
+                paginate: None,
+            })
+            .unwrap();
+
+        assert_eq!(active_only.items, vec![active]);
+    }
+
+    #[tokio::test]
+    async fn fail_revoke_allowance_of_other_account() {
+        test_utils::init_canister_system();
+
+        let allowance = mock_allowance();
+        ALLOWANCE_REPOSITORY.insert(allowance.id, allowance.clone());
+
+        let result = ALLOWANCE_SERVICE
+            .revoke(RevokeAllowanceOperationInput {
+                from_account_id: [1; 16],
+                allowance_id: allowance.id,
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err().code, "ACCOUNT_MISMATCH".to_string());
+    }
+
+    #[tokio::test]
+    async fn fail_revoke_already_revoked_allowance() {
+        test_utils::init_canister_system();
+
+        let mut allowance = mock_allowance();
+        allowance.status = AllowanceStatus::Revoked { revoked_at: 1 };
+        ALLOWANCE_REPOSITORY.insert(allowance.id, allowance.clone());
+
+        let result = ALLOWANCE_SERVICE
+            .revoke(RevokeAllowanceOperationInput {
+                from_account_id: allowance.account_id,
+                allowance_id: allowance.id,
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err().code, "ALREADY_REVOKED".to_string());
+    }
+
+    #[tokio::test]
+    async fn fail_approve_on_native_account() {
+        test_utils::init_canister_system();
+
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let result = ALLOWANCE_SERVICE
+            .approve(
+                [0; 16],
+                ApproveAllowanceOperationInput {
+                    from_account_id: account.id,
+                    spender: mock_allowance().spender,
+                    amount: Nat::from(1u64),
+                    expected_allowance: None,
+                    expires_at: None,
+                    fee: None,
+                },
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().code,
+            "UNSUPPORTED_ACCOUNT_STANDARD".to_string()
+        );
+    }
+}
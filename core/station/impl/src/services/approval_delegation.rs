@@ -0,0 +1,139 @@
+use crate::{
+    core::{evaluation::REQUEST_MATCHER, generate_uuid_v4, ic_cdk::next_time},
+    models::{
+        request_specifier::Match, AddApprovalDelegationOperationInput, ApprovalDelegation, Request,
+        UserId,
+    },
+    repositories::{ApprovalDelegationRepository, APPROVAL_DELEGATION_REPOSITORY},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, model::ModelValidator, repository::Repository};
+use std::{collections::HashSet, sync::Arc};
+
+lazy_static! {
+    pub static ref APPROVAL_DELEGATION_SERVICE: Arc<ApprovalDelegationService> = Arc::new(
+        ApprovalDelegationService::new(Arc::clone(&APPROVAL_DELEGATION_REPOSITORY))
+    );
+}
+
+#[derive(Default, Debug)]
+pub struct ApprovalDelegationService {
+    approval_delegation_repository: Arc<ApprovalDelegationRepository>,
+}
+
+impl ApprovalDelegationService {
+    pub fn new(approval_delegation_repository: Arc<ApprovalDelegationRepository>) -> Self {
+        Self {
+            approval_delegation_repository,
+        }
+    }
+
+    /// Delegates the approval rights of the delegator to the delegate until the delegation expires.
+    pub async fn add_delegation(
+        &self,
+        input: AddApprovalDelegationOperationInput,
+    ) -> ServiceResult<ApprovalDelegation> {
+        let delegation = ApprovalDelegation {
+            id: *generate_uuid_v4().await.as_bytes(),
+            delegator_id: input.delegator_id,
+            delegate_id: input.delegate_id,
+            specifier: input.specifier,
+            expires_at: input.expires_at,
+            created_timestamp: next_time(),
+        };
+
+        delegation.validate()?;
+
+        self.approval_delegation_repository
+            .insert(delegation.id, delegation.clone());
+
+        Ok(delegation)
+    }
+
+    /// Finds the delegates that can approve the request on behalf of any of the given approvers.
+    pub fn find_delegates_for_request(
+        &self,
+        request: &Request,
+        approvers: &HashSet<UserId>,
+    ) -> HashSet<UserId> {
+        let now = next_time();
+
+        approvers
+            .iter()
+            .flat_map(|approver_id| {
+                self.approval_delegation_repository
+                    .find_active_by_delegator(approver_id, now)
+            })
+            .filter(|delegation| match &delegation.specifier {
+                Some(specifier) => REQUEST_MATCHER
+                    .is_match((request.to_owned(), specifier.to_owned()))
+                    .unwrap_or(false),
+                None => true,
+            })
+            .map(|delegation| delegation.delegate_id)
+            .collect()
+    }
+
+    /// Removes the active delegations that the user has given or received.
+    pub fn remove_user_delegations(&self, user_id: &UserId) {
+        let now = next_time();
+
+        for delegation in self
+            .approval_delegation_repository
+            .find_active_by_delegator(user_id, now)
+            .into_iter()
+            .chain(
+                self.approval_delegation_repository
+                    .find_active_by_delegate(user_id, now),
+            )
+        {
+            self.approval_delegation_repository.remove(&delegation.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models::user_test_utils::mock_user, repositories::USER_REPOSITORY};
+
+    #[tokio::test]
+    async fn add_delegation_between_users() {
+        let delegator = mock_user();
+        let delegate = mock_user();
+        USER_REPOSITORY.insert(delegator.to_key(), delegator.clone());
+        USER_REPOSITORY.insert(delegate.to_key(), delegate.clone());
+
+        let delegation = APPROVAL_DELEGATION_SERVICE
+            .add_delegation(AddApprovalDelegationOperationInput {
+                delegator_id: delegator.id,
+                delegate_id: delegate.id,
+                specifier: None,
+                expires_at: u64::MAX,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            APPROVAL_DELEGATION_REPOSITORY.find_active_by_delegate(&delegate.id, next_time()),
+            vec![delegation]
+        );
+    }
+
+    #[tokio::test]
+    async fn fail_delegation_to_self() {
+        let delegator = mock_user();
+        USER_REPOSITORY.insert(delegator.to_key(), delegator.clone());
+
+        let result = APPROVAL_DELEGATION_SERVICE
+            .add_delegation(AddApprovalDelegationOperationInput {
+                delegator_id: delegator.id,
+                delegate_id: delegator.id,
+                specifier: None,
+                expires_at: u64::MAX,
+            })
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{
+    core::{
+        authorization::Authorization,
+        generate_uuid_v4,
+        ic_cdk::next_time,
+        utils::{paginated_items, retain_accessible_resources, PaginatedData, PaginatedItemsArgs},
+        CallContext,
+    },
+    errors::AssetError,
+    models::{
+        resource::{Resource, ResourceAction, ResourceId},
+        Account, AddAssetOperationInput, Asset, AssetCallerPrivileges, AssetId, ChangeMetadata,
+        EditAssetOperationInput, RemoveAssetOperationInput,
+    },
+    repositories::{AccountRepository, AssetRepository, ACCOUNT_REPOSITORY, ASSET_REPOSITORY},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, model::ModelValidator, repository::Repository};
+use station_api::ListAssetsInput;
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref ASSET_SERVICE: Arc<AssetService> = Arc::new(AssetService::new(
+        Arc::clone(&ASSET_REPOSITORY),
+        Arc::clone(&ACCOUNT_REPOSITORY),
+    ));
+}
+
+#[derive(Default, Debug)]
+pub struct AssetService {
+    asset_repository: Arc<AssetRepository>,
+    account_repository: Arc<AccountRepository>,
+}
+
+impl AssetService {
+    pub const DEFAULT_ASSETS_LIMIT: u16 = 100;
+    pub const MAX_LIST_ASSETS_LIMIT: u16 = 1000;
+
+    pub fn new(
+        asset_repository: Arc<AssetRepository>,
+        account_repository: Arc<AccountRepository>,
+    ) -> Self {
+        Self {
+            asset_repository,
+            account_repository,
+        }
+    }
+
+    /// Returns the asset with the given id.
+    pub fn get(&self, asset_id: &AssetId) -> ServiceResult<Asset> {
+        let asset = self
+            .asset_repository
+            .get(asset_id)
+            .ok_or(AssetError::NotFound {
+                id: Uuid::from_bytes(*asset_id).hyphenated().to_string(),
+            })?;
+
+        Ok(asset)
+    }
+
+    /// Returns the caller privileges for the given asset.
+    pub async fn get_caller_privileges_for_asset(
+        &self,
+        asset_id: &AssetId,
+        ctx: &CallContext,
+    ) -> ServiceResult<AssetCallerPrivileges> {
+        Ok(AssetCallerPrivileges {
+            id: *asset_id,
+            can_edit: Authorization::is_allowed(
+                ctx,
+                &Resource::Asset(ResourceAction::Update(ResourceId::Id(*asset_id))),
+            ),
+            can_delete: Authorization::is_allowed(
+                ctx,
+                &Resource::Asset(ResourceAction::Delete(ResourceId::Id(*asset_id))),
+            ),
+        })
+    }
+
+    /// Returns the assets that the caller has access to, sorted by symbol.
+    pub async fn list(
+        &self,
+        input: ListAssetsInput,
+        ctx: Option<&CallContext>,
+    ) -> ServiceResult<PaginatedData<Asset>> {
+        let mut assets = self.asset_repository.list();
+        assets.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+
+        // filter out assets that the caller does not have access to read
+        if let Some(ctx) = ctx {
+            retain_accessible_resources(ctx, &mut assets, |asset| {
+                Resource::Asset(ResourceAction::Read(ResourceId::Id(asset.id)))
+            });
+        }
+
+        let result = paginated_items(PaginatedItemsArgs {
+            offset: input.paginate.to_owned().and_then(|p| p.offset),
+            limit: input.paginate.and_then(|p| p.limit),
+            default_limit: Some(Self::DEFAULT_ASSETS_LIMIT),
+            max_limit: Some(Self::MAX_LIST_ASSETS_LIMIT),
+            items: &assets,
+        })?;
+
+        Ok(result)
+    }
+
+    /// Creates a new asset.
+    pub async fn create(&self, input: AddAssetOperationInput) -> ServiceResult<Asset> {
+        let asset_id = generate_uuid_v4().await;
+        let asset = Asset {
+            id: *asset_id.as_bytes(),
+            blockchain: input.blockchain,
+            standard: input.standard,
+            symbol: input.symbol,
+            name: input.name,
+            metadata: input.metadata,
+            last_modification_timestamp: next_time(),
+        };
+
+        asset.validate()?;
+
+        self.asset_repository.insert(asset.id, asset.clone());
+
+        Ok(asset)
+    }
+
+    /// Edits an existing asset.
+    ///
+    /// The accounts that hold the asset keep a copy of its symbol and metadata, hence they are
+    /// updated as well to reflect the changes.
+    pub async fn edit(&self, input: EditAssetOperationInput) -> ServiceResult<Asset> {
+        let mut asset = self.get(&input.asset_id)?;
+
+        if let Some(name) = input.name {
+            asset.name = name;
+        }
+
+        if let Some(symbol) = input.symbol {
+            asset.symbol = symbol;
+        }
+
+        let previous_metadata = asset.metadata.as_btreemap().clone();
+        if let Some(change_metadata) = input.change_metadata {
+            asset.metadata.change(change_metadata);
+        }
+        // the keys removed from the asset are removed from its accounts as well, while the metadata
+        // that belongs to the accounts themselves is kept
+        let removed_keys = previous_metadata
+            .into_keys()
+            .filter(|key| !asset.metadata.as_btreemap().contains_key(key))
+            .collect::<Vec<_>>();
+
+        asset.last_modification_timestamp = next_time();
+        asset.validate()?;
+
+        self.asset_repository.insert(asset.id, asset.clone());
+
+        for mut account in self.find_accounts_by_asset(&asset.id) {
+            account.symbol = asset.symbol.clone();
+            account
+                .metadata
+                .change(ChangeMetadata::RemoveKeys(removed_keys.clone()));
+            account.metadata.change(ChangeMetadata::OverrideSpecifiedBy(
+                asset.metadata.as_btreemap().clone(),
+            ));
+            account.last_modification_timestamp = next_time();
+
+            self.account_repository.insert(account.to_key(), account);
+        }
+
+        Ok(asset)
+    }
+
+    /// Removes an existing asset, assets that are still held by accounts can't be removed.
+    pub async fn remove(&self, input: RemoveAssetOperationInput) -> ServiceResult<Asset> {
+        let asset = self.get(&input.asset_id)?;
+
+        let accounts = self.find_accounts_by_asset(&asset.id);
+        if !accounts.is_empty() {
+            Err(AssetError::AssetInUse {
+                accounts: accounts.len(),
+            })?;
+        }
+
+        self.asset_repository.remove(&asset.id);
+
+        Ok(asset)
+    }
+
+    fn find_accounts_by_asset(&self, asset_id: &AssetId) -> Vec<Account> {
+        self.account_repository
+            .list()
+            .into_iter()
+            .filter(|account| account.asset_id == *asset_id)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{account_test_utils::mock_account, Blockchain, BlockchainStandard, Metadata},
+    };
+
+    fn add_asset_input(symbol: &str) -> AddAssetOperationInput {
+        AddAssetOperationInput {
+            blockchain: Blockchain::InternetComputer,
+            standard: BlockchainStandard::Native,
+            symbol: symbol.to_string(),
+            name: "Internet Computer".to_string(),
+            metadata: Metadata::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_asset() {
+        test_utils::init_canister_system();
+
+        let asset = ASSET_SERVICE.create(add_asset_input("ICP")).await.unwrap();
+
+        assert_eq!(ASSET_SERVICE.get(&asset.id).unwrap(), asset);
+    }
+
+    #[tokio::test]
+    async fn fail_create_duplicated_asset() {
+        test_utils::init_canister_system();
+
+        ASSET_SERVICE.create(add_asset_input("ICP")).await.unwrap();
+        let result = ASSET_SERVICE.create(add_asset_input("icp")).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn edit_asset_updates_accounts() {
+        test_utils::init_canister_system();
+
+        let asset = ASSET_SERVICE.create(add_asset_input("ICP")).await.unwrap();
+        let mut account = mock_account();
+        account.asset_id = asset.id;
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let asset = ASSET_SERVICE
+            .edit(EditAssetOperationInput {
+                asset_id: asset.id,
+                name: None,
+                symbol: Some("ICP2".to_string()),
+                change_metadata: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(asset.symbol, "ICP2");
+        assert_eq!(
+            ACCOUNT_REPOSITORY.get(&account.to_key()).unwrap().symbol,
+            "ICP2"
+        );
+    }
+
+    #[tokio::test]
+    async fn edit_asset_removes_deleted_metadata_from_accounts() {
+        test_utils::init_canister_system();
+
+        let mut input = add_asset_input("ICP");
+        input.metadata = Metadata::new(
+            [
+                ("ledger_canister_id".to_string(), "ledger".to_string()),
+                ("index_canister_id".to_string(), "index".to_string()),
+            ]
+            .into(),
+        );
+        let asset = ASSET_SERVICE.create(input).await.unwrap();
+        let mut account = mock_account();
+        account.asset_id = asset.id;
+        account.metadata = Metadata::new(
+            [
+                ("ledger_canister_id".to_string(), "ledger".to_string()),
+                ("index_canister_id".to_string(), "index".to_string()),
+                ("label".to_string(), "treasury".to_string()),
+            ]
+            .into(),
+        );
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        ASSET_SERVICE
+            .edit(EditAssetOperationInput {
+                asset_id: asset.id,
+                name: None,
+                symbol: None,
+                change_metadata: Some(ChangeMetadata::ReplaceAllBy(
+                    [("ledger_canister_id".to_string(), "new-ledger".to_string())].into(),
+                )),
+            })
+            .await
+            .unwrap();
+
+        let account = ACCOUNT_REPOSITORY.get(&account.to_key()).unwrap();
+        assert_eq!(
+            account.metadata.as_btreemap(),
+            &[
+                ("label".to_string(), "treasury".to_string()),
+                ("ledger_canister_id".to_string(), "new-ledger".to_string()),
+            ]
+            .into()
+        );
+    }
+
+    #[tokio::test]
+    async fn fail_remove_asset_in_use() {
+        test_utils::init_canister_system();
+
+        let asset = ASSET_SERVICE.create(add_asset_input("ICP")).await.unwrap();
+        let mut account = mock_account();
+        account.asset_id = asset.id;
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let result = ASSET_SERVICE
+            .remove(RemoveAssetOperationInput { asset_id: asset.id })
+            .await;
+
+        assert_eq!(result.unwrap_err().code, "ASSET_IN_USE".to_string());
+
+        ACCOUNT_REPOSITORY.remove(&account.to_key());
+
+        assert!(ASSET_SERVICE
+            .remove(RemoveAssetOperationInput { asset_id: asset.id })
+            .await
+            .is_ok());
+        assert!(ASSET_SERVICE.get(&asset.id).is_err());
+    }
+}
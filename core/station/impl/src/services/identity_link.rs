@@ -0,0 +1,322 @@
+use crate::{
+    core::{generate_uuid_v4, ic_cdk::next_time, CallContext},
+    errors::{IdentityLinkError, UserError},
+    mappers::HelperMapper,
+    models::{
+        IdentityLink, LinkUserIdentityOperation, LinkUserIdentityOperationInput, Request,
+        RequestExecutionPlan, RequestOperation, User, UserStatus,
+    },
+    repositories::{IdentityLinkRepository, IDENTITY_LINK_REPOSITORY},
+    services::{RequestService, UserService, REQUEST_SERVICE, USER_SERVICE},
+};
+use candid::Principal;
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult, model::ModelValidator, repository::Repository, types::Timestamp,
+};
+use station_api::{ConfirmIdentityLinkInput, StartIdentityLinkInput};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref IDENTITY_LINK_SERVICE: Arc<IdentityLinkService> =
+        Arc::new(IdentityLinkService::new(
+            Arc::clone(&IDENTITY_LINK_REPOSITORY),
+            Arc::clone(&USER_SERVICE),
+            Arc::clone(&REQUEST_SERVICE),
+        ));
+}
+
+#[derive(Default, Debug)]
+pub struct IdentityLinkService {
+    identity_link_repository: Arc<IdentityLinkRepository>,
+    user_service: Arc<UserService>,
+    request_service: Arc<RequestService>,
+}
+
+impl IdentityLinkService {
+    pub fn new(
+        identity_link_repository: Arc<IdentityLinkRepository>,
+        user_service: Arc<UserService>,
+        request_service: Arc<RequestService>,
+    ) -> Self {
+        Self {
+            identity_link_repository,
+            user_service,
+            request_service,
+        }
+    }
+
+    /// Starts linking a new identity to the caller user, the link needs to be confirmed by the new
+    /// identity within the confirmation window.
+    pub async fn start_identity_link(
+        &self,
+        input: StartIdentityLinkInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<IdentityLink> {
+        let user = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let now = next_time();
+
+        self.remove_expired_links(now);
+
+        if user.status != UserStatus::Active {
+            Err(IdentityLinkError::UserNotActive)?
+        }
+
+        self.assert_identity_is_free(&input.new_identity)?;
+
+        if !input.retire_current_identity
+            && user.identities.len() >= User::IDENTITIES_RANGE.1 as usize
+        {
+            Err(UserError::TooManyIdentities {
+                max_identities: User::IDENTITIES_RANGE.1,
+            })?
+        }
+
+        // a new link for the same identity replaces the previous one of the user
+        for previous_link in self
+            .identity_link_repository
+            .find_by_new_identity(&input.new_identity)
+            .into_iter()
+            .filter(|link| link.user_id == user.id)
+        {
+            self.identity_link_repository.remove(&previous_link.id);
+        }
+
+        let link = IdentityLink {
+            id: *generate_uuid_v4().await.as_bytes(),
+            user_id: user.id,
+            current_identity: ctx.caller(),
+            new_identity: input.new_identity,
+            retire_current_identity: input.retire_current_identity,
+            expires_at: now + IdentityLink::CONFIRMATION_WINDOW_NS,
+            created_timestamp: now,
+        };
+
+        link.validate()?;
+
+        self.identity_link_repository
+            .insert(link.id, link.to_owned());
+
+        Ok(link)
+    }
+
+    /// Confirms the identity link from the new identity, which proves that the user controls it.
+    ///
+    /// The link can only be used once, the identity is then added to the user through a request so
+    /// that the request policies of the station still apply.
+    pub async fn confirm_identity_link(
+        &self,
+        input: ConfirmIdentityLinkInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Request> {
+        let link_id = HelperMapper::to_uuid(input.link_id)?;
+        let link = self
+            .identity_link_repository
+            .get(link_id.as_bytes())
+            .ok_or(IdentityLinkError::NotFound {
+                id: link_id.hyphenated().to_string(),
+            })?;
+
+        if ctx.caller() != link.new_identity {
+            Err(IdentityLinkError::NotNewIdentity)?
+        }
+
+        self.identity_link_repository.remove(&link.id);
+
+        if link.is_expired_at(next_time()) {
+            Err(IdentityLinkError::Expired {
+                id: link_id.hyphenated().to_string(),
+            })?
+        }
+
+        let user = self.user_service.get_user(&link.user_id)?;
+
+        if user.status != UserStatus::Active {
+            Err(IdentityLinkError::UserNotActive)?
+        }
+
+        if !user.identities.contains(&link.current_identity) {
+            Err(IdentityLinkError::ValidationError {
+                info: "The identity that started the link no longer belongs to the user"
+                    .to_string(),
+            })?
+        }
+
+        self.assert_identity_is_free(&link.new_identity)?;
+
+        let request = Request::new(
+            *generate_uuid_v4().await.as_bytes(),
+            user.id,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::LinkUserIdentity(LinkUserIdentityOperation {
+                input: LinkUserIdentityOperationInput {
+                    user_id: user.id,
+                    identity: link.new_identity,
+                    retired_identity: link
+                        .retire_current_identity
+                        .then_some(link.current_identity),
+                },
+            }),
+            RequestExecutionPlan::Immediate,
+            "Identity link".to_string(),
+            None,
+        );
+
+        self.request_service.submit_request(request).await
+    }
+
+    fn assert_identity_is_free(&self, identity: &Principal) -> ServiceResult<()> {
+        if let Ok(user) = self.user_service.get_user_by_identity(identity) {
+            Err(UserError::IdentityAlreadyHasUser {
+                user: Uuid::from_bytes(user.id).hyphenated().to_string(),
+            })?
+        }
+
+        Ok(())
+    }
+
+    fn remove_expired_links(&self, now: Timestamp) {
+        for link in self.identity_link_repository.find_expired(now) {
+            self.identity_link_repository.remove(&link.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            request_policy_rule::RequestPolicyRule, request_policy_test_utils::mock_request_policy,
+            request_specifier::RequestSpecifier, resource::ResourceIds, user_test_utils::mock_user,
+            RequestStatus,
+        },
+        repositories::{request_policy::REQUEST_POLICY_REPOSITORY, USER_REPOSITORY},
+    };
+
+    fn setup() -> User {
+        test_utils::init_canister_system();
+
+        let mut user = mock_user();
+        user.identities = vec![Principal::from_slice(&[1; 29])];
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        user
+    }
+
+    #[tokio::test]
+    async fn link_identity_once_the_new_identity_confirms() {
+        let user = setup();
+        let mut policy = mock_request_policy();
+        policy.specifier = RequestSpecifier::LinkUserIdentity(ResourceIds::Any);
+        policy.rule = RequestPolicyRule::AutoApproved;
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.to_owned());
+
+        let current_identity = Principal::from_slice(&[1; 29]);
+        let new_identity = Principal::from_slice(&[2; 29]);
+        let link = IDENTITY_LINK_SERVICE
+            .start_identity_link(
+                StartIdentityLinkInput {
+                    new_identity,
+                    retire_current_identity: true,
+                },
+                &CallContext::new(current_identity),
+            )
+            .await
+            .unwrap();
+        let link_id = Uuid::from_bytes(link.id).hyphenated().to_string();
+
+        let result = IDENTITY_LINK_SERVICE
+            .confirm_identity_link(
+                ConfirmIdentityLinkInput {
+                    link_id: link_id.clone(),
+                },
+                &CallContext::new(Principal::from_slice(&[3; 29])),
+            )
+            .await;
+
+        assert_eq!(result.unwrap_err().code, "NOT_NEW_IDENTITY");
+
+        let request = IDENTITY_LINK_SERVICE
+            .confirm_identity_link(
+                ConfirmIdentityLinkInput {
+                    link_id: link_id.clone(),
+                },
+                &CallContext::new(new_identity),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(request.requested_by, user.id);
+        assert_eq!(request.status, RequestStatus::Approved);
+        assert_eq!(
+            request.operation,
+            RequestOperation::LinkUserIdentity(LinkUserIdentityOperation {
+                input: LinkUserIdentityOperationInput {
+                    user_id: user.id,
+                    identity: new_identity,
+                    retired_identity: Some(current_identity),
+                },
+            })
+        );
+
+        // the link can only be confirmed once
+        let result = IDENTITY_LINK_SERVICE
+            .confirm_identity_link(
+                ConfirmIdentityLinkInput { link_id },
+                &CallContext::new(new_identity),
+            )
+            .await;
+
+        assert_eq!(result.unwrap_err().code, "NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn fail_confirming_expired_link() {
+        let user = setup();
+        let link = IdentityLink {
+            id: *Uuid::new_v4().as_bytes(),
+            user_id: user.id,
+            current_identity: Principal::from_slice(&[1; 29]),
+            new_identity: Principal::from_slice(&[2; 29]),
+            retire_current_identity: false,
+            expires_at: 1,
+            created_timestamp: 0,
+        };
+        IDENTITY_LINK_REPOSITORY.insert(link.id, link.clone());
+
+        let result = IDENTITY_LINK_SERVICE
+            .confirm_identity_link(
+                ConfirmIdentityLinkInput {
+                    link_id: Uuid::from_bytes(link.id).hyphenated().to_string(),
+                },
+                &CallContext::new(link.new_identity),
+            )
+            .await;
+
+        assert_eq!(result.unwrap_err().code, "EXPIRED");
+        assert!(IDENTITY_LINK_REPOSITORY.get(&link.id).is_none());
+    }
+
+    #[tokio::test]
+    async fn fail_linking_identity_of_another_user() {
+        setup();
+        let mut other_user = mock_user();
+        other_user.identities = vec![Principal::from_slice(&[4; 29])];
+        USER_REPOSITORY.insert(other_user.to_key(), other_user.clone());
+
+        let result = IDENTITY_LINK_SERVICE
+            .start_identity_link(
+                StartIdentityLinkInput {
+                    new_identity: Principal::from_slice(&[4; 29]),
+                    retire_current_identity: false,
+                },
+                &CallContext::new(Principal::from_slice(&[1; 29])),
+            )
+            .await;
+
+        assert_eq!(result.unwrap_err().code, "IDENTITY_ALREADY_HAS_USER");
+    }
+}
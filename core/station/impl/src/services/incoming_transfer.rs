@@ -0,0 +1,95 @@
+use crate::{
+    core::ic_cdk::next_time,
+    factories::blockchains::BlockchainApiFactory,
+    models::{Account, IncomingTransfer, IncomingTransferSyncState},
+    repositories::{
+        IncomingTransferRepository, IncomingTransferSyncStateRepository,
+        INCOMING_TRANSFER_REPOSITORY, INCOMING_TRANSFER_SYNC_STATE_REPOSITORY,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, repository::Repository};
+use std::sync::Arc;
+
+lazy_static! {
+    pub static ref INCOMING_TRANSFER_SERVICE: Arc<IncomingTransferService> =
+        Arc::new(IncomingTransferService::new(
+            Arc::clone(&INCOMING_TRANSFER_REPOSITORY),
+            Arc::clone(&INCOMING_TRANSFER_SYNC_STATE_REPOSITORY),
+        ));
+}
+
+#[derive(Default, Debug)]
+pub struct IncomingTransferService {
+    incoming_transfer_repository: Arc<IncomingTransferRepository>,
+    sync_state_repository: Arc<IncomingTransferSyncStateRepository>,
+}
+
+impl IncomingTransferService {
+    /// The number of ledger transactions that are read at once for each account.
+    pub const PAGE_SIZE: u64 = 100;
+
+    pub fn new(
+        incoming_transfer_repository: Arc<IncomingTransferRepository>,
+        sync_state_repository: Arc<IncomingTransferSyncStateRepository>,
+    ) -> Self {
+        Self {
+            incoming_transfer_repository,
+            sync_state_repository,
+        }
+    }
+
+    /// Returns how far the ledger history of the account was indexed.
+    pub fn get_sync_state(&self, account: &Account) -> IncomingTransferSyncState {
+        self.sync_state_repository
+            .get(&account.id)
+            .unwrap_or_else(|| IncomingTransferSyncState::new(account.id))
+    }
+
+    /// Indexes the next page of the ledger history of the account and stores the deposits it contains.
+    ///
+    /// Returns `true` if the account is synced with its ledger history, `false` if there are older
+    /// transactions left to index.
+    pub async fn index_account(&self, account: &Account) -> ServiceResult<bool> {
+        let mut sync_state = self.get_sync_state(account);
+        let blockchain_api = BlockchainApiFactory::build(&account.blockchain, &account.standard)?;
+        let transactions = blockchain_api
+            .account_transactions(account, sync_state.walk_cursor_tx_id, Self::PAGE_SIZE)
+            .await?;
+
+        let now = next_time();
+        let mut reached_indexed = false;
+        for transaction in &transactions {
+            if sync_state.is_indexed(transaction.tx_id) {
+                reached_indexed = true;
+                break;
+            }
+
+            if let Some(deposit) = &transaction.deposit {
+                let incoming_transfer = IncomingTransfer {
+                    account_id: account.id,
+                    tx_id: transaction.tx_id,
+                    from_address: deposit.from_address.clone(),
+                    amount: candid::Nat(deposit.amount.clone()),
+                    timestamp: transaction.timestamp,
+                    created_timestamp: now,
+                };
+
+                self.incoming_transfer_repository
+                    .insert(incoming_transfer.to_key(), incoming_transfer);
+            }
+
+            sync_state.advance(transaction.tx_id);
+        }
+
+        if reached_indexed || (transactions.len() as u64) < Self::PAGE_SIZE {
+            sync_state.complete_walk();
+        }
+
+        sync_state.last_modification_timestamp = now;
+        self.sync_state_repository
+            .insert(sync_state.account_id, sync_state.clone());
+
+        Ok(sync_state.is_synced())
+    }
+}
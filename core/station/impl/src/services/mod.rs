@@ -5,6 +5,14 @@ pub use account::*;
 
 mod address_book;
 pub use address_book::*;
+mod allowance;
+pub use allowance::*;
+
+mod approval_delegation;
+pub use approval_delegation::*;
+
+mod asset;
+pub use asset::*;
 
 mod notification;
 pub use notification::*;
@@ -12,21 +20,42 @@ pub use notification::*;
 mod transfer;
 pub use transfer::*;
 
+mod incoming_transfer;
+pub use incoming_transfer::*;
+
 mod user;
 pub use user::*;
 
+mod identity_link;
+pub use identity_link::*;
+
+mod recovery;
+pub use recovery::*;
+
 mod user_group;
 pub use user_group::*;
 
+mod role_template;
+pub use role_template::*;
+
 mod request;
 pub use request::*;
 
+mod request_comment;
+pub use request_comment::*;
+
+mod request_template;
+pub use request_template::*;
+
 mod system;
 pub use system::*;
 
 mod request_policy;
 pub use request_policy::*;
 
+mod standing_order;
+pub use standing_order::*;
+
 mod change_canister;
 pub use change_canister::*;
 
@@ -1,14 +1,20 @@
+use super::request_policy::LOCK_OUT_SENSITIVE_RESOURCES;
 use crate::{
     core::{
+        authorization::Authorization,
         utils::{paginated_items, PaginatedData, PaginatedItemsArgs},
         validation::{EnsureIdExists, EnsureUser, EnsureUserGroup},
     },
+    errors::PermissionError,
     models::{
-        permission::{Allow, Permission},
+        permission::{AccessExplanation, Allow, AuthScope, Permission},
         resource::Resource,
-        EditPermissionOperationInput, User, UserGroup,
+        EditPermissionOperationInput, User, UserGroup, UserId, UserStatus,
+    },
+    repositories::{
+        permission::{PermissionRepository, PERMISSION_REPOSITORY},
+        USER_REPOSITORY,
     },
-    repositories::permission::{PermissionRepository, PERMISSION_REPOSITORY},
     services::{UserGroupService, UserService, USER_GROUP_SERVICE, USER_SERVICE},
 };
 use candid::CandidType;
@@ -87,6 +93,65 @@ impl PermissionService {
         Ok(permission)
     }
 
+    /// Validates that the permission change doesn't leave the lock-out sensitive resources
+    /// without any active user that can access them.
+    pub fn validate_permission_change(
+        &self,
+        input: &EditPermissionOperationInput,
+    ) -> Result<(), PermissionError> {
+        if !LOCK_OUT_SENSITIVE_RESOURCES.contains(&input.resource) {
+            return Ok(());
+        }
+
+        let current = self.get_permission(&input.resource).allow;
+        let mut changed = current.clone();
+        if let Some(scope) = &input.auth_scope {
+            changed.auth_scope = scope.clone();
+        }
+        if let Some(users) = &input.users {
+            changed.users = users.clone();
+        }
+        if let Some(user_groups) = &input.user_groups {
+            changed.user_groups = user_groups.clone();
+        }
+
+        if admits_active_users(&current, None) && !admits_active_users(&changed, None) {
+            return Err(PermissionError::LockOut {
+                resource: input.resource.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Validates that removing the user doesn't leave the lock-out sensitive resources without
+    /// any active user that can access them.
+    pub fn validate_user_removal(&self, user_id: &UserId) -> Result<(), PermissionError> {
+        for resource in LOCK_OUT_SENSITIVE_RESOURCES {
+            let allow = self.get_permission(&resource).allow;
+
+            if admits_active_users(&allow, None) && !admits_active_users(&allow, Some(user_id)) {
+                return Err(PermissionError::LockOut {
+                    resource: resource.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the user from the users that are explicitly allowed by the permissions.
+    pub fn remove_user_from_permissions(&self, user_id: &UserId) {
+        for mut permission in self.permission_repository.list() {
+            if permission.allow.users.contains(user_id) {
+                permission.allow.users.retain(|id| id != user_id);
+
+                self.permission_repository
+                    .insert(permission.key(), permission.to_owned());
+            }
+        }
+    }
+
     pub async fn list_permissions(
         &self,
         input: ListPermissionsInput,
@@ -110,6 +175,30 @@ impl PermissionService {
         Ok(result)
     }
 
+    /// Explains the access of the user to the given resource, or to the resources of all the
+    /// permissions if no resource is provided.
+    pub fn explain_access(
+        &self,
+        user_id: &UserId,
+        resource: Option<Resource>,
+    ) -> ServiceResult<Vec<AccessExplanation>> {
+        let user = self.user_service.get_user(user_id)?;
+        let resources = match resource {
+            Some(resource) => vec![resource],
+            None => self
+                .permission_repository
+                .list()
+                .into_iter()
+                .map(|permission| permission.resource)
+                .collect(),
+        };
+
+        Ok(resources
+            .iter()
+            .map(|resource| Authorization::explain(&user, resource))
+            .collect())
+    }
+
     pub fn get_permissions_dependencies(
         &self,
         policies: &Vec<Permission>,
@@ -139,6 +228,28 @@ impl PermissionService {
     }
 }
 
+/// Checks if at least one active user, other than the excluded one, is allowed by the rule.
+fn admits_active_users(allow: &Allow, excluded_user: Option<&UserId>) -> bool {
+    let is_excluded = |user_id: &UserId| excluded_user == Some(user_id);
+
+    match allow.auth_scope {
+        AuthScope::Public | AuthScope::Authenticated => true,
+        AuthScope::Restricted => {
+            allow.users.iter().any(|user_id| {
+                !is_excluded(user_id)
+                    && USER_REPOSITORY
+                        .get(&User::key(*user_id))
+                        .is_some_and(|user| user.is_active())
+            }) || allow.user_groups.iter().any(|group_id| {
+                USER_REPOSITORY
+                    .find_by_group_and_status(group_id, &UserStatus::Active)
+                    .iter()
+                    .any(|user| !is_excluded(&user.id))
+            })
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,7 +257,9 @@ mod tests {
         core::validation::disable_mock_resource_validation,
         models::{
             permission::{permission_test_utils::mock_permission, AuthScope},
-            resource::{AccountResourceAction, RequestResourceAction, ResourceId},
+            resource::{
+                AccountResourceAction, PermissionResourceAction, RequestResourceAction, ResourceId,
+            },
             user_group_test_utils::mock_user_group,
             user_test_utils::mock_user,
         },
@@ -200,6 +313,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn explain_access_of_all_permissions() {
+        let mut user = mock_user();
+        user.groups = vec![];
+        USER_REPOSITORY.insert(user.to_key(), user.to_owned());
+
+        let permission = mock_permission();
+        PERMISSION_REPOSITORY.insert(permission.key(), permission.to_owned());
+
+        let explanations = PERMISSION_SERVICE.explain_access(&user.id, None).unwrap();
+
+        assert_eq!(explanations.len(), PERMISSION_REPOSITORY.list().len());
+        assert!(explanations
+            .iter()
+            .any(|explanation| explanation.resource == permission.resource));
+    }
+
+    #[test]
+    fn fail_explain_access_of_unknown_user() {
+        let result = PERMISSION_SERVICE.explain_access(
+            &[9; 16],
+            Some(Resource::Request(RequestResourceAction::List)),
+        );
+
+        assert!(result.is_err());
+    }
+
     #[test]
     fn get_permission_dependencies_finds_users() {
         let service = PERMISSION_SERVICE.clone();
@@ -240,6 +380,77 @@ mod tests {
         assert_eq!(result.groups.len(), 1);
     }
 
+    #[test]
+    fn fail_permission_change_that_locks_out_all_users() {
+        let mut user = mock_user();
+        user.id = [1; 16];
+        USER_REPOSITORY.insert(user.to_key(), user.to_owned());
+        let resource = Resource::Permission(PermissionResourceAction::Update);
+        let permission = Permission::new(Allow::users(vec![user.id]), resource.clone());
+        PERMISSION_REPOSITORY.insert(permission.key(), permission.to_owned());
+
+        let result = PERMISSION_SERVICE.validate_permission_change(&EditPermissionOperationInput {
+            resource: resource.clone(),
+            auth_scope: None,
+            users: Some(vec![]),
+            user_groups: None,
+        });
+
+        assert_eq!(
+            result,
+            Err(PermissionError::LockOut {
+                resource: resource.to_string()
+            })
+        );
+
+        PERMISSION_SERVICE
+            .validate_permission_change(&EditPermissionOperationInput {
+                resource,
+                auth_scope: Some(AuthScope::Authenticated),
+                users: Some(vec![]),
+                user_groups: None,
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn remove_user_from_permissions_and_detect_lock_out() {
+        let mut user = mock_user();
+        user.id = [1; 16];
+        USER_REPOSITORY.insert(user.to_key(), user.to_owned());
+        let mut another_user = mock_user();
+        another_user.id = [2; 16];
+        USER_REPOSITORY.insert(another_user.to_key(), another_user.to_owned());
+
+        let resource = Resource::Permission(PermissionResourceAction::Update);
+        let permission = Permission::new(Allow::users(vec![user.id]), resource.clone());
+        PERMISSION_REPOSITORY.insert(permission.key(), permission.to_owned());
+
+        assert_eq!(
+            PERMISSION_SERVICE.validate_user_removal(&user.id),
+            Err(PermissionError::LockOut {
+                resource: resource.to_string()
+            })
+        );
+        PERMISSION_SERVICE
+            .validate_user_removal(&another_user.id)
+            .unwrap();
+
+        let permission = Permission::new(
+            Allow::users(vec![user.id, another_user.id]),
+            resource.clone(),
+        );
+        PERMISSION_REPOSITORY.insert(permission.key(), permission.to_owned());
+
+        PERMISSION_SERVICE.validate_user_removal(&user.id).unwrap();
+        PERMISSION_SERVICE.remove_user_from_permissions(&user.id);
+
+        assert_eq!(
+            PERMISSION_SERVICE.get_permission(&resource).allow.users,
+            vec![another_user.id]
+        );
+    }
+
     #[tokio::test]
     async fn list_permissions_should_use_offset_and_limit() {
         for _ in 0..20 {
@@ -0,0 +1,511 @@
+use crate::{
+    core::{
+        ic_cdk::{api::print, next_time},
+        init::DEFAULT_PERMISSIONS,
+        read_system_info, write_system_info, CallContext,
+    },
+    errors::RecoveryError,
+    models::{
+        AddUserOperationInput, EditPermissionOperationInput, EditUserOperationInput,
+        NotificationType, RecoveryAction, RecoveryAdmin, RecoveryAttempt, UserGroup, UserStatus,
+        ADMIN_GROUP_ID,
+    },
+    repositories::{
+        UserGroupRepository, UserRepository, UserWhereClause, USER_GROUP_REPOSITORY,
+        USER_REPOSITORY,
+    },
+    services::{
+        permission::{PermissionService, PERMISSION_SERVICE},
+        NotificationService, SystemService, UserService, NOTIFICATION_SERVICE, SYSTEM_SERVICE,
+        USER_SERVICE,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, repository::Repository, utils::timestamp_to_rfc3339};
+use std::sync::Arc;
+
+lazy_static! {
+    pub static ref RECOVERY_SERVICE: Arc<RecoveryService> = Arc::new(RecoveryService::new(
+        Arc::clone(&SYSTEM_SERVICE),
+        Arc::clone(&USER_SERVICE),
+        Arc::clone(&PERMISSION_SERVICE),
+        Arc::clone(&NOTIFICATION_SERVICE),
+        Arc::clone(&USER_REPOSITORY),
+        Arc::clone(&USER_GROUP_REPOSITORY),
+    ));
+}
+
+#[derive(Debug)]
+pub struct RecoveryService {
+    system_service: Arc<SystemService>,
+    user_service: Arc<UserService>,
+    permission_service: Arc<PermissionService>,
+    notification_service: Arc<NotificationService>,
+    user_repository: Arc<UserRepository>,
+    user_group_repository: Arc<UserGroupRepository>,
+}
+
+impl RecoveryService {
+    pub fn new(
+        system_service: Arc<SystemService>,
+        user_service: Arc<UserService>,
+        permission_service: Arc<PermissionService>,
+        notification_service: Arc<NotificationService>,
+        user_repository: Arc<UserRepository>,
+        user_group_repository: Arc<UserGroupRepository>,
+    ) -> Self {
+        Self {
+            system_service,
+            user_service,
+            permission_service,
+            notification_service,
+            user_repository,
+            user_group_repository,
+        }
+    }
+
+    /// Submits the recovery action on behalf of the calling guardian.
+    ///
+    /// The action is scheduled once the threshold of guardians submitted it and is executed by the
+    /// first submission after the delay has passed. Returns the pending attempt and whether the
+    /// action was executed.
+    pub async fn submit_recovery(
+        &self,
+        action: RecoveryAction,
+        ctx: &CallContext,
+    ) -> ServiceResult<(RecoveryAttempt, bool)> {
+        self.system_service.assert_system_readiness();
+
+        let mut system_info = read_system_info();
+        let config = system_info
+            .get_recovery_config()
+            .cloned()
+            .ok_or(RecoveryError::NotConfigured)?;
+
+        if !config.is_guardian(&ctx.caller()) {
+            Err(RecoveryError::NotGuardian)?
+        }
+
+        let now = next_time();
+        let mut attempt = match system_info.get_recovery_attempt().cloned() {
+            Some(attempt) if !attempt.is_expired_at(now) => {
+                if attempt.action != action {
+                    Err(RecoveryError::RecoveryInProgress {
+                        expires_at: timestamp_to_rfc3339(
+                            &attempt.executable_at.unwrap_or(
+                                attempt
+                                    .started_at
+                                    .saturating_add(RecoveryAttempt::EXPIRATION_NS),
+                            ),
+                        ),
+                    })?
+                }
+
+                attempt
+            }
+            _ => {
+                self.notify_users(
+                    "Station recovery started".to_string(),
+                    format!(
+                        "A recovery guardian started a station recovery to {}, cancel it if it was not expected.",
+                        describe_action(&action)
+                    ),
+                )
+                .await;
+
+                RecoveryAttempt {
+                    action,
+                    approvals: Vec::new(),
+                    started_at: now,
+                    executable_at: None,
+                }
+            }
+        };
+
+        if !attempt.approvals.contains(&ctx.caller()) {
+            attempt.approvals.push(ctx.caller());
+        }
+
+        if attempt.executable_at.is_none() && attempt.approvals.len() >= config.threshold as usize {
+            let executable_at = now.saturating_add(config.delay_ns());
+            attempt.executable_at = Some(executable_at);
+
+            self.notify_users(
+                "Station recovery scheduled".to_string(),
+                format!(
+                    "The recovery guardians approved a station recovery to {}, it can be executed after {} unless it is cancelled.",
+                    describe_action(&attempt.action),
+                    timestamp_to_rfc3339(&executable_at)
+                ),
+            )
+            .await;
+        }
+
+        if !attempt.is_executable_at(now) {
+            system_info.set_recovery_attempt(Some(attempt.clone()));
+            write_system_info(system_info);
+
+            return Ok((attempt, false));
+        }
+
+        // the attempt is cleared before the execution so that it can't be executed twice
+        system_info.set_recovery_attempt(None);
+        write_system_info(system_info);
+
+        self.execute_action(&attempt.action).await?;
+
+        self.notify_users(
+            "Station recovery executed".to_string(),
+            format!(
+                "The recovery guardians executed the station recovery to {}.",
+                describe_action(&attempt.action)
+            ),
+        )
+        .await;
+
+        Ok((attempt, true))
+    }
+
+    /// Cancels the pending recovery, returns the cancelled attempt.
+    pub async fn cancel_recovery(&self, ctx: &CallContext) -> ServiceResult<RecoveryAttempt> {
+        let mut system_info = read_system_info();
+        let attempt = system_info
+            .get_recovery_attempt()
+            .cloned()
+            .ok_or(RecoveryError::NoRecoveryInProgress)?;
+
+        system_info.set_recovery_attempt(None);
+        write_system_info(system_info);
+
+        self.notify_users(
+            "Station recovery cancelled".to_string(),
+            format!(
+                "The station recovery to {} was cancelled by {}.",
+                describe_action(&attempt.action),
+                ctx.caller().to_text()
+            ),
+        )
+        .await;
+
+        Ok(attempt)
+    }
+
+    async fn execute_action(&self, action: &RecoveryAction) -> ServiceResult<()> {
+        self.ensure_admin_group();
+
+        match action {
+            RecoveryAction::InstallAdminIdentity(admin) => self.install_admin_identity(admin).await,
+            RecoveryAction::ReenableAdminGroup => self.reenable_admin_group().await,
+        }
+    }
+
+    fn ensure_admin_group(&self) {
+        if self.user_group_repository.get(ADMIN_GROUP_ID).is_none() {
+            self.user_group_repository.insert(
+                *ADMIN_GROUP_ID,
+                UserGroup {
+                    id: *ADMIN_GROUP_ID,
+                    name: "Admin".to_string(),
+                    last_modification_timestamp: next_time(),
+                },
+            );
+        }
+    }
+
+    async fn install_admin_identity(&self, admin: &RecoveryAdmin) -> ServiceResult<()> {
+        match self.user_service.get_user_by_identity(&admin.identity) {
+            Ok(user) => {
+                let mut groups = user.groups.clone();
+                if !groups.contains(ADMIN_GROUP_ID) {
+                    groups.push(*ADMIN_GROUP_ID);
+                }
+
+                self.user_service
+                    .edit_user(EditUserOperationInput {
+                        user_id: user.id,
+                        name: None,
+                        identities: None,
+                        groups: Some(groups),
+                        status: Some(UserStatus::Active),
+                    })
+                    .await
+            }
+            Err(_) => self.user_service.add_user(AddUserOperationInput {
+                name: admin.name.clone(),
+                identities: vec![admin.identity],
+                groups: vec![*ADMIN_GROUP_ID],
+                status: UserStatus::Active,
+            }),
+        }
+        .map_err(|e| RecoveryError::ExecutionFailed {
+            reason: e.to_string(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Reactivates the members of the admin group and grants it back the default admin permissions,
+    /// users that were removed from the group have to be added back with a regular request.
+    async fn reenable_admin_group(&self) -> ServiceResult<()> {
+        for status in [UserStatus::Inactive, UserStatus::Suspended] {
+            for user in self
+                .user_repository
+                .find_by_group_and_status(ADMIN_GROUP_ID, &status)
+            {
+                self.user_service
+                    .edit_user(EditUserOperationInput {
+                        user_id: user.id,
+                        name: None,
+                        identities: None,
+                        groups: None,
+                        status: Some(UserStatus::Active),
+                    })
+                    .await
+                    .map_err(|e| RecoveryError::ExecutionFailed {
+                        reason: e.to_string(),
+                    })?;
+            }
+        }
+
+        for (allow, resource) in DEFAULT_PERMISSIONS.iter() {
+            if !allow.user_groups.contains(ADMIN_GROUP_ID) {
+                continue;
+            }
+
+            let mut user_groups = self
+                .permission_service
+                .get_permission(resource)
+                .allow
+                .user_groups;
+            if user_groups.contains(ADMIN_GROUP_ID) {
+                continue;
+            }
+
+            user_groups.push(*ADMIN_GROUP_ID);
+
+            self.permission_service
+                .edit_permission(EditPermissionOperationInput {
+                    resource: resource.clone(),
+                    auth_scope: None,
+                    users: None,
+                    user_groups: Some(user_groups),
+                })
+                .await
+                .map_err(|e| RecoveryError::ExecutionFailed {
+                    reason: e.to_string(),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Recoveries bypass the request approvals, so every step is announced to all active users.
+    async fn notify_users(&self, title: String, message: String) {
+        print(format!("{}: {}", title, message));
+
+        let users = self.user_repository.find_where(UserWhereClause {
+            search_term: None,
+            statuses: Some(vec![UserStatus::Active]),
+        });
+
+        for user in users {
+            self.notification_service
+                .send_notification(
+                    user.id,
+                    NotificationType::SystemMessage,
+                    title.clone(),
+                    Some(message.clone()),
+                )
+                .await;
+        }
+    }
+}
+
+fn describe_action(action: &RecoveryAction) -> String {
+    match action {
+        RecoveryAction::InstallAdminIdentity(admin) => format!(
+            "install {} ({}) as an admin",
+            admin.name,
+            admin.identity.to_text()
+        ),
+        RecoveryAction::ReenableAdminGroup => "re-enable the admin group".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            permission::{Allow, Permission},
+            recovery_test_utils::mock_recovery_config,
+            resource::{Resource, UserResourceAction},
+            user_test_utils::mock_user,
+        },
+        repositories::{permission::PERMISSION_REPOSITORY, NOTIFICATION_REPOSITORY},
+    };
+    use candid::Principal;
+
+    fn setup_recovery() {
+        test_utils::init_canister_system();
+
+        let mut system_info = read_system_info();
+        system_info.set_recovery_config(Some(mock_recovery_config()));
+        write_system_info(system_info);
+    }
+
+    fn guardian(n: u8) -> CallContext {
+        CallContext::new(Principal::from_slice(&[n; 29]))
+    }
+
+    fn install_admin_action() -> RecoveryAction {
+        RecoveryAction::InstallAdminIdentity(RecoveryAdmin {
+            name: "Recovered Admin".to_string(),
+            identity: Principal::from_slice(&[9; 29]),
+        })
+    }
+
+    /// Moves the pending recovery past its delay.
+    fn skip_delay() {
+        let mut system_info = read_system_info();
+        let mut attempt = system_info.get_recovery_attempt().cloned().unwrap();
+        attempt.executable_at = Some(0);
+        system_info.set_recovery_attempt(Some(attempt));
+        write_system_info(system_info);
+    }
+
+    #[tokio::test]
+    async fn fail_submit_recovery_from_non_guardian() {
+        setup_recovery();
+
+        let result = RECOVERY_SERVICE
+            .submit_recovery(install_admin_action(), &guardian(7))
+            .await;
+
+        assert!(result.is_err());
+        assert!(read_system_info().get_recovery_attempt().is_none());
+    }
+
+    #[tokio::test]
+    async fn recovery_is_scheduled_at_threshold_and_executed_after_delay() {
+        setup_recovery();
+
+        let (attempt, executed) = RECOVERY_SERVICE
+            .submit_recovery(install_admin_action(), &guardian(1))
+            .await
+            .unwrap();
+
+        assert!(!executed);
+        assert!(attempt.executable_at.is_none());
+
+        let (attempt, executed) = RECOVERY_SERVICE
+            .submit_recovery(install_admin_action(), &guardian(2))
+            .await
+            .unwrap();
+
+        assert!(!executed);
+        assert!(attempt.executable_at.is_some());
+
+        skip_delay();
+
+        let (_, executed) = RECOVERY_SERVICE
+            .submit_recovery(install_admin_action(), &guardian(1))
+            .await
+            .unwrap();
+
+        assert!(executed);
+        assert!(read_system_info().get_recovery_attempt().is_none());
+
+        let admin = USER_SERVICE
+            .get_user_by_identity(&Principal::from_slice(&[9; 29]))
+            .unwrap();
+
+        assert!(admin.groups.contains(ADMIN_GROUP_ID));
+        assert_eq!(admin.status, UserStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn fail_submit_different_action_while_pending() {
+        setup_recovery();
+
+        RECOVERY_SERVICE
+            .submit_recovery(install_admin_action(), &guardian(1))
+            .await
+            .unwrap();
+
+        let result = RECOVERY_SERVICE
+            .submit_recovery(RecoveryAction::ReenableAdminGroup, &guardian(2))
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn cancel_recovery_notifies_users() {
+        setup_recovery();
+
+        let mut user = mock_user();
+        user.status = UserStatus::Active;
+        USER_REPOSITORY.insert(user.to_key(), user.to_owned());
+
+        RECOVERY_SERVICE
+            .submit_recovery(install_admin_action(), &guardian(1))
+            .await
+            .unwrap();
+
+        RECOVERY_SERVICE
+            .cancel_recovery(&CallContext::new(user.identities[0]))
+            .await
+            .unwrap();
+
+        assert!(read_system_info().get_recovery_attempt().is_none());
+        assert_eq!(NOTIFICATION_REPOSITORY.find_by_user_id(user.id).len(), 2);
+        assert!(RECOVERY_SERVICE
+            .cancel_recovery(&CallContext::new(user.identities[0]))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn reenable_admin_group_restores_default_permissions() {
+        setup_recovery();
+
+        let mut admin = mock_user();
+        admin.groups = vec![*ADMIN_GROUP_ID];
+        admin.status = UserStatus::Inactive;
+        USER_REPOSITORY.insert(admin.to_key(), admin.to_owned());
+
+        let resource = Resource::User(UserResourceAction::Create);
+        PERMISSION_REPOSITORY.insert(
+            resource.clone(),
+            Permission::new(Allow::default(), resource.clone()),
+        );
+
+        for n in [1, 2] {
+            RECOVERY_SERVICE
+                .submit_recovery(RecoveryAction::ReenableAdminGroup, &guardian(n))
+                .await
+                .unwrap();
+        }
+
+        skip_delay();
+
+        let (_, executed) = RECOVERY_SERVICE
+            .submit_recovery(RecoveryAction::ReenableAdminGroup, &guardian(3))
+            .await
+            .unwrap();
+
+        assert!(executed);
+        assert_eq!(
+            USER_REPOSITORY.get(&admin.to_key()).unwrap().status,
+            UserStatus::Active
+        );
+        assert!(PERMISSION_REPOSITORY
+            .get(&resource)
+            .unwrap()
+            .allow
+            .user_groups
+            .contains(ADMIN_GROUP_ID));
+    }
+}
@@ -1,23 +1,34 @@
 use crate::{
     core::{
         authorization::Authorization,
+        evaluation::{
+            REQUEST_MATCHER, REQUEST_POLICY_RULE_EVALUATOR,
+            REQUEST_POSSIBLE_APPROVERS_REQUEST_POLICY_RULE_EVALUATOR,
+        },
+        ic_cdk::api::time,
+        request::{RequestEvaluator, RequestPossibleApproversFinder},
         utils::{paginated_items, retain_accessible_resources, PaginatedData, PaginatedItemsArgs},
         CallContext,
     },
-    errors::RequestError,
+    errors::{ApprovalDelegationError, RequestError},
     factories::requests::RequestFactory,
     mappers::HelperMapper,
     models::{
         resource::{RequestResourceAction, Resource, ResourceId},
-        DisplayUser, NotificationType, Request, RequestAdditionalInfo, RequestApprovalStatus,
-        RequestCallerPrivileges, RequestCreatedNotification, RequestRejectedNotification,
-        RequestStatus, RequestStatusCode,
+        DisplayUser, DraftRequestPolicy, EvaluationStatus, NotificationType, Request,
+        RequestAdditionalInfo, RequestApprovalStatus, RequestCallerPrivileges,
+        RequestCancelledNotification, RequestCreatedNotification, RequestPolicySimulation,
+        RequestRejectedNotification, RequestStatus, RequestStatusCode,
+        RequestTimeLockedNotification, UserId,
     },
     repositories::{
-        EvaluationResultRepository, RequestRepository, RequestWhereClause,
-        REQUEST_EVALUATION_RESULT_REPOSITORY, REQUEST_REPOSITORY,
+        request_policy::REQUEST_POLICY_REPOSITORY, EvaluationResultRepository, RequestRepository,
+        RequestWhereClause, REQUEST_EVALUATION_RESULT_REPOSITORY, REQUEST_REPOSITORY,
+    },
+    services::{
+        NotificationService, UserService, APPROVAL_DELEGATION_SERVICE, NOTIFICATION_SERVICE,
+        USER_SERVICE,
     },
-    services::{NotificationService, UserService, NOTIFICATION_SERVICE, USER_SERVICE},
 };
 use ic_cdk::print;
 use lazy_static::lazy_static;
@@ -25,10 +36,11 @@ use orbit_essentials::utils::rfc3339_to_timestamp;
 use orbit_essentials::{api::ServiceResult, model::ModelValidator};
 use orbit_essentials::{repository::Repository, types::UUID};
 use station_api::{
-    CreateRequestInput, GetNextApprovableRequestInput, ListRequestsInput,
-    SubmitRequestApprovalInput,
+    CancelRequestInput, ChangeRequestApprovalInput, CreateRequestInput,
+    GetNextApprovableRequestInput, ListRequestsInput, SimulateRequestInput,
+    SubmitRequestApprovalInput, VetoRequestInput,
 };
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 use uuid::Uuid;
 
 lazy_static! {
@@ -316,7 +328,17 @@ impl RequestService {
         ctx: &CallContext,
     ) -> ServiceResult<Request> {
         let requester = self.user_service.get_user_by_identity(&ctx.caller())?;
-        let mut request = RequestFactory::create_request(requester.id, input).await?;
+        let request = RequestFactory::create_request(requester.id, input).await?;
+
+        self.submit_request(request).await
+    }
+
+    /// Submits a request that was already created for its requester, it is evaluated right away
+    /// and the requester approves it if they have the rights to do so.
+    ///
+    /// This is used by flows that build the request themselves instead of taking it from the caller input.
+    pub async fn submit_request(&self, mut request: Request) -> ServiceResult<Request> {
+        let requester_id = request.requested_by;
 
         // Different request types may have different validation rules.
         request.validate()?;
@@ -326,8 +348,8 @@ impl RequestService {
         self.request_repository
             .insert(request.to_key(), request.to_owned());
 
-        if request.can_approve(&requester.id).await {
-            request.add_approval(requester.id, RequestApprovalStatus::Approved, None)?;
+        if request.is_approvable_by_requester(&request.find_matching_policy_rules())? {
+            request.add_approval(requester_id, RequestApprovalStatus::Approved, None)?;
         }
 
         // When a request is created, it is immediately evaluated to determine its status.
@@ -344,6 +366,8 @@ impl RequestService {
 
         if request.status == RequestStatus::Created {
             self.created_request_hook(&request).await;
+        } else if request.status == RequestStatus::Approved {
+            self.approved_request_hook(&request).await;
         } else if request.status == RequestStatus::Rejected {
             self.rejected_request_hook(&request).await;
         }
@@ -364,6 +388,78 @@ impl RequestService {
             .await;
     }
 
+    /// Notifies the approvers of a time locked request that they can still veto it.
+    async fn approved_request_hook(&self, request: &Request) {
+        if request.time_lock_ns().is_none() {
+            return;
+        }
+
+        // the request is scheduled as soon as it is approved, the time lock is part of its schedule
+        let Some(RequestStatus::Scheduled { scheduled_at }) = self
+            .request_repository
+            .get(&request.to_key())
+            .map(|request| request.status)
+        else {
+            return;
+        };
+
+        let possible_approvers = match request.find_all_possible_approvers().await {
+            Ok(approvers) => approvers,
+            Err(_) => {
+                print(format!(
+                    "Failed to find all possible approvers for request {}",
+                    Uuid::from_bytes(request.id).hyphenated()
+                ));
+                return;
+            }
+        };
+
+        for approver in possible_approvers {
+            self.notification_service
+                .send_notification(
+                    approver,
+                    NotificationType::RequestTimeLocked(RequestTimeLockedNotification {
+                        request_id: request.id,
+                        unlocks_at: scheduled_at,
+                    }),
+                    request.title.to_owned(),
+                    request.summary.to_owned(),
+                )
+                .await;
+        }
+    }
+
+    /// Notifies the approvers that the request was cancelled and no longer needs their decision.
+    async fn cancelled_request_hook(&self, request: &Request, cancelled_by: &UUID) {
+        let possible_approvers = match request.find_all_possible_approvers().await {
+            Ok(approvers) => approvers,
+            Err(_) => {
+                print(format!(
+                    "Failed to find all possible approvers for request {}",
+                    Uuid::from_bytes(request.id).hyphenated()
+                ));
+                return;
+            }
+        };
+
+        for approver in possible_approvers {
+            if approver == *cancelled_by {
+                continue;
+            }
+
+            self.notification_service
+                .send_notification(
+                    approver,
+                    NotificationType::RequestCancelled(RequestCancelledNotification {
+                        request_id: request.id,
+                    }),
+                    request.title.to_owned(),
+                    request.summary.to_owned(),
+                )
+                .await;
+        }
+    }
+
     pub async fn failed_request_hook(&self, request: &Request) {
         self.notification_service
             .send_notification(
@@ -415,15 +511,119 @@ impl RequestService {
         let request_id = HelperMapper::to_uuid(input.request_id)?;
         let mut request = self.get_request(request_id.as_bytes())?;
 
-        if !request.can_approve(&approver.id).await {
-            Err(RequestError::ApprovalNotAllowed)?
-        }
+        let rights_owners = request.find_approval_rights_owners(&approver.id);
+        let rights_owner = match input.on_behalf_of {
+            Some(delegator_id) => {
+                let delegator_id = *HelperMapper::to_uuid(delegator_id)?.as_bytes();
+
+                if delegator_id == approver.id || !rights_owners.contains(&delegator_id) {
+                    Err(ApprovalDelegationError::NotDelegated {
+                        delegator_id: Uuid::from_bytes(delegator_id).hyphenated().to_string(),
+                    })?
+                }
+
+                delegator_id
+            }
+            // the caller's own rights are preferred over the delegated ones
+            None => *rights_owners
+                .first()
+                .ok_or(RequestError::ApprovalNotAllowed)?,
+        };
 
         let approval_decision = input.decision.into();
 
-        request.add_approval(approver.id, approval_decision, input.reason)?;
+        if rights_owner == approver.id {
+            request.add_approval(approver.id, approval_decision, input.reason)?;
+        } else {
+            request.add_delegated_approval(
+                rights_owner,
+                approver.id,
+                approval_decision,
+                input.reason,
+            )?;
+        }
 
         // Must happen after the approval is added to the request to ensure the approval is counted.
+        self.reevaluate_request(&mut request).await?;
+
+        Ok(request)
+    }
+
+    /// Changes the decision that the caller already added to the request, or withdraws it if no
+    /// new decision is given, the request is then evaluated again with the remaining decisions.
+    pub async fn change_request_approval(
+        &self,
+        input: ChangeRequestApprovalInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Request> {
+        let approver = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let request_id = HelperMapper::to_uuid(input.request_id)?;
+        let mut request = self.get_request(request_id.as_bytes())?;
+
+        let rights_owner = match input.on_behalf_of {
+            Some(delegator_id) => {
+                let delegator_id = *HelperMapper::to_uuid(delegator_id)?.as_bytes();
+
+                if delegator_id == approver.id
+                    || !APPROVAL_DELEGATION_SERVICE
+                        .find_delegates_for_request(&request, &HashSet::from([delegator_id]))
+                        .contains(&approver.id)
+                {
+                    Err(ApprovalDelegationError::NotDelegated {
+                        delegator_id: Uuid::from_bytes(delegator_id).hyphenated().to_string(),
+                    })?
+                }
+
+                delegator_id
+            }
+            None => approver.id,
+        };
+
+        request.change_approval(
+            rights_owner,
+            (rights_owner != approver.id).then_some(approver.id),
+            input.decision.map(Into::into),
+            input.reason,
+        )?;
+
+        self.reevaluate_request(&mut request).await?;
+
+        Ok(request)
+    }
+
+    /// Returns the ids of the open requests that the user is a possible approver of.
+    pub async fn find_open_requests_approvable_by(&self, user_id: &UserId) -> Vec<UUID> {
+        let mut request_ids = Vec::new();
+
+        for request in
+            self.request_repository
+                .find_by_status(RequestStatusCode::Created, None, None)
+        {
+            if request
+                .find_all_possible_approvers()
+                .await
+                .is_ok_and(|approvers| approvers.contains(user_id))
+            {
+                request_ids.push(request.id);
+            }
+        }
+
+        request_ids
+    }
+
+    /// Evaluates the given requests again, e.g. after the approvers of their policies changed.
+    pub async fn reevaluate_requests(&self, request_ids: &[UUID]) -> ServiceResult<()> {
+        for request_id in request_ids {
+            let mut request = self.get_request(request_id)?;
+
+            self.reevaluate_request(&mut request).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Evaluates the request after its approvals changed and stores the result.
+    async fn reevaluate_request(&self, request: &mut Request) -> ServiceResult<()> {
         let maybe_evaluation = request.reevaluate().await?;
 
         self.request_repository
@@ -434,10 +634,171 @@ impl RequestService {
                 .insert(request.id, evaluation);
         }
 
-        if request.status == RequestStatus::Rejected {
-            self.rejected_request_hook(&request).await;
+        if request.status == RequestStatus::Approved {
+            self.approved_request_hook(request).await;
+        } else if request.status == RequestStatus::Rejected {
+            self.rejected_request_hook(request).await;
+        }
+
+        Ok(())
+    }
+
+    /// Vetoes a time locked request that is waiting for its execution, which rejects it.
+    ///
+    /// Any of the possible approvers of the request can veto it until the time lock passes.
+    pub async fn veto_request(
+        &self,
+        input: VetoRequestInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Request> {
+        let approver = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let request_id = HelperMapper::to_uuid(input.request_id)?;
+        let mut request = self.get_request(request_id.as_bytes())?;
+
+        let is_time_locked = match request.status {
+            RequestStatus::Scheduled { scheduled_at } => {
+                scheduled_at > time() && request.time_lock_ns().is_some()
+            }
+            _ => false,
+        };
+
+        if !is_time_locked
+            || !request
+                .find_all_possible_approvers()
+                .await?
+                .contains(&approver.id)
+        {
+            Err(RequestError::VetoNotAllowed)?
+        }
+
+        request.add_veto(approver.id, input.reason)?;
+
+        self.request_repository
+            .insert(request.to_key(), request.to_owned());
+
+        self.rejected_request_hook(&request).await;
+
+        Ok(request)
+    }
+
+    /// Evaluates the operation against the request policies without creating the request.
+    ///
+    /// Draft policies replace the stored policy with the same id, or are evaluated in addition to
+    /// the stored policies when they have no id.
+    pub async fn simulate_request(
+        &self,
+        input: SimulateRequestInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<RequestPolicySimulation> {
+        let requester = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let mut request = RequestFactory::create_request(
+            requester.id,
+            CreateRequestInput {
+                operation: input.operation,
+                title: None,
+                summary: None,
+                execution_plan: None,
+            },
+        )
+        .await?;
+
+        request.validate()?;
+
+        let mut draft_policies = Vec::new();
+        for draft_policy in input.draft_policies.unwrap_or_default() {
+            let draft_policy = DraftRequestPolicy::try_from(draft_policy)?;
+
+            draft_policy.validate()?;
+
+            if let Some(policy_id) = &draft_policy.policy_id {
+                if REQUEST_POLICY_REPOSITORY.get(policy_id).is_none() {
+                    Err(RequestError::PolicyNotFound {
+                        id: Uuid::from_bytes(*policy_id).hyphenated().to_string(),
+                    })?
+                }
+            }
+
+            draft_policies.push(draft_policy);
+        }
+
+        // the policies are matched by resource, as when the request is evaluated
+        let request_resources = request.operation.to_resources();
+        let mut matched_policies: Vec<DraftRequestPolicy> = Vec::new();
+        for policy in request_resources
+            .iter()
+            .flat_map(|resource| REQUEST_POLICY_REPOSITORY.find_by_resource(resource.to_owned()))
+        {
+            let is_replaced = draft_policies
+                .iter()
+                .any(|draft_policy| draft_policy.policy_id == Some(policy.id));
+            let is_matched = matched_policies
+                .iter()
+                .any(|matched_policy| matched_policy.policy_id == Some(policy.id));
+
+            if !is_replaced && !is_matched {
+                matched_policies.push(policy.into());
+            }
+        }
+
+        matched_policies.extend(draft_policies.into_iter().filter(|draft_policy| {
+            draft_policy
+                .specifier
+                .to_resources()
+                .iter()
+                .any(|resource| request_resources.contains(resource))
+        }));
+
+        let rules = matched_policies
+            .iter()
+            .map(|policy| policy.rule.to_owned())
+            .collect::<Vec<_>>();
+
+        let possible_approvers = RequestPossibleApproversFinder::new(
+            REQUEST_MATCHER.to_owned(),
+            REQUEST_POSSIBLE_APPROVERS_REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            &request,
+        )
+        .find_for_rules(&rules)?;
+
+        // the requester approves the request right away when they are allowed to, as when it is created
+        if request.is_approvable_by_requester(&rules)? {
+            request.add_approval(requester.id, RequestApprovalStatus::Approved, None)?;
         }
 
+        let evaluation = RequestEvaluator::new(
+            REQUEST_MATCHER.to_owned(),
+            REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            request,
+        )
+        .evaluate_rules(&rules)?;
+
+        Ok(RequestPolicySimulation {
+            auto_approved: evaluation.status == EvaluationStatus::Approved,
+            matched_policies,
+            evaluation,
+            possible_approvers,
+        })
+    }
+
+    /// Cancels a request that is still pending or waiting for its execution.
+    ///
+    /// The access is checked by the caller, by default only the requester can cancel the request.
+    pub async fn cancel_request(
+        &self,
+        input: CancelRequestInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Request> {
+        let caller = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let request_id = HelperMapper::to_uuid(input.request_id)?;
+        let mut request = self.get_request(request_id.as_bytes())?;
+
+        request.cancel(input.reason)?;
+
+        self.request_repository
+            .insert(request.to_key(), request.to_owned());
+
+        self.cancelled_request_hook(&request, &caller.id).await;
+
         Ok(request)
     }
 }
@@ -449,6 +810,7 @@ mod tests {
         core::test_utils,
         models::{
             account_test_utils::mock_account,
+            asset_test_utils::add_asset,
             permission::Allow,
             request_policy_rule::RequestPolicyRule,
             request_policy_test_utils::mock_request_policy,
@@ -456,10 +818,10 @@ mod tests {
             request_test_utils::mock_request,
             resource::ResourceIds,
             user_test_utils::mock_user,
-            AddAccountOperationInput, AddUserOperation, AddUserOperationInput, Blockchain,
-            BlockchainStandard, Metadata, Percentage, RequestApproval, RequestOperation,
-            RequestPolicy, RequestStatus, TransferOperation, TransferOperationInput, User,
-            UserGroup, UserStatus, ADMIN_GROUP_ID,
+            AddAccountOperationInput, AddApprovalDelegationOperationInput, AddUserOperation,
+            AddUserOperationInput, Metadata, Percentage, RequestApproval, RequestOperation,
+            RequestPolicy, RequestStatus, TimeLock, TransferOperation, TransferOperationInput,
+            User, UserGroup, UserStatus, ADMIN_GROUP_ID,
         },
         repositories::{
             request_policy::REQUEST_POLICY_REPOSITORY, AccountRepository, NOTIFICATION_REPOSITORY,
@@ -469,8 +831,11 @@ mod tests {
     };
     use candid::Principal;
     use station_api::{
-        ListRequestsOperationTypeDTO, RequestApprovalStatusDTO, RequestStatusCodeDTO,
+        DraftRequestPolicyDTO, ListRequestsOperationTypeDTO, QuorumDTO, RequestApprovalStatusDTO,
+        RequestPolicyRuleDTO, RequestSpecifierDTO, RequestStatusCodeDTO, ResourceIdsDTO,
+        UserSpecifierDTO,
     };
+    use std::collections::HashSet;
 
     struct TestContext {
         repository: RequestRepository,
@@ -583,6 +948,7 @@ mod tests {
                         .to_string(),
                     decision: RequestApprovalStatusDTO::Rejected,
                     reason: None,
+                    on_behalf_of: None,
                 },
                 &ctx.call_context,
             )
@@ -596,65 +962,724 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn request_creation_triggers_notifications() {
+    async fn changed_approval_reevaluates_request() {
         let ctx = setup();
-        // creates other users
-        let mut related_user = mock_user();
-        related_user.identities = vec![Principal::from_slice(&[25; 29])];
-        related_user.id = [25; 16];
-        related_user.status = UserStatus::Active;
-
-        let mut unrelated_user = mock_user();
-        unrelated_user.identities = vec![Principal::from_slice(&[26; 29])];
-        unrelated_user.id = [26; 16];
-        unrelated_user.status = UserStatus::Active;
-
-        USER_REPOSITORY.insert(related_user.to_key(), related_user.clone());
-        USER_REPOSITORY.insert(unrelated_user.to_key(), unrelated_user.clone());
-
-        // creates the account for the transfer
-        let account = mock_account();
-
-        ctx.account_repository
-            .insert(account.to_key(), account.clone());
+        let mut other_user = mock_user();
+        other_user.identities = vec![Principal::from_slice(&[25; 29])];
+        other_user.id = [25; 16];
+        other_user.status = UserStatus::Active;
+        USER_REPOSITORY.insert(other_user.to_key(), other_user.clone());
 
-        // creates a request policy that will match the new request
+        let mut request = mock_request();
+        request.requested_by = [8; 16];
+        request.status = RequestStatus::Created;
+        request.approvals = vec![];
         let mut request_policy = mock_request_policy();
         request_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
-        request_policy.rule = RequestPolicyRule::QuorumPercentage(
-            UserSpecifier::Id(vec![ctx.caller_user.id, related_user.id]),
-            Percentage(100),
+        request_policy.rule = RequestPolicyRule::Quorum(
+            UserSpecifier::Id(vec![ctx.caller_user.id, other_user.id]),
+            2,
         );
+
+        ctx.repository.insert(request.to_key(), request.to_owned());
         REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy.to_owned());
 
-        // creates the request
+        let request_id = Uuid::from_bytes(request.id.to_owned())
+            .hyphenated()
+            .to_string();
+
+        let request = ctx
+            .service
+            .submit_request_approval(
+                SubmitRequestApprovalInput {
+                    request_id: request_id.clone(),
+                    decision: RequestApprovalStatusDTO::Approved,
+                    reason: None,
+                    on_behalf_of: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(request.status, RequestStatus::Created);
+
+        // withdrawing keeps the request open for a new decision
+        let request = ctx
+            .service
+            .change_request_approval(
+                ChangeRequestApprovalInput {
+                    request_id: request_id.clone(),
+                    decision: None,
+                    reason: None,
+                    on_behalf_of: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(request.status, RequestStatus::Created);
+        assert!(request.approvals.is_empty());
+
         ctx.service
-            .create_request(
-                station_api::CreateRequestInput {
-                    operation: station_api::RequestOperationInput::Transfer(
-                        station_api::TransferOperationInput {
-                            from_account_id: Uuid::from_bytes(account.id.to_owned())
-                                .hyphenated()
-                                .to_string(),
-                            amount: candid::Nat(100u32.into()),
-                            fee: None,
-                            metadata: vec![],
-                            network: None,
-                            to: "0x1234".to_string(),
-                        },
-                    ),
-                    title: None,
-                    summary: None,
-                    execution_plan: None,
+            .submit_request_approval(
+                SubmitRequestApprovalInput {
+                    request_id: request_id.clone(),
+                    decision: RequestApprovalStatusDTO::Approved,
+                    reason: None,
+                    on_behalf_of: None,
                 },
                 &ctx.call_context,
             )
             .await
             .unwrap();
 
-        let notifications = NOTIFICATION_REPOSITORY.list();
-        assert_eq!(notifications.len(), 1);
-        assert_eq!(notifications[0].target_user_id, related_user.id);
+        // flipping the decision makes the quorum unreachable
+        let request = ctx
+            .service
+            .change_request_approval(
+                ChangeRequestApprovalInput {
+                    request_id,
+                    decision: Some(RequestApprovalStatusDTO::Rejected),
+                    reason: Some("wrong destination".to_string()),
+                    on_behalf_of: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(request.status, RequestStatus::Rejected);
+        assert_eq!(request.approval_history.len(), 4);
+        assert_eq!(
+            ctx.service
+                .get_request(&request.id)
+                .unwrap()
+                .approval_history,
+            request.approval_history
+        );
+    }
+
+    #[tokio::test]
+    async fn delegate_approves_on_behalf_of_delegator() {
+        let ctx = setup();
+        let mut delegator = mock_user();
+        delegator.id = [25; 16];
+        delegator.identities = vec![Principal::from_slice(&[25; 29])];
+        delegator.status = UserStatus::Active;
+        USER_REPOSITORY.insert(delegator.to_key(), delegator.clone());
+
+        let mut request = mock_request();
+        request.requested_by = [8; 16];
+        request.status = RequestStatus::Created;
+        request.approvals = vec![];
+        let mut request_policy = mock_request_policy();
+        request_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        request_policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Id(vec![delegator.id]), 1);
+
+        ctx.repository.insert(request.to_key(), request.to_owned());
+        REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy.to_owned());
+
+        let request_id = Uuid::from_bytes(request.id.to_owned())
+            .hyphenated()
+            .to_string();
+        let submit_approval = || SubmitRequestApprovalInput {
+            request_id: request_id.clone(),
+            decision: RequestApprovalStatusDTO::Approved,
+            reason: None,
+            on_behalf_of: Some(Uuid::from_bytes(delegator.id).hyphenated().to_string()),
+        };
+
+        // the caller has no rights of their own and nothing was delegated yet
+        assert!(ctx
+            .service
+            .submit_request_approval(submit_approval(), &ctx.call_context)
+            .await
+            .is_err());
+
+        APPROVAL_DELEGATION_SERVICE
+            .add_delegation(AddApprovalDelegationOperationInput {
+                delegator_id: delegator.id,
+                delegate_id: ctx.caller_user.id,
+                specifier: Some(RequestSpecifier::Transfer(ResourceIds::Any)),
+                expires_at: u64::MAX,
+            })
+            .await
+            .unwrap();
+
+        let request = ctx
+            .service
+            .submit_request_approval(submit_approval(), &ctx.call_context)
+            .await
+            .unwrap();
+
+        assert_eq!(request.status, RequestStatus::Approved);
+        assert_eq!(request.approvals[0].approver_id, delegator.id);
+        assert_eq!(request.approvals[0].delegate_id, Some(ctx.caller_user.id));
+    }
+
+    #[tokio::test]
+    async fn delegate_changes_the_decision_made_on_behalf_of_delegator() {
+        let ctx = setup();
+        let mut delegator = mock_user();
+        delegator.id = [25; 16];
+        delegator.identities = vec![Principal::from_slice(&[25; 29])];
+        delegator.status = UserStatus::Active;
+        USER_REPOSITORY.insert(delegator.to_key(), delegator.clone());
+        let mut other_approver = mock_user();
+        other_approver.id = [26; 16];
+        other_approver.identities = vec![Principal::from_slice(&[26; 29])];
+        other_approver.status = UserStatus::Active;
+        USER_REPOSITORY.insert(other_approver.to_key(), other_approver.clone());
+
+        let mut request = mock_request();
+        request.requested_by = [8; 16];
+        request.status = RequestStatus::Created;
+        request.approvals = vec![];
+        let mut request_policy = mock_request_policy();
+        request_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        request_policy.rule =
+            RequestPolicyRule::Quorum(UserSpecifier::Id(vec![delegator.id, other_approver.id]), 2);
+
+        ctx.repository.insert(request.to_key(), request.to_owned());
+        REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy.to_owned());
+
+        APPROVAL_DELEGATION_SERVICE
+            .add_delegation(AddApprovalDelegationOperationInput {
+                delegator_id: delegator.id,
+                delegate_id: ctx.caller_user.id,
+                specifier: Some(RequestSpecifier::Transfer(ResourceIds::Any)),
+                expires_at: u64::MAX,
+            })
+            .await
+            .unwrap();
+
+        let request_id = Uuid::from_bytes(request.id.to_owned())
+            .hyphenated()
+            .to_string();
+        let on_behalf_of = Some(Uuid::from_bytes(delegator.id).hyphenated().to_string());
+
+        let request = ctx
+            .service
+            .submit_request_approval(
+                SubmitRequestApprovalInput {
+                    request_id: request_id.clone(),
+                    decision: RequestApprovalStatusDTO::Approved,
+                    reason: None,
+                    on_behalf_of: on_behalf_of.clone(),
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(request.status, RequestStatus::Created);
+
+        let request = ctx
+            .service
+            .change_request_approval(
+                ChangeRequestApprovalInput {
+                    request_id,
+                    decision: Some(RequestApprovalStatusDTO::Rejected),
+                    reason: None,
+                    on_behalf_of,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(request.status, RequestStatus::Rejected);
+        assert_eq!(request.approvals.len(), 1);
+        assert_eq!(request.approvals[0].approver_id, delegator.id);
+        assert_eq!(request.approvals[0].delegate_id, Some(ctx.caller_user.id));
+        assert_eq!(request.approvals[0].status, RequestApprovalStatus::Rejected);
+    }
+
+    /// Adds a time locked transfer request that only the delegator can approve, and delegates the
+    /// delegator's approval rights to the caller.
+    async fn setup_delegated_time_locked_request(ctx: &TestContext) -> Request {
+        let mut delegator = mock_user();
+        delegator.id = [25; 16];
+        delegator.identities = vec![Principal::from_slice(&[25; 29])];
+        delegator.status = UserStatus::Active;
+        USER_REPOSITORY.insert(delegator.to_key(), delegator.clone());
+
+        let mut account = mock_account();
+        account.id = *Uuid::new_v4().as_bytes();
+        let mut request = mock_request();
+        request.requested_by = [8; 16];
+        request.status = RequestStatus::Created;
+        request.operation = RequestOperation::Transfer(TransferOperation {
+            transfer_id: None,
+            input: TransferOperationInput {
+                from_account_id: account.id,
+                amount: candid::Nat(100u32.into()),
+                fee: None,
+                metadata: Metadata::default(),
+                network: "mainnet".to_string(),
+                to: "0x1234".to_string(),
+            },
+        });
+        request.approvals = vec![];
+        let mut request_policy = mock_request_policy();
+        request_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        request_policy.rule = RequestPolicyRule::And(vec![
+            RequestPolicyRule::Quorum(UserSpecifier::Id(vec![delegator.id]), 1),
+            RequestPolicyRule::TimeLock(TimeLock {
+                delay_ns: 60 * 1_000_000_000,
+            }),
+        ]);
+
+        ctx.account_repository
+            .insert(account.to_key(), account.clone());
+        ctx.repository.insert(request.to_key(), request.to_owned());
+        REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy.to_owned());
+
+        APPROVAL_DELEGATION_SERVICE
+            .add_delegation(AddApprovalDelegationOperationInput {
+                delegator_id: delegator.id,
+                delegate_id: ctx.caller_user.id,
+                specifier: Some(RequestSpecifier::Transfer(ResourceIds::Any)),
+                expires_at: u64::MAX,
+            })
+            .await
+            .unwrap();
+
+        request
+    }
+
+    #[tokio::test]
+    async fn open_requests_approvable_by_delegate() {
+        let ctx = setup();
+        let request = setup_delegated_time_locked_request(&ctx).await;
+
+        assert_eq!(
+            ctx.service
+                .find_open_requests_approvable_by(&ctx.caller_user.id)
+                .await,
+            vec![request.id]
+        );
+        assert!(request
+            .find_all_possible_approvers()
+            .await
+            .unwrap()
+            .contains(&ctx.caller_user.id));
+    }
+
+    #[tokio::test]
+    async fn delegate_can_veto_time_locked_request() {
+        let ctx = setup();
+        let request = setup_delegated_time_locked_request(&ctx).await;
+        let request_id = Uuid::from_bytes(request.id).hyphenated().to_string();
+
+        ctx.service
+            .submit_request_approval(
+                SubmitRequestApprovalInput {
+                    request_id: request_id.clone(),
+                    decision: RequestApprovalStatusDTO::Approved,
+                    reason: None,
+                    on_behalf_of: Some(Uuid::from_bytes([25; 16]).hyphenated().to_string()),
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            ctx.service.get_request(&request.id).unwrap().status,
+            RequestStatus::Scheduled { .. }
+        ));
+
+        let vetoed_request = ctx
+            .service
+            .veto_request(
+                VetoRequestInput {
+                    request_id,
+                    reason: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(vetoed_request.status, RequestStatus::Rejected);
+    }
+
+    #[tokio::test]
+    async fn time_locked_request_can_be_vetoed() {
+        let ctx = setup();
+        let account_id = Uuid::new_v4();
+        let mut account = mock_account();
+        account.id = *account_id.as_bytes();
+        let mut request = mock_request();
+        request.requested_by = [8; 16];
+        request.status = RequestStatus::Created;
+        request.operation = RequestOperation::Transfer(TransferOperation {
+            transfer_id: None,
+            input: TransferOperationInput {
+                from_account_id: *account_id.as_bytes(),
+                amount: candid::Nat(100u32.into()),
+                fee: None,
+                metadata: Metadata::default(),
+                network: "mainnet".to_string(),
+                to: "0x1234".to_string(),
+            },
+        });
+        request.approvals = vec![];
+        let mut request_policy = mock_request_policy();
+        request_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        request_policy.rule = RequestPolicyRule::And(vec![
+            RequestPolicyRule::Quorum(UserSpecifier::Id(vec![ctx.caller_user.id]), 1),
+            RequestPolicyRule::TimeLock(TimeLock {
+                delay_ns: 60 * 1_000_000_000,
+            }),
+        ]);
+
+        ctx.account_repository
+            .insert(account.to_key(), account.clone());
+        ctx.repository.insert(request.to_key(), request.to_owned());
+        REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy.to_owned());
+
+        let request_id = Uuid::from_bytes(request.id.to_owned())
+            .hyphenated()
+            .to_string();
+        let approved_at = time();
+
+        ctx.service
+            .submit_request_approval(
+                SubmitRequestApprovalInput {
+                    request_id: request_id.clone(),
+                    decision: RequestApprovalStatusDTO::Approved,
+                    reason: None,
+                    on_behalf_of: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        // the approved request waits for the time lock to pass
+        let RequestStatus::Scheduled { scheduled_at } =
+            ctx.service.get_request(&request.id).unwrap().status
+        else {
+            panic!("Request not scheduled");
+        };
+        assert!(scheduled_at >= approved_at + 60 * 1_000_000_000);
+
+        let notifications = NOTIFICATION_REPOSITORY.list();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].target_user_id, ctx.caller_user.id);
+        assert_eq!(
+            notifications[0].notification_type,
+            NotificationType::RequestTimeLocked(RequestTimeLockedNotification {
+                request_id: request.id,
+                unlocks_at: scheduled_at,
+            })
+        );
+
+        let vetoed_request = ctx
+            .service
+            .veto_request(
+                VetoRequestInput {
+                    request_id: request_id.clone(),
+                    reason: Some("wrong destination".to_string()),
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(vetoed_request.status, RequestStatus::Rejected);
+        assert_eq!(vetoed_request.approvals.len(), 1);
+        assert_eq!(
+            vetoed_request.approvals[0].status,
+            RequestApprovalStatus::Rejected
+        );
+
+        // a request that is no longer scheduled can not be vetoed
+        let result = ctx
+            .service
+            .veto_request(
+                VetoRequestInput {
+                    request_id,
+                    reason: None,
+                },
+                &ctx.call_context,
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn simulate_request_with_draft_policies() {
+        let ctx = setup();
+        let mut approver = mock_user();
+        approver.identities = vec![Principal::from_slice(&[25; 29])];
+        approver.id = [25; 16];
+        approver.status = UserStatus::Active;
+        USER_REPOSITORY.insert(approver.to_key(), approver.clone());
+
+        let account = mock_account();
+        ctx.account_repository
+            .insert(account.to_key(), account.clone());
+
+        let mut request_policy = mock_request_policy();
+        request_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        request_policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Id(vec![approver.id]), 1);
+        REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy.to_owned());
+
+        let policy_id = Uuid::from_bytes(request_policy.id).hyphenated().to_string();
+        let simulate = |draft_policies| SimulateRequestInput {
+            operation: station_api::RequestOperationInput::Transfer(
+                station_api::TransferOperationInput {
+                    from_account_id: Uuid::from_bytes(account.id.to_owned())
+                        .hyphenated()
+                        .to_string(),
+                    amount: candid::Nat(100u32.into()),
+                    fee: None,
+                    metadata: vec![],
+                    network: None,
+                    to: "7f2e1b156f29b09f8efe9248c4e2d1f754434887a4bae0317e06c7d3442e7922"
+                        .to_string(),
+                },
+            ),
+            draft_policies,
+        };
+
+        let simulation = ctx
+            .service
+            .simulate_request(simulate(None), &ctx.call_context)
+            .await
+            .unwrap();
+
+        assert_eq!(simulation.evaluation.status, EvaluationStatus::Pending);
+        assert!(!simulation.auto_approved);
+        assert_eq!(simulation.possible_approvers, HashSet::from([approver.id]));
+        assert_eq!(simulation.matched_policies.len(), 1);
+        assert_eq!(
+            simulation.matched_policies[0].policy_id,
+            Some(request_policy.id)
+        );
+
+        // the draft replaces the stored policy, so the requester approves it right away
+        let simulation = ctx
+            .service
+            .simulate_request(
+                simulate(Some(vec![DraftRequestPolicyDTO {
+                    policy_id: Some(policy_id),
+                    specifier: RequestSpecifierDTO::Transfer(ResourceIdsDTO::Any),
+                    rule: RequestPolicyRuleDTO::Quorum(QuorumDTO {
+                        approvers: UserSpecifierDTO::Id(vec![Uuid::from_bytes(ctx.caller_user.id)
+                            .hyphenated()
+                            .to_string()]),
+                        min_approved: 1,
+                    }),
+                }])),
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(simulation.evaluation.status, EvaluationStatus::Approved);
+        assert!(simulation.auto_approved);
+        assert_eq!(
+            simulation.possible_approvers,
+            HashSet::from([ctx.caller_user.id])
+        );
+        assert_eq!(simulation.matched_policies.len(), 1);
+
+        // drafts without id are evaluated next to the stored policies
+        let simulation = ctx
+            .service
+            .simulate_request(
+                simulate(Some(vec![DraftRequestPolicyDTO {
+                    policy_id: None,
+                    specifier: RequestSpecifierDTO::AddUser,
+                    rule: RequestPolicyRuleDTO::AutoApproved,
+                }])),
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(simulation.matched_policies.len(), 1);
+        assert_eq!(simulation.evaluation.status, EvaluationStatus::Pending);
+
+        // nothing is stored by the simulation
+        assert!(ctx.repository.list().is_empty());
+    }
+
+    #[tokio::test]
+    async fn requester_can_cancel_pending_request() {
+        let ctx = setup();
+        let mut approver = mock_user();
+        approver.identities = vec![Principal::from_slice(&[25; 29])];
+        approver.id = [25; 16];
+        approver.status = UserStatus::Active;
+        USER_REPOSITORY.insert(approver.to_key(), approver.clone());
+
+        let mut request = mock_request();
+        request.requested_by = ctx.caller_user.id;
+        request.status = RequestStatus::Created;
+        request.approvals = vec![];
+        let mut request_policy = mock_request_policy();
+        request_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        request_policy.rule =
+            RequestPolicyRule::Quorum(UserSpecifier::Id(vec![ctx.caller_user.id, approver.id]), 2);
+
+        ctx.repository.insert(request.to_key(), request.to_owned());
+        REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy.to_owned());
+
+        let request_id = Uuid::from_bytes(request.id.to_owned())
+            .hyphenated()
+            .to_string();
+
+        let cancelled_request = ctx
+            .service
+            .cancel_request(
+                CancelRequestInput {
+                    request_id: request_id.clone(),
+                    reason: Some("no longer needed".to_string()),
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            cancelled_request.status,
+            RequestStatus::Cancelled {
+                reason: Some("no longer needed".to_string())
+            }
+        );
+
+        // only the other approver is notified, the requester cancelled it
+        let notifications = NOTIFICATION_REPOSITORY.list();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].target_user_id, approver.id);
+        assert_eq!(
+            notifications[0].notification_type,
+            NotificationType::RequestCancelled(RequestCancelledNotification {
+                request_id: request.id,
+            })
+        );
+
+        let result = ctx
+            .service
+            .cancel_request(
+                CancelRequestInput {
+                    request_id,
+                    reason: None,
+                },
+                &ctx.call_context,
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn request_creation_triggers_notifications() {
+        let ctx = setup();
+        // creates other users
+        let mut related_user = mock_user();
+        related_user.identities = vec![Principal::from_slice(&[25; 29])];
+        related_user.id = [25; 16];
+        related_user.status = UserStatus::Active;
+
+        let mut unrelated_user = mock_user();
+        unrelated_user.identities = vec![Principal::from_slice(&[26; 29])];
+        unrelated_user.id = [26; 16];
+        unrelated_user.status = UserStatus::Active;
+
+        USER_REPOSITORY.insert(related_user.to_key(), related_user.clone());
+        USER_REPOSITORY.insert(unrelated_user.to_key(), unrelated_user.clone());
+
+        // creates the account for the transfer
+        let account = mock_account();
+
+        ctx.account_repository
+            .insert(account.to_key(), account.clone());
+
+        // creates a request policy that will match the new request
+        let mut request_policy = mock_request_policy();
+        request_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        request_policy.rule = RequestPolicyRule::QuorumPercentage(
+            UserSpecifier::Id(vec![ctx.caller_user.id, related_user.id]),
+            Percentage(100),
+        );
+        REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy.to_owned());
+
+        // creates the request
+        ctx.service
+            .create_request(
+                station_api::CreateRequestInput {
+                    operation: station_api::RequestOperationInput::Transfer(
+                        station_api::TransferOperationInput {
+                            from_account_id: Uuid::from_bytes(account.id.to_owned())
+                                .hyphenated()
+                                .to_string(),
+                            amount: candid::Nat(100u32.into()),
+                            fee: None,
+                            metadata: vec![],
+                            network: None,
+                            to: "7f2e1b156f29b09f8efe9248c4e2d1f754434887a4bae0317e06c7d3442e7922"
+                                .to_string(),
+                        },
+                    ),
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        let notifications = NOTIFICATION_REPOSITORY.list();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].target_user_id, related_user.id);
+    }
+
+    #[tokio::test]
+    async fn fail_transfer_request_with_invalid_destination() {
+        let ctx = setup();
+        let account = mock_account();
+
+        ctx.account_repository
+            .insert(account.to_key(), account.clone());
+
+        let result = ctx
+            .service
+            .create_request(
+                station_api::CreateRequestInput {
+                    operation: station_api::RequestOperationInput::Transfer(
+                        station_api::TransferOperationInput {
+                            from_account_id: Uuid::from_bytes(account.id.to_owned())
+                                .hyphenated()
+                                .to_string(),
+                            amount: candid::Nat(100u32.into()),
+                            fee: None,
+                            metadata: vec![],
+                            network: None,
+                            // the last character breaks the checksum of the account identifier
+                            to: "7f2e1b156f29b09f8efe9248c4e2d1f754434887a4bae0317e06c7d3442e7923"
+                                .to_string(),
+                        },
+                    ),
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                },
+                &ctx.call_context,
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert!(REQUEST_REPOSITORY.list().is_empty());
     }
 
     #[tokio::test]
@@ -679,7 +1704,9 @@ mod tests {
                     operation: station_api::RequestOperationInput::AddAddressBookEntry(
                         station_api::AddAddressBookEntryOperationInput {
                             address_owner: "".to_owned(),
-                            address: "abc".to_owned(),
+                            address:
+                                "7f2e1b156f29b09f8efe9248c4e2d1f754434887a4bae0317e06c7d3442e7922"
+                                    .to_owned(),
                             blockchain: "icp".to_owned(),
                             standard: "native".to_owned(),
                             metadata: vec![],
@@ -775,8 +1802,7 @@ mod tests {
             .account_service
             .create_account(AddAccountOperationInput {
                 name: "foo".to_string(),
-                blockchain: Blockchain::InternetComputer,
-                standard: BlockchainStandard::Native,
+                asset_id: add_asset("ICP").id,
                 metadata: Metadata::default(),
                 transfer_request_policy: Some(RequestPolicyRule::QuorumPercentage(
                     UserSpecifier::Id(vec![ctx.caller_user.id, transfer_requester_user.id]),
@@ -834,6 +1860,7 @@ mod tests {
                     status: RequestApprovalStatus::Approved,
                     status_reason: None,
                     approver_id: transfer.requested_by,
+                    delegate_id: None,
                 }];
                 ctx.repository
                     .insert(transfer.to_key(), transfer.to_owned());
@@ -924,6 +1951,7 @@ mod tests {
                         .hyphenated()
                         .to_string(),
                     reason: None,
+                    on_behalf_of: None,
                 },
                 &ctx.call_context,
             )
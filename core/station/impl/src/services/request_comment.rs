@@ -0,0 +1,393 @@
+use crate::{
+    core::{
+        authorization::Authorization,
+        generate_uuid_v4,
+        ic_cdk::next_time,
+        utils::{paginated_items, PaginatedData, PaginatedItemsArgs},
+        CallContext,
+    },
+    errors::{RequestCommentError, RequestError},
+    mappers::HelperMapper,
+    models::{
+        resource::{RequestResourceAction, Resource, ResourceId},
+        NotificationType, Request, RequestComment, RequestCommentId,
+        RequestCommentMentionNotification, UserId,
+    },
+    repositories::{
+        RequestCommentRepository, RequestRepository, REQUEST_COMMENT_REPOSITORY, REQUEST_REPOSITORY,
+    },
+    services::{NotificationService, UserService, NOTIFICATION_SERVICE, USER_SERVICE},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, model::ModelValidator, repository::Repository};
+use station_api::{AddRequestCommentInput, EditRequestCommentInput, ListRequestCommentsInput};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref REQUEST_COMMENT_SERVICE: Arc<RequestCommentService> =
+        Arc::new(RequestCommentService::new(
+            Arc::clone(&REQUEST_COMMENT_REPOSITORY),
+            Arc::clone(&REQUEST_REPOSITORY),
+            Arc::clone(&USER_SERVICE),
+            Arc::clone(&NOTIFICATION_SERVICE),
+        ));
+}
+
+#[derive(Default, Debug)]
+pub struct RequestCommentService {
+    request_comment_repository: Arc<RequestCommentRepository>,
+    request_repository: Arc<RequestRepository>,
+    user_service: Arc<UserService>,
+    notification_service: Arc<NotificationService>,
+}
+
+impl RequestCommentService {
+    pub const DEFAULT_COMMENTS_LIMIT: u16 = 100;
+    pub const MAX_LIST_COMMENTS_LIMIT: u16 = 1000;
+
+    pub fn new(
+        request_comment_repository: Arc<RequestCommentRepository>,
+        request_repository: Arc<RequestRepository>,
+        user_service: Arc<UserService>,
+        notification_service: Arc<NotificationService>,
+    ) -> Self {
+        Self {
+            request_comment_repository,
+            request_repository,
+            user_service,
+            notification_service,
+        }
+    }
+
+    /// Returns the comment with the given id.
+    pub fn get(&self, comment_id: &RequestCommentId) -> ServiceResult<RequestComment> {
+        let comment = self.request_comment_repository.get(comment_id).ok_or(
+            RequestCommentError::NotFound {
+                id: Uuid::from_bytes(*comment_id).hyphenated().to_string(),
+            },
+        )?;
+
+        Ok(comment)
+    }
+
+    /// Returns the comments of the request, from the oldest to the newest.
+    pub fn list_comments(
+        &self,
+        input: ListRequestCommentsInput,
+    ) -> ServiceResult<PaginatedData<RequestComment>> {
+        let request = self.get_request(&input.request_id)?;
+        let comments = self.request_comment_repository.find_by_request(&request.id);
+
+        let result = paginated_items(PaginatedItemsArgs {
+            offset: input.paginate.to_owned().and_then(|p| p.offset),
+            limit: input.paginate.and_then(|p| p.limit),
+            default_limit: Some(Self::DEFAULT_COMMENTS_LIMIT),
+            max_limit: Some(Self::MAX_LIST_COMMENTS_LIMIT),
+            items: &comments,
+        })?;
+
+        Ok(result)
+    }
+
+    /// Adds the comment of the caller to the request and notifies the mentioned users.
+    pub async fn add_comment(
+        &self,
+        input: AddRequestCommentInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<RequestComment> {
+        let author = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let request = self.get_request(&input.request_id)?;
+        let now = next_time();
+        let comment = RequestComment {
+            id: *generate_uuid_v4().await.as_bytes(),
+            request_id: request.id,
+            author_id: author.id,
+            content: input.content,
+            mentions: Self::to_mentions(input.mentions)?,
+            created_timestamp: now,
+            last_modification_timestamp: now,
+        };
+
+        comment.validate()?;
+        self.assert_mentions_access(&request, &comment, &comment.mentions)?;
+
+        self.request_comment_repository
+            .insert(comment.id, comment.to_owned());
+
+        self.notify_mentions(&request, &comment, &comment.mentions)
+            .await;
+
+        Ok(comment)
+    }
+
+    /// Edits the comment that the caller wrote, only the users that were not mentioned before are
+    /// notified.
+    pub async fn edit_comment(
+        &self,
+        input: EditRequestCommentInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<RequestComment> {
+        let author = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let comment_id = HelperMapper::to_uuid(input.comment_id)?;
+        let mut comment = self.get(comment_id.as_bytes())?;
+
+        if comment.author_id != author.id {
+            Err(RequestCommentError::NotAuthor {
+                id: comment_id.hyphenated().to_string(),
+            })?
+        }
+
+        let request = self.get_request(&Uuid::from_bytes(comment.request_id).to_string())?;
+        let mentions = Self::to_mentions(input.mentions)?;
+        let new_mentions = mentions
+            .iter()
+            .filter(|user_id| !comment.mentions.contains(user_id))
+            .copied()
+            .collect::<Vec<_>>();
+
+        comment.content = input.content;
+        comment.mentions = mentions;
+        comment.last_modification_timestamp = next_time();
+
+        comment.validate()?;
+        self.assert_mentions_access(&request, &comment, &new_mentions)?;
+
+        self.request_comment_repository
+            .insert(comment.id, comment.to_owned());
+
+        self.notify_mentions(&request, &comment, &new_mentions)
+            .await;
+
+        Ok(comment)
+    }
+
+    fn get_request(&self, request_id: &str) -> ServiceResult<Request> {
+        let request_id = HelperMapper::to_uuid(request_id.to_string())?;
+        let request = self
+            .request_repository
+            .get(&Request::key(*request_id.as_bytes()))
+            .ok_or(RequestError::NotFound {
+                request_id: request_id.hyphenated().to_string(),
+            })?;
+
+        Ok(request)
+    }
+
+    /// Parses the mentioned users, the same user is only mentioned once.
+    fn to_mentions(mentions: Vec<String>) -> ServiceResult<Vec<UserId>> {
+        let mut user_ids: Vec<UserId> = Vec::new();
+        for mention in mentions {
+            let user_id = *HelperMapper::to_uuid(mention)?.as_bytes();
+            if !user_ids.contains(&user_id) {
+                user_ids.push(user_id);
+            }
+        }
+
+        Ok(user_ids)
+    }
+
+    /// Checks that the mentioned users can read the request, so that the notification doesn't
+    /// disclose the request to users without access to it.
+    fn assert_mentions_access(
+        &self,
+        request: &Request,
+        comment: &RequestComment,
+        mentions: &[UserId],
+    ) -> ServiceResult<()> {
+        let resource = Resource::Request(RequestResourceAction::Read(ResourceId::Id(request.id)));
+
+        for user_id in mentions {
+            if *user_id == comment.author_id {
+                continue;
+            }
+
+            let user = self.user_service.get_user(user_id)?;
+            if !Authorization::explain(&user, &resource).allowed {
+                Err(RequestCommentError::MentionNotAllowed {
+                    user_id: Uuid::from_bytes(*user_id).hyphenated().to_string(),
+                })?
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn notify_mentions(
+        &self,
+        request: &Request,
+        comment: &RequestComment,
+        mentions: &[UserId],
+    ) {
+        for user_id in mentions {
+            if *user_id == comment.author_id {
+                continue;
+            }
+
+            self.notification_service
+                .send_notification(
+                    *user_id,
+                    NotificationType::RequestCommentMention(RequestCommentMentionNotification {
+                        request_id: request.id,
+                        comment_id: comment.id,
+                    }),
+                    request.title.to_owned(),
+                    Some(comment.content.to_owned()),
+                )
+                .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{request_test_utils::mock_request, user_test_utils::mock_user, User},
+        repositories::{NOTIFICATION_REPOSITORY, USER_REPOSITORY},
+    };
+    use candid::Principal;
+
+    struct TestContext {
+        service: RequestCommentService,
+        author: User,
+        request: Request,
+        call_context: CallContext,
+    }
+
+    fn setup() -> TestContext {
+        test_utils::init_canister_system();
+
+        let caller_principal = Principal::from_slice(&[9; 29]);
+        let mut author = mock_user();
+        author.identities = vec![caller_principal];
+        USER_REPOSITORY.insert(author.to_key(), author.clone());
+
+        let request = mock_request();
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        TestContext {
+            service: RequestCommentService::new(
+                Arc::clone(&REQUEST_COMMENT_REPOSITORY),
+                Arc::clone(&REQUEST_REPOSITORY),
+                Arc::clone(&USER_SERVICE),
+                Arc::clone(&NOTIFICATION_SERVICE),
+            ),
+            author,
+            request,
+            call_context: CallContext::new(caller_principal),
+        }
+    }
+
+    #[tokio::test]
+    async fn add_comment_notifies_mentioned_users() {
+        let ctx = setup();
+        let mut mentioned = mock_user();
+        // the requester can read the request
+        mentioned.id = ctx.request.requested_by;
+        USER_REPOSITORY.insert(mentioned.to_key(), mentioned.clone());
+
+        let comment = ctx
+            .service
+            .add_comment(
+                AddRequestCommentInput {
+                    request_id: Uuid::from_bytes(ctx.request.id).hyphenated().to_string(),
+                    content: "Can you confirm the amount?".to_string(),
+                    mentions: vec![
+                        Uuid::from_bytes(mentioned.id).hyphenated().to_string(),
+                        Uuid::from_bytes(ctx.author.id).hyphenated().to_string(),
+                    ],
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(comment.author_id, ctx.author.id);
+        assert_eq!(
+            REQUEST_COMMENT_REPOSITORY.find_by_request(&ctx.request.id),
+            vec![comment]
+        );
+        assert_eq!(
+            NOTIFICATION_REPOSITORY.find_by_user_id(mentioned.id).len(),
+            1
+        );
+        // authors are not notified about their own mentions
+        assert!(NOTIFICATION_REPOSITORY
+            .find_by_user_id(ctx.author.id)
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn fail_mention_of_user_without_request_access() {
+        let ctx = setup();
+        let outsider = mock_user();
+        USER_REPOSITORY.insert(outsider.to_key(), outsider.clone());
+
+        let result = ctx
+            .service
+            .add_comment(
+                AddRequestCommentInput {
+                    request_id: Uuid::from_bytes(ctx.request.id).hyphenated().to_string(),
+                    content: "Have a look at this transfer".to_string(),
+                    mentions: vec![Uuid::from_bytes(outsider.id).hyphenated().to_string()],
+                },
+                &ctx.call_context,
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert!(REQUEST_COMMENT_REPOSITORY
+            .find_by_request(&ctx.request.id)
+            .is_empty());
+        assert!(NOTIFICATION_REPOSITORY
+            .find_by_user_id(outsider.id)
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn only_author_can_edit_comment() {
+        let ctx = setup();
+        let comment = ctx
+            .service
+            .add_comment(
+                AddRequestCommentInput {
+                    request_id: Uuid::from_bytes(ctx.request.id).hyphenated().to_string(),
+                    content: "first draft".to_string(),
+                    mentions: vec![],
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        let edit_input = EditRequestCommentInput {
+            comment_id: Uuid::from_bytes(comment.id).hyphenated().to_string(),
+            content: "edited".to_string(),
+            mentions: vec![],
+        };
+
+        let mut other_user = mock_user();
+        other_user.identities = vec![Principal::from_slice(&[10; 29])];
+        USER_REPOSITORY.insert(other_user.to_key(), other_user.clone());
+
+        assert!(ctx
+            .service
+            .edit_comment(
+                edit_input.clone(),
+                &CallContext::new(Principal::from_slice(&[10; 29]))
+            )
+            .await
+            .is_err());
+
+        let edited = ctx
+            .service
+            .edit_comment(edit_input, &ctx.call_context)
+            .await
+            .unwrap();
+
+        assert_eq!(edited.content, "edited");
+        assert_eq!(edited.created_timestamp, comment.created_timestamp);
+    }
+}
@@ -5,21 +5,27 @@ use crate::{
         utils::{paginated_items, retain_accessible_resources, PaginatedData, PaginatedItemsArgs},
         CallContext,
     },
-    errors::RequestError,
+    errors::{RequestError, RequestPolicyError},
     models::{
-        request_policy_rule::RequestPolicyRuleInput,
-        request_specifier::RequestSpecifier,
-        resource::{Resource, ResourceAction, ResourceId},
-        AddRequestPolicyOperationInput, EditRequestPolicyOperationInput, RequestPolicy,
-        RequestPolicyCallerPrivileges,
+        request_policy_rule::{RequestPolicyRule, RequestPolicyRuleInput},
+        request_specifier::{RequestSpecifier, UserSpecifier},
+        resource::{
+            ChangeCanisterResourceAction, PermissionResourceAction, Resource, ResourceAction,
+            ResourceId,
+        },
+        AddRequestPolicyOperationInput, DraftRequestPolicy, EditRequestPolicyOperationInput,
+        RequestPolicy, RequestPolicyCallerPrivileges, RequestPolicyLintWarning, User, UserStatus,
+    },
+    repositories::{
+        request_policy::{RequestPolicyRepository, REQUEST_POLICY_REPOSITORY},
+        USER_GROUP_REPOSITORY, USER_REPOSITORY,
     },
-    repositories::request_policy::{RequestPolicyRepository, REQUEST_POLICY_REPOSITORY},
 };
 use lazy_static::lazy_static;
 use orbit_essentials::{api::ServiceResult, cdk::api::print, types::UUID};
 use orbit_essentials::{model::ModelValidator, repository::Repository};
 use station_api::ListRequestPoliciesInput;
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 use uuid::Uuid;
 
 lazy_static! {
@@ -28,6 +34,14 @@ lazy_static! {
     );
 }
 
+/// The resources that must always keep someone able to approve their requests, otherwise the
+/// station can no longer change its policies, permissions or code.
+pub(crate) const LOCK_OUT_SENSITIVE_RESOURCES: [Resource; 3] = [
+    Resource::ChangeCanister(ChangeCanisterResourceAction::Create),
+    Resource::Permission(PermissionResourceAction::Update),
+    Resource::RequestPolicy(ResourceAction::Update(ResourceId::Any)),
+];
+
 #[derive(Default, Debug)]
 pub struct RequestPolicyService {
     request_policy_repository: Arc<RequestPolicyRepository>,
@@ -157,6 +171,184 @@ impl RequestPolicyService {
         Ok(())
     }
 
+    /// Lints the rule against the current users and groups of the station.
+    pub fn lint_rule(&self, rule: &RequestPolicyRule) -> Vec<RequestPolicyLintWarning> {
+        let mut specifiers = Vec::new();
+        collect_user_specifiers(rule, &mut specifiers);
+
+        let mut warnings = Vec::new();
+        for specifier in specifiers {
+            match specifier {
+                UserSpecifier::Any => {}
+                UserSpecifier::Id(ids) => {
+                    if ids.is_empty() {
+                        warnings.push(RequestPolicyLintWarning::EmptyUserSpecifier);
+                    }
+                }
+                UserSpecifier::Group(group_ids) => {
+                    if group_ids.is_empty() {
+                        warnings.push(RequestPolicyLintWarning::EmptyUserSpecifier);
+                    }
+
+                    for group_id in group_ids {
+                        let warning = if USER_GROUP_REPOSITORY.get(group_id).is_none() {
+                            RequestPolicyLintWarning::UnknownUserGroup {
+                                group_id: *group_id,
+                            }
+                        } else if USER_REPOSITORY
+                            .find_by_group_and_status(group_id, &UserStatus::Active)
+                            .is_empty()
+                        {
+                            RequestPolicyLintWarning::EmptyUserGroup {
+                                group_id: *group_id,
+                            }
+                        } else {
+                            continue;
+                        };
+
+                        if !warnings.contains(&warning) {
+                            warnings.push(warning);
+                        }
+                    }
+                }
+            }
+        }
+
+        if !is_satisfiable(rule, &active_approvers) {
+            warnings.push(RequestPolicyLintWarning::UnsatisfiableRule);
+        }
+
+        // rules that approve by design, e.g. `AutoApproved`, are not reported
+        if is_always_approved(rule, &active_approvers) && !is_always_approved(rule, &|_| None) {
+            warnings.push(RequestPolicyLintWarning::ApprovedWithoutApprovers);
+        }
+
+        warnings
+    }
+
+    /// Validates that replacing the policy `removed_policy_id` with `added_policy` is safe.
+    ///
+    /// Rejects rules that can never approve a request and changes that leave the lock-out sensitive
+    /// resources without a policy that the current users can satisfy, other problems are returned
+    /// as warnings.
+    pub fn validate_policy_change(
+        &self,
+        removed_policy_id: Option<&UUID>,
+        added_policy: Option<&DraftRequestPolicy>,
+    ) -> Result<Vec<RequestPolicyLintWarning>, RequestPolicyError> {
+        if let Some(policy) = added_policy {
+            if !is_satisfiable(&policy.rule, &specified_approvers) {
+                return Err(RequestPolicyError::UnsatisfiableRule {
+                    info: "no combination of approvals can approve the request".to_string(),
+                });
+            }
+        }
+
+        for resource in LOCK_OUT_SENSITIVE_RESOURCES {
+            let current_rules = self
+                .request_policy_repository
+                .find_by_resource(resource.clone())
+                .into_iter()
+                .map(|policy| (policy.id, policy.rule))
+                .collect::<Vec<_>>();
+
+            let is_approvable = |rules: &[&RequestPolicyRule]| {
+                rules
+                    .iter()
+                    .any(|rule| is_satisfiable(rule, &active_approvers))
+            };
+
+            if !is_approvable(
+                &current_rules
+                    .iter()
+                    .map(|(_, rule)| rule)
+                    .collect::<Vec<_>>(),
+            ) {
+                // There is nothing to lock out of if the requests could not be approved already.
+                continue;
+            }
+
+            let mut changed_rules = current_rules
+                .iter()
+                .filter(|(id, _)| Some(id) != removed_policy_id)
+                .map(|(_, rule)| rule)
+                .collect::<Vec<_>>();
+
+            if let Some(policy) = added_policy {
+                if policy.specifier.to_resources().contains(&resource) {
+                    changed_rules.push(&policy.rule);
+                }
+            }
+
+            if !is_approvable(&changed_rules) {
+                return Err(RequestPolicyError::LockOut {
+                    resource: resource.to_string(),
+                });
+            }
+        }
+
+        Ok(added_policy
+            .map(|policy| self.lint_rule(&policy.rule))
+            .unwrap_or_default())
+    }
+
+    /// Validates that removing the user doesn't leave the lock-out sensitive resources without a
+    /// policy that the remaining active users can satisfy, or with a policy that approves their
+    /// requests because no approver is left.
+    pub fn validate_user_removal(&self, user_id: &UUID) -> Result<(), RequestPolicyError> {
+        let remaining_approvers = |specifier: &UserSpecifier| {
+            active_approvers(specifier).map(|mut users| {
+                users.remove(user_id);
+                users
+            })
+        };
+
+        for resource in LOCK_OUT_SENSITIVE_RESOURCES {
+            let rules = self
+                .request_policy_repository
+                .find_by_resource(resource.clone())
+                .into_iter()
+                .map(|policy| policy.rule)
+                .collect::<Vec<_>>();
+
+            if rules
+                .iter()
+                .any(|rule| is_satisfiable(rule, &active_approvers))
+                && !rules
+                    .iter()
+                    .any(|rule| is_satisfiable(rule, &remaining_approvers))
+            {
+                return Err(RequestPolicyError::LockOut {
+                    resource: resource.to_string(),
+                });
+            }
+
+            if !rules
+                .iter()
+                .any(|rule| is_always_approved(rule, &active_approvers))
+                && rules
+                    .iter()
+                    .any(|rule| is_always_approved(rule, &remaining_approvers))
+            {
+                return Err(RequestPolicyError::ApprovedWithoutApprovers {
+                    resource: resource.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the user from the approvers that are explicitly listed in the policy rules.
+    pub fn remove_user_from_policies(&self, user_id: &UUID) {
+        for mut policy in self.request_policy_repository.list() {
+            if remove_user_from_rule(&mut policy.rule, user_id) {
+                self.request_policy_repository
+                    .insert(policy.id, policy.to_owned());
+            }
+        }
+    }
+
     pub async fn get_caller_privileges_for_request_policy(
         &self,
         policy_id: &UUID,
@@ -198,15 +390,197 @@ impl RequestPolicyService {
     }
 }
 
+/// Resolves the users that can approve for a user specifier, `None` if they can't be known.
+type ApproversResolver<'a> = dyn Fn(&UserSpecifier) -> Option<HashSet<UUID>> + 'a;
+
+/// Resolves the approvers that are explicitly listed in the specifier.
+fn specified_approvers(specifier: &UserSpecifier) -> Option<HashSet<UUID>> {
+    match specifier {
+        UserSpecifier::Id(ids) => Some(ids.iter().copied().collect()),
+        UserSpecifier::Any | UserSpecifier::Group(_) => None,
+    }
+}
+
+/// Resolves the approvers among the currently active users of the station.
+fn active_approvers(specifier: &UserSpecifier) -> Option<HashSet<UUID>> {
+    let approvers = match specifier {
+        UserSpecifier::Any => USER_REPOSITORY
+            .list()
+            .into_iter()
+            .filter(|user| user.is_active())
+            .map(|user| user.id)
+            .collect(),
+        UserSpecifier::Id(ids) => ids
+            .iter()
+            .filter(|id| {
+                USER_REPOSITORY
+                    .get(&User::key(**id))
+                    .is_some_and(|user| user.is_active())
+            })
+            .copied()
+            .collect(),
+        UserSpecifier::Group(group_ids) => group_ids
+            .iter()
+            .flat_map(|group_id| {
+                USER_REPOSITORY.find_by_group_and_status(group_id, &UserStatus::Active)
+            })
+            .map(|user| user.id)
+            .collect(),
+    };
+
+    Some(approvers)
+}
+
+fn collect_user_specifiers<'a>(
+    rule: &'a RequestPolicyRule,
+    specifiers: &mut Vec<&'a UserSpecifier>,
+) {
+    match rule {
+        RequestPolicyRule::QuorumPercentage(specifier, _)
+        | RequestPolicyRule::Quorum(specifier, _) => specifiers.push(specifier),
+        RequestPolicyRule::WeightedQuorum(quorum) => {
+            specifiers.extend(quorum.weights.iter().map(|weight| &weight.approvers))
+        }
+        RequestPolicyRule::And(rules) | RequestPolicyRule::Or(rules) => {
+            for rule in rules {
+                collect_user_specifiers(rule, specifiers);
+            }
+        }
+        RequestPolicyRule::Not(rule) => collect_user_specifiers(rule, specifiers),
+        RequestPolicyRule::AutoApproved
+        | RequestPolicyRule::AllowListedByMetadata(_)
+        | RequestPolicyRule::AllowListed
+        | RequestPolicyRule::AllowListedWithinLimit(_)
+        | RequestPolicyRule::AllowListedWithinRollingLimit(_)
+        | RequestPolicyRule::AmountAtMost(_)
+        | RequestPolicyRule::AmountAbove(_)
+        | RequestPolicyRule::TimeLock(_) => {}
+    }
+}
+
+/// Removes the user from the `UserSpecifier::Id` lists of the rule, returns true if any changed.
+fn remove_user_from_rule(rule: &mut RequestPolicyRule, user_id: &UUID) -> bool {
+    let remove_from_specifier = |specifier: &mut UserSpecifier| match specifier {
+        UserSpecifier::Id(ids) if ids.contains(user_id) => {
+            ids.retain(|id| id != user_id);
+            true
+        }
+        _ => false,
+    };
+
+    match rule {
+        RequestPolicyRule::QuorumPercentage(specifier, _)
+        | RequestPolicyRule::Quorum(specifier, _) => remove_from_specifier(specifier),
+        RequestPolicyRule::WeightedQuorum(quorum) => {
+            let mut changed = false;
+            for weight in quorum.weights.iter_mut() {
+                changed |= remove_from_specifier(&mut weight.approvers);
+            }
+
+            changed
+        }
+        RequestPolicyRule::And(rules) | RequestPolicyRule::Or(rules) => {
+            let mut changed = false;
+            for rule in rules.iter_mut() {
+                changed |= remove_user_from_rule(rule, user_id);
+            }
+
+            changed
+        }
+        RequestPolicyRule::Not(rule) => remove_user_from_rule(rule, user_id),
+        RequestPolicyRule::AutoApproved
+        | RequestPolicyRule::AllowListedByMetadata(_)
+        | RequestPolicyRule::AllowListed
+        | RequestPolicyRule::AllowListedWithinLimit(_)
+        | RequestPolicyRule::AllowListedWithinRollingLimit(_)
+        | RequestPolicyRule::AmountAtMost(_)
+        | RequestPolicyRule::AmountAbove(_)
+        | RequestPolicyRule::TimeLock(_) => false,
+    }
+}
+
+/// Checks if some combination of approvals of the resolved approvers can approve the rule.
+///
+/// Rules that depend on the request content are assumed to be satisfiable. Quorums are always
+/// satisfiable since their evaluation caps the minimum at the number of possible approvers.
+fn is_satisfiable(rule: &RequestPolicyRule, approvers: &ApproversResolver<'_>) -> bool {
+    // time locks never approve a request by themselves
+    if rule.is_neutral() {
+        return false;
+    }
+
+    match rule {
+        RequestPolicyRule::TimeLock(_) => false,
+        RequestPolicyRule::AutoApproved
+        | RequestPolicyRule::AllowListedByMetadata(_)
+        | RequestPolicyRule::AllowListed
+        | RequestPolicyRule::AllowListedWithinLimit(_)
+        | RequestPolicyRule::AllowListedWithinRollingLimit(_)
+        | RequestPolicyRule::AmountAtMost(_)
+        | RequestPolicyRule::AmountAbove(_)
+        | RequestPolicyRule::Quorum(_, _)
+        | RequestPolicyRule::QuorumPercentage(_, _)
+        | RequestPolicyRule::WeightedQuorum(_) => true,
+        RequestPolicyRule::And(rules) => rules
+            .iter()
+            .filter(|rule| !rule.is_neutral())
+            .all(|rule| is_satisfiable(rule, approvers)),
+        RequestPolicyRule::Or(rules) => rules.iter().any(|rule| is_satisfiable(rule, approvers)),
+        RequestPolicyRule::Not(rule) => !is_always_approved(rule, approvers),
+    }
+}
+
+/// Checks if the rule approves the request regardless of the approvals.
+///
+/// Quorums without any resolved approvers approve the request right away, as their minimum is
+/// capped at zero possible approvers.
+fn is_always_approved(rule: &RequestPolicyRule, approvers: &ApproversResolver<'_>) -> bool {
+    if rule.is_neutral() {
+        return false;
+    }
+
+    let has_no_approvers =
+        |specifier: &UserSpecifier| approvers(specifier).is_some_and(|users| users.is_empty());
+
+    match rule {
+        RequestPolicyRule::AutoApproved => true,
+        RequestPolicyRule::Quorum(specifier, min_approved) => {
+            *min_approved == 0 || has_no_approvers(specifier)
+        }
+        RequestPolicyRule::QuorumPercentage(specifier, percentage) => {
+            percentage.0 == 0 || has_no_approvers(specifier)
+        }
+        RequestPolicyRule::WeightedQuorum(quorum) => {
+            quorum.min_weight == 0
+                || quorum.weights.iter().all(|approver_weight| {
+                    approver_weight.weight == 0 || has_no_approvers(&approver_weight.approvers)
+                })
+        }
+        RequestPolicyRule::And(rules) => rules
+            .iter()
+            .filter(|rule| !rule.is_neutral())
+            .all(|rule| is_always_approved(rule, approvers)),
+        RequestPolicyRule::Or(rules) => {
+            rules.iter().any(|rule| is_always_approved(rule, approvers))
+        }
+        RequestPolicyRule::Not(rule) => !is_satisfiable(rule, approvers),
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         core::ic_cdk::api::id as self_canister_id,
         models::{
-            account_test_utils::mock_account, request_policy_rule::RequestPolicyRule,
-            request_policy_test_utils::mock_request_policy, request_specifier::RequestSpecifier,
+            account_test_utils::mock_account,
+            request_policy_rule::{RequestPolicyRule, TimeLock},
+            request_policy_test_utils::mock_request_policy,
+            request_specifier::RequestSpecifier,
             resource::ResourceIds,
+            user_group_test_utils::mock_user_group,
+            user_test_utils::add_user,
         },
     };
 
@@ -320,4 +694,167 @@ mod tests {
 
         assert!(account.configs_request_policy_id.is_none());
     }
+
+    #[test]
+    fn fail_policy_change_with_unsatisfiable_rule() {
+        for rule in [
+            RequestPolicyRule::Not(Box::new(RequestPolicyRule::AutoApproved)),
+            RequestPolicyRule::Not(Box::new(RequestPolicyRule::Quorum(
+                UserSpecifier::Id(vec![]),
+                1,
+            ))),
+            RequestPolicyRule::TimeLock(TimeLock { delay_ns: 10 }),
+            RequestPolicyRule::Not(Box::new(RequestPolicyRule::TimeLock(TimeLock {
+                delay_ns: 10,
+            }))),
+        ] {
+            let result = REQUEST_POLICY_SERVICE.validate_policy_change(
+                None,
+                Some(&DraftRequestPolicy {
+                    policy_id: None,
+                    specifier: RequestSpecifier::AddAccount,
+                    rule,
+                }),
+            );
+
+            assert!(matches!(
+                result,
+                Err(RequestPolicyError::UnsatisfiableRule { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn quorum_above_the_possible_approvers_is_satisfiable() {
+        let user = add_user(&[1; 16]);
+
+        // the minimum is capped at the single possible approver when the request is evaluated
+        let warnings = REQUEST_POLICY_SERVICE
+            .validate_policy_change(
+                None,
+                Some(&DraftRequestPolicy {
+                    policy_id: None,
+                    specifier: RequestSpecifier::AddAccount,
+                    rule: RequestPolicyRule::Quorum(UserSpecifier::Id(vec![user.id]), 2),
+                }),
+            )
+            .unwrap();
+
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn lint_rule_warns_about_rules_without_approvers() {
+        let mut user = add_user(&[1; 16]);
+        user.status = UserStatus::Inactive;
+        USER_REPOSITORY.insert(user.to_key(), user.to_owned());
+
+        assert_eq!(
+            REQUEST_POLICY_SERVICE.lint_rule(&RequestPolicyRule::Quorum(
+                UserSpecifier::Id(vec![user.id]),
+                1
+            )),
+            vec![RequestPolicyLintWarning::ApprovedWithoutApprovers]
+        );
+        assert!(REQUEST_POLICY_SERVICE
+            .lint_rule(&RequestPolicyRule::AutoApproved)
+            .is_empty());
+    }
+
+    #[test]
+    fn fail_policy_change_that_locks_out_upgrades() {
+        let mut policy = mock_request_policy();
+        policy.specifier = RequestSpecifier::ChangeCanister;
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.to_owned());
+
+        let result = REQUEST_POLICY_SERVICE.validate_policy_change(Some(&policy.id), None);
+
+        assert_eq!(
+            result,
+            Err(RequestPolicyError::LockOut {
+                resource: Resource::ChangeCanister(ChangeCanisterResourceAction::Create)
+                    .to_string()
+            })
+        );
+
+        let mut fallback_policy = mock_request_policy();
+        fallback_policy.specifier = RequestSpecifier::ChangeCanister;
+        REQUEST_POLICY_REPOSITORY.insert(fallback_policy.id, fallback_policy.to_owned());
+
+        assert!(REQUEST_POLICY_SERVICE
+            .validate_policy_change(Some(&policy.id), None)
+            .is_ok());
+    }
+
+    #[test]
+    fn remove_user_from_policies_and_detect_lock_out() {
+        let user = add_user(&[1; 16]);
+        let another_user = add_user(&[2; 16]);
+
+        let mut policy = mock_request_policy();
+        policy.specifier = RequestSpecifier::ChangeCanister;
+        policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Id(vec![user.id]), 1);
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.to_owned());
+
+        // the quorum would approve the requests right away without its only approver
+        assert_eq!(
+            REQUEST_POLICY_SERVICE.validate_user_removal(&user.id),
+            Err(RequestPolicyError::ApprovedWithoutApprovers {
+                resource: Resource::ChangeCanister(ChangeCanisterResourceAction::Create)
+                    .to_string()
+            })
+        );
+        assert!(REQUEST_POLICY_SERVICE
+            .validate_user_removal(&another_user.id)
+            .is_ok());
+
+        policy.rule = RequestPolicyRule::Or(vec![
+            RequestPolicyRule::Quorum(UserSpecifier::Id(vec![user.id, another_user.id]), 1),
+            RequestPolicyRule::Not(Box::new(RequestPolicyRule::Quorum(
+                UserSpecifier::Id(vec![user.id]),
+                1,
+            ))),
+        ]);
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.to_owned());
+
+        assert!(REQUEST_POLICY_SERVICE
+            .validate_user_removal(&user.id)
+            .is_ok());
+
+        REQUEST_POLICY_SERVICE.remove_user_from_policies(&user.id);
+
+        assert_eq!(
+            REQUEST_POLICY_SERVICE
+                .get_request_policy(&policy.id)
+                .unwrap()
+                .rule,
+            RequestPolicyRule::Or(vec![
+                RequestPolicyRule::Quorum(UserSpecifier::Id(vec![another_user.id]), 1),
+                RequestPolicyRule::Not(Box::new(RequestPolicyRule::Quorum(
+                    UserSpecifier::Id(vec![]),
+                    1,
+                ))),
+            ])
+        );
+    }
+
+    #[test]
+    fn lint_rule_warns_about_empty_user_groups() {
+        let group = mock_user_group();
+        USER_GROUP_REPOSITORY.insert(group.id, group.to_owned());
+
+        let warnings = REQUEST_POLICY_SERVICE.lint_rule(&RequestPolicyRule::Or(vec![
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![group.id]), 1),
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![[9; 16]]), 1),
+        ]));
+
+        assert_eq!(
+            warnings,
+            vec![
+                RequestPolicyLintWarning::EmptyUserGroup { group_id: group.id },
+                RequestPolicyLintWarning::UnknownUserGroup { group_id: [9; 16] },
+                RequestPolicyLintWarning::ApprovedWithoutApprovers,
+            ]
+        );
+    }
 }
@@ -0,0 +1,282 @@
+use crate::{
+    core::{
+        generate_uuid_v4,
+        ic_cdk::next_time,
+        utils::{paginated_items, PaginatedData, PaginatedItemsArgs},
+    },
+    errors::RequestTemplateError,
+    mappers::HelperMapper,
+    models::{RequestTemplate, RequestTemplateId, RequestTemplateOperation},
+    repositories::{RequestTemplateRepository, REQUEST_TEMPLATE_REPOSITORY},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, model::ModelValidator, repository::Repository};
+use station_api::{
+    AddRequestTemplateInput, CreateRequestFromTemplateInput, CreateRequestInput,
+    EditRequestTemplateInput, ListRequestTemplatesInput, RequestOperationInput,
+    TransferOperationInput,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref REQUEST_TEMPLATE_SERVICE: Arc<RequestTemplateService> = Arc::new(
+        RequestTemplateService::new(Arc::clone(&REQUEST_TEMPLATE_REPOSITORY))
+    );
+}
+
+#[derive(Default, Debug)]
+pub struct RequestTemplateService {
+    request_template_repository: Arc<RequestTemplateRepository>,
+}
+
+impl RequestTemplateService {
+    pub const DEFAULT_TEMPLATES_LIMIT: u16 = 100;
+    pub const MAX_LIST_TEMPLATES_LIMIT: u16 = 1000;
+
+    pub fn new(request_template_repository: Arc<RequestTemplateRepository>) -> Self {
+        Self {
+            request_template_repository,
+        }
+    }
+
+    /// Returns the template with the given id.
+    pub fn get(&self, template_id: &RequestTemplateId) -> ServiceResult<RequestTemplate> {
+        let template = self.request_template_repository.get(template_id).ok_or(
+            RequestTemplateError::NotFound {
+                id: Uuid::from_bytes(*template_id).hyphenated().to_string(),
+            },
+        )?;
+
+        Ok(template)
+    }
+
+    /// Returns the templates sorted by name.
+    pub fn list(
+        &self,
+        input: ListRequestTemplatesInput,
+    ) -> ServiceResult<PaginatedData<RequestTemplate>> {
+        let mut templates = self.request_template_repository.list();
+        templates.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let result = paginated_items(PaginatedItemsArgs {
+            offset: input.paginate.to_owned().and_then(|p| p.offset),
+            limit: input.paginate.and_then(|p| p.limit),
+            default_limit: Some(Self::DEFAULT_TEMPLATES_LIMIT),
+            max_limit: Some(Self::MAX_LIST_TEMPLATES_LIMIT),
+            items: &templates,
+        })?;
+
+        Ok(result)
+    }
+
+    pub async fn add(&self, input: AddRequestTemplateInput) -> ServiceResult<RequestTemplate> {
+        let now = next_time();
+        let template = RequestTemplate {
+            id: *generate_uuid_v4().await.as_bytes(),
+            name: input.name,
+            description: input.description,
+            title: input.title,
+            summary: input.summary,
+            operation: RequestTemplateOperation::try_from(input.operation)?,
+            created_timestamp: now,
+            last_modification_timestamp: now,
+        };
+
+        template.validate()?;
+
+        self.request_template_repository
+            .insert(template.id, template.to_owned());
+
+        Ok(template)
+    }
+
+    pub fn edit(&self, input: EditRequestTemplateInput) -> ServiceResult<RequestTemplate> {
+        let template_id = HelperMapper::to_uuid(input.template_id)?;
+        let mut template = self.get(template_id.as_bytes())?;
+
+        if let Some(name) = input.name {
+            template.name = name;
+        }
+
+        if let Some(description) = input.description {
+            template.description = Some(description);
+        }
+
+        if let Some(title) = input.title {
+            template.title = Some(title);
+        }
+
+        if let Some(summary) = input.summary {
+            template.summary = Some(summary);
+        }
+
+        if let Some(operation) = input.operation {
+            template.operation = RequestTemplateOperation::try_from(operation)?;
+        }
+
+        template.last_modification_timestamp = next_time();
+        template.validate()?;
+
+        self.request_template_repository
+            .insert(template.id, template.to_owned());
+
+        Ok(template)
+    }
+
+    pub fn remove(&self, template_id: &RequestTemplateId) -> ServiceResult<RequestTemplate> {
+        let template = self.get(template_id)?;
+
+        self.request_template_repository.remove(&template.id);
+
+        Ok(template)
+    }
+
+    /// Fills the placeholders of the template with the given parameters and returns the input of
+    /// the request to create.
+    ///
+    /// Every placeholder must be provided, while parameters for fixed fields are rejected so that
+    /// the created requests stay consistent with the template.
+    pub fn to_create_request_input(
+        &self,
+        input: CreateRequestFromTemplateInput,
+    ) -> ServiceResult<CreateRequestInput> {
+        let template_id = HelperMapper::to_uuid(input.template_id)?;
+        let template = self.get(template_id.as_bytes())?;
+
+        let operation = match template.operation {
+            RequestTemplateOperation::Transfer(transfer) => {
+                RequestOperationInput::Transfer(TransferOperationInput {
+                    from_account_id: Uuid::from_bytes(transfer.from_account_id)
+                        .hyphenated()
+                        .to_string(),
+                    to: Self::fill_placeholder("destination", transfer.to, input.destination)?,
+                    amount: Self::fill_placeholder("amount", transfer.amount, input.amount)?,
+                    fee: None,
+                    metadata: transfer.metadata.into(),
+                    network: None,
+                })
+            }
+        };
+
+        Ok(CreateRequestInput {
+            operation,
+            title: Some(template.title.unwrap_or(template.name)),
+            summary: input.summary.or(template.summary),
+            execution_plan: input.execution_plan,
+        })
+    }
+
+    fn fill_placeholder<T>(
+        name: &str,
+        fixed_value: Option<T>,
+        value: Option<T>,
+    ) -> Result<T, RequestTemplateError> {
+        match (fixed_value, value) {
+            (Some(fixed_value), None) => Ok(fixed_value),
+            (None, Some(value)) => Ok(value),
+            (Some(_), Some(_)) => Err(RequestTemplateError::FixedParameter {
+                name: name.to_string(),
+            }),
+            (None, None) => Err(RequestTemplateError::MissingParameter {
+                name: name.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            account_test_utils::mock_account, request_template_test_utils::mock_request_template,
+            TransferRequestTemplate,
+        },
+        repositories::ACCOUNT_REPOSITORY,
+    };
+
+    fn setup() -> (RequestTemplateService, RequestTemplate) {
+        test_utils::init_canister_system();
+
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let mut template = mock_request_template();
+        template.operation = RequestTemplateOperation::Transfer(TransferRequestTemplate {
+            from_account_id: account.id,
+            to: Some("vendor-address".to_string()),
+            amount: None,
+            metadata: Default::default(),
+        });
+        template.summary = Some("Monthly vendor payment".to_string());
+        REQUEST_TEMPLATE_REPOSITORY.insert(template.id, template.clone());
+
+        (
+            RequestTemplateService::new(Arc::clone(&REQUEST_TEMPLATE_REPOSITORY)),
+            template,
+        )
+    }
+
+    fn instantiate_input(template: &RequestTemplate) -> CreateRequestFromTemplateInput {
+        CreateRequestFromTemplateInput {
+            template_id: Uuid::from_bytes(template.id).hyphenated().to_string(),
+            amount: Some(candid::Nat::from(100u64)),
+            destination: None,
+            summary: None,
+            execution_plan: None,
+        }
+    }
+
+    #[test]
+    fn instantiate_template_fills_placeholders() {
+        let (service, template) = setup();
+
+        let input = service
+            .to_create_request_input(instantiate_input(&template))
+            .unwrap();
+
+        assert_eq!(input.title, Some(template.name));
+        assert_eq!(input.summary, template.summary);
+
+        match input.operation {
+            RequestOperationInput::Transfer(transfer) => {
+                assert_eq!(transfer.to, "vendor-address");
+                assert_eq!(transfer.amount, candid::Nat::from(100u64));
+            }
+            _ => panic!("unexpected operation"),
+        }
+    }
+
+    #[test]
+    fn fail_instantiate_template_with_missing_placeholder() {
+        let (service, template) = setup();
+        let mut input = instantiate_input(&template);
+        input.amount = None;
+
+        let result = service.to_create_request_input(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn fail_instantiate_template_overriding_fixed_value() {
+        let (service, template) = setup();
+        let mut input = instantiate_input(&template);
+        input.destination = Some("other-address".to_string());
+
+        let result = service.to_create_request_input(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn remove_template() {
+        let (service, template) = setup();
+
+        service.remove(&template.id).unwrap();
+
+        assert!(service.get(&template.id).is_err());
+    }
+}
@@ -0,0 +1,425 @@
+use crate::{
+    models::{
+        request_policy_rule::RequestPolicyRule, request_specifier::UserSpecifier,
+        AddRequestPolicyOperationInput, ApplyRoleTemplateOperationInput, DraftRequestPolicy,
+        EditPermissionOperationInput, EditRequestPolicyOperationInput, RoleTemplate,
+        RoleTemplateDiff, UserGroupId,
+    },
+    repositories::request_policy::{RequestPolicyRepository, REQUEST_POLICY_REPOSITORY},
+    services::{
+        permission::{PermissionService, PERMISSION_SERVICE},
+        RequestPolicyService, UserGroupService, REQUEST_POLICY_SERVICE, USER_GROUP_SERVICE,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, repository::Repository};
+use std::sync::Arc;
+
+lazy_static! {
+    pub static ref ROLE_TEMPLATE_SERVICE: Arc<RoleTemplateService> =
+        Arc::new(RoleTemplateService::new(
+            Arc::clone(&USER_GROUP_SERVICE),
+            Arc::clone(&PERMISSION_SERVICE),
+            Arc::clone(&REQUEST_POLICY_SERVICE),
+            Arc::clone(&REQUEST_POLICY_REPOSITORY),
+        ));
+}
+
+#[derive(Debug)]
+pub struct RoleTemplateService {
+    user_group_service: Arc<UserGroupService>,
+    permission_service: Arc<PermissionService>,
+    request_policy_service: Arc<RequestPolicyService>,
+    request_policy_repository: Arc<RequestPolicyRepository>,
+}
+
+impl RoleTemplateService {
+    pub fn new(
+        user_group_service: Arc<UserGroupService>,
+        permission_service: Arc<PermissionService>,
+        request_policy_service: Arc<RequestPolicyService>,
+        request_policy_repository: Arc<RequestPolicyRepository>,
+    ) -> Self {
+        Self {
+            user_group_service,
+            permission_service,
+            request_policy_service,
+            request_policy_repository,
+        }
+    }
+
+    /// Returns the permissions and request policies that applying the template would add for the group.
+    pub fn get_role_template_diff(
+        &self,
+        user_group_id: &UserGroupId,
+        template: &RoleTemplate,
+    ) -> ServiceResult<RoleTemplateDiff> {
+        self.user_group_service.get(user_group_id)?;
+
+        let mut diff = RoleTemplateDiff::default();
+
+        for resource in template.permissions() {
+            let permission = self.permission_service.get_permission(&resource);
+
+            if permission.allow.user_groups.contains(user_group_id) {
+                diff.unchanged_permissions.push(resource);
+            } else {
+                diff.added_permissions.push(resource);
+            }
+        }
+
+        let policies = self.request_policy_repository.list();
+
+        for specifier in template.request_specifiers() {
+            let matching_policies = policies
+                .iter()
+                .filter(|policy| policy.specifier == specifier)
+                .collect::<Vec<_>>();
+
+            // the group joins the approvers of the existing policies with their thresholds, since
+            // an additional policy would give the group its own way to approve the requests
+            let changes = if matching_policies.is_empty() {
+                vec![DraftRequestPolicy {
+                    policy_id: None,
+                    specifier: specifier.clone(),
+                    rule: RoleTemplate::request_policy_rule(user_group_id),
+                }]
+            } else if matching_policies
+                .iter()
+                .any(|policy| is_group_approver(&policy.rule, user_group_id))
+            {
+                diff.unchanged_request_policies.push(specifier);
+                continue;
+            } else {
+                matching_policies
+                    .iter()
+                    .filter_map(|policy| {
+                        let mut rule = policy.rule.clone();
+
+                        add_group_to_rule(&mut rule, user_group_id).then(|| DraftRequestPolicy {
+                            policy_id: Some(policy.id),
+                            specifier: policy.specifier.clone(),
+                            rule,
+                        })
+                    })
+                    .collect()
+            };
+
+            if changes.is_empty() {
+                diff.skipped_request_policies.push(specifier);
+                continue;
+            }
+
+            for change in changes {
+                for warning in self
+                    .request_policy_service
+                    .validate_policy_change(change.policy_id.as_ref(), Some(&change))?
+                {
+                    if !diff.warnings.contains(&warning) {
+                        diff.warnings.push(warning);
+                    }
+                }
+
+                diff.request_policy_changes.push(change);
+            }
+
+            diff.added_request_policies.push(specifier);
+        }
+
+        Ok(diff)
+    }
+
+    /// Grants the permissions of the template to the group and makes the group an approver of the
+    /// request policies of the template, what the group already has is kept as is.
+    pub async fn apply_role_template(
+        &self,
+        input: ApplyRoleTemplateOperationInput,
+    ) -> ServiceResult<RoleTemplateDiff> {
+        let diff = self.get_role_template_diff(&input.user_group_id, &input.template)?;
+
+        for resource in diff.added_permissions.iter() {
+            let mut user_groups = self
+                .permission_service
+                .get_permission(resource)
+                .allow
+                .user_groups;
+            user_groups.push(input.user_group_id);
+
+            self.permission_service
+                .edit_permission(EditPermissionOperationInput {
+                    resource: resource.clone(),
+                    auth_scope: None,
+                    users: None,
+                    user_groups: Some(user_groups),
+                })
+                .await?;
+        }
+
+        for change in diff.request_policy_changes.iter() {
+            match change.policy_id {
+                Some(policy_id) => {
+                    self.request_policy_service
+                        .edit_request_policy(EditRequestPolicyOperationInput {
+                            policy_id,
+                            specifier: None,
+                            rule: Some(change.rule.clone()),
+                        })
+                        .await?;
+                }
+                None => {
+                    self.request_policy_service
+                        .add_request_policy(AddRequestPolicyOperationInput {
+                            specifier: change.specifier.clone(),
+                            rule: change.rule.clone(),
+                        })
+                        .await?;
+                }
+            }
+        }
+
+        Ok(diff)
+    }
+}
+
+/// Checks if the members of the group are approvers of the rule, outside of its negated parts.
+fn is_group_approver(rule: &RequestPolicyRule, user_group_id: &UserGroupId) -> bool {
+    let includes_group = |specifier: &UserSpecifier| match specifier {
+        UserSpecifier::Any => true,
+        UserSpecifier::Group(group_ids) => group_ids.contains(user_group_id),
+        UserSpecifier::Id(_) => false,
+    };
+
+    match rule {
+        RequestPolicyRule::QuorumPercentage(specifier, _)
+        | RequestPolicyRule::Quorum(specifier, _) => includes_group(specifier),
+        RequestPolicyRule::WeightedQuorum(quorum) => quorum
+            .weights
+            .iter()
+            .any(|weight| includes_group(&weight.approvers)),
+        RequestPolicyRule::And(rules) | RequestPolicyRule::Or(rules) => rules
+            .iter()
+            .any(|rule| is_group_approver(rule, user_group_id)),
+        RequestPolicyRule::Not(_)
+        | RequestPolicyRule::AutoApproved
+        | RequestPolicyRule::AllowListedByMetadata(_)
+        | RequestPolicyRule::AllowListed
+        | RequestPolicyRule::AllowListedWithinLimit(_)
+        | RequestPolicyRule::AllowListedWithinRollingLimit(_)
+        | RequestPolicyRule::AmountAtMost(_)
+        | RequestPolicyRule::AmountAbove(_)
+        | RequestPolicyRule::TimeLock(_) => false,
+    }
+}
+
+/// Adds the group to the group approvers of the rule, outside of its negated parts, returns true
+/// if any changed.
+fn add_group_to_rule(rule: &mut RequestPolicyRule, user_group_id: &UserGroupId) -> bool {
+    let add_to_specifier = |specifier: &mut UserSpecifier| match specifier {
+        UserSpecifier::Group(group_ids) if !group_ids.contains(user_group_id) => {
+            group_ids.push(*user_group_id);
+            true
+        }
+        _ => false,
+    };
+
+    match rule {
+        RequestPolicyRule::QuorumPercentage(specifier, _)
+        | RequestPolicyRule::Quorum(specifier, _) => add_to_specifier(specifier),
+        RequestPolicyRule::WeightedQuorum(quorum) => {
+            let mut changed = false;
+            for weight in quorum.weights.iter_mut() {
+                changed |= add_to_specifier(&mut weight.approvers);
+            }
+
+            changed
+        }
+        RequestPolicyRule::And(rules) | RequestPolicyRule::Or(rules) => {
+            let mut changed = false;
+            for rule in rules.iter_mut() {
+                changed |= add_group_to_rule(rule, user_group_id);
+            }
+
+            changed
+        }
+        RequestPolicyRule::Not(_)
+        | RequestPolicyRule::AutoApproved
+        | RequestPolicyRule::AllowListedByMetadata(_)
+        | RequestPolicyRule::AllowListed
+        | RequestPolicyRule::AllowListedWithinLimit(_)
+        | RequestPolicyRule::AllowListedWithinRollingLimit(_)
+        | RequestPolicyRule::AmountAtMost(_)
+        | RequestPolicyRule::AmountAbove(_)
+        | RequestPolicyRule::TimeLock(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            request_policy_test_utils::mock_request_policy,
+            request_specifier::RequestSpecifier,
+            resource::{AccountResourceAction, Resource, ResourceId, ResourceIds},
+            UserGroup,
+        },
+        repositories::{permission::PERMISSION_REPOSITORY, USER_GROUP_REPOSITORY},
+    };
+
+    fn add_group() -> UserGroup {
+        let group = UserGroup {
+            id: [7; 16],
+            name: "Treasury".to_string(),
+            last_modification_timestamp: 0,
+        };
+
+        USER_GROUP_REPOSITORY.insert(group.id, group.to_owned());
+
+        group
+    }
+
+    #[tokio::test]
+    async fn apply_treasurer_template_adds_permissions_and_policies() {
+        test_utils::init_canister_system();
+        let group = add_group();
+
+        let diff = ROLE_TEMPLATE_SERVICE
+            .apply_role_template(ApplyRoleTemplateOperationInput {
+                user_group_id: group.id,
+                template: RoleTemplate::Treasurer,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            diff.added_permissions.len(),
+            RoleTemplate::Treasurer.permissions().len()
+        );
+        assert!(PERMISSION_REPOSITORY
+            .get(&Resource::Account(AccountResourceAction::Transfer(
+                ResourceId::Any
+            )))
+            .unwrap()
+            .allow
+            .user_groups
+            .contains(&group.id));
+        assert!(REQUEST_POLICY_REPOSITORY.list().iter().any(|policy| {
+            policy.specifier == RequestSpecifier::Transfer(ResourceIds::Any)
+                && policy.rule == RoleTemplate::request_policy_rule(&group.id)
+        }));
+
+        // applying the template again doesn't change anything
+        let diff = ROLE_TEMPLATE_SERVICE
+            .get_role_template_diff(&group.id, &RoleTemplate::Treasurer)
+            .unwrap();
+
+        assert!(diff.added_permissions.is_empty());
+        assert!(diff.added_request_policies.is_empty());
+        assert_eq!(
+            diff.unchanged_request_policies,
+            RoleTemplate::Treasurer.request_specifiers()
+        );
+    }
+
+    #[tokio::test]
+    async fn diff_keeps_permissions_already_granted() {
+        test_utils::init_canister_system();
+        let group = add_group();
+
+        ROLE_TEMPLATE_SERVICE
+            .apply_role_template(ApplyRoleTemplateOperationInput {
+                user_group_id: group.id,
+                template: RoleTemplate::Viewer,
+            })
+            .await
+            .unwrap();
+
+        let diff = ROLE_TEMPLATE_SERVICE
+            .get_role_template_diff(&group.id, &RoleTemplate::Auditor)
+            .unwrap();
+
+        assert_eq!(
+            diff.unchanged_permissions,
+            RoleTemplate::Viewer.permissions()
+        );
+        assert_eq!(
+            diff.added_permissions.len(),
+            RoleTemplate::Auditor.permissions().len() - RoleTemplate::Viewer.permissions().len()
+        );
+    }
+
+    #[tokio::test]
+    async fn apply_template_adds_group_to_existing_policies() {
+        test_utils::init_canister_system();
+        let group = add_group();
+
+        let mut transfer_policy = mock_request_policy();
+        transfer_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        transfer_policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Group(vec![[1; 16]]), 2);
+        REQUEST_POLICY_REPOSITORY.insert(transfer_policy.id, transfer_policy.to_owned());
+
+        let mut add_account_policy = mock_request_policy();
+        add_account_policy.specifier = RequestSpecifier::AddAccount;
+        add_account_policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Id(vec![[2; 16]]), 1);
+        REQUEST_POLICY_REPOSITORY.insert(add_account_policy.id, add_account_policy.to_owned());
+
+        let policies_count = REQUEST_POLICY_REPOSITORY.len();
+
+        let diff = ROLE_TEMPLATE_SERVICE
+            .apply_role_template(ApplyRoleTemplateOperationInput {
+                user_group_id: group.id,
+                template: RoleTemplate::Treasurer,
+            })
+            .await
+            .unwrap();
+
+        // the group joins the approvers of the transfer policy with the same threshold
+        assert_eq!(
+            REQUEST_POLICY_REPOSITORY
+                .get(&transfer_policy.id)
+                .unwrap()
+                .rule,
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![[1; 16], group.id]), 2)
+        );
+        assert!(!REQUEST_POLICY_REPOSITORY.list().iter().any(|policy| {
+            policy.specifier == RequestSpecifier::Transfer(ResourceIds::Any)
+                && policy.id != transfer_policy.id
+        }));
+
+        // the policy that lists its approvers by id is left as is
+        assert_eq!(
+            diff.skipped_request_policies,
+            vec![RequestSpecifier::AddAccount]
+        );
+        assert_eq!(
+            REQUEST_POLICY_REPOSITORY
+                .get(&add_account_policy.id)
+                .unwrap()
+                .rule,
+            add_account_policy.rule
+        );
+
+        // new policies are only added for the requests without a policy
+        assert_eq!(
+            REQUEST_POLICY_REPOSITORY.len(),
+            policies_count + diff.added_request_policies.len() - 1
+        );
+
+        let diff = ROLE_TEMPLATE_SERVICE
+            .get_role_template_diff(&group.id, &RoleTemplate::Treasurer)
+            .unwrap();
+
+        assert!(diff.added_request_policies.is_empty());
+        assert!(diff.request_policy_changes.is_empty());
+    }
+
+    #[test]
+    fn fail_diff_for_unknown_group() {
+        test_utils::init_canister_system();
+
+        assert!(ROLE_TEMPLATE_SERVICE
+            .get_role_template_diff(&[9; 16], &RoleTemplate::Viewer)
+            .is_err());
+    }
+}
@@ -0,0 +1,470 @@
+use crate::{
+    core::{authorization::Authorization, generate_uuid_v4, ic_cdk::next_time, CallContext},
+    errors::StandingOrderError,
+    factories::blockchains::BlockchainApiFactory,
+    mappers::HelperMapper,
+    models::{
+        resource::{RequestResourceAction, Resource, ResourceId},
+        NotificationType, Request, RequestExecutionPlan, RequestFailedNotification, RequestId,
+        RequestOperation, RequestStatus, StandingOrder, StandingOrderFailure, StandingOrderStatus,
+        Transfer, TransferOperationInput, UpcomingExecution,
+    },
+    repositories::{
+        RequestRepository, StandingOrderRepository, REQUEST_REPOSITORY, STANDING_ORDER_REPOSITORY,
+    },
+    services::{
+        AccountService, NotificationService, TransferService, ACCOUNT_SERVICE, NOTIFICATION_SERVICE,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult, repository::Repository, types::Timestamp, utils::rfc3339_to_timestamp,
+};
+use station_api::ListUpcomingExecutionsInput;
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref STANDING_ORDER_SERVICE: Arc<StandingOrderService> =
+        Arc::new(StandingOrderService::new(
+            Arc::clone(&STANDING_ORDER_REPOSITORY),
+            Arc::clone(&REQUEST_REPOSITORY),
+            Arc::clone(&ACCOUNT_SERVICE),
+            Arc::clone(&NOTIFICATION_SERVICE),
+        ));
+}
+
+#[derive(Default, Debug)]
+pub struct StandingOrderService {
+    standing_order_repository: Arc<StandingOrderRepository>,
+    request_repository: Arc<RequestRepository>,
+    account_service: Arc<AccountService>,
+    notification_service: Arc<NotificationService>,
+    transfer_service: TransferService,
+}
+
+impl StandingOrderService {
+    pub const DEFAULT_UPCOMING_EXECUTIONS_LIMIT: u16 = 20;
+    pub const MAX_UPCOMING_EXECUTIONS_LIMIT: u16 = 100;
+
+    pub fn new(
+        standing_order_repository: Arc<StandingOrderRepository>,
+        request_repository: Arc<RequestRepository>,
+        account_service: Arc<AccountService>,
+        notification_service: Arc<NotificationService>,
+    ) -> Self {
+        Self {
+            standing_order_repository,
+            request_repository,
+            account_service,
+            notification_service,
+            transfer_service: TransferService::default(),
+        }
+    }
+
+    /// Returns the standing order created by the given request.
+    pub fn get(&self, request_id: &RequestId) -> ServiceResult<StandingOrder> {
+        let standing_order =
+            self.standing_order_repository
+                .get(request_id)
+                .ok_or(StandingOrderError::NotFound {
+                    request_id: Uuid::from_bytes(*request_id).hyphenated().to_string(),
+                })?;
+
+        Ok(standing_order)
+    }
+
+    /// Creates the standing order of an approved request that has a recurring execution plan.
+    ///
+    /// The first execution is the first occurrence of the plan that is not in the past, the
+    /// standing order is finished right away if the plan has no such occurrence.
+    pub fn start(&self, request: &Request) -> ServiceResult<StandingOrder> {
+        let RequestExecutionPlan::Recurring(plan) = &request.execution_plan else {
+            Err(StandingOrderError::ValidationError {
+                info: "The request does not have a recurring execution plan".to_string(),
+            })?
+        };
+
+        let transfer = Self::transfer_input(request)?;
+        let now = next_time();
+        let next_occurrence = plan.next_occurrence(0, now);
+
+        let standing_order = StandingOrder {
+            request_id: request.id,
+            account_id: transfer.from_account_id,
+            plan: plan.clone(),
+            next_occurrence: next_occurrence.map(|(occurrence, _)| occurrence),
+            next_execution_at: next_occurrence.map(|(_, execution_time)| execution_time),
+            transfer_ids: vec![],
+            last_failure: None,
+            status: match next_occurrence {
+                Some(_) => StandingOrderStatus::Active,
+                None => StandingOrderStatus::Finished { finished_at: now },
+            },
+            created_timestamp: now,
+            last_modification_timestamp: now,
+        };
+
+        self.standing_order_repository
+            .insert(standing_order.request_id, standing_order.clone());
+
+        Ok(standing_order)
+    }
+
+    /// Creates the transfer of the next occurrence of the standing order and moves the schedule
+    /// to the following occurrence.
+    ///
+    /// A transfer that cannot be created does not stop the standing order, the occurrence is
+    /// skipped instead, recorded as the last failure and the requester is notified. Once the plan
+    /// has no more occurrences the standing order is finished and its request is completed.
+    pub async fn execute_next_occurrence(
+        &self,
+        request_id: &RequestId,
+    ) -> ServiceResult<StandingOrder> {
+        let mut standing_order = self.get(request_id)?;
+
+        if !standing_order.is_active() {
+            Err(StandingOrderError::NotActive {
+                request_id: Uuid::from_bytes(*request_id).hyphenated().to_string(),
+            })?;
+        }
+
+        let mut request = self
+            .request_repository
+            .get(&Request::key(*request_id))
+            .ok_or(StandingOrderError::NotFound {
+                request_id: Uuid::from_bytes(*request_id).hyphenated().to_string(),
+            })?;
+
+        let now = next_time();
+        match self.create_transfer(&request).await {
+            Ok(transfer) => standing_order.transfer_ids.push(transfer.id),
+            Err(e) => {
+                let occurrence = standing_order.next_occurrence.unwrap_or_default();
+                let reason = e.to_string();
+
+                self.notification_service
+                    .send_notification(
+                        request.requested_by,
+                        NotificationType::RequestFailed(RequestFailedNotification {
+                            request_id: request.id,
+                        }),
+                        request.title.to_owned(),
+                        Some(format!(
+                            "The transfer of occurrence {} was skipped: {}",
+                            occurrence + 1,
+                            reason
+                        )),
+                    )
+                    .await;
+
+                standing_order.last_failure = Some(StandingOrderFailure {
+                    occurrence,
+                    reason,
+                    failed_at: now,
+                });
+            }
+        }
+
+        let next_occurrence = standing_order
+            .next_occurrence
+            .and_then(|occurrence| occurrence.checked_add(1))
+            .and_then(|occurrence| standing_order.plan.next_occurrence(occurrence, now));
+
+        standing_order.next_occurrence = next_occurrence.map(|(occurrence, _)| occurrence);
+        standing_order.next_execution_at =
+            next_occurrence.map(|(_, execution_time)| execution_time);
+        standing_order.last_modification_timestamp = now;
+
+        if next_occurrence.is_none() {
+            standing_order.status = StandingOrderStatus::Finished { finished_at: now };
+
+            request.status = RequestStatus::Completed { completed_at: now };
+            request.last_modification_timestamp = now;
+            self.request_repository
+                .insert(request.to_key(), request.to_owned());
+        }
+
+        self.standing_order_repository
+            .insert(standing_order.request_id, standing_order.clone());
+
+        Ok(standing_order)
+    }
+
+    /// Cancels the standing order, no further transfers are created and its request is cancelled.
+    ///
+    /// Transfers of past occurrences are not affected.
+    pub fn cancel(
+        &self,
+        request_id: &RequestId,
+        cancelled_by_request_id: &RequestId,
+    ) -> ServiceResult<StandingOrder> {
+        let mut standing_order = self.get(request_id)?;
+
+        if !standing_order.is_active() {
+            Err(StandingOrderError::NotActive {
+                request_id: Uuid::from_bytes(*request_id).hyphenated().to_string(),
+            })?;
+        }
+
+        let now = next_time();
+        standing_order.status = StandingOrderStatus::Cancelled {
+            cancelled_at: now,
+            cancelled_by_request_id: *cancelled_by_request_id,
+        };
+        standing_order.next_occurrence = None;
+        standing_order.next_execution_at = None;
+        standing_order.last_modification_timestamp = now;
+
+        if let Some(mut request) = self.request_repository.get(&Request::key(*request_id)) {
+            request.status = RequestStatus::Cancelled {
+                reason: Some(format!(
+                    "The standing order was cancelled by request {}",
+                    Uuid::from_bytes(*cancelled_by_request_id).hyphenated()
+                )),
+            };
+            request.last_modification_timestamp = now;
+            self.request_repository
+                .insert(request.to_key(), request.to_owned());
+        }
+
+        self.standing_order_repository
+            .insert(standing_order.request_id, standing_order.clone());
+
+        Ok(standing_order)
+    }
+
+    /// Returns the active standing orders that have an execution due until the given time.
+    pub fn find_due(&self, until: Timestamp) -> Vec<StandingOrder> {
+        self.standing_order_repository.find_due(until)
+    }
+
+    /// Returns the active standing orders.
+    pub fn find_active(&self) -> Vec<StandingOrder> {
+        self.standing_order_repository.find_active()
+    }
+
+    /// Lists the upcoming executions of the active standing orders whose requests the caller can
+    /// read, sorted by execution time.
+    pub fn list_upcoming_executions(
+        &self,
+        input: ListUpcomingExecutionsInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Vec<UpcomingExecution>> {
+        let from_account_id = match input.from_account_id {
+            Some(account_id) => Some(*HelperMapper::to_uuid(account_id)?.as_bytes()),
+            None => None,
+        };
+        let until = input.until.map(|dt| rfc3339_to_timestamp(&dt));
+        let limit = input
+            .limit
+            .unwrap_or(Self::DEFAULT_UPCOMING_EXECUTIONS_LIMIT)
+            .min(Self::MAX_UPCOMING_EXECUTIONS_LIMIT) as usize;
+
+        let mut executions = Vec::new();
+        for standing_order in self.standing_order_repository.find_active() {
+            if from_account_id.is_some_and(|account_id| account_id != standing_order.account_id) {
+                continue;
+            }
+
+            if !Authorization::is_allowed(
+                ctx,
+                &Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+                    standing_order.request_id,
+                ))),
+            ) {
+                continue;
+            }
+
+            let Some(request) = self
+                .request_repository
+                .get(&Request::key(standing_order.request_id))
+            else {
+                continue;
+            };
+            let Ok(transfer) = Self::transfer_input(&request) else {
+                continue;
+            };
+
+            // each standing order contributes at most `limit` executions, as only the earliest
+            // `limit` executions of all standing orders are returned
+            let mut occurrence = standing_order.next_occurrence;
+            let mut listed = 0;
+            while let Some(current) = occurrence {
+                let Some(execution_time) = standing_order.plan.occurrence_at(current) else {
+                    break;
+                };
+
+                if listed >= limit || until.is_some_and(|until| execution_time > until) {
+                    break;
+                }
+
+                executions.push(UpcomingExecution {
+                    request_id: standing_order.request_id,
+                    occurrence: current,
+                    execution_time,
+                    from_account_id: transfer.from_account_id,
+                    to: transfer.to.clone(),
+                    amount: transfer.amount.clone(),
+                });
+
+                listed += 1;
+                occurrence = current.checked_add(1);
+            }
+        }
+
+        executions.sort_by_key(|execution| (execution.execution_time, execution.request_id));
+        executions.truncate(limit);
+
+        Ok(executions)
+    }
+
+    /// Returns the transfer that the standing order of the request repeats.
+    fn transfer_input(request: &Request) -> ServiceResult<TransferOperationInput> {
+        match &request.operation {
+            RequestOperation::Transfer(operation) => Ok(operation.input.clone()),
+            operation => Err(StandingOrderError::UnsupportedOperation {
+                operation: operation.to_string(),
+            })?,
+        }
+    }
+
+    async fn create_transfer(&self, request: &Request) -> ServiceResult<Transfer> {
+        let input = Self::transfer_input(request)?;
+        let account = self.account_service.get_account(&input.from_account_id)?;
+
+        let fee = match &input.fee {
+            Some(fee) => fee.clone(),
+            None => {
+                let blockchain_api =
+                    BlockchainApiFactory::build(&account.blockchain, &account.standard)?;
+                let transaction_fee = blockchain_api.transaction_fee(&account).await?;
+
+                candid::Nat(transaction_fee.fee)
+            }
+        };
+
+        self.transfer_service.add_transfer(Transfer::new(
+            request.id,
+            *generate_uuid_v4().await.as_bytes(),
+            request.requested_by,
+            input.from_account_id,
+            input.to,
+            input.metadata,
+            input.amount,
+            fee,
+            input.network,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{ic_cdk::api::id as self_canister_id, test_utils},
+        models::{request_test_utils::mock_request, RecurrenceInterval, RecurringExecutionPlan},
+        repositories::NOTIFICATION_REPOSITORY,
+    };
+
+    fn mock_recurring_request(max_occurrences: Option<u32>) -> Request {
+        let mut request = mock_request();
+        request.status = RequestStatus::Processing { started_at: 0 };
+        request.execution_plan = RequestExecutionPlan::Recurring(RecurringExecutionPlan {
+            interval: RecurrenceInterval::Days(1),
+            start_at: next_time() + 60 * 1_000_000_000,
+            end_at: None,
+            max_occurrences,
+        });
+
+        REQUEST_REPOSITORY.insert(request.to_key(), request.to_owned());
+
+        request
+    }
+
+    #[test]
+    fn start_schedules_the_first_occurrence() {
+        test_utils::init_canister_system();
+        let request = mock_recurring_request(Some(2));
+
+        let standing_order = STANDING_ORDER_SERVICE.start(&request).unwrap();
+
+        assert!(standing_order.is_active());
+        assert_eq!(standing_order.next_occurrence, Some(0));
+        assert!(STANDING_ORDER_SERVICE.get(&request.id).is_ok());
+    }
+
+    #[tokio::test]
+    async fn failed_occurrence_is_recorded_and_notified() {
+        test_utils::init_canister_system();
+        // the source account of the mocked transfer does not exist, so the transfer fails
+        let request = mock_recurring_request(Some(2));
+        STANDING_ORDER_SERVICE.start(&request).unwrap();
+
+        let standing_order = STANDING_ORDER_SERVICE
+            .execute_next_occurrence(&request.id)
+            .await
+            .unwrap();
+
+        assert!(standing_order.is_active());
+        assert!(standing_order.transfer_ids.is_empty());
+        assert_eq!(standing_order.next_occurrence, Some(1));
+        assert_eq!(standing_order.last_failure.unwrap().occurrence, 0);
+        assert!(NOTIFICATION_REPOSITORY
+            .find_by_user_id(request.requested_by)
+            .iter()
+            .any(|notification| notification.notification_type
+                == NotificationType::RequestFailed(RequestFailedNotification {
+                    request_id: request.id
+                })));
+    }
+
+    #[test]
+    fn cancel_stops_the_standing_order_and_its_request() {
+        test_utils::init_canister_system();
+        let request = mock_recurring_request(None);
+        STANDING_ORDER_SERVICE.start(&request).unwrap();
+
+        let standing_order = STANDING_ORDER_SERVICE
+            .cancel(&request.id, &[7; 16])
+            .unwrap();
+
+        assert!(!standing_order.is_active());
+        assert!(standing_order.next_execution_at.is_none());
+        assert!(matches!(
+            REQUEST_REPOSITORY
+                .get(&Request::key(request.id))
+                .unwrap()
+                .status,
+            RequestStatus::Cancelled { .. }
+        ));
+
+        // a cancelled standing order cannot be cancelled again
+        assert!(STANDING_ORDER_SERVICE
+            .cancel(&request.id, &[7; 16])
+            .is_err());
+    }
+
+    #[test]
+    fn list_upcoming_executions_respects_the_limit() {
+        test_utils::init_canister_system();
+        let request = mock_recurring_request(None);
+        STANDING_ORDER_SERVICE.start(&request).unwrap();
+
+        let executions = STANDING_ORDER_SERVICE
+            .list_upcoming_executions(
+                ListUpcomingExecutionsInput {
+                    from_account_id: None,
+                    until: None,
+                    limit: Some(3),
+                },
+                &CallContext::new(self_canister_id()),
+            )
+            .unwrap();
+
+        assert_eq!(executions.len(), 3);
+        assert!(executions
+            .windows(2)
+            .all(|pair| pair[0].execution_time < pair[1].execution_time));
+    }
+}
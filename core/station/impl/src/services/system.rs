@@ -10,7 +10,7 @@ use crate::{
     errors::SystemError,
     models::{
         system::{SystemInfo, SystemState},
-        ManageSystemInfoOperationInput, RequestId, RequestKey, RequestStatus,
+        ChangeRecoveryConfig, ManageSystemInfoOperationInput, RequestId, RequestKey, RequestStatus,
     },
     repositories::{RequestRepository, REQUEST_REPOSITORY},
 };
@@ -86,6 +86,14 @@ impl SystemService {
             system_info.set_name(name.clone());
         }
 
+        match input.recovery_config {
+            Some(ChangeRecoveryConfig::Set(config)) => {
+                system_info.set_recovery_config(Some(config))
+            }
+            Some(ChangeRecoveryConfig::Remove) => system_info.set_recovery_config(None),
+            None => {}
+        }
+
         write_system_info(system_info);
     }
 
@@ -207,6 +215,9 @@ impl SystemService {
         // adds the default admin group
         init_canister_sync_handlers::add_admin_group();
 
+        // adds the assets that are supported out of the box
+        init_canister_sync_handlers::add_default_assets();
+
         // registers the admins of the canister
         init_canister_sync_handlers::set_admins(input.admins.clone())?;
 
@@ -263,6 +274,9 @@ impl SystemService {
             write_system_info(system_info.clone());
         }
 
+        // makes sure the assets that are supported out of the box are registered
+        init_canister_sync_handlers::add_default_assets();
+
         if let Some(name) = &input.name {
             system_info.set_name(name.clone());
 
@@ -279,11 +293,14 @@ impl SystemService {
 
 mod init_canister_sync_handlers {
     use crate::core::ic_cdk::{api::print, next_time};
-    use crate::models::{AddUserOperationInput, UserStatus};
+    use crate::models::{
+        AddUserOperationInput, Asset, Blockchain, BlockchainStandard, Metadata, UserStatus,
+        ICP_ASSET_ID,
+    };
     use crate::services::USER_SERVICE;
     use crate::{
         models::{UserGroup, ADMIN_GROUP_ID},
-        repositories::USER_GROUP_REPOSITORY,
+        repositories::{ASSET_REPOSITORY, USER_GROUP_REPOSITORY},
     };
     use orbit_essentials::api::ApiError;
     use orbit_essentials::repository::Repository;
@@ -302,6 +319,24 @@ mod init_canister_sync_handlers {
         );
     }
 
+    pub fn add_default_assets() {
+        // adds the native ICP asset, the registered asset is kept as is if it was already added
+        if ASSET_REPOSITORY.get(ICP_ASSET_ID).is_none() {
+            ASSET_REPOSITORY.insert(
+                ICP_ASSET_ID.to_owned(),
+                Asset {
+                    id: ICP_ASSET_ID.to_owned(),
+                    blockchain: Blockchain::InternetComputer,
+                    standard: BlockchainStandard::Native,
+                    symbol: "ICP".to_owned(),
+                    name: "Internet Computer".to_owned(),
+                    metadata: Metadata::default(),
+                    last_modification_timestamp: next_time(),
+                },
+            );
+        }
+    }
+
     /// Registers the newly added admins of the canister.
     pub fn set_admins(admins: Vec<AdminInitInput>) -> Result<(), ApiError> {
         print(format!("Registering {} admin users", admins.len()));
@@ -451,7 +486,8 @@ mod install_canister_handlers {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::request_test_utils::mock_request;
+    use crate::models::{request_test_utils::mock_request, ICP_ASSET_ID};
+    use crate::repositories::ASSET_REPOSITORY;
     use candid::Principal;
     use station_api::AdminInitInput;
 
@@ -469,6 +505,7 @@ mod tests {
             .await;
 
         assert!(result.is_ok());
+        assert!(ASSET_REPOSITORY.get(ICP_ASSET_ID).is_some());
     }
 
     #[tokio::test]
@@ -1,17 +1,23 @@
 use super::{AccountService, UserService};
 use crate::{
-    core::{authorization::Authorization, CallContext},
+    core::{authorization::Authorization, ic_cdk::next_time, CallContext},
     errors::{AccountError, TransferError},
+    factories::{blockchains::BlockchainApiFactory, requests::RequestFactory},
     mappers::HelperMapper,
     models::{
+        indexes::transfer_account_index::TransferAccountIndex,
         resource::{AccountResourceAction, Resource, ResourceId},
-        Transfer, TransferId,
+        AccountActivity, RequestApprovalStatus, RequestOperation, Transfer, TransferEstimate,
+        TransferId,
     },
-    repositories::TransferRepository,
+    repositories::{IncomingTransferRepository, TransferRepository},
 };
 use orbit_essentials::repository::Repository;
 use orbit_essentials::{api::ServiceResult, model::ModelValidator, utils::rfc3339_to_timestamp};
-use station_api::ListAccountTransfersInput;
+use station_api::{
+    CreateRequestInput, EstimateTransferInput, ListAccountActivityInput, ListAccountTransfersInput,
+    RequestOperationInput,
+};
 use uuid::Uuid;
 
 #[derive(Default, Debug)]
@@ -19,6 +25,7 @@ pub struct TransferService {
     user_service: UserService,
     account_service: AccountService,
     transfer_repository: TransferRepository,
+    incoming_transfer_repository: IncomingTransferRepository,
 }
 
 impl TransferService {
@@ -81,6 +88,108 @@ impl TransferService {
         Ok(transfers)
     }
 
+    /// Returns the transfers made from the account together with the deposits into it, most recent first.
+    ///
+    /// Without a time range the activity of the last 7 days is returned.
+    pub fn list_account_activity(
+        &self,
+        input: ListAccountActivityInput,
+    ) -> ServiceResult<Vec<AccountActivity>> {
+        let account = self
+            .account_service
+            .get_account(HelperMapper::to_uuid(input.account_id)?.as_bytes())?;
+
+        let to_dt = input
+            .to_dt
+            .map(|dt| rfc3339_to_timestamp(dt.as_str()))
+            .unwrap_or_else(next_time);
+        let from_dt = input
+            .from_dt
+            .map(|dt| rfc3339_to_timestamp(dt.as_str()))
+            .unwrap_or_else(|| {
+                to_dt.saturating_sub(TransferAccountIndex::DEFAULT_CRITERIA_INTERVAL_NS)
+            });
+
+        let mut activity: Vec<AccountActivity> = self
+            .transfer_repository
+            .find_by_account(account.id, Some(from_dt), Some(to_dt), None)
+            .into_iter()
+            .map(AccountActivity::Outgoing)
+            .chain(
+                self.incoming_transfer_repository
+                    .find_by_account(account.id, Some(from_dt), Some(to_dt))
+                    .into_iter()
+                    .map(AccountActivity::Incoming),
+            )
+            .collect();
+
+        activity.sort_by_key(|entry| std::cmp::Reverse(entry.timestamp()));
+
+        Ok(activity)
+    }
+
+    /// Validates the transfer the same way as its request would be validated on creation and
+    /// evaluates the policies that would apply to it, without creating the request.
+    ///
+    /// The requester is counted as an approver if they could approve the request, as happens when
+    /// the request is created.
+    pub async fn estimate_transfer(
+        &self,
+        input: EstimateTransferInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<TransferEstimate> {
+        let requester = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let mut request = RequestFactory::create_request(
+            requester.id,
+            CreateRequestInput {
+                operation: RequestOperationInput::Transfer(input.transfer),
+                title: None,
+                summary: None,
+                execution_plan: None,
+            },
+        )
+        .await?;
+
+        request.validate()?;
+
+        let RequestOperation::Transfer(operation) = &request.operation else {
+            Err(TransferError::ValidationError {
+                info: "The request is not a transfer".to_string(),
+            })?
+        };
+        let account = self
+            .account_service
+            .get_account(&operation.input.from_account_id)?;
+        let blockchain_api = BlockchainApiFactory::build(&account.blockchain, &account.standard)?;
+        let fee = match &operation.input.fee {
+            Some(fee) => fee.clone(),
+            None => candid::Nat(blockchain_api.transaction_fee(&account).await?.fee),
+        };
+        let balance = candid::Nat(blockchain_api.balance(&account).await?);
+
+        let required = operation.input.amount.clone() + fee.clone();
+        if balance < required {
+            Err(TransferError::InsufficientBalance {
+                balance: balance.to_string(),
+                required: required.to_string(),
+            })?
+        }
+
+        let possible_approvers = request.find_all_possible_approvers().await?;
+        if request.is_approvable_by_requester(&request.find_matching_policy_rules())? {
+            request.add_approval(requester.id, RequestApprovalStatus::Approved, None)?;
+        }
+
+        let evaluation_result = request.evaluate()?;
+
+        Ok(TransferEstimate {
+            fee,
+            balance,
+            evaluation_result,
+            possible_approvers,
+        })
+    }
+
     fn assert_transfer_access(&self, transfer: &Transfer, ctx: &CallContext) -> ServiceResult<()> {
         let caller_user = self.user_service.get_user_by_identity(&ctx.caller())?;
         let is_transfer_creator = caller_user.id == transfer.initiator_user;
@@ -8,8 +8,8 @@ use crate::{
     mappers::{authorization::USER_PRIVILEGES, UserMapper},
     models::{
         resource::{Resource, ResourceId, UserResourceAction},
-        AddUserOperationInput, EditUserOperationInput, User, UserCallerPrivileges, UserId,
-        ADMIN_GROUP_ID,
+        AddUserOperationInput, EditUserOperationInput, LinkUserIdentityOperationInput, User,
+        UserCallerPrivileges, UserId, UserStatus, ADMIN_GROUP_ID,
     },
     repositories::{UserRepository, UserWhereClause},
 };
@@ -65,6 +65,10 @@ impl UserService {
                 ctx,
                 &Resource::User(UserResourceAction::Update(ResourceId::Id(*user_id))),
             ),
+            can_remove: Authorization::is_allowed(
+                ctx,
+                &Resource::User(UserResourceAction::Remove(ResourceId::Id(*user_id))),
+            ),
         })
     }
 
@@ -98,6 +102,10 @@ impl UserService {
     ///
     /// This method should only be called by a system call (self canister call or controller).
     pub fn add_user(&self, input: AddUserOperationInput) -> ServiceResult<User> {
+        if input.status == UserStatus::Archived {
+            Err(UserError::ArchivedStatusNotAllowed)?
+        }
+
         for identity in input.identities.iter() {
             self.assert_identity_has_no_associated_user(identity, None)?;
         }
@@ -119,6 +127,16 @@ impl UserService {
     pub async fn edit_user(&self, input: EditUserOperationInput) -> ServiceResult<User> {
         let mut user = self.get_user(&input.user_id)?;
 
+        if user.is_archived() {
+            Err(UserError::UserArchived {
+                user: Uuid::from_bytes(user.id).hyphenated().to_string(),
+            })?
+        }
+
+        if input.status == Some(UserStatus::Archived) {
+            Err(UserError::ArchivedStatusNotAllowed)?
+        }
+
         if let Some(identities) = &input.identities {
             for identity in identities.iter() {
                 self.assert_identity_has_no_associated_user(identity, Some(user.id))?;
@@ -137,6 +155,64 @@ impl UserService {
         Ok(user)
     }
 
+    /// Archives the user associated with the given user id and returns the archived user.
+    ///
+    /// The user keeps its name so that past requests can still display it, but its identities and
+    /// group memberships are removed so that it can no longer access the station.
+    ///
+    /// This method should only be called by a system call (self canister call or controller).
+    pub fn archive_user(&self, user_id: &UserId) -> ServiceResult<User> {
+        let mut user = self.get_user(user_id)?;
+
+        if user.is_archived() {
+            Err(UserError::UserArchived {
+                user: Uuid::from_bytes(user.id).hyphenated().to_string(),
+            })?
+        }
+
+        user.status = UserStatus::Archived;
+        user.identities = Vec::new();
+        user.groups = Vec::new();
+        user.validate()?;
+
+        self.user_repository.insert(user.to_key(), user.to_owned());
+
+        Ok(user)
+    }
+
+    /// Adds the confirmed identity to the user and removes the retired identity, if any.
+    ///
+    /// The identities are computed from the current state of the user so that other changes made
+    /// while the link was pending are kept.
+    ///
+    /// This method should only be called by a system call (self canister call or controller).
+    pub fn link_identity(&self, input: LinkUserIdentityOperationInput) -> ServiceResult<User> {
+        let mut user = self.get_user(&input.user_id)?;
+
+        if user.is_archived() {
+            Err(UserError::UserArchived {
+                user: Uuid::from_bytes(user.id).hyphenated().to_string(),
+            })?
+        }
+
+        self.assert_identity_has_no_associated_user(&input.identity, Some(user.id))?;
+
+        if let Some(retired_identity) = &input.retired_identity {
+            user.identities
+                .retain(|identity| identity != retired_identity);
+        }
+
+        if !user.identities.contains(&input.identity) {
+            user.identities.push(input.identity);
+        }
+
+        user.validate()?;
+
+        self.user_repository.insert(user.to_key(), user.to_owned());
+
+        Ok(user)
+    }
+
     /// Returns the list of users from the given pagination parameters.
     ///
     /// The default limit is 100 and the maximum limit is 1000.
@@ -501,6 +577,107 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn archive_user_keeps_tombstone() {
+        let ctx: TestContext = setup();
+        let mut user = user_test_utils::mock_user();
+        user.name = "Departed".to_string();
+        user.identities = vec![Principal::from_slice(&[4; 29])];
+        user.groups = vec![*ADMIN_GROUP_ID];
+        ctx.repository.insert(user.to_key(), user.clone());
+
+        let archived = ctx.service.archive_user(&user.id).unwrap();
+
+        assert_eq!(archived.status, UserStatus::Archived);
+        assert_eq!(archived.name, "Departed");
+        assert!(archived.identities.is_empty());
+        assert!(archived.groups.is_empty());
+        assert!(ctx
+            .repository
+            .find_by_identity(&Principal::from_slice(&[4; 29]))
+            .is_none());
+
+        let result = ctx
+            .service
+            .edit_user(EditUserOperationInput {
+                user_id: user.id,
+                name: Some("Returned".to_string()),
+                identities: None,
+                groups: None,
+                status: Some(UserStatus::Active),
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err().code, "USER_ARCHIVED");
+        assert!(ctx.service.archive_user(&user.id).is_err());
+    }
+
+    #[test]
+    fn link_identity_retires_previous_identity() {
+        let ctx: TestContext = setup();
+        let mut user = user_test_utils::mock_user();
+        user.identities = vec![Principal::from_slice(&[4; 29])];
+        ctx.repository.insert(user.to_key(), user.clone());
+
+        let linked = ctx
+            .service
+            .link_identity(LinkUserIdentityOperationInput {
+                user_id: user.id,
+                identity: Principal::from_slice(&[5; 29]),
+                retired_identity: Some(Principal::from_slice(&[4; 29])),
+            })
+            .unwrap();
+
+        assert_eq!(linked.identities, vec![Principal::from_slice(&[5; 29])]);
+        assert!(ctx
+            .repository
+            .find_by_identity(&Principal::from_slice(&[4; 29]))
+            .is_none());
+        assert_eq!(
+            ctx.repository
+                .find_by_identity(&Principal::from_slice(&[5; 29]))
+                .unwrap()
+                .id,
+            user.id
+        );
+
+        let mut other_user = user_test_utils::mock_user();
+        other_user.identities = vec![Principal::from_slice(&[6; 29])];
+        ctx.repository
+            .insert(other_user.to_key(), other_user.clone());
+
+        let result = ctx.service.link_identity(LinkUserIdentityOperationInput {
+            user_id: user.id,
+            identity: Principal::from_slice(&[6; 29]),
+            retired_identity: None,
+        });
+
+        assert_eq!(result.unwrap_err().code, "IDENTITY_ALREADY_HAS_USER");
+    }
+
+    #[tokio::test]
+    async fn fail_archiving_user_through_edit() {
+        let ctx: TestContext = setup();
+        let user = user_test_utils::mock_user();
+        ctx.repository.insert(user.to_key(), user.clone());
+
+        let result = ctx
+            .service
+            .edit_user(EditUserOperationInput {
+                user_id: user.id,
+                name: None,
+                identities: None,
+                groups: None,
+                status: Some(UserStatus::Archived),
+            })
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "ARCHIVED_STATUS_NOT_ALLOWED: Users can only be archived by removing them."
+        );
+    }
+
     #[tokio::test]
     async fn list_users_should_use_offset_and_limit() {
         let ctx: TestContext = setup();
@@ -1,6 +1,8 @@
 use crate::types::Timestamp;
 use std::cell::RefCell;
-use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+use time::{
+    format_description::well_known::Rfc3339, util::days_in_year_month, Date, Month, OffsetDateTime,
+};
 
 pub fn timestamp_to_rfc3339(nanoseconds_since_epoch: &Timestamp) -> String {
     let nanoseconds = *nanoseconds_since_epoch as i128;
@@ -20,6 +22,29 @@ pub fn rfc3339_to_timestamp(rfc3339: &str) -> Timestamp {
     datetime as Timestamp
 }
 
+/// Adds the given number of calendar months to the timestamp, keeping the time of the day.
+///
+/// If the day of the month does not exist in the resulting month, the last day of that month is used,
+/// e.g. adding one month to January 31st results in February 28th (or 29th on leap years).
+///
+/// Returns `None` if the resulting date can't be represented as a timestamp.
+pub fn add_months_to_timestamp(
+    nanoseconds_since_epoch: &Timestamp,
+    months: u32,
+) -> Option<Timestamp> {
+    let datetime =
+        OffsetDateTime::from_unix_timestamp_nanos(*nanoseconds_since_epoch as i128).ok()?;
+
+    let total_months = datetime.year() as i64 * 12 + (datetime.month() as i64 - 1) + months as i64;
+    let year = i32::try_from(total_months.div_euclid(12)).ok()?;
+    let month = Month::try_from((total_months.rem_euclid(12) + 1) as u8).ok()?;
+    let day = datetime.day().min(days_in_year_month(year, month));
+
+    let date = Date::from_calendar_date(year, month, day).ok()?;
+
+    Timestamp::try_from(datetime.replace_date(date).unix_timestamp_nanos()).ok()
+}
+
 thread_local! {
     static CURRENT_TIME: RefCell<u64> = const { RefCell::new(0) };
 }
@@ -58,6 +83,36 @@ mod tests {
         assert_eq!(timestamp, 1_710_843_144_770_000_000);
     }
 
+    #[test]
+    fn adds_months_to_timestamp() {
+        let timestamp = rfc3339_to_timestamp("2024-01-31T10:00:00Z");
+
+        assert_eq!(
+            timestamp_to_rfc3339(&add_months_to_timestamp(&timestamp, 0).unwrap()),
+            "2024-01-31T10:00:00Z"
+        );
+        assert_eq!(
+            timestamp_to_rfc3339(&add_months_to_timestamp(&timestamp, 1).unwrap()),
+            "2024-02-29T10:00:00Z"
+        );
+        assert_eq!(
+            timestamp_to_rfc3339(&add_months_to_timestamp(&timestamp, 3).unwrap()),
+            "2024-04-30T10:00:00Z"
+        );
+        assert_eq!(
+            timestamp_to_rfc3339(&add_months_to_timestamp(&timestamp, 13).unwrap()),
+            "2025-02-28T10:00:00Z"
+        );
+    }
+
+    #[test]
+    fn adding_months_past_the_supported_dates_returns_none() {
+        let timestamp = rfc3339_to_timestamp("2024-01-31T10:00:00Z");
+
+        assert_eq!(add_months_to_timestamp(&timestamp, 12 * 600), None);
+        assert_eq!(add_months_to_timestamp(&timestamp, u32::MAX), None);
+    }
+
     #[test]
     fn time_increments_correctly() {
         // The first call to `time` should return the current round time.
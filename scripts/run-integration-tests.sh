@@ -39,6 +39,7 @@ cd ../..
 if [ $DOWNLOAD_NNS_CANISTERS == "true" ]; then
     ./scripts/download-nns-canister-wasm.sh icp_ledger ledger-canister
     ./scripts/download-nns-canister-wasm.sh icp_index ic-icp-index-canister
+    ./scripts/download-nns-canister-wasm.sh icrc1_ledger ic-icrc1-ledger
 fi
 
 cargo test --package integration-tests $TESTNAME -- --test-threads $TEST_THREADS
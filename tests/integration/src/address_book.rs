@@ -1,16 +1,18 @@
 use crate::interfaces::{default_account, get_icp_balance, send_icp_to_account, ICP, ICP_FEE};
 use crate::setup::{setup_new_env, WALLET_ADMIN_USER};
-use crate::utils::{execute_request, get_user, user_test_id};
+use crate::utils::{execute_request, get_icp_asset, get_user, user_test_id};
 use crate::TestEnv;
 use ic_ledger_types::AccountIdentifier;
-use pocket_ic::update_candid_as;
+use pocket_ic::{query_candid_as, update_candid_as};
 use station_api::{
     AddAccountOperationInput, AddAddressBookEntryOperationInput, AddressChainInput, AllowDTO,
-    ApiErrorDTO, ChangeMetadataDTO, EditAddressBookEntryOperationInput,
-    GetAddressBookEntryInputDTO, GetAddressBookEntryResponseDTO, ListAddressBookEntriesInputDTO,
+    ApiErrorDTO, ChangeMetadataDTO, CreateRequestInput, CreateRequestResponse,
+    EditAddressBookEntryOperationInput, GetAddressBookEntryInputDTO,
+    GetAddressBookEntryResponseDTO, ListAddressBookEntriesInputDTO,
     ListAddressBookEntriesResponseDTO, MetadataDTO, RemoveAddressBookEntryOperationInput,
     RequestOperationDTO, RequestOperationInput, RequestPolicyRuleDTO, RequestStatusDTO,
-    TransferOperationInput,
+    RollingTransferLimitDTO, TransferLimitDTO, TransferLimitScopeDTO, TransferOperationInput,
+    ValidateAddressInput, ValidateAddressResponse,
 };
 
 #[test]
@@ -23,7 +25,7 @@ fn address_book_entry_lifecycle() {
     let add_address_book_entry =
         RequestOperationInput::AddAddressBookEntry(AddAddressBookEntryOperationInput {
             address_owner: "John Doe".to_string(),
-            address: "0x1234".to_string(),
+            address: "7f2e1b156f29b09f8efe9248c4e2d1f754434887a4bae0317e06c7d3442e7922".to_string(),
             blockchain: "icp".to_string(),
             standard: "native".to_string(),
             metadata: vec![MetadataDTO {
@@ -45,7 +47,10 @@ fn address_book_entry_lifecycle() {
         _ => panic!("unexpected request operation"),
     };
     assert_eq!(address_book_entry.address_owner, "John Doe".to_string());
-    assert_eq!(address_book_entry.address, "0x1234".to_string());
+    assert_eq!(
+        address_book_entry.address,
+        "7f2e1b156f29b09f8efe9248c4e2d1f754434887a4bae0317e06c7d3442e7922".to_string()
+    );
     assert_eq!(address_book_entry.blockchain, "icp".to_string());
     assert_eq!(address_book_entry.standard, "native".to_string());
     assert_eq!(
@@ -60,7 +65,7 @@ fn address_book_entry_lifecycle() {
     let add_address_book_entry =
         RequestOperationInput::AddAddressBookEntry(AddAddressBookEntryOperationInput {
             address_owner: "Max Mustermann".to_string(),
-            address: "0x1234".to_string(),
+            address: "7f2e1b156f29b09f8efe9248c4e2d1f754434887a4bae0317e06c7d3442e7922".to_string(),
             blockchain: "icp".to_string(),
             standard: "native".to_string(),
             metadata: vec![MetadataDTO {
@@ -80,7 +85,7 @@ fn address_book_entry_lifecycle() {
     let add_address_book_entry =
         RequestOperationInput::AddAddressBookEntry(AddAddressBookEntryOperationInput {
             address_owner: "Max Mustermann".to_string(),
-            address: "0x5678".to_string(),
+            address: "ecc5e355645f130a8555996c0aea449582c3e45ad3faa19fedf9eab996e6a447".to_string(),
             blockchain: "icp".to_string(),
             standard: "native".to_string(),
             metadata: vec![MetadataDTO {
@@ -105,7 +110,10 @@ fn address_book_entry_lifecycle() {
         next_address_book_entry.address_owner,
         "Max Mustermann".to_string()
     );
-    assert_eq!(next_address_book_entry.address, "0x5678".to_string());
+    assert_eq!(
+        next_address_book_entry.address,
+        "ecc5e355645f130a8555996c0aea449582c3e45ad3faa19fedf9eab996e6a447".to_string()
+    );
     assert_eq!(next_address_book_entry.blockchain, "icp".to_string());
     assert_eq!(next_address_book_entry.standard, "native".to_string());
     assert_eq!(
@@ -259,10 +267,10 @@ fn check_address_book_for_transfer() {
     let admin_user = get_user(&env, WALLET_ADMIN_USER, canister_ids.station);
 
     // create account for admin user
+    let icp_asset = get_icp_asset(&env, WALLET_ADMIN_USER, canister_ids.station);
     let add_account = RequestOperationInput::AddAccount(AddAccountOperationInput {
         name: "admin".to_string(),
-        blockchain: "icp".to_string(),
-        standard: "native".to_string(),
+        asset_id: icp_asset.id,
         read_permission: AllowDTO {
             auth_scope: station_api::AuthScopeDTO::Restricted,
             user_groups: vec![],
@@ -348,3 +356,181 @@ fn check_address_book_for_transfer() {
     let new_balance = get_icp_balance(&env, john_doe_id);
     assert_eq!(new_balance, ICP);
 }
+
+#[test]
+fn check_address_book_transfer_limit() {
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = setup_new_env();
+
+    // create address book entry for John Doe
+    let john_doe_id = user_test_id(1);
+    let john_doe_account = default_account(john_doe_id);
+    let add_address_book_entry =
+        RequestOperationInput::AddAddressBookEntry(AddAddressBookEntryOperationInput {
+            address_owner: "John Doe".to_string(),
+            address: john_doe_account.clone(),
+            blockchain: "icp".to_string(),
+            standard: "native".to_string(),
+            metadata: vec![],
+        });
+    execute_request(
+        &env,
+        WALLET_ADMIN_USER,
+        canister_ids.station,
+        add_address_book_entry,
+    )
+    .unwrap();
+
+    // create an account that auto approves up to 1.5 ICP per day to the address book
+    let admin_user = get_user(&env, WALLET_ADMIN_USER, canister_ids.station);
+    let icp_asset = get_icp_asset(&env, WALLET_ADMIN_USER, canister_ids.station);
+    let add_account = RequestOperationInput::AddAccount(AddAccountOperationInput {
+        name: "admin".to_string(),
+        asset_id: icp_asset.id,
+        read_permission: AllowDTO {
+            auth_scope: station_api::AuthScopeDTO::Restricted,
+            user_groups: vec![],
+            users: vec![admin_user.id.clone()],
+        },
+        configs_permission: AllowDTO {
+            auth_scope: station_api::AuthScopeDTO::Restricted,
+            user_groups: vec![],
+            users: vec![admin_user.id.clone()],
+        },
+        transfer_permission: AllowDTO {
+            auth_scope: station_api::AuthScopeDTO::Restricted,
+            user_groups: vec![],
+            users: vec![admin_user.id.clone()],
+        },
+        configs_request_policy: Some(RequestPolicyRuleDTO::AutoApproved),
+        transfer_request_policy: Some(RequestPolicyRuleDTO::AllowListedWithinRollingLimit(
+            RollingTransferLimitDTO {
+                limit: TransferLimitDTO {
+                    scope: TransferLimitScopeDTO::PerAccount,
+                    max_amount: (ICP + ICP / 2).into(),
+                },
+                window_seconds: 24 * 60 * 60,
+            },
+        )),
+        metadata: vec![],
+    });
+    let add_account_request =
+        execute_request(&env, WALLET_ADMIN_USER, canister_ids.station, add_account).unwrap();
+    let admin_account = match add_account_request.operation {
+        RequestOperationDTO::AddAccount(add_account) => add_account.account.unwrap(),
+        _ => panic!("unexpected request operation"),
+    };
+
+    // send ICP to admin user's station account
+    let admin_account_address = AccountIdentifier::from_hex(&admin_account.address).unwrap();
+    send_icp_to_account(
+        &env,
+        controller,
+        admin_account_address,
+        2 * (ICP + ICP_FEE),
+        0,
+    )
+    .unwrap();
+
+    // the first transfer is within the limit
+    let transfer = RequestOperationInput::Transfer(TransferOperationInput {
+        from_account_id: admin_account.id,
+        to: john_doe_account,
+        amount: ICP.into(),
+        fee: None,
+        metadata: vec![],
+        network: None,
+    });
+    execute_request(
+        &env,
+        WALLET_ADMIN_USER,
+        canister_ids.station,
+        transfer.clone(),
+    )
+    .unwrap();
+    assert_eq!(get_icp_balance(&env, john_doe_id), ICP);
+
+    // the second transfer would exceed the limit of the day
+    let transfer_error =
+        execute_request(&env, WALLET_ADMIN_USER, canister_ids.station, transfer).unwrap_err();
+    match transfer_error {
+        Some(RequestStatusDTO::Rejected { .. }) => (),
+        _ => panic!("unexpected transfer status"),
+    };
+    assert_eq!(get_icp_balance(&env, john_doe_id), ICP);
+}
+
+#[test]
+fn validate_addresses() {
+    let TestEnv {
+        env, canister_ids, ..
+    } = setup_new_env();
+
+    let validate_address = |blockchain: &str, standard: &str, address: &str| {
+        let res: (Result<ValidateAddressResponse, ApiErrorDTO>,) = query_candid_as(
+            &env,
+            canister_ids.station,
+            WALLET_ADMIN_USER,
+            "validate_address",
+            (ValidateAddressInput {
+                blockchain: blockchain.to_string(),
+                standard: standard.to_string(),
+                address: address.to_string(),
+            },),
+        )
+        .unwrap();
+
+        res.0.unwrap()
+    };
+
+    assert!(validate_address("icp", "native", &default_account(WALLET_ADMIN_USER)).valid);
+    assert!(validate_address("icp", "icrc1", &WALLET_ADMIN_USER.to_text()).valid);
+    assert!(
+        validate_address(
+            "eth",
+            "native",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        )
+        .valid
+    );
+    assert!(
+        validate_address(
+            "btc",
+            "native",
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"
+        )
+        .valid
+    );
+
+    let invalid = validate_address("icp", "native", "0x1234");
+    assert!(!invalid.valid);
+    assert!(invalid.reason.is_some());
+
+    // address book entries with invalid addresses are rejected when the request is created
+    let res: (Result<CreateRequestResponse, ApiErrorDTO>,) = update_candid_as(
+        &env,
+        canister_ids.station,
+        WALLET_ADMIN_USER,
+        "create_request",
+        (CreateRequestInput {
+            operation: RequestOperationInput::AddAddressBookEntry(
+                AddAddressBookEntryOperationInput {
+                    address_owner: "John Doe".to_string(),
+                    address: "0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+                    blockchain: "eth".to_string(),
+                    standard: "native".to_string(),
+                    metadata: vec![],
+                },
+            ),
+            title: None,
+            summary: None,
+            execution_plan: None,
+        },),
+    )
+    .unwrap();
+    res.0.unwrap_err();
+}
@@ -0,0 +1,449 @@
+use crate::interfaces::{
+    get_icrc1_balance, get_icrc2_allowance, send_icrc1, transfer_from_icrc2, ICRC1_FEE,
+};
+use crate::setup::{create_icrc1_ledger, setup_new_env, WALLET_ADMIN_USER};
+use crate::utils::{execute_request, execute_request_with_extra_ticks, user_test_id};
+use crate::{CanisterIds, TestEnv};
+use candid::{Nat, Principal};
+use icrc_ledger_types::icrc1::account::Account as Icrc1Account;
+use orbit_essentials::api::ApiResult;
+use pocket_ic::{update_candid_as, PocketIc};
+use station_api::{
+    AccountDTO, AddAccountOperationInput, AddAssetOperationInput, AllowDTO, AllowanceSpenderDTO,
+    AllowanceStatusDTO, ApproveAllowanceOperationInput, AuthScopeDTO, BatchTransferOperationInput,
+    EstimateTransferInput, EstimateTransferResponse, EvaluationStatusDTO,
+    ListAccountAllowancesInput, ListAccountAllowancesResponse, MeResponse, MetadataDTO,
+    RequestOperationDTO, RequestOperationInput, RequestPolicyRuleDTO, RequestStatusDTO,
+    RevokeAllowanceOperationInput, TransferOperationInput,
+};
+use std::str::FromStr;
+
+const TOKEN: u64 = 100_000_000;
+
+struct Icrc1AccountEnv {
+    env: PocketIc,
+    canister_ids: CanisterIds,
+    ledger_canister_id: Principal,
+    account_dto: AccountDTO,
+    account_address: Icrc1Account,
+}
+
+/// Registers the token of the local ICRC-1 ledger as an asset with the given symbol.
+fn add_icrc1_asset(
+    env: &PocketIc,
+    canister_ids: &CanisterIds,
+    ledger_canister_id: Principal,
+    symbol: &str,
+) -> String {
+    let add_asset = RequestOperationInput::AddAsset(AddAssetOperationInput {
+        blockchain: "icp".to_string(),
+        standard: "icrc1".to_string(),
+        symbol: symbol.to_string(),
+        name: "Test token".to_string(),
+        metadata: vec![MetadataDTO {
+            key: "ledger_canister_id".to_string(),
+            value: ledger_canister_id.to_text(),
+        }],
+    });
+    let request = execute_request(env, WALLET_ADMIN_USER, canister_ids.station, add_asset)
+        .expect("the asset creation request must be completed");
+
+    match request.operation {
+        RequestOperationDTO::AddAsset(add_asset) => add_asset.asset.unwrap().id,
+        _ => panic!("request must be AddAsset"),
+    }
+}
+
+/// Creates an account that holds the tokens of the asset and that only the given user can use.
+fn add_icrc1_account(
+    env: &PocketIc,
+    canister_ids: &CanisterIds,
+    asset_id: String,
+    user_id: String,
+) -> Result<AccountDTO, Option<RequestStatusDTO>> {
+    let permission = AllowDTO {
+        auth_scope: AuthScopeDTO::Restricted,
+        user_groups: vec![],
+        users: vec![user_id],
+    };
+    let add_account = RequestOperationInput::AddAccount(AddAccountOperationInput {
+        name: "icrc1".to_string(),
+        asset_id,
+        read_permission: permission.clone(),
+        configs_permission: permission.clone(),
+        transfer_permission: permission,
+        transfer_request_policy: Some(RequestPolicyRuleDTO::AutoApproved),
+        configs_request_policy: Some(RequestPolicyRuleDTO::AutoApproved),
+        metadata: vec![],
+    });
+    let request = execute_request(env, WALLET_ADMIN_USER, canister_ids.station, add_account)?;
+
+    match request.operation {
+        RequestOperationDTO::AddAccount(add_account) => Ok(add_account.account.unwrap()),
+        _ => panic!("request must be AddAccount"),
+    }
+}
+
+/// Deploys a local ICRC-1 ledger and creates a funded station account that holds its tokens.
+fn setup_funded_icrc1_account() -> Icrc1AccountEnv {
+    let TestEnv {
+        mut env,
+        canister_ids,
+        controller,
+        minter,
+        ..
+    } = setup_new_env();
+
+    let ledger_canister_id = create_icrc1_ledger(
+        &mut env,
+        controller,
+        minter,
+        "CKTEST",
+        8,
+        vec![(
+            Icrc1Account {
+                owner: WALLET_ADMIN_USER,
+                subaccount: None,
+            },
+            TOKEN + ICRC1_FEE,
+        )],
+    );
+
+    // register user
+    let res: (ApiResult<MeResponse>,) =
+        update_candid_as(&env, canister_ids.station, WALLET_ADMIN_USER, "me", ()).unwrap();
+    let user_dto = res.0.unwrap().me;
+
+    let asset_id = add_icrc1_asset(&env, &canister_ids, ledger_canister_id, "CKTEST");
+    let account_dto = add_icrc1_account(&env, &canister_ids, asset_id, user_dto.id)
+        .expect("the account creation request must be completed");
+
+    // the symbol and the decimals are read from the ledger metadata
+    assert_eq!(account_dto.symbol, "CKTEST");
+    assert_eq!(account_dto.decimals, 8);
+
+    // the account address is an ICRC-1 textual account owned by the station
+    let account_address = Icrc1Account::from_str(&account_dto.address).unwrap();
+    assert_eq!(account_address.owner, canister_ids.station);
+    assert!(account_address.subaccount.is_some());
+
+    // fund the station account
+    send_icrc1(
+        &env,
+        ledger_canister_id,
+        WALLET_ADMIN_USER,
+        account_address,
+        TOKEN,
+    )
+    .unwrap();
+    assert_eq!(
+        get_icrc1_balance(&env, ledger_canister_id, account_address),
+        Nat::from(TOKEN)
+    );
+
+    Icrc1AccountEnv {
+        env,
+        canister_ids,
+        ledger_canister_id,
+        account_dto,
+        account_address,
+    }
+}
+
+#[test]
+fn fail_icrc1_account_with_symbol_that_differs_from_the_ledger() {
+    let TestEnv {
+        mut env,
+        canister_ids,
+        controller,
+        minter,
+        ..
+    } = setup_new_env();
+    let ledger_canister_id = create_icrc1_ledger(&mut env, controller, minter, "CKTEST", 8, vec![]);
+
+    let res: (ApiResult<MeResponse>,) =
+        update_candid_as(&env, canister_ids.station, WALLET_ADMIN_USER, "me", ()).unwrap();
+    let user_dto = res.0.unwrap().me;
+
+    let asset_id = add_icrc1_asset(&env, &canister_ids, ledger_canister_id, "OTHER");
+    let status = add_icrc1_account(&env, &canister_ids, asset_id, user_dto.id)
+        .expect_err("the account creation request must fail");
+
+    assert!(matches!(status, Some(RequestStatusDTO::Failed { .. })));
+}
+
+#[test]
+fn make_icrc1_transfer_successful() {
+    let Icrc1AccountEnv {
+        env,
+        canister_ids,
+        ledger_canister_id,
+        account_dto,
+        account_address,
+    } = setup_funded_icrc1_account();
+    let beneficiary_id = user_test_id(1);
+
+    // make transfer request to beneficiary
+    let beneficiary_account = Icrc1Account {
+        owner: beneficiary_id,
+        subaccount: None,
+    };
+    let transfer = RequestOperationInput::Transfer(TransferOperationInput {
+        from_account_id: account_dto.id,
+        to: beneficiary_account.to_string(),
+        amount: (TOKEN - ICRC1_FEE).into(),
+        fee: None,
+        metadata: vec![],
+        network: None,
+    });
+    execute_request_with_extra_ticks(&env, WALLET_ADMIN_USER, canister_ids.station, transfer, 2)
+        .expect("the transfer request must be completed");
+
+    // check balances after completed transfer
+    assert_eq!(
+        get_icrc1_balance(&env, ledger_canister_id, beneficiary_account),
+        Nat::from(TOKEN - ICRC1_FEE)
+    );
+    assert_eq!(
+        get_icrc1_balance(&env, ledger_canister_id, account_address),
+        Nat::from(0u64)
+    );
+}
+
+#[test]
+fn estimate_icrc1_transfer() {
+    let Icrc1AccountEnv {
+        env,
+        canister_ids,
+        ledger_canister_id,
+        account_dto,
+        ..
+    } = setup_funded_icrc1_account();
+    let beneficiary_account = Icrc1Account {
+        owner: user_test_id(1),
+        subaccount: None,
+    };
+    let estimate_transfer = |amount: u64| {
+        let res: (ApiResult<EstimateTransferResponse>,) = update_candid_as(
+            &env,
+            canister_ids.station,
+            WALLET_ADMIN_USER,
+            "estimate_transfer",
+            (EstimateTransferInput {
+                transfer: TransferOperationInput {
+                    from_account_id: account_dto.id.clone(),
+                    to: beneficiary_account.to_string(),
+                    amount: amount.into(),
+                    fee: None,
+                    metadata: vec![],
+                    network: None,
+                },
+            },),
+        )
+        .unwrap();
+
+        res.0
+    };
+
+    // the fee is read from the ledger and the transfer policy of the account auto approves it
+    let estimate = estimate_transfer(TOKEN - ICRC1_FEE).unwrap();
+    assert_eq!(estimate.fee, Nat::from(ICRC1_FEE));
+    assert_eq!(estimate.balance, Nat::from(TOKEN));
+    assert!(matches!(
+        estimate.evaluation_result.status,
+        EvaluationStatusDTO::Approved
+    ));
+
+    // the balance does not cover the amount and the fee
+    let error = estimate_transfer(TOKEN).unwrap_err();
+    assert_eq!(error.code, "INSUFFICIENT_BALANCE");
+
+    // the estimations did not transfer any tokens
+    assert_eq!(
+        get_icrc1_balance(&env, ledger_canister_id, beneficiary_account),
+        Nat::from(0u64)
+    );
+}
+
+#[test]
+fn make_icrc1_batch_transfer_successful() {
+    let Icrc1AccountEnv {
+        env,
+        canister_ids,
+        ledger_canister_id,
+        account_dto,
+        account_address,
+    } = setup_funded_icrc1_account();
+    let beneficiaries = [user_test_id(1), user_test_id(2)].map(|owner| Icrc1Account {
+        owner,
+        subaccount: None,
+    });
+    let amount = TOKEN / 4;
+
+    // make a single batch transfer request that pays all the beneficiaries
+    let batch_transfer = RequestOperationInput::BatchTransfer(BatchTransferOperationInput {
+        transfers: beneficiaries
+            .iter()
+            .map(|beneficiary| TransferOperationInput {
+                from_account_id: account_dto.id.clone(),
+                to: beneficiary.to_string(),
+                amount: amount.into(),
+                fee: None,
+                metadata: vec![],
+                network: None,
+            })
+            .collect(),
+    });
+    let request = execute_request_with_extra_ticks(
+        &env,
+        WALLET_ADMIN_USER,
+        canister_ids.station,
+        batch_transfer,
+        4,
+    )
+    .expect("the batch transfer request must be completed");
+
+    let batch_transfer = match request.operation {
+        RequestOperationDTO::BatchTransfer(batch_transfer) => batch_transfer,
+        _ => panic!("request must be BatchTransfer"),
+    };
+    assert_eq!(batch_transfer.summary.total, 2);
+    assert_eq!(batch_transfer.summary.completed, 2);
+    assert_eq!(batch_transfer.summary.csv.lines().count(), 3);
+    assert!(batch_transfer
+        .rows
+        .iter()
+        .all(|row| row.transfer_id.is_some()));
+
+    // check balances after completed transfers
+    for beneficiary in beneficiaries {
+        assert_eq!(
+            get_icrc1_balance(&env, ledger_canister_id, beneficiary),
+            Nat::from(amount)
+        );
+    }
+    assert_eq!(
+        get_icrc1_balance(&env, ledger_canister_id, account_address),
+        Nat::from(TOKEN - 2 * (amount + ICRC1_FEE))
+    );
+}
+
+#[test]
+fn approve_and_revoke_icrc2_allowance() {
+    let Icrc1AccountEnv {
+        env,
+        canister_ids,
+        ledger_canister_id,
+        account_dto,
+        account_address,
+    } = setup_funded_icrc1_account();
+    let spender = Icrc1Account {
+        owner: user_test_id(1),
+        subaccount: Some([7; 32]),
+    };
+
+    // approve an allowance for the spender
+    let approve = RequestOperationInput::ApproveAllowance(ApproveAllowanceOperationInput {
+        from_account_id: account_dto.id.clone(),
+        spender: AllowanceSpenderDTO {
+            owner: spender.owner,
+            subaccount: spender.subaccount.map(|subaccount| subaccount.to_vec()),
+        },
+        amount: Nat::from(TOKEN / 2),
+        expected_allowance: None,
+        expires_at: None,
+        fee: None,
+    });
+    let request =
+        execute_request_with_extra_ticks(&env, WALLET_ADMIN_USER, canister_ids.station, approve, 2)
+            .expect("the allowance approval request must be completed");
+    let allowance = match request.operation {
+        RequestOperationDTO::ApproveAllowance(approve) => approve.allowance.unwrap(),
+        _ => panic!("request must be ApproveAllowance"),
+    };
+
+    assert_eq!(allowance.amount, Nat::from(TOKEN / 2));
+    assert_eq!(
+        get_icrc2_allowance(&env, ledger_canister_id, account_address, spender).allowance,
+        Nat::from(TOKEN / 2)
+    );
+
+    // the allowance is listed for the account
+    let res: (ApiResult<ListAccountAllowancesResponse>,) = update_candid_as(
+        &env,
+        canister_ids.station,
+        WALLET_ADMIN_USER,
+        "list_account_allowances",
+        (ListAccountAllowancesInput {
+            account_id: account_dto.id.clone(),
+            status: Some(AllowanceStatusDTO::Active),
+            paginate: None,
+        },),
+    )
+    .unwrap();
+    let allowances = res.0.unwrap().allowances;
+    assert_eq!(allowances.len(), 1);
+    assert_eq!(allowances[0].id, allowance.id);
+
+    // the spender pulls tokens from the station account with the allowance
+    let destination = Icrc1Account {
+        owner: spender.owner,
+        subaccount: None,
+    };
+    transfer_from_icrc2(
+        &env,
+        ledger_canister_id,
+        spender,
+        account_address,
+        destination,
+        TOKEN / 4,
+    )
+    .expect("the spender must be able to use the allowance");
+
+    assert_eq!(
+        get_icrc1_balance(&env, ledger_canister_id, destination),
+        Nat::from(TOKEN / 4)
+    );
+    assert_eq!(
+        get_icrc2_allowance(&env, ledger_canister_id, account_address, spender).allowance,
+        Nat::from(TOKEN / 2 - TOKEN / 4 - ICRC1_FEE)
+    );
+
+    // revoke the allowance
+    let revoke = RequestOperationInput::RevokeAllowance(RevokeAllowanceOperationInput {
+        from_account_id: account_dto.id.clone(),
+        allowance_id: allowance.id,
+    });
+    execute_request_with_extra_ticks(&env, WALLET_ADMIN_USER, canister_ids.station, revoke, 2)
+        .expect("the allowance revocation request must be completed");
+
+    assert_eq!(
+        get_icrc2_allowance(&env, ledger_canister_id, account_address, spender).allowance,
+        Nat::from(0u64)
+    );
+
+    // the spender can't pull tokens once the allowance is revoked
+    assert!(transfer_from_icrc2(
+        &env,
+        ledger_canister_id,
+        spender,
+        account_address,
+        destination,
+        1,
+    )
+    .is_err());
+
+    let res: (ApiResult<ListAccountAllowancesResponse>,) = update_candid_as(
+        &env,
+        canister_ids.station,
+        WALLET_ADMIN_USER,
+        "list_account_allowances",
+        (ListAccountAllowancesInput {
+            account_id: account_dto.id,
+            status: Some(AllowanceStatusDTO::Revoked),
+            paginate: None,
+        },),
+    )
+    .unwrap();
+    assert_eq!(res.0.unwrap().allowances.len(), 1);
+}
@@ -1,8 +1,16 @@
-use candid::{CandidType, Principal};
+use candid::{CandidType, Nat, Principal};
 use ic_ledger_types::{
     AccountBalanceArgs, AccountIdentifier, Memo, Tokens, TransferArgs, TransferError,
     DEFAULT_SUBACCOUNT,
 };
+use icrc_ledger_types::icrc1::{
+    account::Account as Icrc1Account,
+    transfer::{TransferArg as Icrc1TransferArg, TransferError as Icrc1TransferError},
+};
+use icrc_ledger_types::icrc2::{
+    allowance::{Allowance as Icrc2Allowance, AllowanceArgs},
+    transfer_from::{TransferFromArgs, TransferFromError},
+};
 use pocket_ic::{update_candid_as, PocketIc};
 use std::collections::{HashMap, HashSet};
 
@@ -26,6 +34,41 @@ pub struct NnsIndexCanisterInitPayload {
     pub ledger_id: Principal,
 }
 
+#[derive(CandidType)]
+pub enum Icrc1LedgerCanisterPayload {
+    Init(Icrc1LedgerCanisterInitPayload),
+}
+
+#[derive(CandidType)]
+pub struct Icrc1LedgerCanisterInitPayload {
+    pub minting_account: Icrc1Account,
+    pub transfer_fee: Nat,
+    pub decimals: Option<u8>,
+    pub token_symbol: String,
+    pub token_name: String,
+    pub metadata: Vec<(String, Icrc1MetadataValue)>,
+    pub initial_balances: Vec<(Icrc1Account, Nat)>,
+    pub archive_options: Icrc1LedgerArchiveOptions,
+    pub feature_flags: Option<Icrc1LedgerFeatureFlags>,
+}
+
+#[derive(CandidType)]
+pub struct Icrc1LedgerFeatureFlags {
+    pub icrc2: bool,
+}
+
+#[derive(CandidType)]
+pub enum Icrc1MetadataValue {
+    Text(String),
+}
+
+#[derive(CandidType)]
+pub struct Icrc1LedgerArchiveOptions {
+    pub num_blocks_to_archive: u64,
+    pub trigger_threshold: u64,
+    pub controller_id: Principal,
+}
+
 pub fn get_icp_balance(env: &PocketIc, user_id: Principal) -> u64 {
     let ledger_canister_id = Principal::from_text("ryjl3-tyaaa-aaaaa-aaaba-cai").unwrap();
     let account = AccountIdentifier::new(&user_id, &DEFAULT_SUBACCOUNT);
@@ -85,3 +128,93 @@ pub fn send_icp(
     let to = AccountIdentifier::new(&beneficiary_id, &DEFAULT_SUBACCOUNT);
     send_icp_to_account(env, sender_id, to, e8s, memo)
 }
+
+pub const ICRC1_FEE: u64 = 10_000;
+
+pub fn get_icrc1_balance(
+    env: &PocketIc,
+    ledger_canister_id: Principal,
+    account: Icrc1Account,
+) -> Nat {
+    let res: (Nat,) = update_candid_as(
+        env,
+        ledger_canister_id,
+        account.owner,
+        "icrc1_balance_of",
+        (account,),
+    )
+    .unwrap();
+    res.0
+}
+
+pub fn send_icrc1(
+    env: &PocketIc,
+    ledger_canister_id: Principal,
+    sender_id: Principal,
+    to: Icrc1Account,
+    amount: u64,
+) -> Result<Nat, Icrc1TransferError> {
+    let transfer_args = Icrc1TransferArg {
+        from_subaccount: None,
+        to,
+        fee: Some(Nat::from(ICRC1_FEE)),
+        created_at_time: None,
+        memo: None,
+        amount: Nat::from(amount),
+    };
+    let res: (Result<Nat, Icrc1TransferError>,) = update_candid_as(
+        env,
+        ledger_canister_id,
+        sender_id,
+        "icrc1_transfer",
+        (transfer_args,),
+    )
+    .unwrap();
+    res.0
+}
+
+pub fn get_icrc2_allowance(
+    env: &PocketIc,
+    ledger_canister_id: Principal,
+    account: Icrc1Account,
+    spender: Icrc1Account,
+) -> Icrc2Allowance {
+    let res: (Icrc2Allowance,) = update_candid_as(
+        env,
+        ledger_canister_id,
+        account.owner,
+        "icrc2_allowance",
+        (AllowanceArgs { account, spender },),
+    )
+    .unwrap();
+    res.0
+}
+
+/// Transfers the tokens of the account to the given destination using the allowance of the spender.
+pub fn transfer_from_icrc2(
+    env: &PocketIc,
+    ledger_canister_id: Principal,
+    spender: Icrc1Account,
+    from: Icrc1Account,
+    to: Icrc1Account,
+    amount: u64,
+) -> Result<Nat, TransferFromError> {
+    let transfer_from_args = TransferFromArgs {
+        spender_subaccount: spender.subaccount,
+        from,
+        to,
+        amount: Nat::from(amount),
+        fee: Some(Nat::from(ICRC1_FEE)),
+        memo: None,
+        created_at_time: None,
+    };
+    let res: (Result<Nat, TransferFromError>,) = update_candid_as(
+        env,
+        ledger_canister_id,
+        spender.owner,
+        "icrc2_transfer_from",
+        (transfer_from_args,),
+    )
+    .unwrap();
+    res.0
+}
@@ -7,6 +7,7 @@ mod address_book;
 mod control_panel_tests;
 mod cycles_monitor_tests;
 mod external_canister_tests;
+mod icrc1_tests;
 mod interfaces;
 mod register_tests;
 mod setup;
@@ -1,11 +1,14 @@
 use crate::interfaces::{
-    NnsIndexCanisterInitPayload, NnsLedgerCanisterInitPayload, NnsLedgerCanisterPayload,
+    Icrc1LedgerArchiveOptions, Icrc1LedgerCanisterInitPayload, Icrc1LedgerCanisterPayload,
+    Icrc1LedgerFeatureFlags, Icrc1MetadataValue, NnsIndexCanisterInitPayload,
+    NnsLedgerCanisterInitPayload, NnsLedgerCanisterPayload, ICRC1_FEE,
 };
 use crate::utils::{controller_test_id, minter_test_id, set_controllers};
 use crate::{CanisterIds, TestEnv};
-use candid::{Encode, Principal};
+use candid::{Encode, Nat, Principal};
 use control_panel_api::UploadCanisterModulesInput;
 use ic_ledger_types::{AccountIdentifier, Tokens, DEFAULT_SUBACCOUNT};
+use icrc_ledger_types::icrc1::account::Account as Icrc1Account;
 use pocket_ic::{PocketIc, PocketIcBuilder};
 use station_api::{AdminInitInput, SystemInit as SystemInitArg, SystemInstall as SystemInstallArg};
 use std::collections::{HashMap, HashSet};
@@ -82,6 +85,52 @@ pub fn create_canister(env: &mut PocketIc, controller: Principal) -> Principal {
     canister_id
 }
 
+/// Deploys a local ICRC-1 ledger with the given initial balances, the minter of the test environment
+/// is used as the minting account of the ledger.
+pub fn create_icrc1_ledger(
+    env: &mut PocketIc,
+    controller: Principal,
+    minter: Principal,
+    token_symbol: &str,
+    decimals: u8,
+    initial_balances: Vec<(Icrc1Account, u64)>,
+) -> Principal {
+    let ledger_canister_id = create_canister(env, controller);
+    let icrc1_ledger_canister_wasm = get_canister_wasm("icrc1_ledger").to_vec();
+    let icrc1_ledger_init_args = Icrc1LedgerCanisterPayload::Init(Icrc1LedgerCanisterInitPayload {
+        minting_account: Icrc1Account {
+            owner: minter,
+            subaccount: None,
+        },
+        transfer_fee: Nat::from(ICRC1_FEE),
+        decimals: Some(decimals),
+        token_symbol: token_symbol.to_string(),
+        token_name: format!("Test {}", token_symbol),
+        metadata: vec![(
+            "icrc1:logo".to_string(),
+            Icrc1MetadataValue::Text("".to_string()),
+        )],
+        initial_balances: initial_balances
+            .into_iter()
+            .map(|(account, amount)| (account, Nat::from(amount)))
+            .collect(),
+        archive_options: Icrc1LedgerArchiveOptions {
+            num_blocks_to_archive: 1_000,
+            trigger_threshold: 2_000,
+            controller_id: controller,
+        },
+        feature_flags: Some(Icrc1LedgerFeatureFlags { icrc2: true }),
+    });
+    env.install_canister(
+        ledger_canister_id,
+        icrc1_ledger_canister_wasm,
+        Encode!(&icrc1_ledger_init_args).unwrap(),
+        Some(controller),
+    );
+
+    ledger_canister_id
+}
+
 fn install_canisters(
     env: &mut PocketIc,
     config: SetupConfig,
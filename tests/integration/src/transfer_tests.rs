@@ -2,14 +2,15 @@ use crate::interfaces::{
     default_account, get_icp_balance, send_icp, send_icp_to_account, ICP, ICP_FEE,
 };
 use crate::setup::{setup_new_env, WALLET_ADMIN_USER};
-use crate::utils::user_test_id;
+use crate::utils::{get_icp_asset, user_test_id};
 use crate::TestEnv;
 use ic_ledger_types::AccountIdentifier;
 use orbit_essentials::api::ApiResult;
 use pocket_ic::{query_candid_as, update_candid_as};
 use station_api::{
-    AddAccountOperationInput, AllowDTO, ApiErrorDTO, CreateRequestInput, CreateRequestResponse,
-    GetRequestInput, GetRequestResponse, GetTransfersInput, GetTransfersResponse,
+    AccountActivityDTO, AddAccountOperationInput, AllowDTO, ApiErrorDTO, CreateRequestInput,
+    CreateRequestResponse, GetRequestInput, GetRequestResponse, GetTransfersInput,
+    GetTransfersResponse, ListAccountActivityInput, ListAccountActivityResponse,
     ListAccountTransfersInput, ListAccountTransfersResponse, MeResponse, QuorumPercentageDTO,
     RequestExecutionScheduleDTO, RequestOperationDTO, RequestOperationInput, RequestPolicyRuleDTO,
     RequestStatusDTO, TransferOperationInput, UserSpecifierDTO,
@@ -33,10 +34,10 @@ fn make_transfer_successful() {
     let user_dto = res.0.unwrap().me;
 
     // create account
+    let icp_asset = get_icp_asset(&env, WALLET_ADMIN_USER, canister_ids.station);
     let create_account_args = AddAccountOperationInput {
         name: "test".to_string(),
-        blockchain: "icp".to_string(),
-        standard: "native".to_string(),
+        asset_id: icp_asset.id,
         read_permission: AllowDTO {
             auth_scope: station_api::AuthScopeDTO::Restricted,
             user_groups: vec![],
@@ -245,7 +246,7 @@ fn make_transfer_successful() {
         WALLET_ADMIN_USER,
         "list_account_transfers",
         (ListAccountTransfersInput {
-            account_id: account_dto.id,
+            account_id: account_dto.id.clone(),
             from_dt: None,
             to_dt: None,
             status: None,
@@ -262,4 +263,34 @@ fn make_transfer_successful() {
     });
 
     assert!(all_have_transaction_hash);
+
+    // wait for the deposit to be indexed (the indexing job runs every 5 minutes)
+    env.advance_time(Duration::from_secs(5 * 60));
+    for _ in 0..5 {
+        env.tick();
+    }
+
+    // the activity of the account has the deposit and the transfer
+    let res: (Result<ListAccountActivityResponse, ApiErrorDTO>,) = query_candid_as(
+        &env,
+        canister_ids.station,
+        WALLET_ADMIN_USER,
+        "list_account_activity",
+        (ListAccountActivityInput {
+            account_id: account_dto.id,
+            from_dt: None,
+            to_dt: None,
+        },),
+    )
+    .unwrap();
+    let activity = res.0.unwrap().activity;
+
+    assert_eq!(activity.len(), 2);
+    assert!(activity.iter().any(|entry| matches!(
+        entry,
+        AccountActivityDTO::Incoming(deposit) if deposit.amount == ICP + ICP_FEE
+    )));
+    assert!(activity
+        .iter()
+        .any(|entry| matches!(entry, AccountActivityDTO::Outgoing(_))));
 }
@@ -5,11 +5,11 @@ use orbit_essentials::api::ApiResult;
 use orbit_essentials::cdk::api::management_canister::main::CanisterId;
 use pocket_ic::{update_candid_as, CallError, PocketIc, UserError, WasmResult};
 use station_api::{
-    AddUserOperationInput, ApiErrorDTO, CreateRequestInput, CreateRequestResponse, GetRequestInput,
-    GetRequestResponse, HealthStatus, MeResponse, RequestApprovalStatusDTO, RequestDTO,
-    RequestExecutionScheduleDTO, RequestOperationDTO, RequestOperationInput, RequestStatusDTO,
-    SubmitRequestApprovalInput, SubmitRequestApprovalResponse, SystemInfoDTO, SystemInfoResponse,
-    UserDTO, UserStatusDTO,
+    AddUserOperationInput, ApiErrorDTO, AssetDTO, CreateRequestInput, CreateRequestResponse,
+    GetRequestInput, GetRequestResponse, HealthStatus, ListAssetsInput, ListAssetsResponse,
+    MeResponse, RequestApprovalStatusDTO, RequestDTO, RequestExecutionScheduleDTO,
+    RequestOperationDTO, RequestOperationInput, RequestStatusDTO, SubmitRequestApprovalInput,
+    SubmitRequestApprovalResponse, SystemInfoDTO, SystemInfoResponse, UserDTO, UserStatusDTO,
 };
 use std::time::Duration;
 
@@ -268,6 +268,7 @@ pub fn submit_request_approval(
         request_id: request.id,
         decision,
         reason: None,
+        on_behalf_of: None,
     };
     let res: (Result<SubmitRequestApprovalResponse, ApiErrorDTO>,) = update_candid_as(
         env,
@@ -322,6 +323,28 @@ pub fn get_user(env: &PocketIc, user_id: Principal, station_canister_id: Princip
     res.0.unwrap().me
 }
 
+/// Returns the native ICP asset that is registered by default when the station is installed.
+pub fn get_icp_asset(
+    env: &PocketIc,
+    user_id: Principal,
+    station_canister_id: Principal,
+) -> AssetDTO {
+    let res: (ApiResult<ListAssetsResponse>,) = update_candid_as(
+        env,
+        station_canister_id,
+        user_id,
+        "list_assets",
+        (ListAssetsInput { paginate: None },),
+    )
+    .unwrap();
+    res.0
+        .unwrap()
+        .assets
+        .into_iter()
+        .find(|asset| asset.blockchain == "icp" && asset.standard == "native")
+        .expect("the ICP asset must be registered")
+}
+
 pub fn canister_status(
     env: &PocketIc,
     sender: Option<Principal>,